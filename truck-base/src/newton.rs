@@ -55,9 +55,36 @@ impl_jacobian!(Matrix4, Vector4);
 /// assert_near2!(sqrt2, f64::sqrt(2.0));
 /// ```
 pub fn solve<V, M>(
+    function: impl Fn(V) -> CalcOutput<V, M>,
+    hint: V,
+    trials: usize,
+) -> Result<V, NewtonLog<V>>
+where
+    V: Sub<Output = V> + Copy + Tolerance,
+    M: Jacobian<V>,
+{
+    solve_with_tolerance(function, hint, trials, TOLERANCE2)
+}
+
+/// Solve equation by Newton's method, which converges if the step is less than `tolerance`.
+///
+/// The step is compared componentwise, and [`solve`] is the case of `tolerance == TOLERANCE2`.
+/// # Examples
+/// ```
+/// use truck_base::newton::*;
+///
+/// let function = |x: f64| CalcOutput {
+///     value: x * x - 2.0,
+///     derivation: 2.0 * x,
+/// };
+/// let sqrt2 = solve_with_tolerance(function, 1.0, 10, 1.0e-3).unwrap();
+/// assert!(f64::abs(sqrt2 - f64::sqrt(2.0)) < 1.0e-3);
+/// ```
+pub fn solve_with_tolerance<V, M>(
     function: impl Fn(V) -> CalcOutput<V, M>,
     mut hint: V,
     trials: usize,
+    tolerance: f64,
 ) -> Result<V, NewtonLog<V>>
 where
     V: Sub<Output = V> + Copy + Tolerance,
@@ -72,7 +99,7 @@ where
             return Err(log);
         };
         let next = hint - inv * value;
-        if next.near2(&hint) {
+        if next.abs_diff_eq(&hint, tolerance) {
             return Ok(hint);
         }
         hint = next;
@@ -1,5 +1,5 @@
 use crate::cgmath64::*;
-use cgmath::AbsDiffEq;
+use cgmath::{AbsDiffEq, RelativeEq};
 use std::fmt::Debug;
 
 /// general tolerance
@@ -19,6 +19,33 @@ pub trait Tolerance: AbsDiffEq<Epsilon = f64> + Debug {
 
 impl<T: AbsDiffEq<Epsilon = f64> + Debug> Tolerance for T {}
 
+/// Tolerance scaled by the magnitude of the values, for the models far from the origin.
+///
+/// [`Tolerance::near`] compares the difference with the absolute `TOLERANCE`, which is tighter
+/// than the precision of `f64` for the coordinates larger than about `1.0e10`, and too tight for
+/// the computed coordinates of large models in general. Here, the values are also near if the
+/// difference is less than `TOLERANCE` times the magnitude of the larger one, componentwise
+/// for vectors, points and matrices.
+/// # Examples
+/// ```
+/// use truck_base::tolerance::*;
+/// assert!(!1.0e6.near(&(1.0e6 + 1.0e-3)));
+/// assert!(1.0e6.near_rel(&(1.0e6 + 1.0e-3)));
+/// assert!(!1.0e6.near_rel(&(1.0e6 + 10.0)));
+/// // the absolute tolerance still works around the origin
+/// assert!(0.0.near_rel(&1.0e-7));
+/// assert!(!1.0.near_rel(&1.1));
+/// ```
+pub trait RelativeTolerance: RelativeEq<Epsilon = f64> + Tolerance {
+    /// The "distance" is less than `TOLERANCE`, or `TOLERANCE` times the magnitude.
+    fn near_rel(&self, other: &Self) -> bool { self.relative_eq(other, TOLERANCE, TOLERANCE) }
+
+    /// The "distance" is less than `TOLERANCE2`, or `TOLERANCE2` times the magnitude.
+    fn near2_rel(&self, other: &Self) -> bool { self.relative_eq(other, TOLERANCE2, TOLERANCE2) }
+}
+
+impl<T: RelativeEq<Epsilon = f64> + Debug> RelativeTolerance for T {}
+
 /// Asserts that `left.near(&right)` (using `Tolerance`).
 #[macro_export]
 macro_rules! assert_near {
@@ -127,3 +154,14 @@ pub trait Origin: Tolerance + Zero {
 }
 
 impl<T: Tolerance + Zero> Origin for T {}
+
+#[test]
+fn near_rel_large_coordinates() {
+    let p = Point3::new(1.0e6, -2.0e6, 3.0e5);
+    let q = p + Vector3::new(1.0e-3, -1.0e-3, 1.0e-4);
+    assert!(!p.near(&q));
+    assert!(p.near_rel(&q));
+    assert!(!p.near_rel(&(p + Vector3::new(0.0, 0.0, 1.0))));
+    assert!(!1.0e6.near2_rel(&(1.0e6 + 1.0e-3)));
+    assert!(1.0e6.near2_rel(&(1.0e6 + 1.0e-7)));
+}
@@ -470,6 +470,12 @@ pub fn derive_parameter_division_2d(input: TokenStream) -> TokenStream {
             let methods = methods! {
                 variants, trait_name,
                 fn parameter_division(&self, range: ((f64, f64), (f64, f64)), tol: f64) -> (Vec<f64>, Vec<f64>),
+                fn parameter_division_with_options(
+                    &self,
+                    range: ((f64, f64), (f64, f64)),
+                    tol: f64,
+                    options: truck_geotrait::algo::surface::DivisionOptions,
+                ) -> (Vec<f64>, Vec<f64>),
             };
             quote! {
                 #[automatically_derived]
@@ -496,6 +502,14 @@ pub fn derive_parameter_division_2d(input: TokenStream) -> TokenStream {
                     fn parameter_division(&self, range: ((f64, f64), (f64, f64)), tol: f64) -> (Vec<f64>, Vec<f64>) {
                         self.0.parameter_division(range, tol)
                     }
+                    fn parameter_division_with_options(
+                        &self,
+                        range: ((f64, f64), (f64, f64)),
+                        tol: f64,
+                        options: truck_geotrait::algo::surface::DivisionOptions,
+                    ) -> (Vec<f64>, Vec<f64>) {
+                        self.0.parameter_division_with_options(range, tol, options)
+                    }
                 }
             }
         }
@@ -522,6 +536,7 @@ pub fn derive_parametric_curve(input: TokenStream) -> TokenStream {
                 variants,
                 trait_name,
                 fn subs(&self, t: f64) -> Self::Point,
+                fn subs_many(&self, params: &[f64]) -> Vec<Self::Point>,
                 fn der(&self, t: f64) -> Self::Vector,
                 fn der2(&self, t: f64) -> Self::Vector,
                 fn der_n(&self, n: usize, t: f64) -> Self::Vector,
@@ -556,6 +571,7 @@ pub fn derive_parametric_curve(input: TokenStream) -> TokenStream {
                     type Point = <#field_type as #trait_name>::Point;
                     type Vector = <#field_type as #trait_name>::Vector;
                     fn subs(&self, t: f64) -> Self::Point { self.0.subs(t) }
+                    fn subs_many(&self, params: &[f64]) -> Vec<Self::Point> { self.0.subs_many(params) }
                     fn der(&self, t: f64) -> Self::Vector { self.0.der(t) }
                     fn der2(&self, t: f64) -> Self::Vector { self.0.der2(t) }
                     fn der_n(&self, n: usize, t: f64) -> Self::Vector { self.0.der_n(n, t) }
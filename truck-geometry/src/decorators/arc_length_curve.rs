@@ -0,0 +1,179 @@
+use super::*;
+use std::iter::{once, successors};
+
+/// the nodes and weights of the 5-point Gauss-Legendre quadrature on `[-1, 1]`
+const GAUSS_LEGENDRE: [(f64, f64); 5] = [
+    (0.0, 0.568_888_888_888_888_9),
+    (-0.538_469_310_105_683_1, 0.478_628_670_499_366_5),
+    (0.538_469_310_105_683_1, 0.478_628_670_499_366_5),
+    (-0.906_179_845_938_664, 0.236_926_885_056_189_1),
+    (0.906_179_845_938_664, 0.236_926_885_056_189_1),
+];
+
+/// the upper bound of the number of doubling the table
+const MAX_DOUBLING: usize = 16;
+
+impl<C> ArcLengthCurve<C>
+where
+    C: BoundedCurve,
+    C::Vector: InnerSpace<Scalar = f64>,
+{
+    /// Creates the arc length reparameterization of `curve`.
+    ///
+    /// The table of the arc length is refined until the total length converges within `tol`.
+    ///
+    /// # Panics
+    /// `tol` must be no less than `TOLERANCE`.
+    pub fn new(curve: C, tol: f64) -> Self {
+        nonpositive_tolerance!(tol);
+        let (t0, t1) = curve.range_tuple();
+        let table = |division: usize| {
+            let params = (0..=division)
+                .map(|i| t0 + (t1 - t0) * i as f64 / division as f64)
+                .collect::<Vec<_>>();
+            let lengths = once(0.0)
+                .chain(params.windows(2).scan(0.0, |len, p| {
+                    *len += gauss_length(&curve, p[0], p[1]);
+                    Some(*len)
+                }))
+                .collect::<Vec<_>>();
+            (params, lengths)
+        };
+        // the tables are doubled until the total length converges
+        let doubled =
+            |(division, (_, lengths), converged): &(usize, (Vec<f64>, Vec<f64>), bool)| {
+                (!converged).then(|| {
+                    let (params, new_lengths) = table(2 * division);
+                    let converged = f64::abs(new_lengths[2 * division] - lengths[*division]) < tol;
+                    (2 * division, (params, new_lengths), converged)
+                })
+            };
+        // SAFETY: `successors` yields at least the first table.
+        let (_, (params, lengths), _) = successors(Some((8, table(8), false)), doubled)
+            .take(MAX_DOUBLING + 1)
+            .last()
+            .unwrap();
+        Self {
+            curve,
+            params,
+            lengths,
+        }
+    }
+
+    /// Returns the parameter of the original curve at the arc length `s`.
+    pub fn parameter_of(&self, s: f64) -> f64 {
+        let s = f64::clamp(s, 0.0, self.length());
+        let i = self
+            .lengths
+            .partition_point(|l| *l < s)
+            .clamp(1, self.lengths.len() - 1)
+            - 1;
+        let (t0, t1) = (self.params[i], self.params[i + 1]);
+        let (s0, s1) = (self.lengths[i], self.lengths[i + 1]);
+        match s1 - s0 > 0.0 {
+            false => t0,
+            true => {
+                // the Newton method safeguarded by the bisection of `[lo, hi]`, which stops when
+                // the residual of the arc length is small
+                let newton = |&(t, lo, hi): &(f64, f64, f64)| {
+                    let f = s0 + gauss_length(&self.curve, t0, t) - s;
+                    let (lo, hi) = match f > 0.0 {
+                        true => (lo, t),
+                        false => (t, hi),
+                    };
+                    let speed = self.curve.der(t).magnitude();
+                    let next = t - f / speed;
+                    let t = match speed > 0.0 && lo < next && next < hi {
+                        true => next,
+                        false => (lo + hi) / 2.0,
+                    };
+                    (f.abs() >= TOLERANCE2).then_some((t, lo, hi))
+                };
+                let t = t0 + (t1 - t0) * (s - s0) / (s1 - s0);
+                // SAFETY: `successors` yields at least the first parameter.
+                let (t, _, _) = successors(Some((t, t0, t1)), newton)
+                    .take(33)
+                    .last()
+                    .unwrap();
+                t
+            }
+        }
+    }
+}
+
+impl<C> ArcLengthCurve<C> {
+    /// Returns the reference of the original curve.
+    #[inline(always)]
+    pub const fn curve(&self) -> &C { &self.curve }
+    /// Returns the total length of the curve.
+    #[inline(always)]
+    pub fn length(&self) -> f64 { self.lengths[self.lengths.len() - 1] }
+}
+
+fn gauss_length<C>(curve: &C, t0: f64, t1: f64) -> f64
+where
+    C: ParametricCurve,
+    C::Vector: InnerSpace<Scalar = f64>, {
+    let (mid, half) = ((t0 + t1) / 2.0, (t1 - t0) / 2.0);
+    GAUSS_LEGENDRE.iter().fold(0.0, |sum, (x, w)| {
+        sum + w * curve.der(mid + half * x).magnitude() * half
+    })
+}
+
+impl<C> ParametricCurve for ArcLengthCurve<C>
+where
+    C: BoundedCurve,
+    C::Vector: InnerSpace<Scalar = f64>,
+{
+    type Point = C::Point;
+    type Vector = C::Vector;
+    #[inline(always)]
+    fn subs(&self, s: f64) -> Self::Point { self.curve.subs(self.parameter_of(s)) }
+    #[inline(always)]
+    fn der(&self, s: f64) -> Self::Vector {
+        let der = self.curve.der(self.parameter_of(s));
+        match der.magnitude().so_small() {
+            true => C::Vector::zero(),
+            false => der.normalize(),
+        }
+    }
+    #[inline(always)]
+    fn der2(&self, s: f64) -> Self::Vector { self.der_n(2, s) }
+    fn der_n(&self, n: usize, s: f64) -> Self::Vector {
+        let t = self.parameter_of(s);
+        match n {
+            0 => self.curve.der_n(0, t),
+            // The arc length parameter is singular where the curve stops.
+            _ if self.curve.der(t).magnitude().so_small() => C::Vector::zero(),
+            _ => {
+                // d/ds = h(t) d/dt with h = 1 / |c'(t)|, applied to the jets at `t`.
+                let cders = self.curve.ders(n, t);
+                let speed = cders.der().abs_ders();
+                let mut h = CurveDers::new(n - 1);
+                h[0] = 1.0 / speed[0];
+                (1..n).for_each(|m| {
+                    let mut c = 1.0;
+                    let sum = (1..=m).fold(0.0, |sum, i| {
+                        c = c * (m + 1 - i) as f64 / i as f64;
+                        sum + c * speed[i] * h[m - i]
+                    });
+                    h[m] = -sum * h[0];
+                });
+                (0..n).fold(cders, |jet, _| {
+                    jet.der().combinatorial_ders(&h, |v, h| v * h)
+                })[0]
+            }
+        }
+    }
+    #[inline(always)]
+    fn parameter_range(&self) -> ParameterRange {
+        (Bound::Included(0.0), Bound::Included(self.length()))
+    }
+}
+
+impl<C> BoundedCurve for ArcLengthCurve<C>
+where
+    C: BoundedCurve,
+    C::Vector: InnerSpace<Scalar = f64>,
+{
+}
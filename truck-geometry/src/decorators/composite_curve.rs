@@ -0,0 +1,114 @@
+use super::*;
+use std::iter::once;
+
+impl<C: BoundedCurve> CompositeCurve<C> {
+    /// Creates the curve joining `curves` in order.
+    ///
+    /// # Panics
+    /// `curves` must not be empty.
+    pub fn new(curves: Vec<C>) -> Self {
+        assert!(!curves.is_empty(), "the composite curve has no segments.");
+        let start = curves[0].range_tuple().0;
+        let lengths = curves.iter().scan(start, |joint, curve| {
+            let (t0, t1) = curve.range_tuple();
+            *joint += t1 - t0;
+            Some(*joint)
+        });
+        let joints = once(start).chain(lengths).collect();
+        Self { curves, joints }
+    }
+
+    /// Returns the index of the segment including the parameter `t` and the parameter on it.
+    /// The parameters at the joints are on the latter segments, except the end of the range.
+    pub fn segment_parameter(&self, t: f64) -> (usize, f64) {
+        let n = self.curves.len();
+        let i = self.joints.partition_point(|joint| *joint <= t).clamp(1, n) - 1;
+        (i, t - self.joints[i] + self.curves[i].range_tuple().0)
+    }
+}
+
+impl<C> CompositeCurve<C> {
+    /// Returns the segments.
+    #[inline(always)]
+    pub fn curves(&self) -> &[C] { &self.curves }
+    /// Returns the parameters of the joints, including both ends of the range.
+    #[inline(always)]
+    pub fn joints(&self) -> &[f64] { &self.joints }
+    /// Returns the segments as the vector.
+    #[inline(always)]
+    pub fn into_curves(self) -> Vec<C> { self.curves }
+}
+
+impl<C: BoundedCurve> ParametricCurve for CompositeCurve<C> {
+    type Point = C::Point;
+    type Vector = C::Vector;
+    #[inline(always)]
+    fn subs(&self, t: f64) -> Self::Point {
+        let (i, t) = self.segment_parameter(t);
+        self.curves[i].subs(t)
+    }
+    #[inline(always)]
+    fn der(&self, t: f64) -> Self::Vector {
+        let (i, t) = self.segment_parameter(t);
+        self.curves[i].der(t)
+    }
+    #[inline(always)]
+    fn der2(&self, t: f64) -> Self::Vector {
+        let (i, t) = self.segment_parameter(t);
+        self.curves[i].der2(t)
+    }
+    #[inline(always)]
+    fn der_n(&self, n: usize, t: f64) -> Self::Vector {
+        let (i, t) = self.segment_parameter(t);
+        self.curves[i].der_n(n, t)
+    }
+    #[inline(always)]
+    fn parameter_range(&self) -> ParameterRange {
+        (
+            Bound::Included(self.joints[0]),
+            Bound::Included(self.joints[self.joints.len() - 1]),
+        )
+    }
+}
+
+impl<C: BoundedCurve> BoundedCurve for CompositeCurve<C> {}
+
+impl<C> ParameterDivision1D for CompositeCurve<C>
+where C: BoundedCurve + ParameterDivision1D<Point = <C as ParametricCurve>::Point>
+{
+    type Point = <C as ParametricCurve>::Point;
+    fn parameter_division(&self, (t0, t1): (f64, f64), tol: f64) -> (Vec<f64>, Vec<Self::Point>) {
+        let (params, points): (Vec<f64>, Vec<_>) = self
+            .curves
+            .iter()
+            .zip(self.joints.windows(2))
+            .filter(|(_, joint)| joint[0] < t1 && t0 < joint[1])
+            .enumerate()
+            .flat_map(|(k, (curve, joint))| {
+                let offset = joint[0] - curve.range_tuple().0;
+                let range = (
+                    f64::max(t0, joint[0]) - offset,
+                    f64::min(t1, joint[1]) - offset,
+                );
+                let (sub_params, sub_points) = curve.parameter_division(range, tol);
+                // the front of the segment is the back of the previous segment
+                sub_params
+                    .into_iter()
+                    .zip(sub_points)
+                    .skip(usize::from(k > 0))
+                    .map(move |(t, p)| (t + offset, p))
+            })
+            .unzip();
+        if !params.is_empty() {
+            return (params, points);
+        }
+        // the range is degenerate or out of the segments
+        let (i, s0) = self.segment_parameter(t0);
+        let offset = t0 - s0;
+        let (sub_params, sub_points) = self.curves[i].parameter_division((s0, t1 - offset), tol);
+        (
+            sub_params.into_iter().map(|t| t + offset).collect(),
+            sub_points,
+        )
+    }
+}
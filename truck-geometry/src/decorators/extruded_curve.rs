@@ -100,16 +100,9 @@ where
         hint: H,
         trials: usize,
     ) -> Option<(f64, f64)> {
-        let hint = match hint.into() {
-            SPHint2D::Parameter(x, y) => (x, y),
-            SPHint2D::Range(range0, range1) => {
-                algo::surface::presearch(self, point, (range0, range1), PRESEARCH_DIVISION)
-            }
-            SPHint2D::None => {
-                algo::surface::presearch(self, point, self.range_tuple(), PRESEARCH_DIVISION)
-            }
-        };
-        algo::surface::search_parameter(self, point, hint, trials)
+        algo::surface::search_with_hint(self, point, hint.into(), PRESEARCH_DIVISION, |hint| {
+            algo::surface::search_parameter(self, point, hint, trials)
+        })
     }
 }
 
@@ -122,16 +115,9 @@ impl<C: ParametricCurve3D + BoundedCurve> SearchNearestParameter<D2> for Extrude
         hint: H,
         trials: usize,
     ) -> Option<(f64, f64)> {
-        let hint = match hint.into() {
-            SPHint2D::Parameter(x, y) => (x, y),
-            SPHint2D::Range(range0, range1) => {
-                algo::surface::presearch(self, point, (range0, range1), PRESEARCH_DIVISION)
-            }
-            SPHint2D::None => {
-                algo::surface::presearch(self, point, self.range_tuple(), PRESEARCH_DIVISION)
-            }
-        };
-        algo::surface::search_nearest_parameter(self, point, hint, trials)
+        algo::surface::search_with_hint(self, point, hint.into(), PRESEARCH_DIVISION, |hint| {
+            algo::surface::search_nearest_parameter(self, point, hint, trials)
+        })
     }
 }
 
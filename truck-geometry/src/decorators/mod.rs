@@ -278,6 +278,59 @@ pub struct TrimmedCurve<C> {
     range: (f64, f64),
 }
 
+/// curve reparameterized by the arc length
+///
+/// The parameter range is `[0, length]`, and `subs(s)` is the point at the arc length `s` from
+/// the front of the original curve. The arc length is tabulated at construction, and the points
+/// are located by Newton's method on each interval of the table.
+/// # Examples
+/// ```
+/// use truck_geometry::prelude::*;
+/// // the curve accelerates since the control points get sparser.
+/// let curve = BSplineCurve::new(
+///     KnotVec::bezier_knot(2),
+///     vec![Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0), Point3::new(4.0, 0.0, 0.0)],
+/// );
+/// let curve = ArcLengthCurve::new(curve, 1.0e-6);
+/// assert_near!(curve.length(), 4.0);
+/// assert_near!(curve.subs(1.0), Point3::new(1.0, 0.0, 0.0));
+/// assert_near!(curve.der(3.0), Vector3::unit_x());
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SelfSameGeometry)]
+pub struct ArcLengthCurve<C> {
+    curve: C,
+    params: Vec<f64>,
+    lengths: Vec<f64>,
+}
+
+/// curve joining several curves in order
+///
+/// The parameter ranges of the segments are shifted so that each segment starts at the end of
+/// the previous one, and the whole range starts at the front of the first segment. Hence the
+/// `i`-th segment is parameterized on `[joints[i], joints[i + 1]]` with the same speed as the
+/// original curve. The end of each segment is assumed to be the front of the next one.
+/// # Examples
+/// ```
+/// use truck_geometry::prelude::*;
+/// let line0 = Line(Point2::new(0.0, 0.0), Point2::new(1.0, 0.0));
+/// let line1 = Line(Point2::new(1.0, 0.0), Point2::new(1.0, 2.0));
+/// let curve = CompositeCurve::new(vec![line0, line1]);
+/// assert_eq!(curve.range_tuple(), (0.0, 2.0));
+/// assert_eq!(curve.joints(), &[0.0, 1.0, 2.0]);
+/// assert_near!(curve.subs(0.5), Point2::new(0.5, 0.0));
+/// assert_near!(curve.subs(1.5), Point2::new(1.0, 1.0));
+/// assert_near!(curve.der(1.5), Vector2::new(0.0, 2.0));
+///
+/// let (params, points) = curve.parameter_division(curve.range_tuple(), 0.01);
+/// assert_eq!(params, vec![0.0, 1.0, 2.0]);
+/// assert_eq!(points[1], Point2::new(1.0, 0.0));
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, SelfSameGeometry)]
+pub struct CompositeCurve<C> {
+    curves: Vec<C>,
+    joints: Vec<f64>,
+}
+
 /// homotopy surface connecting two curves.
 ///
 /// # Examples
@@ -346,6 +399,8 @@ pub struct ApproxFilletSurface<S0, S1> {
 }
 
 mod af_surface;
+mod arc_length_curve;
+mod composite_curve;
 mod extruded_curve;
 mod homotopy;
 mod intersection_curve;
@@ -13,6 +13,64 @@ impl<E, T: One> Processor<E, T> {
     }
 
     /// Creates new transformed processor
+    ///
+    /// The transform is applied lazily in evaluation: the points are transformed by the matrix,
+    /// the derivatives by its linear part, and the query points of the parameter searches by
+    /// its inverse. So the geometry is transformed without rebuilding the entity.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let curve = BSplineCurve::new(
+    ///     KnotVec::bezier_knot(2),
+    ///     vec![
+    ///         Point3::new(0.0, 0.0, 0.0),
+    ///         Point3::new(1.0, 2.0, 0.0),
+    ///         Point3::new(2.0, 0.0, 1.0),
+    ///     ],
+    /// );
+    /// let surface = BSplineSurface::new(
+    ///     (KnotVec::bezier_knot(2), KnotVec::bezier_knot(1)),
+    ///     vec![
+    ///         vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0)],
+    ///         vec![Point3::new(1.0, 0.0, 1.0), Point3::new(1.0, 1.0, 1.0)],
+    ///         vec![Point3::new(2.0, 0.0, 0.0), Point3::new(2.0, 1.0, 0.0)],
+    ///     ],
+    /// );
+    /// let mat = Matrix4::from_translation(Vector3::new(1.0, -2.0, 3.0))
+    ///     * Matrix4::from_axis_angle(Vector3::new(1.0, 1.0, 0.0).normalize(), Rad(0.7));
+    ///
+    /// // lazy transforms
+    /// let lazy_curve = Processor::with_transform(curve.clone(), mat);
+    /// let lazy_surface = Processor::with_transform(surface.clone(), mat);
+    /// // baked transforms
+    /// let baked_curve = curve.transformed(mat);
+    /// let baked_surface = surface.transformed(mat);
+    ///
+    /// const N: usize = 10;
+    /// for i in 0..=N {
+    ///     let t = i as f64 / N as f64;
+    ///     assert_near!(lazy_curve.subs(t), baked_curve.subs(t));
+    ///     assert_near!(lazy_curve.der(t), baked_curve.der(t));
+    ///     assert_near!(lazy_curve.der2(t), baked_curve.der2(t));
+    ///     let p = baked_curve.subs(t);
+    ///     assert_near!(lazy_curve.search_parameter(p, None, 100).unwrap(), t);
+    ///     for j in 0..=N {
+    ///         let v = j as f64 / N as f64;
+    ///         assert_near!(lazy_surface.subs(t, v), baked_surface.subs(t, v));
+    ///         assert_near!(lazy_surface.uder(t, v), baked_surface.uder(t, v));
+    ///         assert_near!(lazy_surface.vder(t, v), baked_surface.vder(t, v));
+    ///         assert_near!(lazy_surface.normal(t, v), baked_surface.normal(t, v));
+    ///         let p = baked_surface.subs(t, v);
+    ///         let (u0, v0) = lazy_surface.search_parameter(p, (0.5, 0.5), 100).unwrap();
+    ///         assert_near!(Vector2::new(u0, v0), Vector2::new(t, v));
+    ///     }
+    /// }
+    ///
+    /// // transforms are composed without touching the entity
+    /// let twice = lazy_curve.transformed(mat);
+    /// assert_eq!(twice.entity(), &curve);
+    /// assert_near!(twice.subs(0.5), baked_curve.transformed(mat).subs(0.5));
+    /// ```
     #[inline(always)]
     pub const fn with_transform(entity: E, transform: T) -> Self {
         Self {
@@ -400,6 +458,15 @@ impl<S: ParameterDivision2D> ParameterDivision2D for Processor<S, Matrix3> {
         &self,
         range: ((f64, f64), (f64, f64)),
         tol: f64,
+    ) -> (Vec<f64>, Vec<f64>) {
+        let options = algo::surface::DivisionOptions::default();
+        self.parameter_division_with_options(range, tol, options)
+    }
+    fn parameter_division_with_options(
+        &self,
+        range: ((f64, f64), (f64, f64)),
+        tol: f64,
+        options: algo::surface::DivisionOptions,
     ) -> (Vec<f64>, Vec<f64>) {
         let a = self.transform;
         let range = match self.orientation {
@@ -412,7 +479,9 @@ impl<S: ParameterDivision2D> ParameterDivision2D for Processor<S, Matrix3> {
         let n = f64::abs(k[0][0])
             .max(f64::abs(k[1][1]))
             .max(f64::abs(k[2][2]));
-        let (udiv, vdiv) = self.entity.parameter_division(range, tol / n);
+        let (udiv, vdiv) = self
+            .entity
+            .parameter_division_with_options(range, tol / n, options);
         match self.orientation {
             true => (udiv, vdiv),
             false => (vdiv, udiv),
@@ -425,6 +494,15 @@ impl<S: ParameterDivision2D> ParameterDivision2D for Processor<S, Matrix4> {
         &self,
         range: ((f64, f64), (f64, f64)),
         tol: f64,
+    ) -> (Vec<f64>, Vec<f64>) {
+        let options = algo::surface::DivisionOptions::default();
+        self.parameter_division_with_options(range, tol, options)
+    }
+    fn parameter_division_with_options(
+        &self,
+        range: ((f64, f64), (f64, f64)),
+        tol: f64,
+        options: algo::surface::DivisionOptions,
     ) -> (Vec<f64>, Vec<f64>) {
         let a = self.transform;
         let range = match self.orientation {
@@ -438,7 +516,9 @@ impl<S: ParameterDivision2D> ParameterDivision2D for Processor<S, Matrix4> {
             .max(f64::abs(k[1][1]))
             .max(f64::abs(k[2][2]))
             / f64::abs(k[3][3]);
-        let (udiv, vdiv) = self.entity.parameter_division(range, tol / n);
+        let (udiv, vdiv) = self
+            .entity
+            .parameter_division_with_options(range, tol / n, options);
         match self.orientation {
             true => (udiv, vdiv),
             false => (vdiv, udiv),
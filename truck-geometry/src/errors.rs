@@ -169,6 +169,27 @@ the number of control points: {1}"
     /// ```
     #[error("The vector of control points and the one of weights have different length.")]
     DifferentLength,
+    /// The control point with the index has a non-positive weight.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// use truck_geometry::errors::Error;
+    /// let bspcurve = BSplineCurve::new(
+    ///     KnotVec::bezier_knot(2),
+    ///     vec![
+    ///         Vector3::new(0.0, 1.0, 1.0),
+    ///         Vector3::new(2.0, 3.0, 0.0), // zero weight
+    ///         Vector3::new(4.0, 5.0, 1.0),
+    ///     ],
+    /// );
+    ///
+    /// assert!(matches!(
+    ///     NurbsCurve::try_new(bspcurve),
+    ///     Err(Error::NonPositiveWeight(1)),
+    /// ));
+    /// ```
+    #[error("The weight of the {0}th control point is not positive.")]
+    NonPositiveWeight(usize),
     /// Gaussian eliminaition is failed. Typically, this is because one has included multiple
     /// parameters for which the B-spline basis functions take the same value.
     /// # Examples
@@ -265,5 +286,6 @@ fn print_messages() {
     writeln!(stderr, "{}\n", Error::EmptyControlPoints).unwrap();
     writeln!(stderr, "{}\n", Error::TooShortKnotVector(1, 2)).unwrap();
     writeln!(stderr, "{}\n", Error::IrregularControlPoints).unwrap();
+    writeln!(stderr, "{}\n", Error::NonPositiveWeight(1)).unwrap();
     writeln!(stderr, "*******************************************************").unwrap();
 }
@@ -125,6 +125,27 @@ impl<P> BSplineCurve<P> {
     #[inline(always)]
     pub fn is_clamped(&self) -> bool { self.knot_vec.is_clamped(self.degree()) }
 
+    /// Returns the number of continuous derivatives at the parameter `t`, derived from the
+    /// multiplicity of the knot. cf. [`KnotVec::continuity_at`]
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vec = KnotVec::from(vec![0.0, 0.0, 0.0, 0.5, 0.5, 1.0, 1.0, 1.0]);
+    /// let ctrl_pts = vec![
+    ///     Vector2::new(0.0, 0.0),
+    ///     Vector2::new(1.0, 1.0),
+    ///     Vector2::new(2.0, 0.0),
+    ///     Vector2::new(3.0, 1.0),
+    ///     Vector2::new(4.0, 0.0),
+    /// ];
+    /// let bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    /// // a corner at the double knot
+    /// assert_eq!(bspcurve.continuity_at(0.5), 0);
+    /// assert_eq!(bspcurve.continuity_at(0.25), usize::MAX);
+    /// ```
+    #[inline(always)]
+    pub fn continuity_at(&self, t: f64) -> usize { self.knot_vec.continuity_at(t, self.degree()) }
+
     /// Normalizes the knot vector  
     #[inline(always)]
     pub fn knot_normalize(&mut self) -> &mut Self {
@@ -379,6 +400,54 @@ impl<P: ControlPoint<f64>> ParametricCurve for BSplineCurve<P> {
     }
     #[inline(always)]
     fn subs(&self, t: f64) -> P { P::from_vec(self.der_n(0, t)) }
+    /// Substitutes all parameters, reusing the knot span of the previous parameter and
+    /// evaluating only the non-zero basis functions.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let curve = BSplineCurve::new(
+    ///     KnotVec::uniform_knot(3, 4),
+    ///     (0..7).map(|i| Point2::new(i as f64, (i * i % 3) as f64)).collect(),
+    /// );
+    /// let params = (0..=100).map(|i| i as f64 / 100.0).collect::<Vec<_>>();
+    /// let points = curve.subs_many(&params);
+    /// params.iter().zip(points).for_each(|(t, p)| assert_near!(p, curve.subs(*t)));
+    /// ```
+    fn subs_many(&self, params: &[f64]) -> Vec<P> {
+        let degree = self.degree();
+        let knots = &self.knot_vec;
+        let n = knots.len() - 1;
+        if knots[0].near(&knots[n]) || n < degree {
+            return params.iter().map(|t| self.subs(*t)).collect();
+        }
+        let end = n - knots.multiplicity(n);
+        params
+            .iter()
+            .scan(degree, |idx, &t| {
+                // the same span as `KnotVec::bspline_basis_functions`
+                if !(knots[*idx] <= t && t < knots[*idx + 1]) {
+                    *idx = match knots.partition_point(|x| *x <= t) {
+                        0 => knots.partition_point(|x| *x <= knots[0]) - 1,
+                        i if i - 1 == n => end,
+                        i => i - 1,
+                    };
+                }
+                Some((*idx, t))
+            })
+            .map(|(idx, t)| match idx {
+                _ if idx < degree || idx >= self.control_points.len() => self.subs(t),
+                _ => {
+                    let sum = local_basis_functions(knots, degree, idx, t)
+                        .into_iter()
+                        .enumerate()
+                        .fold(P::Diff::zero(), |sum, (r, b)| {
+                            sum + self.control_points[idx - degree + r].to_vec() * b
+                        });
+                    P::from_vec(sum)
+                }
+            })
+            .collect()
+    }
     #[inline(always)]
     fn der(&self, t: f64) -> P::Diff { self.der_n(1, t) }
     #[inline(always)]
@@ -500,6 +569,35 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineCurve<P> {
         self
     }
 
+    /// Returns the curve with the knots `knots` inserted, which is the same as `self` as a curve.
+    ///
+    /// The knots are inserted one by one in order by [`BSplineCurve::add_knot`], so `knots` may
+    /// be unsorted and contains the multiple knots.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vec = KnotVec::bezier_knot(2);
+    /// let ctrl_pts = vec![Vector2::new(-1.0, 1.0), Vector2::new(0.0, -1.0), Vector2::new(1.0, 1.0)];
+    /// let bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    ///
+    /// let refined = bspcurve.inserted_knots(&[0.5, 0.25, 0.5]);
+    /// assert_eq!(
+    ///     refined.knot_vec(),
+    ///     &KnotVec::from(vec![0.0, 0.0, 0.0, 0.25, 0.5, 0.5, 1.0, 1.0, 1.0]),
+    /// );
+    /// assert!(refined.near2_as_curve(&bspcurve));
+    /// ```
+    /// # Remarks
+    /// As [`BSplineCurve::add_knot`], the knots out of the range of the knot vector extend the
+    /// knot vector, and the curve may be changed.
+    pub fn inserted_knots(&self, knots: &[f64]) -> BSplineCurve<P> {
+        let mut curve = self.clone();
+        knots.iter().for_each(|x| {
+            curve.add_knot(*x);
+        });
+        curve
+    }
+
     /// Removes a knot corresponding to the indices `idx`, and do not change `self` as a curve.
     /// If cannot remove the knot, do not change `self` and return `self`.
     /// # Examples
@@ -1350,3 +1448,28 @@ fn cubic_bezier_interpolation_test() {
     assert_near!(der.front(), der0);
     assert_near!(der.back(), der1);
 }
+
+/// the non-zero basis functions on the knot span `[knots[idx], knots[idx + 1])` by the
+/// triangular scheme of Cox-de Boor
+fn local_basis_functions(knots: &[f64], degree: usize, idx: usize, t: f64) -> Vec<f64> {
+    let left = |k: usize| t - knots[idx + 1 - k];
+    let right = |k: usize| knots[idx + k] - t;
+    (1..=degree).fold(vec![1.0], |basis, j| {
+        let temps = (0..j)
+            .map(|r| basis[r] / (right(r + 1) + left(j - r)))
+            .collect::<Vec<_>>();
+        // the `r`-th function is the part saved from the `r - 1`-th one and its own part
+        (0..=j)
+            .map(|r| {
+                let saved = match r {
+                    0 => 0.0,
+                    _ => left(j + 1 - r) * temps[r - 1],
+                };
+                match r < j {
+                    true => saved + right(r + 1) * temps[r],
+                    false => saved,
+                }
+            })
+            .collect()
+    })
+}
@@ -239,6 +239,31 @@ impl<P> BSplineSurface<P> {
         self.knot_vecs.0.is_clamped(self.udegree()) && self.knot_vecs.1.is_clamped(self.vdegree())
     }
 
+    /// Returns the number of continuous derivatives at the parameter `(u, v)`, the minimum of the
+    /// continuities in the u- and v-directions derived from the multiplicities of the knots.
+    /// cf. [`KnotVec::continuity_at`]
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// // C0 along the knot line u = 0.5
+    /// let uknot_vec = KnotVec::from(vec![0.0, 0.0, 0.0, 0.5, 0.5, 1.0, 1.0, 1.0]);
+    /// let vknot_vec = KnotVec::from(vec![0.0, 0.0, 0.0, 0.5, 1.0, 1.0, 1.0]);
+    /// let ctrl_pts = (0..5)
+    ///     .map(|i| (0..4).map(|j| Vector3::new(i as f64, j as f64, (i % 2) as f64)).collect())
+    ///     .collect();
+    /// let bspsurface = BSplineSurface::new((uknot_vec, vknot_vec), ctrl_pts);
+    /// assert_eq!(bspsurface.continuity_at(0.5, 0.25), 0);
+    /// assert_eq!(bspsurface.continuity_at(0.25, 0.5), 1);
+    /// assert_eq!(bspsurface.continuity_at(0.25, 0.25), usize::MAX);
+    /// ```
+    #[inline(always)]
+    pub fn continuity_at(&self, u: f64, v: f64) -> usize {
+        usize::min(
+            self.knot_vecs.0.continuity_at(u, self.udegree()),
+            self.knot_vecs.1.continuity_at(v, self.vdegree()),
+        )
+    }
+
     /// Swaps two parameters.
     /// # Examples
     /// ```
@@ -1586,9 +1611,25 @@ where P: EuclideanSpace<Scalar = f64, Diff = <P as ControlPoint<f64>>::Diff>
     ) -> (Vec<f64>, Vec<f64>) {
         algo::surface::parameter_division(self, range, tol)
     }
+    fn parameter_division_with_options(
+        &self,
+        range: ((f64, f64), (f64, f64)),
+        tol: f64,
+        options: algo::surface::DivisionOptions,
+    ) -> (Vec<f64>, Vec<f64>) {
+        let (udiv, vdiv, _) =
+            algo::surface::parameter_division_with_options(self, range, tol, options);
+        (udiv, vdiv)
+    }
 }
 
-impl ParametricSurface3D for BSplineSurface<Point3> {}
+impl ParametricSurface3D for BSplineSurface<Point3> {
+    /// Returns the bounding box of all control points, which includes the whole surface.
+    #[inline(always)]
+    fn bounding_box(&self, _: ((f64, f64), (f64, f64)), _: f64) -> BoundingBox<Point3> {
+        self.roughly_bounding_box()
+    }
+}
 
 impl<V> BoundedSurface for BSplineSurface<V> where BSplineSurface<V>: ParametricSurface {}
 
@@ -1612,16 +1653,9 @@ where
         hint: H,
         trials: usize,
     ) -> Option<(f64, f64)> {
-        let hint = match hint.into() {
-            SPHint2D::Parameter(x, y) => (x, y),
-            SPHint2D::Range(range0, range1) => {
-                algo::surface::presearch(self, point, (range0, range1), PRESEARCH_DIVISION)
-            }
-            SPHint2D::None => {
-                algo::surface::presearch(self, point, self.range_tuple(), PRESEARCH_DIVISION)
-            }
-        };
-        algo::surface::search_parameter(self, point, hint, trials)
+        algo::surface::search_with_hint(self, point, hint.into(), PRESEARCH_DIVISION, |hint| {
+            algo::surface::search_parameter(self, point, hint, trials)
+        })
     }
 }
 
@@ -1639,16 +1673,9 @@ where
         hint: H,
         trials: usize,
     ) -> Option<(f64, f64)> {
-        let hint = match hint.into() {
-            SPHint2D::Parameter(x, y) => (x, y),
-            SPHint2D::Range(range0, range1) => {
-                algo::surface::presearch(self, point, (range0, range1), PRESEARCH_DIVISION)
-            }
-            SPHint2D::None => {
-                algo::surface::presearch(self, point, self.range_tuple(), PRESEARCH_DIVISION)
-            }
-        };
-        algo::surface::search_nearest_parameter(self, point, hint, trials)
+        algo::surface::search_with_hint(self, point, hint.into(), PRESEARCH_DIVISION, |hint| {
+            algo::surface::search_nearest_parameter(self, point, hint, trials)
+        })
     }
 }
 
@@ -74,6 +74,27 @@ impl KnotVec {
         self.iter().filter(|u| self[i].near(u)).count()
     }
 
+    /// Returns the number of continuous derivatives at `x` of the B-spline functions of `degree`.
+    ///
+    /// The continuity at a knot of multiplicity `m` is `degree - m`, and it saturates to `0` if
+    /// `m > degree`, i.e. if the functions are discontinuous. Returns `usize::MAX` if `x` is not
+    /// near any knot, since the functions are polynomial there.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::KnotVec;
+    /// let knot_vec = KnotVec::from(vec![0.0, 0.0, 0.0, 1.0, 2.0, 2.0, 3.0, 3.0, 3.0]);
+    /// assert_eq!(knot_vec.continuity_at(1.0, 2), 1);
+    /// assert_eq!(knot_vec.continuity_at(2.0, 2), 0);
+    /// assert_eq!(knot_vec.continuity_at(1.5, 2), usize::MAX);
+    /// ```
+    #[inline(always)]
+    pub fn continuity_at(&self, x: f64, degree: usize) -> usize {
+        match self.iter().filter(|t| x.near(t)).count() {
+            0 => usize::MAX,
+            multi => degree.saturating_sub(multi),
+        }
+    }
+
     /// Adds a knot and return the index of the added knot.
     /// # Examples
     /// ```
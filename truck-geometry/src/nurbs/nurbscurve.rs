@@ -55,6 +55,11 @@ impl<V> NurbsCurve<V> {
     #[inline(always)]
     pub fn is_clamped(&self) -> bool { self.0.knot_vec.is_clamped(self.0.degree()) }
 
+    /// Returns the number of continuous derivatives at the parameter `t`, derived from the
+    /// multiplicity of the knot. cf.[`BSplineCurve::continuity_at`]
+    #[inline(always)]
+    pub fn continuity_at(&self, t: f64) -> usize { self.0.continuity_at(t) }
+
     /// Normalizes the knot vector. cf.[`BSplineCurve::knot_normalize`]
     #[inline(always)]
     pub fn knot_normalize(&mut self) -> &mut Self {
@@ -93,6 +98,94 @@ impl<V: Homogeneous<Scalar = f64>> NurbsCurve<V> {
             .collect();
         Ok(Self(BSplineCurve::new_unchecked(knot_vec, control_points)))
     }
+
+    /// Constructs the rationalized B-spline curve, checking that all weights are positive.
+    ///
+    /// [`NurbsCurve::new`] accepts non-positive weights, e.g. the control points at infinity,
+    /// and the points on the curve are not defined where the total weight vanishes.
+    /// Evaluation near such parameters falls back to the nearest parameter with a regular weight.
+    /// # Failures
+    /// Returns [`Error::NonPositiveWeight`] if some control point has a non-positive weight.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// use truck_geometry::errors::Error;
+    /// let bspcurve = BSplineCurve::new(
+    ///     KnotVec::bezier_knot(2),
+    ///     vec![
+    ///         Vector3::new(0.0, 0.0, 1.0),
+    ///         Vector3::new(1.0, 1.0, 1.0),
+    ///         Vector3::new(2.0, 0.0, 1.0),
+    ///     ],
+    /// );
+    /// assert!(NurbsCurve::try_new(bspcurve.clone()).is_ok());
+    ///
+    /// let mut bspcurve = bspcurve;
+    /// // the total weight `(1 - 2t)^2` vanishes at `t = 0.5`.
+    /// *bspcurve.control_point_mut(1) = Vector3::new(-1.0, -1.0, -1.0);
+    /// assert!(matches!(
+    ///     NurbsCurve::try_new(bspcurve.clone()),
+    ///     Err(Error::NonPositiveWeight(1)),
+    /// ));
+    ///
+    /// // `new` accepts the curve, and the evaluation does not return NaN.
+    /// let curve = NurbsCurve::new(bspcurve);
+    /// let (p, d) = (curve.subs(0.5), curve.der(0.5));
+    /// assert!(!p.x.is_nan() && !p.y.is_nan() && !d.x.is_nan() && !d.y.is_nan());
+    /// ```
+    pub fn try_new(curve: BSplineCurve<V>) -> Result<Self> {
+        match curve.control_points.iter().position(|p| p.weight() <= 0.0) {
+            Some(idx) => Err(Error::NonPositiveWeight(idx)),
+            None => Ok(Self(curve)),
+        }
+    }
+
+    /// Returns the dehomogenized position of the control point corresponding to the index `idx`.
+    /// # Panics
+    /// Panics if `idx` is out of range.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let curve = NurbsCurve::new(BSplineCurve::new(
+    ///     KnotVec::bezier_knot(1),
+    ///     vec![Vector3::new(0.0, 0.0, 1.0), Vector3::new(4.0, 2.0, 2.0)],
+    /// ));
+    /// assert_eq!(curve.control_point_position(1), Point2::new(2.0, 1.0));
+    /// ```
+    #[inline(always)]
+    pub fn control_point_position(&self, idx: usize) -> V::Point {
+        let len = self.0.control_points.len();
+        assert!(
+            idx < len,
+            "control point index {idx} is out of range 0..{len}"
+        );
+        self.0.control_points[idx].to_point()
+    }
+
+    /// Moves the control point corresponding to the index `idx` to `point`, preserving its weight.
+    /// # Panics
+    /// Panics if `idx` is out of range.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let mut curve = NurbsCurve::new(BSplineCurve::new(
+    ///     KnotVec::bezier_knot(1),
+    ///     vec![Vector3::new(0.0, 0.0, 1.0), Vector3::new(4.0, 2.0, 2.0)],
+    /// ));
+    /// curve.set_control_point_position(1, Point2::new(1.0, 3.0));
+    /// assert_eq!(*curve.control_point(1), Vector3::new(2.0, 6.0, 2.0));
+    /// ```
+    #[inline(always)]
+    pub fn set_control_point_position(&mut self, idx: usize, point: V::Point) -> &mut Self {
+        let len = self.0.control_points.len();
+        assert!(
+            idx < len,
+            "control point index {idx} is out of range 0..{len}"
+        );
+        let pt = &mut self.0.control_points[idx];
+        *pt = V::from_point_weight(point, pt.weight());
+        self
+    }
 }
 
 impl<V: Homogeneous<Scalar = f64> + ControlPoint<f64, Diff = V>> NurbsCurve<V> {
@@ -214,6 +307,43 @@ impl<V: Homogeneous<Scalar = f64> + ControlPoint<f64, Diff = V> + Tolerance> Nur
         self
     }
 
+    /// Returns the part of the curve on the parameter range `(t0, t1)`.
+    ///
+    /// # Details
+    /// The knots `t0` and `t1` are inserted up to the full multiplicity, and the control points
+    /// between them are extracted with their weights. The returned curve is independent of `self`,
+    /// is defined on `(t0, t1)`, and coincides with `self` on this range.
+    ///
+    /// # Panics
+    /// Panics if `t0` is not less than `t1`.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// // the quarter of the unit circle
+    /// let knot_vec = KnotVec::bezier_knot(2);
+    /// let control_points = vec![
+    ///     Vector4::new(1.0, 0.0, 0.0, 1.0),
+    ///     Vector4::new(1.0, 1.0, 0.0, 1.0),
+    ///     Vector4::new(0.0, 2.0, 0.0, 2.0),
+    /// ];
+    /// let curve = NurbsCurve::new(BSplineCurve::new(knot_vec, control_points));
+    ///
+    /// let sub = curve.subcurve(0.25, 0.75);
+    /// assert_eq!(sub.range_tuple(), (0.25, 0.75));
+    /// for i in 0..=10 {
+    ///     let t = 0.25 + 0.05 * i as f64;
+    ///     assert_near!(sub.subs(t), curve.subs(t));
+    /// }
+    /// ```
+    pub fn subcurve(&self, t0: f64, t1: f64) -> Self {
+        assert!(t0 < t1, "the parameter range is empty: ({t0}, {t1})");
+        let mut curve = self.clone();
+        let mut sub = curve.cut(t0);
+        sub.cut(t1);
+        sub
+    }
+
     /// Removes a knot corresponding to the indices `idx`, and do not change `self` as a curve.
     /// If cannot remove the knot, do not change `self` and return `self`.
     /// cf.[`BSplineCurve::remove_knot`]
@@ -489,6 +619,36 @@ where
     }
 }
 
+impl<V: Homogeneous<Scalar = f64> + ControlPoint<f64, Diff = V>> NurbsCurve<V>
+where <V::Point as EuclideanSpace>::Diff: InnerSpace<Scalar = f64>
+{
+    /// Returns the curve reparameterized by the arc length, e.g. for feedrate control.
+    ///
+    /// See [`ArcLengthCurve`] for details.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let curve = NurbsCurve::new(BSplineCurve::new(
+    ///     KnotVec::bezier_knot(2),
+    ///     vec![
+    ///         Vector4::new(0.0, 0.0, 0.0, 1.0),
+    ///         Vector4::new(0.0, 2.0, 0.0, 2.0),
+    ///         Vector4::new(1.0, 1.0, 0.0, 1.0),
+    ///     ],
+    /// ));
+    /// let curve = curve.reparameterized_by_arclength(1.0e-6);
+    /// let (_, length) = curve.range_tuple();
+    /// (0..=10).for_each(|i| {
+    ///     let s = length * i as f64 / 10.0;
+    ///     assert_near!(curve.der(s).magnitude(), 1.0);
+    /// });
+    /// ```
+    #[inline(always)]
+    pub fn reparameterized_by_arclength(&self, tol: f64) -> ArcLengthCurve<Self> {
+        ArcLengthCurve::new(self.clone(), tol)
+    }
+}
+
 impl<V: Homogeneous<Scalar = f64>> NurbsCurve<V>
 where V::Point: Bounded<Scalar = f64>
 {
@@ -502,15 +662,55 @@ where V::Point: Bounded<Scalar = f64>
 impl<V: Homogeneous<Scalar = f64> + ControlPoint<f64, Diff = V>> ParametricCurve for NurbsCurve<V> {
     type Point = V::Point;
     type Vector = <V::Point as EuclideanSpace>::Diff;
-    fn der_n(&self, n: usize, t: f64) -> Self::Vector { self.0.ders(n, t).rat_ders()[n] }
-    fn ders(&self, n: usize, t: f64) -> CurveDers<Self::Vector> { self.0.ders(n, t).rat_ders() }
+    fn der_n(&self, n: usize, t: f64) -> Self::Vector { self.ders(n, t)[n] }
+    fn ders(&self, n: usize, t: f64) -> CurveDers<Self::Vector> {
+        let ders = self.0.ders(n, t);
+        match self.vanishing_weight(ders[0].weight()) {
+            false => ders.rat_ders(),
+            true => self.0.ders(n, self.regular_parameter(t)).rat_ders(),
+        }
+    }
     #[inline(always)]
-    fn subs(&self, t: f64) -> Self::Point { self.0.subs(t).to_point() }
+    fn subs(&self, t: f64) -> Self::Point {
+        let p = self.0.subs(t);
+        match self.vanishing_weight(p.weight()) {
+            false => p.to_point(),
+            true => self.0.subs(self.regular_parameter(t)).to_point(),
+        }
+    }
+    #[inline(always)]
+    fn subs_many(&self, params: &[f64]) -> Vec<Self::Point> {
+        self.0
+            .subs_many(params)
+            .into_iter()
+            .zip(params)
+            .map(|(p, t)| match self.vanishing_weight(p.weight()) {
+                false => p.to_point(),
+                true => self.subs(*t),
+            })
+            .collect()
+    }
     #[inline(always)]
-    fn der(&self, t: f64) -> Self::Vector { rat_der(&[self.0.subs(t), self.0.der(t)]) }
+    fn der(&self, t: f64) -> Self::Vector {
+        let p = self.0.subs(t);
+        match self.vanishing_weight(p.weight()) {
+            false => rat_der(&[p, self.0.der(t)]),
+            true => {
+                let t = self.regular_parameter(t);
+                rat_der(&[self.0.subs(t), self.0.der(t)])
+            }
+        }
+    }
     #[inline(always)]
     fn der2(&self, t: f64) -> Self::Vector {
-        rat_der(&[self.0.subs(t), self.0.der(t), self.0.der2(t)])
+        let p = self.0.subs(t);
+        match self.vanishing_weight(p.weight()) {
+            false => rat_der(&[p, self.0.der(t), self.0.der2(t)]),
+            true => {
+                let t = self.regular_parameter(t);
+                rat_der(&[self.0.subs(t), self.0.der(t), self.0.der2(t)])
+            }
+        }
     }
     #[inline(always)]
     fn parameter_range(&self) -> ParameterRange {
@@ -523,6 +723,33 @@ impl<V: Homogeneous<Scalar = f64> + ControlPoint<f64, Diff = V>> ParametricCurve
 
 impl<V: Homogeneous<Scalar = f64> + ControlPoint<f64, Diff = V>> BoundedCurve for NurbsCurve<V> {}
 
+impl<V: Homogeneous<Scalar = f64> + ControlPoint<f64, Diff = V>> NurbsCurve<V> {
+    /// Returns whether the weight `w` vanishes relative to the largest weight of the control
+    /// points.
+    fn vanishing_weight(&self, w: f64) -> bool {
+        let weights = self.0.control_points.iter().map(|p| p.weight().abs());
+        let max = weights.fold(0.0, f64::max);
+        w.abs() <= TOLERANCE * max
+    }
+
+    /// Returns the parameter near `t` at which the weight does not vanish.
+    ///
+    /// The parameter is moved toward the middle of the knot range by `TOLERANCE`, doubling the
+    /// step until the weight does not vanish. If no such parameter is found, returns the last one.
+    fn regular_parameter(&self, t: f64) -> f64 {
+        let (t0, t1) = (
+            self.0.knot_vec[0],
+            self.0.knot_vec[self.0.knot_vec.len() - 1],
+        );
+        let sign = f64::signum((t0 + t1) / 2.0 - t);
+        let moved = |i: i32| f64::clamp(t + sign * TOLERANCE * f64::powi(2.0, i), t0, t1);
+        (0..16)
+            .map(moved)
+            .find(|s| !self.vanishing_weight(self.0.subs(*s).weight()))
+            .unwrap_or_else(|| moved(15))
+    }
+}
+
 impl<V: Clone> Invertible for NurbsCurve<V> {
     #[inline(always)]
     fn invert(&mut self) { self.0.invert(); }
@@ -163,6 +163,12 @@ impl<V> NurbsSurface<V> {
     /// Returns whether the knot vectors are clamped or not.
     #[inline(always)]
     pub fn is_clamped(&self) -> bool { self.0.is_clamped() }
+
+    /// Returns the number of continuous derivatives at the parameter `(u, v)`, derived from the
+    /// multiplicities of the knots. cf.[`BSplineSurface::continuity_at`]
+    #[inline(always)]
+    pub fn continuity_at(&self, u: f64, v: f64) -> usize { self.0.continuity_at(u, v) }
+
     /// Swaps two parameters.
     pub fn swap_axes(&mut self) -> &mut Self
     where V: Clone {
@@ -221,6 +227,67 @@ impl<V: Homogeneous<Scalar = f64>> NurbsSurface<V> {
             control_points,
         )))
     }
+
+    /// Returns the dehomogenized position of the control point corresponding to the index `(idx0, idx1)`.
+    /// # Panics
+    /// Panics if `(idx0, idx1)` is out of range.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let surface = NurbsSurface::new(BSplineSurface::new(
+    ///     (KnotVec::bezier_knot(1), KnotVec::bezier_knot(1)),
+    ///     vec![
+    ///         vec![Vector4::new(0.0, 0.0, 0.0, 1.0), Vector4::new(0.0, 2.0, 0.0, 2.0)],
+    ///         vec![Vector4::new(1.0, 0.0, 0.0, 1.0), Vector4::new(2.0, 2.0, 4.0, 2.0)],
+    ///     ],
+    /// ));
+    /// assert_eq!(surface.control_point_position(1, 1), Point3::new(1.0, 1.0, 2.0));
+    /// ```
+    #[inline(always)]
+    pub fn control_point_position(&self, idx0: usize, idx1: usize) -> V::Point {
+        self.checked_control_point(idx0, idx1).to_point()
+    }
+
+    /// Moves the control point corresponding to the index `(idx0, idx1)` to `point`, preserving its weight.
+    /// # Panics
+    /// Panics if `(idx0, idx1)` is out of range.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let mut surface = NurbsSurface::new(BSplineSurface::new(
+    ///     (KnotVec::bezier_knot(1), KnotVec::bezier_knot(1)),
+    ///     vec![
+    ///         vec![Vector4::new(0.0, 0.0, 0.0, 1.0), Vector4::new(0.0, 2.0, 0.0, 2.0)],
+    ///         vec![Vector4::new(1.0, 0.0, 0.0, 1.0), Vector4::new(2.0, 2.0, 4.0, 2.0)],
+    ///     ],
+    /// ));
+    /// surface.set_control_point_position(1, 1, Point3::new(1.0, 1.0, 0.0));
+    /// assert_eq!(*surface.control_point(1, 1), Vector4::new(2.0, 2.0, 0.0, 2.0));
+    /// ```
+    #[inline(always)]
+    pub fn set_control_point_position(
+        &mut self,
+        idx0: usize,
+        idx1: usize,
+        point: V::Point,
+    ) -> &mut Self {
+        let pt = self.checked_control_point(idx0, idx1);
+        let new = V::from_point_weight(point, pt.weight());
+        self.0.control_points[idx0][idx1] = new;
+        self
+    }
+
+    fn checked_control_point(&self, idx0: usize, idx1: usize) -> V {
+        let (len0, len1) = (
+            self.0.control_points.len(),
+            self.0.control_points.first().map_or(0, Vec::len),
+        );
+        assert!(
+            idx0 < len0 && idx1 < len1,
+            "control point index ({idx0}, {idx1}) is out of range (0..{len0}, 0..{len1})"
+        );
+        self.0.control_points[idx0][idx1]
+    }
 }
 
 impl<V: Homogeneous<Scalar = f64> + ControlPoint<f64, Diff = V>> NurbsSurface<V> {
@@ -515,16 +582,9 @@ where
         hint: H,
         trials: usize,
     ) -> Option<(f64, f64)> {
-        let hint = match hint.into() {
-            SPHint2D::Parameter(x, y) => (x, y),
-            SPHint2D::Range(range0, range1) => {
-                algo::surface::presearch(self, point, (range0, range1), PRESEARCH_DIVISION)
-            }
-            SPHint2D::None => {
-                algo::surface::presearch(self, point, self.range_tuple(), PRESEARCH_DIVISION)
-            }
-        };
-        algo::surface::search_nearest_parameter(self, point, hint, trials)
+        algo::surface::search_with_hint(self, point, hint.into(), PRESEARCH_DIVISION, |hint| {
+            algo::surface::search_nearest_parameter(self, point, hint, trials)
+        })
     }
 }
 
@@ -610,6 +670,12 @@ impl ParametricSurface3D for NurbsSurface<Vector4> {
         let vd = self.0.vder(u, v);
         rat_der(&[pt, ud]).cross(rat_der(&[pt, vd])).normalize()
     }
+    /// Returns the bounding box of all dehomogenized control points, which includes the whole
+    /// surface if all weights are positive.
+    #[inline(always)]
+    fn bounding_box(&self, _: ((f64, f64), (f64, f64)), _: f64) -> BoundingBox<Point3> {
+        self.roughly_bounding_box()
+    }
 }
 
 impl<V: Homogeneous<Scalar = f64> + ControlPoint<f64, Diff = V>> ParameterDivision2D
@@ -624,6 +690,16 @@ where V::Point: MetricSpace<Metric = f64> + HashGen<f64>
     ) -> (Vec<f64>, Vec<f64>) {
         algo::surface::parameter_division(self, range, tol)
     }
+    fn parameter_division_with_options(
+        &self,
+        range: ((f64, f64), (f64, f64)),
+        tol: f64,
+        options: algo::surface::DivisionOptions,
+    ) -> (Vec<f64>, Vec<f64>) {
+        let (udiv, vdiv, _) =
+            algo::surface::parameter_division_with_options(self, range, tol, options);
+        (udiv, vdiv)
+    }
 }
 
 impl<V> BoundedSurface for NurbsSurface<V> where Self: ParametricSurface {}
@@ -756,7 +832,8 @@ where
 {
     type Point = V::Point;
     /// Search the parameter `(u, v)` such that `self.subs(u, v).rational_projection()` is near `pt`.
-    /// If cannot find, then return `None`.
+    /// If cannot find, then return `None`. If the hint is a range, the parameters out of the range
+    /// are not returned.
     /// # Examples
     /// ```
     /// use truck_geometry::prelude::*;
@@ -780,16 +857,9 @@ where
         hint: H,
         trials: usize,
     ) -> Option<(f64, f64)> {
-        let hint = match hint.into() {
-            SPHint2D::Parameter(x, y) => (x, y),
-            SPHint2D::Range(range0, range1) => {
-                algo::surface::presearch(self, point, (range0, range1), PRESEARCH_DIVISION)
-            }
-            SPHint2D::None => {
-                algo::surface::presearch(self, point, self.range_tuple(), PRESEARCH_DIVISION)
-            }
-        };
-        algo::surface::search_parameter(self, point, hint, trials)
+        algo::surface::search_with_hint(self, point, hint.into(), PRESEARCH_DIVISION, |hint| {
+            algo::surface::search_parameter(self, point, hint, trials)
+        })
     }
 }
 
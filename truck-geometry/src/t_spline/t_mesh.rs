@@ -2368,6 +2368,16 @@ impl ParameterDivision2D for Tmesh<Point3> {
     ) -> (Vec<f64>, Vec<f64>) {
         algo::surface::parameter_division(self, range, tol)
     }
+    fn parameter_division_with_options(
+        &self,
+        range: ((f64, f64), (f64, f64)),
+        tol: f64,
+        options: algo::surface::DivisionOptions,
+    ) -> (Vec<f64>, Vec<f64>) {
+        let (udiv, vdiv, _) =
+            algo::surface::parameter_division_with_options(self, range, tol, options);
+        (udiv, vdiv)
+    }
 }
 
 impl Invertible for Tmesh<Point3> {
@@ -2408,12 +2418,9 @@ impl SearchParameter<D2> for Tmesh<Point3> {
         hint: H,
         trials: usize,
     ) -> Option<(f64, f64)> {
-        let hint = match hint.into() {
-            SPHint2D::Parameter(u, v) => (u, v),
-            SPHint2D::Range(x, y) => algo::surface::presearch(self, point, (x, y), 100),
-            SPHint2D::None => algo::surface::presearch(self, point, self.range_tuple(), 100),
-        };
-        algo::surface::search_parameter(self, point, hint, trials)
+        algo::surface::search_with_hint(self, point, hint.into(), 100, |hint| {
+            algo::surface::search_parameter(self, point, hint, trials)
+        })
     }
 }
 
@@ -2425,12 +2432,9 @@ impl SearchNearestParameter<D2> for Tmesh<Point3> {
         hint: H,
         trials: usize,
     ) -> Option<(f64, f64)> {
-        let hint = match hint.into() {
-            SPHint2D::Parameter(u, v) => (u, v),
-            SPHint2D::Range(x, y) => algo::surface::presearch(self, point, (x, y), 100),
-            SPHint2D::None => algo::surface::presearch(self, point, self.range_tuple(), 100),
-        };
-        algo::surface::search_nearest_parameter(self, point, hint, trials)
+        algo::surface::search_with_hint(self, point, hint.into(), 100, |hint| {
+            algo::surface::search_nearest_parameter(self, point, hint, trials)
+        })
     }
 }
 
@@ -216,6 +216,69 @@ fn test_invert() {
     }
 }
 
+#[test]
+fn test_inserted_knots() {
+    let knot_vec = KnotVec::from(vec![0.0, 0.0, 0.0, 0.0, 0.3, 0.6, 1.0, 1.0, 1.0, 1.0]);
+    let ctrl_pts = (0..6)
+        .map(|i| Point3::new(i as f64, (i * i % 5) as f64, (i % 3) as f64))
+        .collect::<Vec<_>>();
+    let bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    let knots = [0.8, 0.1, 0.3, 0.45, 0.45, 0.45];
+    let refined = bspcurve.inserted_knots(&knots);
+    assert_eq!(refined.degree(), 3);
+    assert_eq!(
+        refined.knot_vec().len(),
+        bspcurve.knot_vec().len() + knots.len()
+    );
+    assert_eq!(
+        refined.control_points().len(),
+        bspcurve.control_points().len() + knots.len(),
+    );
+    assert_eq!(refined.knot_vec().multiplicity(8), 3);
+
+    const N: usize = 100;
+    (0..=N).for_each(|i| {
+        let t = i as f64 / N as f64;
+        assert_near!(refined.subs(t), bspcurve.subs(t));
+        assert_near!(refined.der(t), bspcurve.der(t));
+    });
+}
+
+#[test]
+fn test_subs_many() {
+    let knot_vec = KnotVec::from(vec![
+        0.0, 0.0, 0.0, 0.0, 0.2, 0.5, 0.5, 0.7, 1.0, 1.0, 1.0, 1.0,
+    ]);
+    let ctrl_pts = (0..8)
+        .map(|i| Point3::new(i as f64, (i * i % 5) as f64, (i % 3) as f64))
+        .collect::<Vec<_>>();
+    let bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    let sorted = (-10..=110).map(|i| i as f64 / 100.0).collect::<Vec<_>>();
+    let unsorted = (0..=100)
+        .map(|i| (i * 37 % 101) as f64 / 100.0)
+        .collect::<Vec<_>>();
+    [sorted, unsorted].into_iter().for_each(|params| {
+        let points = bspcurve.subs_many(&params);
+        assert_eq!(points.len(), params.len());
+        params.iter().zip(points).for_each(|(t, p)| {
+            assert_near!(p, bspcurve.subs(*t));
+        });
+    });
+    let nurbs = NurbsCurve::new(BSplineCurve::new(
+        KnotVec::bezier_knot(2),
+        vec![
+            Vector3::new(1.0, 0.0, 1.0),
+            Vector3::new(1.0, 1.0, 1.0),
+            Vector3::new(0.0, 2.0, 2.0),
+        ],
+    ));
+    let params = (0..=20).map(|i| i as f64 / 20.0).collect::<Vec<_>>();
+    let points = nurbs.subs_many(&params);
+    params.iter().zip(points).for_each(|(t, p)| {
+        assert_near!(p, nurbs.subs(*t));
+    });
+}
+
 #[test]
 #[ignore]
 fn bsp_bench() {
@@ -231,3 +294,29 @@ fn bsp_bench() {
 
     println!("bsp-bench: {}ms", instant.elapsed().as_millis());
 }
+
+#[test]
+#[ignore]
+fn subs_many_bench() {
+    const N: usize = 1000000;
+    let bspcurve = BSplineCurve::new(
+        KnotVec::uniform_knot(3, 10),
+        (0..13)
+            .map(|i| Point2::new(i as f64, (i % 4) as f64))
+            .collect(),
+    );
+    let params = (0..=N).map(|i| i as f64 / N as f64).collect::<Vec<_>>();
+
+    let instant = std::time::Instant::now();
+    let points0 = params.iter().map(|t| bspcurve.subs(*t)).collect::<Vec<_>>();
+    println!("subs: {}ms", instant.elapsed().as_millis());
+
+    let instant = std::time::Instant::now();
+    let points1 = bspcurve.subs_many(&params);
+    println!("subs_many: {}ms", instant.elapsed().as_millis());
+
+    points0
+        .into_iter()
+        .zip(points1)
+        .for_each(|(p, q)| assert_near!(p, q));
+}
@@ -243,3 +243,54 @@ proptest! {
         prop_assert!((der0 - der1).magnitude() < 0.01 * der0.magnitude());
     }
 }
+
+#[test]
+fn ray_intersection() {
+    // a bump over the unit square
+    let knot_vecs = (KnotVec::bezier_knot(3), KnotVec::bezier_knot(2));
+    let ctrl_pts = (0..4)
+        .map(|i| {
+            (0..3)
+                .map(|j| {
+                    let z = if (i == 1 || i == 2) && j == 1 {
+                        1.0
+                    } else {
+                        0.0
+                    };
+                    Point3::new(i as f64 / 3.0, j as f64 / 2.0, z)
+                })
+                .collect()
+        })
+        .collect();
+    let surface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    let (u, v) = (0.3, 0.7);
+    let target = surface.subs(u, v);
+    let dir = Vector3::new(-1.0, 2.0, -3.0);
+    let origin = target - 2.0 * dir;
+    let (u0, v0, t) = algo::surface::ray_intersection(&surface, origin, dir, 0.01).unwrap();
+    assert_near!(u0, u);
+    assert_near!(v0, v);
+    assert_near!(t, 2.0 * dir.magnitude());
+
+    // missing the surface
+    let res = algo::surface::ray_intersection(&surface, origin, -dir, 0.01);
+    assert!(res.is_none());
+
+    // a channel whose two walls are hit by the horizontal ray, and the nearer one is returned
+    let knot_vecs = (KnotVec::bezier_knot(3), KnotVec::bezier_knot(1));
+    let ctrl_pts = [(0.0, 1.0), (0.0, -1.0), (1.0, -1.0), (1.0, 1.0)]
+        .into_iter()
+        .map(|(x, z)| vec![Point3::new(x, 0.0, z), Point3::new(x, 1.0, z)])
+        .collect();
+    let channel = BSplineSurface::new(knot_vecs, ctrl_pts);
+    let origin = Point3::new(2.0, 0.5, 0.2);
+    let dir = -Vector3::unit_x();
+    let (u, v, t) = algo::surface::ray_intersection(&channel, origin, dir, 0.01).unwrap();
+    assert!(u > 0.5);
+    assert_near!(v, 0.5);
+    assert_near!(channel.subs(u, v), origin + t * dir);
+    let origin = Point3::new(-1.0, 0.5, 0.2);
+    let (u, v, t) = algo::surface::ray_intersection(&channel, origin, -dir, 0.01).unwrap();
+    assert!(u < 0.5);
+    assert_near!(channel.subs(u, v), origin - t * dir);
+}
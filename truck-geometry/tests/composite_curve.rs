@@ -0,0 +1,69 @@
+use truck_geometry::prelude::*;
+
+fn two_segments() -> CompositeCurve<BSplineCurve<Point3>> {
+    let curve0 = BSplineCurve::new(
+        KnotVec::bezier_knot(2),
+        vec![
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(1.0, 1.0, 0.0),
+            Point3::new(2.0, 0.0, 0.0),
+        ],
+    );
+    // the parameter range is [0.5, 2.5]
+    let curve1 = BSplineCurve::new(
+        KnotVec::from(vec![0.5, 0.5, 0.5, 0.5, 2.5, 2.5, 2.5, 2.5]),
+        vec![
+            Point3::new(2.0, 0.0, 0.0),
+            Point3::new(3.0, -1.0, 0.0),
+            Point3::new(4.0, 0.0, 1.0),
+            Point3::new(5.0, 0.0, 0.0),
+        ],
+    );
+    CompositeCurve::new(vec![curve0, curve1])
+}
+
+#[test]
+fn composite_curve_is_continuous() {
+    let curve = two_segments();
+    let (curve0, curve1) = (&curve.curves()[0], &curve.curves()[1]);
+    assert_eq!(curve.range_tuple(), (0.0, 3.0));
+    assert_eq!(curve.joints(), &[0.0, 1.0, 3.0]);
+    assert_near!(curve.subs(0.5), curve0.subs(0.5));
+    assert_near!(curve.subs(2.0), curve1.subs(1.5));
+
+    // no gap at the joint
+    let eps = 1.0e-8;
+    assert!(curve.subs(1.0 - eps).distance(curve.subs(1.0 + eps)) < 1.0e-6);
+    assert_near!(curve.subs(1.0), Point3::new(2.0, 0.0, 0.0));
+    assert_eq!(curve.front(), Point3::new(0.0, 0.0, 0.0));
+    assert_eq!(curve.back(), Point3::new(5.0, 0.0, 0.0));
+}
+
+#[test]
+fn composite_curve_division() {
+    let curve = two_segments();
+    let tol = 0.01;
+    let (params, points) = curve.parameter_division(curve.range_tuple(), tol);
+    assert_eq!(params.len(), points.len());
+    assert_eq!(params[0], 0.0);
+    assert_eq!(params[params.len() - 1], 3.0);
+    assert!(params.windows(2).all(|t| t[0] < t[1]));
+    assert!(params.contains(&1.0));
+    params.iter().zip(&points).for_each(|(t, p)| {
+        assert_near!(curve.subs(*t), *p);
+    });
+    params.windows(2).for_each(|t| {
+        let mid = (curve.subs(t[0]) + curve.subs(t[1]).to_vec()) / 2.0;
+        let dist = mid.distance(curve.subs((t[0] + t[1]) / 2.0));
+        assert!(dist < tol, "{dist}");
+    });
+
+    // a part of the range in the second segment
+    let (params, _) = curve.parameter_division((1.5, 2.5), tol);
+    assert_eq!(params[0], 1.5);
+    assert_eq!(params[params.len() - 1], 2.5);
+    // a degenerate range
+    let (params, points) = curve.parameter_division((2.0, 2.0), tol);
+    assert!(params.iter().all(|t| *t == 2.0));
+    assert_near!(points[0], curve.subs(2.0));
+}
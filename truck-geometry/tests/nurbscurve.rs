@@ -21,6 +21,36 @@ fn nurbs_circle() {
     }
 }
 
+#[test]
+fn move_control_point() {
+    let knot_vec = KnotVec::bezier_knot(2);
+    let control_points = vec![
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+        Vector4::new(2.0, 0.0, 0.0, 2.0),
+        Vector4::new(2.0, 0.0, 0.0, 1.0),
+    ];
+    let mut curve = NurbsCurve::new(BSplineCurve::new(knot_vec, control_points));
+    assert_near!(curve.control_point_position(1), Point3::new(1.0, 0.0, 0.0));
+
+    curve.set_control_point_position(1, Point3::new(1.0, 3.0, 0.0));
+    assert_near!(curve.control_point(1).weight(), 2.0);
+    assert_near!(curve.control_point_position(1), Point3::new(1.0, 3.0, 0.0));
+    // The middle of the curve is pulled toward the moved control point.
+    let p = curve.subs(0.5);
+    assert_near!(p.x, 1.0);
+    assert!(p.y > 1.5);
+}
+
+#[test]
+#[should_panic(expected = "out of range")]
+fn control_point_out_of_range() {
+    let curve = NurbsCurve::new(BSplineCurve::new(
+        KnotVec::bezier_knot(1),
+        vec![Vector3::new(0.0, 0.0, 1.0), Vector3::new(1.0, 0.0, 1.0)],
+    ));
+    curve.control_point_position(2);
+}
+
 proptest! {
     #[test]
     fn test_der_n(
@@ -137,3 +167,77 @@ fn test_parameter_division() {
         assert!(dist < tol, "large distance: {dist}");
     }
 }
+
+#[test]
+fn arclength_reparameterization() {
+    // a cubic Bézier whose speed varies strongly
+    let curve = NurbsCurve::new(BSplineCurve::new(
+        KnotVec::bezier_knot(3),
+        vec![
+            Vector4::new(0.0, 0.0, 0.0, 1.0),
+            Vector4::new(0.1, 0.0, 0.0, 1.0),
+            Vector4::new(3.0, 2.0, 1.0, 2.0),
+            Vector4::new(4.0, 0.0, 1.0, 1.0),
+        ],
+    ));
+    let reparam = curve.reparameterized_by_arclength(TOLERANCE);
+    let (s0, s1) = reparam.range_tuple();
+    assert_eq!(s0, 0.0);
+    assert_near!(reparam.front(), curve.front());
+    assert_near!(reparam.back(), curve.back());
+
+    // compares with the length of a fine polyline
+    const N: usize = 10000;
+    let polyline_length = (0..N).fold(0.0, |sum, i| {
+        let (t0, t1) = (i as f64 / N as f64, (i + 1) as f64 / N as f64);
+        sum + curve.subs(t0).distance(curve.subs(t1))
+    });
+    assert!(f64::abs(s1 - polyline_length) < 1.0e-5);
+
+    const EPS: f64 = 1.0e-4;
+    (1..20).for_each(|i| {
+        let s = s1 * i as f64 / 20.0;
+        assert_near!(reparam.der(s).magnitude(), 1.0);
+        // The chord of a small interval has the length of the interval.
+        let chord = reparam.subs(s + EPS).distance(reparam.subs(s - EPS));
+        assert!(f64::abs(chord - 2.0 * EPS) < 1.0e-8);
+        // The higher derivatives agree with the differences.
+        let der2 = (reparam.der(s + EPS) - reparam.der(s - EPS)) / (2.0 * EPS);
+        assert!((reparam.der2(s) - der2).magnitude() < 1.0e-5);
+        assert!(reparam.der(s).dot(reparam.der2(s)).so_small());
+        let der3 = (reparam.der2(s + EPS) - reparam.der2(s - EPS)) / (2.0 * EPS);
+        assert!((reparam.der_n(3, s) - der3).magnitude() < 1.0e-4);
+    });
+}
+
+#[test]
+fn subcurve_of_arc() {
+    // the half of the unit circle
+    let knot_vec = KnotVec::from(vec![0.0, 0.0, 0.0, 1.0, 1.0, 2.0, 2.0, 2.0]);
+    let control_points = vec![
+        Vector4::new(1.0, 0.0, 0.0, 1.0),
+        Vector4::new(1.0, 1.0, 0.0, 1.0),
+        Vector4::new(0.0, 2.0, 0.0, 2.0),
+        Vector4::new(-1.0, 1.0, 0.0, 1.0),
+        Vector4::new(-1.0, 0.0, 0.0, 1.0),
+    ];
+    let curve = NurbsCurve::new(BSplineCurve::new(knot_vec, control_points));
+
+    let sub = curve.subcurve(0.3, 1.6);
+    assert_eq!(sub.range_tuple(), (0.3, 1.6));
+    assert_near!(sub.front(), curve.subs(0.3));
+    assert_near!(sub.back(), curve.subs(1.6));
+    const N: usize = 20;
+    (0..=N).for_each(|i| {
+        let t = 0.3 + 1.3 * i as f64 / N as f64;
+        let p = sub.subs(t);
+        assert_near!(p.to_vec().magnitude(), 1.0);
+        assert_near!(p.z, 0.0);
+        assert_near!(p, curve.subs(t));
+    });
+    assert!(sub.control_points().iter().all(|v| v.weight() > 0.0));
+
+    // the subcurve on the whole range is the same curve
+    let whole = curve.subcurve(0.0, 2.0);
+    assert!(whole.near_as_curve(&curve));
+}
@@ -41,6 +41,35 @@ fn nurbs_sphere() {
     }
 }
 
+#[test]
+fn move_control_point() {
+    let knot_vec = KnotVec::bezier_knot(2);
+    let control_points = (0..3)
+        .map(|i| {
+            (0..3)
+                .map(|j| Vector4::new(i as f64, j as f64, 0.0, 1.0) * (1.0 + (i * j) as f64))
+                .collect()
+        })
+        .collect();
+    let mut surface = NurbsSurface::new(BSplineSurface::new(
+        (knot_vec.clone(), knot_vec),
+        control_points,
+    ));
+    assert_near!(
+        surface.control_point_position(1, 1),
+        Point3::new(1.0, 1.0, 0.0)
+    );
+
+    surface.set_control_point_position(1, 1, Point3::new(1.0, 1.0, 3.0));
+    assert_near!(surface.control_point(1, 1).weight(), 2.0);
+    assert_near!(
+        surface.control_point_position(1, 1),
+        Point3::new(1.0, 1.0, 3.0)
+    );
+    // The middle of the surface is pulled toward the moved control point.
+    assert!(surface.subs(0.5, 0.5).z > 0.5);
+}
+
 proptest! {
     #[test]
     fn test_der_mn(
@@ -82,3 +111,22 @@ proptest! {
         prop_assert!((der0 - der1).magnitude() < 0.01 * der0.magnitude());
     }
 }
+
+#[test]
+fn nurbs_bounding_box() {
+    let surface = NurbsSurface::new(BSplineSurface::new(
+        (KnotVec::bezier_knot(1), KnotVec::bezier_knot(1)),
+        vec![
+            vec![
+                Vector4::new(0.0, 0.0, 0.0, 1.0),
+                Vector4::new(0.0, 2.0, 0.0, 2.0),
+            ],
+            vec![
+                Vector4::new(1.0, 0.0, 0.0, 1.0),
+                Vector4::new(2.0, 2.0, 2.0, 2.0),
+            ],
+        ],
+    ));
+    let bdd = surface.bounding_box(((0.0, 1.0), (0.0, 1.0)), 0.01);
+    assert_eq!(bdd, surface.roughly_bounding_box());
+}
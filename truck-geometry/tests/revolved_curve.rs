@@ -176,3 +176,29 @@ fn include_curve_abnormal1() {
     let line = BSplineCurve::new(KnotVec::bezier_knot(1), vec![pt0, pt1]);
     assert!(!surface.include(&line));
 }
+
+#[test]
+fn search_all_parameters_on_seam() {
+    let line = BSplineCurve::new(
+        KnotVec::bezier_knot(1),
+        vec![Point3::new(1.0, 0.0, 0.0), Point3::new(1.0, 0.0, 1.0)],
+    );
+    let cylinder = RevolutedCurve::by_revolution(line, Point3::origin(), Vector3::unit_z());
+
+    let seam = Point3::new(1.0, 0.0, 0.5);
+    let params = algo::surface::search_all_parameters(&cylinder, seam, 1.0e-3);
+    assert_eq!(params.len(), 2);
+    assert_near!(params[0].0, 0.5);
+    assert_near!(params[0].1, 0.0);
+    assert_near!(params[1].0, 0.5);
+    assert_near!(params[1].1, 2.0 * PI);
+
+    let point = cylinder.subs(0.25, 1.0);
+    let params = algo::surface::search_all_parameters(&cylinder, point, 1.0e-3);
+    assert_eq!(params.len(), 1);
+    assert_near!(params[0].0, 0.25);
+    assert_near!(params[0].1, 1.0);
+
+    let far = Point3::new(2.0, 0.0, 0.5);
+    assert!(algo::surface::search_all_parameters(&cylinder, far, 1.0e-3).is_empty());
+}
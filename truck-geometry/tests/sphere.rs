@@ -85,3 +85,45 @@ fn sphere_derivation_test() {
         }
     }
 }
+
+#[test]
+fn bounding_box() {
+    let center = Point3::new(1.0, 2.0, 3.0);
+    let sphere = Sphere::new(center, 2.0);
+    let bdd = sphere.bounding_box(((0.0, PI), (0.0, 2.0 * PI)), 0.01);
+    let exact = BoundingBox::from_iter([
+        center - Vector3::from_value(2.0),
+        center + Vector3::from_value(2.0),
+    ]);
+    assert_eq!(bdd + exact, bdd);
+    // conservative, but not too loose
+    assert!(bdd.diagonal().magnitude() < exact.diagonal().magnitude() * 1.1);
+
+    // the upper hemisphere
+    let bdd = sphere.bounding_box(((0.0, PI / 2.0), (0.0, 2.0 * PI)), 0.01);
+    assert!(bdd.contains(center + Vector3::new(0.0, 0.0, 2.0)));
+    assert!(bdd.min().z > center.z - 0.2);
+}
+
+#[test]
+fn curvatures() {
+    let radius = 2.5;
+    let sphere = Sphere::new(Point3::new(1.0, -2.0, 3.0), radius);
+    (1..10).for_each(|i| {
+        (0..10).for_each(|j| {
+            let (u, v) = (PI * i as f64 / 10.0, 2.0 * PI * j as f64 / 10.0);
+            assert_near!(sphere.gaussian_curvature(u, v), 1.0 / (radius * radius));
+            assert_near!(sphere.mean_curvature(u, v), -1.0 / radius);
+        })
+    });
+    // The parametrization is singular at the poles.
+    assert!(sphere.gaussian_curvature(0.0, 0.0).is_nan());
+
+    let plane = Plane::new(
+        Point3::origin(),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 2.0, 1.0),
+    );
+    assert_near!(plane.gaussian_curvature(0.3, 0.4), 0.0);
+    assert_near!(plane.mean_curvature(0.3, 0.4), 0.0);
+}
@@ -96,3 +96,13 @@ proptest! {
         prop_assert!((der0 - der1).magnitude() < 0.01 * der0.magnitude());
     }
 }
+
+#[test]
+fn search_all_parameters_on_seams() {
+    let torus = Torus::new(Point3::origin(), 2.0, 1.0);
+    let params = algo::surface::search_all_parameters(&torus, torus.subs(0.0, 0.0), 1.0e-3);
+    assert_eq!(params.len(), 4);
+    params
+        .iter()
+        .for_each(|(u, v)| assert_near!(torus.subs(*u, *v), torus.subs(0.0, 0.0)));
+}
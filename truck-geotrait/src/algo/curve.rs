@@ -1,4 +1,6 @@
 use super::*;
+use itertools::iproduct;
+use std::ops::Bound;
 use surface::{SsnpVector, SspVector};
 
 /// Divides the domain into equal parts, examines all the values, and returns `t` such that `curve.subs(t)` is closest to `point`.
@@ -238,3 +240,192 @@ where
 {
     surface::search_parameter(&SubSurface { curve0, curve1 }, P::origin(), hint, trials)
 }
+
+/// The object-safe interface of the bounded planar curves, by which [`all_intersections`]
+/// accepts the sets of curves of different types, e.g. `&[&dyn BoundedCurve2DObject]`.
+///
+/// This is implemented for all bounded planar curves.
+pub trait BoundedCurve2DObject {
+    /// Substitutes the parameter `t`, cf. [`ParametricCurve::subs`].
+    fn subs_object(&self, t: f64) -> Point2;
+    /// Returns the derivation, cf. [`ParametricCurve::der`].
+    fn der_object(&self, t: f64) -> Vector2;
+    /// Returns the 2nd-order derivation, cf. [`ParametricCurve::der2`].
+    fn der2_object(&self, t: f64) -> Vector2;
+    /// Returns the `n`th-order derivation, cf. [`ParametricCurve::der_n`].
+    fn der_n_object(&self, n: usize, t: f64) -> Vector2;
+    /// Returns the parameter range, cf. [`BoundedCurve::range_tuple`].
+    fn range_tuple_object(&self) -> (f64, f64);
+}
+
+impl<C: ParametricCurve2D + BoundedCurve> BoundedCurve2DObject for C {
+    #[inline(always)]
+    fn subs_object(&self, t: f64) -> Point2 { self.subs(t) }
+    #[inline(always)]
+    fn der_object(&self, t: f64) -> Vector2 { self.der(t) }
+    #[inline(always)]
+    fn der2_object(&self, t: f64) -> Vector2 { self.der2(t) }
+    #[inline(always)]
+    fn der_n_object(&self, n: usize, t: f64) -> Vector2 { self.der_n(n, t) }
+    #[inline(always)]
+    fn range_tuple_object(&self) -> (f64, f64) { self.range_tuple() }
+}
+
+/// The curve given by the reference to a [`BoundedCurve2DObject`], which may be unsized.
+#[derive(Debug)]
+struct CurveObject<'a, C: ?Sized>(&'a C);
+
+impl<C: ?Sized> Clone for CurveObject<'_, C> {
+    fn clone(&self) -> Self { *self }
+}
+
+impl<C: ?Sized> Copy for CurveObject<'_, C> {}
+
+impl<C: BoundedCurve2DObject + ?Sized> ParametricCurve for CurveObject<'_, C> {
+    type Point = Point2;
+    type Vector = Vector2;
+    #[inline(always)]
+    fn subs(&self, t: f64) -> Point2 { self.0.subs_object(t) }
+    #[inline(always)]
+    fn der(&self, t: f64) -> Vector2 { self.0.der_object(t) }
+    #[inline(always)]
+    fn der2(&self, t: f64) -> Vector2 { self.0.der2_object(t) }
+    #[inline(always)]
+    fn der_n(&self, n: usize, t: f64) -> Vector2 { self.0.der_n_object(n, t) }
+    #[inline(always)]
+    fn parameter_range(&self) -> ParameterRange {
+        let (t0, t1) = self.0.range_tuple_object();
+        (Bound::Included(t0), Bound::Included(t1))
+    }
+}
+
+impl<C: BoundedCurve2DObject + ?Sized> BoundedCurve for CurveObject<'_, C> {}
+
+/// Returns all intersections of the pairs of `curves` as the tuples `(i, j, t, s)`, where
+/// `i < j` and the point of the `i`th curve at `t` is the intersection point of the `j`th curve
+/// at `s`.
+///
+/// # Details
+/// The curves may be of different types by the trait objects [`BoundedCurve2DObject`]. Each
+/// curve is divided into a polyline by [`parameter_division`] with `tol`. The pairs whose
+/// bounding boxes of the polylines, expanded by `tol`, are disjoint are pruned by sweeping the
+/// boxes along the x-axis, and so are the pairs of the segments with disjoint bounding boxes.
+/// Each crossing of the segments is refined by [`search_intersection_parameter`]. If the
+/// refinement fails or goes out of the ranges, e.g. at a tangency, the crossing of the polylines
+/// is returned as it is, if the points on the curves are within `tol`. The intersections are
+/// sorted by `(i, j, t)`, and the ones of the same pair closer than `tol` are merged.
+///
+/// The overlapping parts of the curves have no isolated intersections, and they are not detected.
+///
+/// # Panics
+/// `tol` must be greater than or equal to `TOLERANCE`.
+pub fn all_intersections<'a, C>(
+    curves: impl IntoIterator<Item = &'a C>,
+    tol: f64,
+) -> Vec<(usize, usize, f64, f64)>
+where
+    C: BoundedCurve2DObject + ?Sized + 'a,
+{
+    nonpositive_tolerance!(tol);
+    let curves = curves.into_iter().map(CurveObject).collect::<Vec<_>>();
+    let polylines = curves
+        .iter()
+        .map(|curve| parameter_division(curve, curve.range_tuple(), tol))
+        .collect::<Vec<_>>();
+    let margin = Vector2::new(tol, tol);
+    let boxes = polylines
+        .iter()
+        .map(|(_, points)| {
+            let bdb = points.iter().collect::<BoundingBox<Point2>>();
+            (bdb.min() - margin, bdb.max() + margin)
+        })
+        .collect::<Vec<_>>();
+    let mut order = (0..curves.len()).collect::<Vec<_>>();
+    order.sort_by(|i, j| boxes[*i].0.x.total_cmp(&boxes[*j].0.x));
+
+    let (order, boxes) = (&order, &boxes);
+    let mut res = order
+        .iter()
+        .enumerate()
+        .flat_map(|(k, &i)| {
+            let (min, max) = boxes[i];
+            order[k + 1..]
+                .iter()
+                .take_while(move |j| boxes[**j].0.x <= max.x)
+                .filter(move |j| boxes[**j].0.y <= max.y && min.y <= boxes[**j].1.y)
+                .map(move |&j| (usize::min(i, j), usize::max(i, j)))
+        })
+        .flat_map(|(i, j)| {
+            let curve0 = (&curves[i], &polylines[i]);
+            let curve1 = (&curves[j], &polylines[j]);
+            let mut params = pair_intersections(curve0, curve1, tol);
+            params.sort_by(|x, y| x.0.total_cmp(&y.0));
+            params.dedup_by(|x, y| curves[i].subs(x.0).distance(curves[i].subs(y.0)) < tol);
+            params.into_iter().map(move |(t, s)| (i, j, t, s))
+        })
+        .collect::<Vec<_>>();
+    res.sort_by(|x, y| (x.0, x.1).cmp(&(y.0, y.1)).then(x.2.total_cmp(&y.2)));
+    res
+}
+
+type Polyline = (Vec<f64>, Vec<Point2>);
+
+fn pair_intersections<C>(
+    (curve0, (params0, points0)): (&C, &Polyline),
+    (curve1, (params1, points1)): (&C, &Polyline),
+    tol: f64,
+) -> Vec<(f64, f64)>
+where
+    C: ParametricCurve2D + BoundedCurve,
+{
+    let (range0, range1) = (curve0.range_tuple(), curve1.range_tuple());
+    let in_range = |t: f64, (t0, t1): (f64, f64)| t0 - TOLERANCE <= t && t <= t1 + TOLERANCE;
+    let margin = Vector2::new(TOLERANCE, TOLERANCE);
+    let segment_boxes = |points: &[Point2]| {
+        let expanded = |bdb: BoundingBox<Point2>| {
+            BoundingBox::from_iter([bdb.min() - margin, bdb.max() + margin])
+        };
+        points
+            .windows(2)
+            .map(|p| expanded(p.iter().collect()))
+            .collect::<Vec<_>>()
+    };
+    let (boxes0, boxes1) = (segment_boxes(points0), segment_boxes(points1));
+    iproduct!(0..boxes0.len(), 0..boxes1.len())
+        .filter(|&(a, b)| !(boxes0[a] ^ boxes1[b]).is_empty())
+        .filter_map(|(a, b)| {
+            let segment0 = (points0[a], points0[a + 1]);
+            let segment1 = (points1[b], points1[b + 1]);
+            let (x, y) = segment_intersection(segment0, segment1)?;
+            let hint = (
+                params0[a] + (params0[a + 1] - params0[a]) * x,
+                params1[b] + (params1[b + 1] - params1[b]) * y,
+            );
+            let refined = search_intersection_parameter(curve0, curve1, hint, 100)
+                .filter(|(t, s)| in_range(*t, range0) && in_range(*s, range1));
+            match refined {
+                Some((t, s)) => Some((t.clamp(range0.0, range0.1), s.clamp(range1.0, range1.1))),
+                None => {
+                    let near = curve0.subs(hint.0).distance(curve1.subs(hint.1)) < tol;
+                    near.then_some(hint)
+                }
+            }
+        })
+        .collect()
+}
+
+/// Returns the ratios `(x, y)` on the segments at their crossing, including the end points.
+fn segment_intersection(
+    (p0, p1): (Point2, Point2),
+    (q0, q1): (Point2, Point2),
+) -> Option<(f64, f64)> {
+    let (d, e, f) = (p1 - p0, q1 - q0, q0 - p0);
+    let cross = |v: Vector2, w: Vector2| v.x * w.y - v.y * w.x;
+    let det = cross(d, e);
+    if det.abs() <= f64::EPSILON * d.magnitude() * e.magnitude() {
+        return None;
+    }
+    let (x, y) = (cross(f, e) / det, cross(f, d) / det);
+    let range = -TOLERANCE..=1.0 + TOLERANCE;
+    (range.contains(&x) && range.contains(&y)).then_some((x, y))
+}
@@ -2,6 +2,7 @@
 
 use crate::traits::*;
 use truck_base::{
+    bounding_box::BoundingBox,
     cgmath64::*,
     hash::HashGen,
     newton::{self, CalcOutput},
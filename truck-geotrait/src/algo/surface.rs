@@ -1,6 +1,8 @@
 use newton::Jacobian;
 
 use super::*;
+use itertools::iproduct;
+use std::ops::Bound;
 
 /// Divides the domain into equal parts, examines all the values, and returns `(u, v)` such that `surface.subs(u, v)` is closest to `point`.
 /// This method is useful to get an efficient hint of `search_nearest_parameter`.
@@ -33,6 +35,39 @@ where
     res
 }
 
+/// Searches the parameter of `point` by `search` from the initial parameter given by `hint`.
+///
+/// # Details
+/// A parameter hint is used as it is. A range hint, or the whole range if there is no hint, is
+/// examined by [`presearch`] with `division`. If the hint is a range, the result out of the range
+/// up to `TOLERANCE` is rejected, so that only the part of the surface on the range is searched.
+pub fn search_with_hint<S>(
+    surface: &S,
+    point: S::Point,
+    hint: SPHint2D,
+    division: usize,
+    search: impl FnOnce((f64, f64)) -> Option<(f64, f64)>,
+) -> Option<(f64, f64)>
+where
+    S: ParametricSurface + BoundedSurface,
+    S::Point: MetricSpace<Metric = f64> + Copy,
+{
+    match hint {
+        SPHint2D::Parameter(u, v) => search((u, v)),
+        SPHint2D::Range(urange, vrange) => {
+            let ((u0, u1), (v0, v1)) = (urange, vrange);
+            let in_range = |(u, v): &(f64, f64)| {
+                u0 - TOLERANCE <= *u
+                    && *u <= u1 + TOLERANCE
+                    && v0 - TOLERANCE <= *v
+                    && *v <= v1 + TOLERANCE
+            };
+            search(presearch(surface, point, (urange, vrange), division)).filter(in_range)
+        }
+        SPHint2D::None => search(presearch(surface, point, surface.range_tuple(), division)),
+    }
+}
+
 /// Vectors whose points returned by the surface that can be the target of [`search_nearest_parameter`].
 pub trait SsnpVector: InnerSpace<Scalar = f64> + Tolerance {
     #[doc(hidden)]
@@ -191,6 +226,54 @@ where
     )
 }
 
+/// Returns all parameters `(u, v)` within one period such that `surface.subs(u, v)` is within
+/// `tol` from `point`.
+///
+/// # Details
+/// One parameter is found by [`SearchNearestParameter`] without hint, and the others are
+/// completed by the periodicity. In each periodic direction with period `p` whose parameter range
+/// starts at `a` (`0.0` if unbounded), the parameter is normalized into `[a, a + p)`, and the
+/// parameter on the seam is returned both as `a` and `a + p`. So a point on a seam has two
+/// parameters, and a point on two seams, e.g. of a torus, has four. The other preimages, e.g.
+/// the arbitrary angles at a pole of a sphere, are not enumerated.
+///
+/// Returns the empty vector if the nearest point is farther than `tol`.
+///
+/// # Panics
+///
+/// `tol` must be more than `TOLERANCE`.
+pub fn search_all_parameters<S>(surface: &S, point: Point3, tol: f64) -> Vec<(f64, f64)>
+where S: ParametricSurface3D + SearchNearestParameter<D2, Point = Point3> {
+    nonpositive_tolerance!(tol);
+    let Some((u, v)) = surface.search_nearest_parameter(point, SPHint2D::None, 100) else {
+        return Vec::new();
+    };
+    if surface.subs(u, v).distance(point) > tol {
+        return Vec::new();
+    }
+    let (urange, vrange) = surface.parameter_range();
+    let us = periodic_representatives(u, surface.u_period(), urange.0);
+    let vs = periodic_representatives(v, surface.v_period(), vrange.0);
+    us.iter()
+        .flat_map(|u| vs.iter().map(move |v| (*u, *v)))
+        .collect()
+}
+
+fn periodic_representatives(t: f64, period: Option<f64>, start: Bound<f64>) -> Vec<f64> {
+    let Some(period) = period else {
+        return vec![t];
+    };
+    let start = match start {
+        Bound::Included(a) | Bound::Excluded(a) => a,
+        Bound::Unbounded => 0.0,
+    };
+    let t = start + (t - start).rem_euclid(period);
+    match (t - start).so_small() || (start + period - t).so_small() {
+        true => vec![start, start + period],
+        false => vec![t],
+    }
+}
+
 /// Searches the parameters of the intersection point of `surface` and `curve`.
 pub fn search_intersection_parameter<C, S>(
     surface: &S,
@@ -215,6 +298,172 @@ where
     }
 }
 
+/// Returns `(u, v, t)` of the nearest intersection of `surface` and the ray
+/// `origin + t * dir.normalize()` with `t >= 0`, so `t` is the distance from `origin`.
+///
+/// # Details
+/// The surface is divided by [`parameter_division`] with `tol` over its whole parameter range,
+/// and each cell is split into two triangles. The hits of the ray on the triangles, slightly
+/// enlarged to be free of cracks, are the seeds of Newton's method for the residual
+/// `surface.subs(u, v) - (origin + t * dir)`. The converged solutions within the parameter range
+/// whose residuals are within `tol` are the intersections, and the one with the least `t` is
+/// returned. Returns `None` if there is no intersection or `dir` is zero.
+///
+/// # Panics
+///
+/// `tol` must be more than `TOLERANCE`.
+pub fn ray_intersection<S>(
+    surface: &S,
+    origin: Point3,
+    dir: Vector3,
+    tol: f64,
+) -> Option<(f64, f64, f64)>
+where
+    S: ParametricSurface3D + BoundedSurface,
+{
+    nonpositive_tolerance!(tol);
+    if dir.magnitude().so_small() {
+        return None;
+    }
+    let dir = dir.normalize();
+    let ((u0, u1), (v0, v1)) = surface.range_tuple();
+    let (udiv, vdiv) = parameter_division(surface, ((u0, u1), (v0, v1)), tol);
+    let points = udiv
+        .iter()
+        .map(|u| {
+            vdiv.iter()
+                .map(|v| surface.subs(*u, *v))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let function = move |Vector3 { x, y, z }| CalcOutput {
+        value: surface.subs(x, y) - (origin + z * dir),
+        derivation: Matrix3::from_cols(surface.uder(x, y), surface.vder(x, y), -dir),
+    };
+    let in_range = |u: f64, v: f64| {
+        u0 - TOLERANCE <= u && u <= u1 + TOLERANCE && v0 - TOLERANCE <= v && v <= v1 + TOLERANCE
+    };
+    let param = |(i, j): (usize, usize)| Vector2::new(udiv[i], vdiv[j]);
+    let cells = (1..udiv.len()).flat_map(|i| (1..vdiv.len()).map(move |j| (i, j)));
+    cells
+        .flat_map(|(i, j)| {
+            let corners = [(i - 1, j - 1), (i, j - 1), (i, j), (i - 1, j)];
+            [[0, 1, 2], [0, 2, 3]].map(|tri| tri.map(|k| corners[k]))
+        })
+        .filter_map(|[a, b, c]| {
+            let triangle = [a, b, c].map(|(i, j)| points[i][j]);
+            let (s, r, t) = ray_triangle(origin, dir, triangle, 0.1)?;
+            let uv = param(a) * (1.0 - s - r) + param(b) * s + param(c) * r;
+            let hint = Vector3::new(uv.x, uv.y, t);
+            let Vector3 { x: u, y: v, z: t } = newton::solve(function, hint, 100).ok()?;
+            let hit = t >= -TOLERANCE
+                && in_range(u, v)
+                && surface.subs(u, v).distance(origin + t * dir) <= tol;
+            hit.then_some((u, v, t))
+        })
+        .min_by(|x, y| x.2.total_cmp(&y.2))
+}
+
+/// Returns the barycentric coordinates `(s, r)` and the parameter `t` of the hit of the ray
+/// `origin + t * dir` on the triangle `p[0] + s * (p[1] - p[0]) + r * (p[2] - p[0])`, by the
+/// Möller-Trumbore algorithm.
+///
+/// # Details
+/// The triangle is enlarged by `margin` times the edges, and `t` may be negative down to `-margin`
+/// times the sum of the lengths of the two edges from `p[0]`. Returns `None` if the ray is
+/// parallel to the triangle or misses it.
+pub fn ray_triangle(
+    origin: Point3,
+    dir: Vector3,
+    p: [Point3; 3],
+    margin: f64,
+) -> Option<(f64, f64, f64)> {
+    let (e0, e1) = (p[1] - p[0], p[2] - p[0]);
+    let h = dir.cross(e1);
+    let det = e0.dot(h);
+    if det.abs() < f64::EPSILON * e0.magnitude() * e1.magnitude() {
+        return None;
+    }
+    let diff = origin - p[0];
+    let s = diff.dot(h) / det;
+    let q = diff.cross(e0);
+    let r = dir.dot(q) / det;
+    let t = e1.dot(q) / det;
+    let inside = s >= -margin && r >= -margin && s + r <= 1.0 + margin;
+    match inside && t >= -margin * (e0.magnitude() + e1.magnitude()) {
+        true => Some((s, r, t)),
+        false => None,
+    }
+}
+
+/// Returns a bounding box including the part of `surface` over `range`.
+///
+/// The surface is sampled on the grid given by `parameter_division` with `tol`, and the box is
+/// expanded by `tol` and the margin estimated from the second derivatives, which bounds the
+/// deviation of the surface from the bilinear interpolation of each cell.
+///
+/// # Panics
+///
+/// `tol` must be more than `TOLERANCE`.
+pub fn bounding_box<S>(
+    surface: &S,
+    range: ((f64, f64), (f64, f64)),
+    tol: f64,
+) -> BoundingBox<Point3>
+where
+    S: ParametricSurface3D + ParameterDivision2D,
+{
+    nonpositive_tolerance!(tol);
+    let (udiv, vdiv) = surface.parameter_division(range, tol);
+    let deviation = |u: f64, v: f64, du: f64, dv: f64| {
+        let (uu, uv, vv) = (
+            surface.uuder(u, v),
+            surface.uvder(u, v),
+            surface.vvder(u, v),
+        );
+        (uu.magnitude() * du * du + 2.0 * uv.magnitude() * du * dv + vv.magnitude() * dv * dv) / 8.0
+    };
+    let margin = iproduct!(udiv.windows(2), vdiv.windows(2)).fold(0.0_f64, |margin, (u, v)| {
+        let (du, dv) = (u[1] - u[0], v[1] - v[0]);
+        let (um, vm) = ((u[0] + u[1]) / 2.0, (v[0] + v[1]) / 2.0);
+        [
+            (u[0], v[0]),
+            (u[0], v[1]),
+            (u[1], v[0]),
+            (u[1], v[1]),
+            (um, vm),
+        ]
+        .into_iter()
+        .fold(margin, |margin, (u, v)| {
+            f64::max(margin, deviation(u, v, du, dv))
+        })
+    });
+    let bdd = udiv
+        .iter()
+        .flat_map(|u| vdiv.iter().map(move |v| surface.subs(*u, *v)))
+        .collect::<BoundingBox<_>>();
+    let margin = Vector3::from_value(margin + tol);
+    [bdd.min() - margin, bdd.max() + margin]
+        .into_iter()
+        .collect()
+}
+
+/// Options of [`parameter_division_with_options`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DivisionOptions {
+    /// The upper bound of the number of the intervals in each direction.
+    pub max_division: usize,
+}
+
+impl Default for DivisionOptions {
+    #[inline(always)]
+    fn default() -> Self {
+        Self {
+            max_division: usize::MAX,
+        }
+    }
+}
+
 /// Creates the surface division
 ///
 /// # Panics
@@ -223,29 +472,77 @@ where
 #[inline(always)]
 pub fn parameter_division<S>(
     surface: &S,
-    (urange, vrange): ((f64, f64), (f64, f64)),
+    range: ((f64, f64), (f64, f64)),
     tol: f64,
 ) -> (Vec<f64>, Vec<f64>)
+where
+    S: ParametricSurface,
+    S::Point: EuclideanSpace<Scalar = f64> + MetricSpace<Metric = f64> + HashGen<f64>,
+{
+    let (udiv, vdiv, _) =
+        parameter_division_with_options(surface, range, tol, DivisionOptions::default());
+    (udiv, vdiv)
+}
+
+/// Creates the surface division with at most `options.max_division` intervals in each direction.
+///
+/// The division is the same as [`parameter_division`] as long as it is within the bound.
+/// Otherwise, each direction stops growing at the bound, and only the intervals with the largest
+/// errors are divided in the last step. The last returned value is `true` if the division has
+/// been truncated, that is, the returned division does not satisfy `tol`.
+///
+/// # Panics
+///
+/// `tol` must be more than `TOLERANCE`, and `options.max_division` must be positive.
+pub fn parameter_division_with_options<S>(
+    surface: &S,
+    (urange, vrange): ((f64, f64), (f64, f64)),
+    tol: f64,
+    options: DivisionOptions,
+) -> (Vec<f64>, Vec<f64>, bool)
 where
     S: ParametricSurface,
     S::Point: EuclideanSpace<Scalar = f64> + MetricSpace<Metric = f64> + HashGen<f64>,
 {
     nonpositive_tolerance!(tol);
+    assert!(options.max_division > 0, "max_division must be positive.");
     let (mut udiv, mut vdiv) = (vec![urange.0, urange.1], vec![vrange.0, vrange.1]);
-    sub_parameter_division(surface, (&mut udiv, &mut vdiv), tol);
-    (udiv, vdiv)
+    let truncated =
+        sub_parameter_division(surface, (&mut udiv, &mut vdiv), tol, options.max_division);
+    (udiv, vdiv, truncated)
+}
+
+/// Evenly thins out the points of `division` so that it has at most `max_division` intervals,
+/// keeping the end points.
+pub(crate) fn thin_out_division(division: Vec<f64>, max_division: usize) -> Vec<f64> {
+    assert!(max_division > 0, "max_division must be positive.");
+    let len = division.len().saturating_sub(1);
+    match len <= max_division {
+        true => division,
+        false => (0..=max_division)
+            .map(|i| division[i * len / max_division])
+            .collect(),
+    }
 }
 
-fn sub_parameter_division<S>(surface: &S, (udiv, vdiv): (&mut Vec<f64>, &mut Vec<f64>), tol: f64)
+/// Returns `true` if some intervals are not divided because of `max_division`.
+fn sub_parameter_division<S>(
+    surface: &S,
+    (udiv, vdiv): (&mut Vec<f64>, &mut Vec<f64>),
+    tol: f64,
+    max_division: usize,
+) -> bool
 where
     S: ParametricSurface,
-    S::Point: EuclideanSpace<Scalar = f64> + MetricSpace<Metric = f64> + HashGen<f64>, {
-    let mut divide_flag0 = vec![false; udiv.len() - 1];
-    let mut divide_flag1 = vec![false; vdiv.len() - 1];
+    S::Point: EuclideanSpace<Scalar = f64> + MetricSpace<Metric = f64> + HashGen<f64>,
+{
+    // the squared errors of the cells requiring division, or zero if not required
+    let mut divide_flag0 = vec![0.0; udiv.len() - 1];
+    let mut divide_flag1 = vec![0.0; vdiv.len() - 1];
 
     for (u, ub) in udiv.windows(2).zip(&mut divide_flag0) {
         for (v, vb) in vdiv.windows(2).zip(&mut divide_flag1) {
-            if *ub && *vb {
+            if *ub > 0.0 && *vb > 0.0 {
                 continue;
             }
             let (u_gen, v_gen) = ((u[0] + u[1]) / 2.0, (v[0] + v[1]) / 2.0);
@@ -265,23 +562,28 @@ where
                     + pt10.to_vec() * p * (1.0 - q)
                     + pt11.to_vec() * p * q,
             );
-            if p0.distance2(pt) > tol * tol {
+            let dist2 = p0.distance2(pt);
+            if dist2 > tol * tol {
                 let delu = pt00.midpoint(pt01).distance(p0) + pt10.midpoint(pt11).distance(p0);
                 let delv = pt00.midpoint(pt10).distance(p0) + pt01.midpoint(pt11).distance(p0);
                 if delu > delv * 2.0 {
-                    *ub = true;
+                    *ub = f64::max(*ub, dist2);
                 } else if delv > delu * 2.0 {
-                    *vb = true;
+                    *vb = f64::max(*vb, dist2);
                 } else {
-                    (*ub, *vb) = (true, true);
+                    *ub = f64::max(*ub, dist2);
+                    *vb = f64::max(*vb, dist2);
                 }
             }
         }
     }
 
+    let truncated0 = cap_division(&mut divide_flag0, max_division);
+    let truncated1 = cap_division(&mut divide_flag1, max_division);
+
     let mut new_udiv = vec![udiv[0]];
     for (u, ub) in udiv.windows(2).zip(divide_flag0) {
-        if ub {
+        if ub > 0.0 {
             new_udiv.push((u[0] + u[1]) / 2.0);
         }
         new_udiv.push(u[1]);
@@ -289,7 +591,7 @@ where
 
     let mut new_vdiv = vec![vdiv[0]];
     for (v, vb) in vdiv.windows(2).zip(divide_flag1) {
-        if vb {
+        if vb > 0.0 {
             new_vdiv.push((v[0] + v[1]) / 2.0);
         }
         new_vdiv.push(v[1]);
@@ -298,6 +600,35 @@ where
     if udiv.len() != new_udiv.len() || vdiv.len() != new_vdiv.len() {
         *udiv = new_udiv;
         *vdiv = new_vdiv;
-        sub_parameter_division(surface, (udiv, vdiv), tol);
+        sub_parameter_division(surface, (udiv, vdiv), tol, max_division) || truncated0 || truncated1
+    } else {
+        truncated0 || truncated1
+    }
+}
+
+/// Unflags the intervals with smaller errors so that the number of intervals after division does
+/// not exceed `max_division`, and returns `true` if some intervals are unflagged.
+///
+/// `flags` are the errors of the intervals to be bisected, or zero for the other intervals, as
+/// in [`parameter_division_with_options`].
+///
+/// # Examples
+/// ```
+/// use truck_geotrait::algo::surface::cap_division;
+/// let mut flags = [0.5, 0.0, 2.0, 1.0];
+/// // only one of the four intervals can be bisected
+/// assert!(cap_division(&mut flags, 5));
+/// assert_eq!(flags, [0.0, 0.0, 2.0, 0.0]);
+/// ```
+pub fn cap_division(flags: &mut [f64], max_division: usize) -> bool {
+    let budget = max_division.saturating_sub(flags.len());
+    let mut flagged = (0..flags.len())
+        .filter(|i| flags[*i] > 0.0)
+        .collect::<Vec<_>>();
+    if flagged.len() <= budget {
+        return false;
     }
+    flagged.sort_by(|i, j| flags[*j].total_cmp(&flags[*i]));
+    flagged[budget..].iter().for_each(|i| flags[*i] = 0.0);
+    true
 }
@@ -188,6 +188,16 @@ where P: EuclideanSpace<Scalar = f64> + MetricSpace<Metric = f64> + HashGen<f64>
     ) -> (Vec<f64>, Vec<f64>) {
         algo::surface::parameter_division(self, range, tol)
     }
+    fn parameter_division_with_options(
+        &self,
+        range: ((f64, f64), (f64, f64)),
+        tol: f64,
+        options: algo::surface::DivisionOptions,
+    ) -> (Vec<f64>, Vec<f64>) {
+        let (udiv, vdiv, _) =
+            algo::surface::parameter_division_with_options(self, range, tol, options);
+        (udiv, vdiv)
+    }
 }
 
 impl<P> PolynomialSurface<P>
@@ -15,6 +15,14 @@ pub trait ParametricCurve: Clone {
     type Vector: Zero + Copy;
     /// Substitutes the parameter `t`.
     fn subs(&self, t: f64) -> Self::Point;
+    /// Substitutes all parameters in `params`.
+    ///
+    /// The default implementation calls [`ParametricCurve::subs`] for each parameter. Curves may
+    /// override it to share the evaluation setup, which works best if `params` is sorted.
+    #[inline(always)]
+    fn subs_many(&self, params: &[f64]) -> Vec<Self::Point> {
+        params.iter().map(|t| self.subs(*t)).collect()
+    }
     /// Returns the derivation.
     fn der(&self, t: f64) -> Self::Vector;
     /// Returns the 2nd-order derivation.
@@ -83,6 +91,8 @@ impl<C: ParametricCurve> ParametricCurve for &C {
     type Vector = C::Vector;
     fn subs(&self, t: f64) -> Self::Point { (*self).subs(t) }
     #[inline(always)]
+    fn subs_many(&self, params: &[f64]) -> Vec<Self::Point> { (*self).subs_many(params) }
+    #[inline(always)]
     fn der(&self, t: f64) -> Self::Vector { (*self).der(t) }
     #[inline(always)]
     fn der2(&self, t: f64) -> Self::Vector { (*self).der2(t) }
@@ -108,6 +118,8 @@ impl<C: ParametricCurve> ParametricCurve for Box<C> {
     type Vector = C::Vector;
     fn subs(&self, t: f64) -> Self::Point { (**self).subs(t) }
     #[inline(always)]
+    fn subs_many(&self, params: &[f64]) -> Vec<Self::Point> { (**self).subs_many(params) }
+    #[inline(always)]
     fn der(&self, t: f64) -> Self::Vector { (**self).der(t) }
     #[inline(always)]
     fn der2(&self, t: f64) -> Self::Vector { (**self).der2(t) }
@@ -1,4 +1,6 @@
 use super::*;
+use crate::algo::surface::{thin_out_division, DivisionOptions};
+use truck_base::{bounding_box::BoundingBox, tolerance::TOLERANCE2};
 
 type Tuple = (f64, f64);
 /// Parametric surface
@@ -149,6 +151,58 @@ pub trait ParametricSurface3D: ParametricSurface<Point = Point3, Vector = Vector
         let abs_vder = cross.dot(cross_vder) / abs;
         (cross_vder * abs - cross * abs_vder) / (abs * abs)
     }
+    /// Returns the Gaussian curvature at `(u, v)`, computed from the first and second
+    /// fundamental forms.
+    ///
+    /// Returns `NaN` at the singular points where `uder` and `vder` are parallel.
+    fn gaussian_curvature(&self, u: f64, v: f64) -> f64 {
+        let ([e0, f0, g0], [e1, f1, g1]) = fundamental_forms(self, u, v);
+        match e0 * g0 - f0 * f0 < TOLERANCE2 {
+            true => f64::NAN,
+            false => (e1 * g1 - f1 * f1) / (e0 * g0 - f0 * f0),
+        }
+    }
+    /// Returns the mean curvature at `(u, v)`, computed from the first and second fundamental forms.
+    ///
+    /// The sign is positive if the surface bends toward [`normal`](ParametricSurface3D::normal),
+    /// e.g. the mean curvature of a sphere with the outward normals is `-1 / radius`.
+    /// Returns `NaN` at the singular points where `uder` and `vder` are parallel.
+    fn mean_curvature(&self, u: f64, v: f64) -> f64 {
+        let ([e0, f0, g0], [e1, f1, g1]) = fundamental_forms(self, u, v);
+        match e0 * g0 - f0 * f0 < TOLERANCE2 {
+            true => f64::NAN,
+            false => (e1 * g0 - 2.0 * f1 * f0 + g1 * e0) / (2.0 * (e0 * g0 - f0 * f0)),
+        }
+    }
+    /// Returns a bounding box conservatively including the part of the surface over `range`.
+    ///
+    /// The default implementation is [`algo::surface::bounding_box`](crate::algo::surface::bounding_box),
+    /// which samples the surface and expands the box by a curvature-based margin.
+    /// Surfaces with exact upper bounds, such as the convex hulls of control points, override it.
+    ///
+    /// # Panics
+    ///
+    /// `tol` must be greater than or equal to `TOLERANCE`.
+    #[inline(always)]
+    fn bounding_box(&self, range: ((f64, f64), (f64, f64)), tol: f64) -> BoundingBox<Point3>
+    where Self: ParameterDivision2D + Sized {
+        crate::algo::surface::bounding_box(self, range, tol)
+    }
+}
+
+/// Returns the coefficients `[E, F, G]` and `[L, M, N]` of the first and second fundamental forms.
+fn fundamental_forms<S>(surface: &S, u: f64, v: f64) -> ([f64; 3], [f64; 3])
+where S: ParametricSurface3D {
+    let (uder, vder) = (surface.uder(u, v), surface.vder(u, v));
+    let normal = surface.normal(u, v);
+    (
+        [uder.dot(uder), uder.dot(vder), vder.dot(vder)],
+        [
+            surface.uuder(u, v).dot(normal),
+            surface.uvder(u, v).dot(normal),
+            surface.vvder(u, v).dot(normal),
+        ],
+    )
 }
 
 impl<S: ParametricSurface3D> ParametricSurface3D for &S {
@@ -193,6 +247,32 @@ pub trait ParameterDivision2D {
     /// `tol` must be greater than or equal to `TOLERANCE`.
     fn parameter_division(&self, range: ((f64, f64), (f64, f64)), tol: f64)
         -> (Vec<f64>, Vec<f64>);
+    /// Creates the surface division with at most `options.max_division` intervals in each
+    /// direction.
+    ///
+    /// The default implementation evenly thins out the division by [`parameter_division`]. The
+    /// surfaces divided by [`parameter_division`](crate::algo::surface::parameter_division)
+    /// override this by [`parameter_division_with_options`], which stops dividing at the bound.
+    ///
+    /// # Panics
+    ///
+    /// `tol` must be greater than or equal to `TOLERANCE`, and `options.max_division` must be
+    /// positive.
+    ///
+    /// [`parameter_division`]: ParameterDivision2D::parameter_division
+    /// [`parameter_division_with_options`]: crate::algo::surface::parameter_division_with_options
+    fn parameter_division_with_options(
+        &self,
+        range: ((f64, f64), (f64, f64)),
+        tol: f64,
+        options: DivisionOptions,
+    ) -> (Vec<f64>, Vec<f64>) {
+        let (udiv, vdiv) = self.parameter_division(range, tol);
+        (
+            thin_out_division(udiv, options.max_division),
+            thin_out_division(vdiv, options.max_division),
+        )
+    }
 }
 
 impl<S: ParameterDivision2D> ParameterDivision2D for &S {
@@ -203,6 +283,14 @@ impl<S: ParameterDivision2D> ParameterDivision2D for &S {
     ) -> (Vec<f64>, Vec<f64>) {
         (*self).parameter_division(range, tol)
     }
+    fn parameter_division_with_options(
+        &self,
+        range: ((f64, f64), (f64, f64)),
+        tol: f64,
+        options: DivisionOptions,
+    ) -> (Vec<f64>, Vec<f64>) {
+        (*self).parameter_division_with_options(range, tol, options)
+    }
 }
 
 impl<S: ParameterDivision2D> ParameterDivision2D for Box<S> {
@@ -213,4 +301,12 @@ impl<S: ParameterDivision2D> ParameterDivision2D for Box<S> {
     ) -> (Vec<f64>, Vec<f64>) {
         (**self).parameter_division(range, tol)
     }
+    fn parameter_division_with_options(
+        &self,
+        range: ((f64, f64), (f64, f64)),
+        tol: f64,
+        options: DivisionOptions,
+    ) -> (Vec<f64>, Vec<f64>) {
+        (**self).parameter_division_with_options(range, tol, options)
+    }
 }
@@ -1,6 +1,8 @@
 #![cfg(feature = "polynomial")]
 
-use truck_base::{cgmath64::*, tolerance::*};
+use std::f64::consts::{FRAC_PI_2, PI};
+use std::ops::Bound;
+use truck_base::{assert_near, cgmath64::*, tolerance::*};
 use truck_geotrait::{polynomial::PolynomialCurve, *};
 
 #[test]
@@ -185,3 +187,67 @@ fn polycurve_intersection_point() {
     println!("searching intersection point error: {}", 10 - count);
     assert!(count >= 7);
 }
+
+#[test]
+fn all_intersections_of_grid() {
+    // the parameter range of the polynomial curves is [-100, 100]
+    let line = |origin: Vector2, dir: Vector2| PolynomialCurve::<Point2>(vec![origin, dir * 0.05]);
+    // four vertical lines and three horizontal lines
+    let verticals = (0..4).map(|i| line(Vector2::new(i as f64, 0.0), Vector2::unit_y()));
+    let horizontals = (0..3).map(|j| line(Vector2::new(0.0, j as f64), Vector2::unit_x()));
+    // a diagonal line crossing all of them, and a line far away
+    let others = [
+        line(Vector2::new(0.0, -0.5), Vector2::new(1.0, 1.0)),
+        line(Vector2::new(0.0, 20.0), Vector2::unit_x()),
+    ];
+    let lines = verticals
+        .chain(horizontals)
+        .chain(others)
+        .collect::<Vec<_>>();
+
+    let res = algo::curve::all_intersections(&lines, 0.01);
+    assert_eq!(res.len(), 4 * 3 + 4 + 3);
+    res.iter().for_each(|(i, j, t, s)| {
+        assert!(i < j);
+        assert_near!(lines[*i].subs(*t), lines[*j].subs(*s));
+    });
+    assert!(res.iter().all(|(i, j, _, _)| *i != 8 && *j != 8));
+    // the crossing of the second vertical line and the third horizontal line
+    let (_, _, t, s) = *res.iter().find(|(i, j, _, _)| (*i, *j) == (1, 6)).unwrap();
+    assert_near!(t, 40.0);
+    assert_near!(s, 20.0);
+}
+
+#[test]
+fn all_intersections_of_mixed_curves() {
+    // the unit circle parametrized by the angle in [0, 2pi]
+    #[derive(Clone, Debug)]
+    struct Circle;
+    impl ParametricCurve for Circle {
+        type Point = Point2;
+        type Vector = Vector2;
+        fn subs(&self, t: f64) -> Point2 { Point2::new(f64::cos(t), f64::sin(t)) }
+        fn der(&self, t: f64) -> Vector2 { Vector2::new(-f64::sin(t), f64::cos(t)) }
+        fn der2(&self, t: f64) -> Vector2 { Vector2::new(-f64::cos(t), -f64::sin(t)) }
+        fn der_n(&self, n: usize, t: f64) -> Vector2 {
+            let angle = t + n as f64 * FRAC_PI_2;
+            Vector2::new(f64::cos(angle), f64::sin(angle))
+        }
+        fn parameter_range(&self) -> ParameterRange {
+            (Bound::Included(0.0), Bound::Included(2.0 * PI))
+        }
+    }
+    impl BoundedCurve for Circle {}
+    // the horizontal line `y = 0.5`, whose parameter range is [-100, 100]
+    let line = PolynomialCurve::<Point2>(vec![Vector2::new(0.0, 0.5), Vector2::new(0.05, 0.0)]);
+
+    let curves: [&dyn algo::curve::BoundedCurve2DObject; 2] = [&Circle, &line];
+    let res = algo::curve::all_intersections(curves, 0.01);
+    assert_eq!(res.len(), 2);
+    res.iter().for_each(|(i, j, t, s)| {
+        assert_eq!((*i, *j), (0, 1));
+        assert_near!(Circle.subs(*t), line.subs(*s));
+    });
+    assert_near!(res[0].2, PI / 6.0);
+    assert_near!(res[1].2, 5.0 * PI / 6.0);
+}
@@ -270,6 +270,37 @@ fn polysurface_division() {
     assert!(count > 8, "wrong answer: {:?}", 10 - count);
 }
 
+#[test]
+fn polysurface_division_cap() {
+    let curve0 = PolynomialCurve::<Point3>(vec![
+        Vector3::new(0.0, 1.0, 0.0),
+        Vector3::new(1.0, 0.0, 0.0),
+        Vector3::new(0.0, 0.0, 3.0),
+    ]);
+    let curve1 = PolynomialCurve::<Point3>(vec![
+        Vector3::new(1.0, 0.0, 0.0),
+        Vector3::new(0.0, 1.0, 0.0),
+        Vector3::new(0.0, 0.0, -2.0),
+    ]);
+    let poly = PolynomialSurface::by_tensor(curve0, curve1);
+    let range = ((-1.0, 1.0), (-1.0, 1.0));
+
+    // The normal case is unchanged.
+    let options = surface::DivisionOptions { max_division: 64 };
+    let (udiv, vdiv, truncated) =
+        surface::parameter_division_with_options(&poly, range, 0.1, options);
+    assert!(!truncated);
+    assert_eq!((udiv, vdiv), surface::parameter_division(&poly, range, 0.1));
+
+    // The tiny tolerance is capped.
+    let (udiv, vdiv, truncated) =
+        surface::parameter_division_with_options(&poly, range, TOLERANCE, options);
+    assert!(truncated);
+    assert!(udiv.len() <= 65 && vdiv.len() <= 65);
+    assert!(udiv.windows(2).all(|u| u[0] < u[1]));
+    assert_eq!((udiv[0], udiv[udiv.len() - 1]), (-1.0, 1.0));
+}
+
 #[test]
 fn test_composite() {
     let curve_vec = vec![
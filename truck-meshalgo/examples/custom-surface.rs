@@ -0,0 +1,105 @@
+//! Tessellate a user-defined analytic surface and output an obj file.
+//!
+//! A surface can be tessellated through the public API if it implements
+//! `ParametricSurface3D`, `ParameterDivision2D` and `SearchParameter<D2, Point = Point3>`.
+//!
+//! ```bash
+//! usage: custom-surface <output obj file>
+//! ```
+//!
+//! The default `<output file>` is custom-surface.obj.
+
+use std::{env, f64::consts::PI, fs::File, ops::Bound::Included};
+use truck_meshalgo::prelude::*;
+use truck_modeling::{builder, Curve, Wire};
+use truck_topology::{Face, Shell};
+
+/// The graph of `amplitude * sin(PI * u) * sin(PI * v)` on `[0, 1] x [0, 1]`.
+#[derive(Clone, Copy, Debug)]
+struct WaveSurface {
+    amplitude: f64,
+}
+
+impl WaveSurface {
+    fn height_der(&self, m: usize, n: usize, u: f64, v: f64) -> f64 {
+        let sin_der = |k: usize, t: f64| PI.powi(k as i32) * f64::sin(PI * t + k as f64 * PI / 2.0);
+        self.amplitude * sin_der(m, u) * sin_der(n, v)
+    }
+}
+
+impl ParametricSurface for WaveSurface {
+    type Point = Point3;
+    type Vector = Vector3;
+    fn subs(&self, u: f64, v: f64) -> Point3 { Point3::new(u, v, self.height_der(0, 0, u, v)) }
+    fn uder(&self, u: f64, v: f64) -> Vector3 { self.der_mn(1, 0, u, v) }
+    fn vder(&self, u: f64, v: f64) -> Vector3 { self.der_mn(0, 1, u, v) }
+    fn uuder(&self, u: f64, v: f64) -> Vector3 { self.der_mn(2, 0, u, v) }
+    fn uvder(&self, u: f64, v: f64) -> Vector3 { self.der_mn(1, 1, u, v) }
+    fn vvder(&self, u: f64, v: f64) -> Vector3 { self.der_mn(0, 2, u, v) }
+    fn der_mn(&self, m: usize, n: usize, u: f64, v: f64) -> Vector3 {
+        let z = self.height_der(m, n, u, v);
+        match (m, n) {
+            (0, 0) => Vector3::new(u, v, z),
+            (1, 0) => Vector3::new(1.0, 0.0, z),
+            (0, 1) => Vector3::new(0.0, 1.0, z),
+            _ => Vector3::new(0.0, 0.0, z),
+        }
+    }
+    fn parameter_range(&self) -> (ParameterRange, ParameterRange) {
+        (
+            (Included(0.0), Included(1.0)),
+            (Included(0.0), Included(1.0)),
+        )
+    }
+}
+
+impl ParametricSurface3D for WaveSurface {}
+
+impl ParameterDivision2D for WaveSurface {
+    fn parameter_division(
+        &self,
+        range: ((f64, f64), (f64, f64)),
+        tol: f64,
+    ) -> (Vec<f64>, Vec<f64>) {
+        algo::surface::parameter_division(self, range, tol)
+    }
+}
+
+impl SearchParameter<D2> for WaveSurface {
+    type Point = Point3;
+    fn search_parameter<H: Into<SPHint2D>>(
+        &self,
+        point: Point3,
+        hint: H,
+        trials: usize,
+    ) -> Option<(f64, f64)> {
+        // The graph is parametrized by its projection, which is the best hint.
+        let hint = match hint.into() {
+            SPHint2D::Parameter(u, v) => (u, v),
+            _ => (point.x, point.y),
+        };
+        algo::surface::search_parameter(self, point, hint, trials)
+    }
+}
+
+fn main() {
+    let args: Vec<_> = env::args().collect();
+    let path = args.get(1).map_or("custom-surface.obj", String::as_str);
+
+    // The boundary of the surface is the unit square on the xy-plane.
+    let v = builder::vertices([
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(1.0, 1.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+    ]);
+    let wire: Wire = (0..4)
+        .map(|i| builder::line(&v[i], &v[(i + 1) % 4]))
+        .collect();
+    let face = Face::new(vec![wire], WaveSurface { amplitude: 0.3 });
+    let shell: Shell<Point3, Curve, WaveSurface> = vec![face].into();
+
+    let poly = shell.triangulation(0.005).to_polygon();
+    let file = File::create(path).expect("failed to create the output file");
+    obj::write(&poly, file).expect("failed to write the mesh");
+}
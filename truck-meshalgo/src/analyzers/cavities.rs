@@ -0,0 +1,100 @@
+use super::*;
+use crate::tessellation::{
+    shell_volume, MeshableShape, MeshableSurface, MeshedShape, PolylineableCurve,
+};
+use truck_topology::{Shell, Solid};
+
+/// The tolerance of the tessellation and the surface integrals in [`solid_with_cavities`].
+const CAVITY_TOLERANCE: f64 = 1.0e-3;
+
+/// The reasons of failures of [`solid_with_cavities`].
+#[derive(Debug, PartialEq, thiserror::Error)]
+pub enum CavityError {
+    /// A shell is not a closed and connected manifold.
+    #[error(transparent)]
+    FromTopology(#[from] truck_topology::errors::Error),
+    /// The parameter search of a boundary point fails in the volume integral of a shell.
+    #[error("The volume of the shell is not determined by the parameter search.")]
+    UndeterminedVolume,
+    /// A cavity is not inside the outer shell.
+    #[error("The cavity must be inside the outer shell.")]
+    CavityNotInside,
+}
+
+/// Creates a solid bounded by the outer shell `outer` and the inner shells `cavities`, i.e. the
+/// solid with internal cavities.
+///
+/// # Details
+/// The orientations of the shells are determined by their signed volumes: `outer` is inverted
+/// if it is oriented inward, and each cavity is inverted if it is oriented outward, so the faces
+/// of the solid are oriented toward the outside of the material. The volumes are the surface
+/// integrals over the trimmed domains of the faces, the same as [`SurfaceArea`], with the
+/// tolerance `1.0e-3`.
+///
+/// A cavity is regarded as nested in `outer` if its front vertex is enclosed by `outer`, which
+/// is judged by [`IncludingPointInDomain::inside`] on the tessellation of `outer` with the same
+/// tolerance. So the distances between the shells must be larger than the tolerance. The
+/// intersections between the shells are not checked.
+///
+/// [`SurfaceArea`]: crate::tessellation::SurfaceArea
+/// # Examples
+/// ```
+/// use truck_meshalgo::analyzers::*;
+/// use truck_modeling::*;
+/// let cube = |p: Point3, q: Point3| -> Shell {
+///     let solid: Solid = primitive::cuboid(BoundingBox::from_iter([p, q]));
+///     solid.into_boundaries().pop().unwrap()
+/// };
+/// let outer = cube(Point3::origin(), Point3::new(3.0, 3.0, 3.0));
+/// let cavity = cube(Point3::new(1.0, 1.0, 1.0), Point3::new(2.0, 2.0, 2.0));
+///
+/// // The cavity is oriented outward, so it is inverted.
+/// let solid = solid_with_cavities(outer.clone(), vec![cavity.clone()]).unwrap();
+/// assert_eq!(solid.boundaries().len(), 2);
+/// let inner = &solid.boundaries()[1];
+/// assert!(inner.iter().zip(&cavity).all(|(f, g)| f.orientation() != g.orientation()));
+/// # assert!(solid.is_geometric_consistent());
+///
+/// // The shell outside `outer` is not a cavity.
+/// let outside = cube(Point3::new(4.0, 0.0, 0.0), Point3::new(5.0, 1.0, 1.0));
+/// assert_eq!(
+///     solid_with_cavities(outer, vec![outside]).unwrap_err(),
+///     CavityError::CavityNotInside,
+/// );
+/// ```
+/// # Failures
+/// - If a shell is not a closed and connected manifold, then returns `CavityError::FromTopology`.
+/// - If the volume of a shell is not determined, then returns `CavityError::UndeterminedVolume`.
+/// - If a cavity is not inside `outer`, then returns `CavityError::CavityNotInside`.
+pub fn solid_with_cavities<C: PolylineableCurve, S: MeshableSurface>(
+    outer: Shell<Point3, C, S>,
+    cavities: Vec<Shell<Point3, C, S>>,
+) -> Result<Solid<Point3, C, S>, CavityError> {
+    let mut boundaries = Solid::try_new([vec![outer], cavities].concat())?.into_boundaries();
+    let invert = |shell: &mut Shell<Point3, C, S>| {
+        shell.face_iter_mut().for_each(|face| {
+            face.invert();
+        })
+    };
+    let volume = |shell: &Shell<Point3, C, S>| {
+        shell_volume(shell, CAVITY_TOLERANCE).ok_or(CavityError::UndeterminedVolume)
+    };
+    if volume(&boundaries[0])? < 0.0 {
+        invert(&mut boundaries[0]);
+    }
+    // SAFETY: `Solid::try_new` has checked that the solid has the outer shell.
+    let (outer, cavities) = boundaries.split_first_mut().unwrap();
+    let mesh = outer.triangulation(CAVITY_TOLERANCE).to_polygon();
+    cavities.iter_mut().try_for_each(|cavity| {
+        // SAFETY: `Solid::try_new` has checked that the shells are closed, so they have vertices.
+        let x = cavity.vertex_iter().next().unwrap().point();
+        if !mesh.inside(x) {
+            return Err(CavityError::CavityNotInside);
+        }
+        if volume(cavity)? > 0.0 {
+            invert(cavity);
+        }
+        Ok(())
+    })?;
+    Ok(Solid::new_unchecked(boundaries))
+}
@@ -1,5 +1,6 @@
 use super::*;
 use array_macro::array;
+use smallvec::SmallVec;
 
 /// Find collisions between two polygon meshes and extract interference lines.
 ///
@@ -22,6 +23,39 @@ pub trait Collision {
     /// # Remarks
     /// The results is not arranged so that included lines make continuous maximal polyline curve.
     fn extract_interference(&self, other: &PolygonMesh) -> Vec<(Point3, Point3)>;
+    /// Returns the pairs of the indices of the intersecting triangles in `self`, in the order of
+    /// [`Faces::triangle_iter`].
+    ///
+    /// # Details
+    /// The candidates are narrowed down by the intervals as [`Collision::extract_interference`].
+    /// The pairs `(i, j)` with `i < j` are sorted. The neighbors touching at the common vertices
+    /// are not reported: the triangles sharing an edge, including the coincident triangles, are
+    /// skipped, and the triangles sharing a vertex are reported only if they intersect apart
+    /// from the vertex. The vertices are common if they are near, even if their position indices
+    /// differ. Same as the other methods, the coplanar triangles are not regarded as intersecting.
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// // a triangle piercing a square
+    /// let mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 1.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///             Point3::new(0.2, 0.5, -1.0),
+    ///             Point3::new(0.8, 0.5, -1.0),
+    ///             Point3::new(0.5, 0.5, 1.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[0, 1, 2], [0, 2, 3], [4, 5, 6]]),
+    /// );
+    /// // The triangles of the square share an edge, so they are not reported.
+    /// assert_eq!(mesh.self_intersections(), vec![(0, 2), (1, 2)]);
+    /// ```
+    fn self_intersections(&self) -> Vec<(usize, usize)>;
 }
 
 impl Collision for PolygonMesh {
@@ -33,6 +67,7 @@ impl Collision for PolygonMesh {
     fn extract_interference(&self, other: &PolygonMesh) -> Vec<(Point3, Point3)> {
         collision(self, other)
     }
+    fn self_intersections(&self) -> Vec<(usize, usize)> { self_intersections(self) }
 }
 
 #[repr(u8)]
@@ -248,6 +283,37 @@ fn are_colliding(poly0: &PolygonMesh, poly1: &PolygonMesh) -> Option<(Point3, Po
     })
 }
 
+fn self_intersections(poly: &PolygonMesh) -> Vec<(usize, usize)> {
+    if poly.positions().is_empty() {
+        return Vec::new();
+    }
+    let unit = hash::take_one_unit(poly.positions()[0]);
+    let tris = poly
+        .faces()
+        .triangle_iter()
+        .map(|face| make_pos_tri(poly, face))
+        .collect::<Vec<_>>();
+    let mut pairs = colliding_segment_pairs(sorted_endpoints(tris.clone(), tris.clone(), unit))
+        .filter(|(idx0, idx1)| idx0 < idx1)
+        .filter(|(idx0, idx1)| !disjoint_bdbs(tris[*idx0], tris[*idx1]))
+        .filter(|(idx0, idx1)| {
+            let (tri0, tri1) = (tris[*idx0], tris[*idx1]);
+            let common = tri0
+                .into_iter()
+                .filter(|p| tri1.iter().any(|q| p.near(q)))
+                .collect::<SmallVec<[_; 3]>>();
+            match (&common[..], collide_triangles(tri0, tri1)) {
+                (_, None) => false,
+                ([], Some(_)) => true,
+                ([p], Some((a, b))) => !(a.near(p) && b.near(p)),
+                _ => false,
+            }
+        })
+        .collect::<Vec<_>>();
+    pairs.sort();
+    pairs
+}
+
 #[test]
 fn collide_triangles_test() {
     let tri0 = [
@@ -0,0 +1,197 @@
+use super::*;
+use rustc_hash::FxHashMap as HashMap;
+use std::cmp::min;
+
+/// The difference between two meshes, returned by [`MeshComparison::diff`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MeshDiff {
+    /// The indices of faces of the former mesh which have no counterpart in the latter mesh.
+    pub removed: Vec<usize>,
+    /// The indices of faces of the latter mesh which have no counterpart in the former mesh.
+    pub added: Vec<usize>,
+    /// The pairs of indices of faces which have the same position indices but are displaced
+    /// more than the tolerance.
+    pub moved: Vec<(usize, usize)>,
+}
+
+impl MeshDiff {
+    /// Returns `true` if there is no difference.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.removed.is_empty() && self.added.is_empty() && self.moved.is_empty()
+    }
+}
+
+/// Compares meshes geometrically, for regression tests with golden meshes.
+pub trait MeshComparison {
+    /// Returns the difference between `self` and `other`.
+    ///
+    /// # Details
+    /// The faces are indexed in the order of [`Faces::face_iter`]. Two faces correspond if they
+    /// have the same number of vertices and the positions coincide within `pos_tol` up to
+    /// cyclic rotation and reversal of the vertex order. So, the result is independent of the
+    /// order of faces and attribute arrays, and of the winding of faces.
+    ///
+    /// The faces which have no geometric counterpart are reported as `moved` if the other mesh
+    /// has a face with the same position indices, and as `removed` or `added` otherwise.
+    ///
+    /// # Panics
+    /// `pos_tol` must be no less than `TOLERANCE`.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// let positions = vec![
+    ///     Point3::new(0.0, 0.0, 0.0),
+    ///     Point3::new(1.0, 0.0, 0.0),
+    ///     Point3::new(1.0, 1.0, 0.0),
+    ///     Point3::new(0.0, 1.0, 0.0),
+    /// ];
+    /// let mesh0 = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: positions.clone(),
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[0, 1, 2], [0, 2, 3]]),
+    /// );
+    /// // the same mesh with another order of positions and faces
+    /// let mesh1 = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: positions.into_iter().rev().collect(),
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[3, 1, 0], [1, 3, 2]]),
+    /// );
+    /// assert!(mesh0.diff(&mesh1, TOLERANCE).is_empty());
+    ///
+    /// let mut mesh2 = mesh0.clone();
+    /// mesh2.positions_mut()[3] = Point3::new(0.0, 2.0, 0.0);
+    /// let diff = mesh0.diff(&mesh2, TOLERANCE);
+    /// assert_eq!(diff.moved, vec![(1, 1)]);
+    /// assert!(diff.removed.is_empty() && diff.added.is_empty());
+    /// ```
+    fn diff(&self, other: &Self, pos_tol: f64) -> MeshDiff;
+    /// Returns `true` if there is no difference between `self` and `other` in the sense of
+    /// [`MeshComparison::diff`].
+    ///
+    /// # Panics
+    /// `pos_tol` must be no less than `TOLERANCE`.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// let mesh0 = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[0, 1, 2]]),
+    /// );
+    /// let mut mesh1 = mesh0.clone();
+    /// mesh1.positions_mut()[2] = Point3::new(0.0, 1.001, 0.0);
+    /// assert!(mesh0.approx_eq(&mesh1, 0.01));
+    /// assert!(!mesh0.approx_eq(&mesh1, 0.0001));
+    /// ```
+    fn approx_eq(&self, other: &Self, pos_tol: f64) -> bool { self.diff(other, pos_tol).is_empty() }
+}
+
+impl MeshComparison for PolygonMesh {
+    fn diff(&self, other: &Self, pos_tol: f64) -> MeshDiff {
+        nonpositive_tolerance!(pos_tol);
+        let faces0 = face_positions(self);
+        let faces1 = face_positions(other);
+        let key = |face: &[Point3]| -> [i64; 3] {
+            let c = face
+                .iter()
+                .fold(Vector3::new(0.0, 0.0, 0.0), |s, p| s + p.to_vec());
+            let c = c / face.len() as f64;
+            [c.x, c.y, c.z].map(|x| (x / pos_tol).floor() as i64)
+        };
+        let mut cells = HashMap::<[i64; 3], Vec<usize>>::default();
+        faces1
+            .iter()
+            .enumerate()
+            .for_each(|(i, face)| cells.entry(key(face)).or_default().push(i));
+
+        let mut matched1 = vec![false; faces1.len()];
+        let unmatched0 = faces0
+            .iter()
+            .enumerate()
+            .filter(|(_, face)| {
+                let [x, y, z] = key(face);
+                let found = neighbors()
+                    .filter_map(|[i, j, k]| cells.get(&[x + i, y + j, z + k]))
+                    .flatten()
+                    .find(|&&idx| !matched1[idx] && same_face(face, &faces1[idx], pos_tol));
+                match found {
+                    Some(&idx) => {
+                        matched1[idx] = true;
+                        false
+                    }
+                    None => true,
+                }
+            })
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+
+        let mut indices1 = HashMap::<Vec<usize>, Vec<usize>>::default();
+        other
+            .faces()
+            .face_iter()
+            .enumerate()
+            .filter(|(i, _)| !matched1[*i])
+            .for_each(|(i, face)| indices1.entry(canonical_indices(face)).or_default().push(i));
+        let faces = self.faces().face_iter().collect::<Vec<_>>();
+        let mut diff = MeshDiff::default();
+        unmatched0.into_iter().for_each(|i| {
+            let candidate = indices1
+                .get_mut(&canonical_indices(faces[i]))
+                .and_then(|idcs| idcs.pop());
+            match candidate {
+                Some(j) => {
+                    matched1[j] = true;
+                    diff.moved.push((i, j));
+                }
+                None => diff.removed.push(i),
+            }
+        });
+        diff.added = (0..faces1.len()).filter(|&j| !matched1[j]).collect();
+        diff
+    }
+}
+
+fn face_positions(mesh: &PolygonMesh) -> Vec<Vec<Point3>> {
+    let positions = mesh.positions();
+    mesh.faces()
+        .face_iter()
+        .map(|face| face.iter().map(|v| positions[v.pos]).collect())
+        .collect()
+}
+
+fn neighbors() -> impl Iterator<Item = [i64; 3]> {
+    (0..27).map(|n| [n % 3 - 1, n / 3 % 3 - 1, n / 9 - 1])
+}
+
+fn same_face(face0: &[Point3], face1: &[Point3], tol: f64) -> bool {
+    let n = face0.len();
+    let near = |i: usize, j: usize| face0[i].distance2(face1[j]) <= tol * tol;
+    n == face1.len()
+        && (0..n).any(|s| {
+            (0..n).all(|i| near(i, (s + i) % n)) || (0..n).all(|i| near(i, (s + n - i) % n))
+        })
+}
+
+/// Position indices rotated to start with the minimum, in the lexicographically smaller direction.
+fn canonical_indices(face: &[Vertex]) -> Vec<usize> {
+    let n = face.len();
+    let s = (0..n).min_by_key(|&i| face[i].pos).unwrap_or_default();
+    let forward = (0..n).map(|i| face[(s + i) % n].pos).collect::<Vec<_>>();
+    let backward = (0..n)
+        .map(|i| face[(s + n - i) % n].pos)
+        .collect::<Vec<_>>();
+    min(forward, backward)
+}
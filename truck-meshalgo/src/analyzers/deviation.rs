@@ -0,0 +1,93 @@
+use super::*;
+
+/// The deviation of a mesh from a reference mesh, returned by [`Deviation::deviation_from`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DeviationStats {
+    /// The maximum of the absolute values of the distances.
+    pub max: f64,
+    /// The mean of the distances, i.e. the signed bias for closed references.
+    pub mean: f64,
+    /// The root mean square of the distances.
+    pub rms: f64,
+    /// The distance at each position of the mesh, for color-mapping.
+    pub distances: Vec<f64>,
+}
+
+/// Deviations between meshes, for inspections.
+pub trait Deviation {
+    /// Returns the deviation of `self` from `reference`.
+    ///
+    /// # Details
+    /// The samples are the positions of `self`, so `distances[i]` is the distance at
+    /// `self.positions()[i]`. The distance is the one to the closest point on the triangles of
+    /// `reference`. If `reference` is closed, the distance is signed by
+    /// [`IncludingPointInDomain::inside`] same as [`SignedDistance::signed_distance`], i.e.
+    /// negative inside, so `reference` must be oriented outward. Otherwise, the distances are
+    /// unsigned. The distances are `INFINITY` if `reference` has no triangles, and the statistics
+    /// are zero if `self` has no positions.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// let square = |z: f64, extra: Vec<Point3>| {
+    ///     let mut positions = vec![
+    ///         Point3::new(0.0, 0.0, z),
+    ///         Point3::new(1.0, 0.0, z),
+    ///         Point3::new(1.0, 1.0, z),
+    ///         Point3::new(0.0, 1.0, z),
+    ///     ];
+    ///     positions.extend(extra);
+    ///     PolygonMesh::new(
+    ///         StandardAttributes { positions, ..Default::default() },
+    ///         Faces::from_iter(&[[0, 1, 2, 3]]),
+    ///     )
+    /// };
+    /// // the unused position is also measured
+    /// let mesh = square(0.1, vec![Point3::new(0.5, 0.5, 0.4)]);
+    ///
+    /// // the open reference gives the unsigned distances
+    /// let stats = mesh.deviation_from(&square(0.0, Vec::new()));
+    /// assert_eq!(stats.distances.len(), 5);
+    /// assert_near!(stats.distances[4], 0.4);
+    /// assert_near!(stats.max, 0.4);
+    /// assert_near!(stats.mean, 0.16);
+    /// assert_near!(stats.rms, f64::sqrt(0.2 / 5.0));
+    /// ```
+    fn deviation_from(&self, reference: &PolygonMesh) -> DeviationStats;
+}
+
+impl Deviation for PolygonMesh {
+    fn deviation_from(&self, reference: &PolygonMesh) -> DeviationStats {
+        let grid = TriangleGrid::from_mesh(reference);
+        let signed = reference.shell_condition() == ShellCondition::Closed;
+        let distance = |p: &Point3| {
+            let Some((_, q)) = grid.closest(*p) else {
+                return f64::INFINITY;
+            };
+            match signed && grid.signed_crossings(*p, hash::take_one_unit(*p)) >= 1 {
+                true => -p.distance(q),
+                false => p.distance(q),
+            }
+        };
+        #[cfg(not(target_arch = "wasm32"))]
+        let distances = {
+            use rayon::prelude::*;
+            self.positions()
+                .par_iter()
+                .map(distance)
+                .collect::<Vec<_>>()
+        };
+        #[cfg(target_arch = "wasm32")]
+        let distances = self.positions().iter().map(distance).collect::<Vec<_>>();
+        if distances.is_empty() {
+            return DeviationStats::default();
+        }
+        let len = distances.len() as f64;
+        DeviationStats {
+            max: distances.iter().map(|d| d.abs()).fold(0.0, f64::max),
+            mean: distances.iter().sum::<f64>() / len,
+            rms: f64::sqrt(distances.iter().map(|d| d * d).sum::<f64>() / len),
+            distances,
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use super::*;
+use crate::common::face_normal;
+use rustc_hash::FxHashMap as HashMap;
+
+/// Detects the "hard" edges of meshes, e.g. for drawing crisp lines.
+pub trait FeatureEdges {
+    /// Returns the edges, as the pairs of position indices, whose dihedral angle exceeds
+    /// `dihedral_tol`, and all boundary edges.
+    ///
+    /// # Details
+    /// The dihedral angle of an edge is the angle between the normals of the two incident
+    /// faces, so the edges on flat regions have angle `0`. The edges incident to more than two
+    /// faces are non-manifold, and are always returned same as the boundary edges.
+    /// The neighbors are determined by position indices, so the positions should be shared in
+    /// advance by [`OptimizingFilter::put_together_same_attrs`]. Each pair is sorted, and the
+    /// returned edges are sorted and unique.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// // two triangles folded at a right angle
+    /// let mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///             Point3::new(0.0, 0.0, 1.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[0, 1, 2], [1, 0, 3]]),
+    /// );
+    /// // all edges are boundaries except the folding edge
+    /// assert_eq!(mesh.feature_edges(Rad(2.0)).len(), 4);
+    /// assert_eq!(mesh.feature_edges(Rad(1.0)).len(), 5);
+    /// ```
+    ///
+    /// [`OptimizingFilter::put_together_same_attrs`]: crate::filters::OptimizingFilter::put_together_same_attrs
+    fn feature_edges(&self, dihedral_tol: Rad<f64>) -> Vec<[usize; 2]>;
+    /// Returns the dihedral angles of the manifold edges, the edges incident to exactly two
+    /// faces, as the map from the sorted pairs of position indices.
+    ///
+    /// The angles are the same as the ones compared in [`FeatureEdges::feature_edges`], so the
+    /// edges whose angles exceed `dihedral_tol` are the feature edges except for the boundary
+    /// and non-manifold edges, which are omitted.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::f64::consts::PI;
+    /// use truck_meshalgo::prelude::*;
+    /// // the unit cube
+    /// let positions = (0..8)
+    ///     .map(|i| Point3::new((i & 1) as f64, ((i >> 1) & 1) as f64, (i >> 2) as f64))
+    ///     .collect::<Vec<_>>();
+    /// let mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions,
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[
+    ///         [0, 2, 3, 1],
+    ///         [4, 5, 7, 6],
+    ///         [0, 1, 5, 4],
+    ///         [1, 3, 7, 5],
+    ///         [3, 2, 6, 7],
+    ///         [2, 0, 4, 6],
+    ///     ]),
+    /// );
+    /// let angles = mesh.dihedral_angles();
+    /// assert_eq!(angles.len(), 12);
+    /// assert!(angles.values().all(|angle| angle.0.near(&(PI / 2.0))));
+    ///
+    /// // the diagonals of the triangulated faces are flat
+    /// let mut mesh = mesh;
+    /// mesh.triangulate();
+    /// let angles = mesh.dihedral_angles();
+    /// assert_eq!(angles.len(), 18);
+    /// assert_eq!(angles.values().filter(|angle| angle.0.so_small()).count(), 6);
+    /// ```
+    fn dihedral_angles(&self) -> HashMap<[usize; 2], Rad<f64>>;
+}
+
+impl FeatureEdges for PolygonMesh {
+    fn feature_edges(&self, dihedral_tol: Rad<f64>) -> Vec<[usize; 2]> {
+        let mut res = edge_normals(self)
+            .into_iter()
+            .filter(|(_, normals)| match normals.as_slice() {
+                [n0, n1] => n0.angle(*n1) > dihedral_tol,
+                _ => true,
+            })
+            .map(|(edge, _)| edge)
+            .collect::<Vec<_>>();
+        res.sort();
+        res
+    }
+    fn dihedral_angles(&self) -> HashMap<[usize; 2], Rad<f64>> {
+        edge_normals(self)
+            .into_iter()
+            .filter_map(|(edge, normals)| match normals.as_slice() {
+                [n0, n1] => Some((edge, n0.angle(*n1))),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+/// Returns the normals of the faces incident to each edge.
+fn edge_normals(mesh: &PolygonMesh) -> HashMap<[usize; 2], Vec<Vector3>> {
+    let positions = mesh.positions();
+    let normals = mesh
+        .face_iter()
+        .map(|face| face_normal(positions, face))
+        .collect::<Vec<_>>();
+    edge_faces(mesh.face_iter())
+        .into_iter()
+        .map(|(edge, faces)| (edge, faces.into_iter().map(|(i, _)| normals[i]).collect()))
+        .collect()
+}
@@ -0,0 +1,135 @@
+use super::*;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+/// Discrete geodesic distances on meshes.
+pub trait GeodesicDistance {
+    /// Returns the geodesic distances from the position with index `source` to all positions.
+    ///
+    /// # Details
+    /// The distances are computed by the fast marching method over the triangulated mesh:
+    /// the front is propagated from the source in Dijkstra order, and each position is updated
+    /// by unfolding the triangles whose other two positions are already fixed. If no such
+    /// unfolding is valid, the distance falls back to the length of the path along edges.
+    /// So, the result is exact on planar meshes and an upper bound of the true geodesic distance
+    /// on general meshes.
+    ///
+    /// The positions not connected to `source` have the distance `f64::INFINITY`.
+    /// If `source` is out of range, all distances are `f64::INFINITY`.
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// let positions = vec![
+    ///     Point3::new(0.0, 0.0, 0.0),
+    ///     Point3::new(1.0, 0.0, 0.0),
+    ///     Point3::new(1.0, 1.0, 0.0),
+    ///     Point3::new(0.0, 1.0, 0.0),
+    ///     Point3::new(5.0, 5.0, 5.0),
+    /// ];
+    /// let mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions,
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[0, 1, 3], [1, 2, 3]]),
+    /// );
+    /// let distances = mesh.geodesic_distances(0);
+    /// assert_near!(distances[1], 1.0);
+    /// assert_near!(distances[2], f64::sqrt(2.0));
+    /// assert_eq!(distances[4], f64::INFINITY);
+    /// ```
+    fn geodesic_distances(&self, source: usize) -> Vec<f64>;
+}
+
+impl GeodesicDistance for PolygonMesh {
+    fn geodesic_distances(&self, source: usize) -> Vec<f64> {
+        let positions = self.positions();
+        let mut triangles = vec![Vec::new(); positions.len()];
+        self.faces().triangle_iter().for_each(|tri| {
+            let tri = tri.map(|v| v.pos);
+            tri.iter().for_each(|&i| triangles[i].push(tri));
+        });
+        let mut distances = vec![f64::INFINITY; positions.len()];
+        let mut fixed = vec![false; positions.len()];
+        let mut heap = BinaryHeap::new();
+        if let Some(distance) = distances.get_mut(source) {
+            *distance = 0.0;
+            heap.push(Front(0.0, source));
+        }
+        while let Some(Front(distance, i)) = heap.pop() {
+            if fixed[i] {
+                continue;
+            }
+            fixed[i] = true;
+            triangles[i].iter().for_each(|tri| {
+                let k = (0..3).find(|&k| tri[k] == i).unwrap_or_default();
+                let (j, l) = (tri[(k + 1) % 3], tri[(k + 2) % 3]);
+                [(j, l), (l, j)].into_iter().for_each(|(c, other)| {
+                    if fixed[c] {
+                        return;
+                    }
+                    let along_edge = distance + positions[i].distance(positions[c]);
+                    let unfolded = match fixed[other] {
+                        true => unfolding_distance(
+                            (positions[i], distance),
+                            (positions[other], distances[other]),
+                            positions[c],
+                        ),
+                        false => None,
+                    };
+                    let new = unfolded.map_or(along_edge, |x| f64::min(x, along_edge));
+                    if new < distances[c] {
+                        distances[c] = new;
+                        heap.push(Front(new, c));
+                    }
+                });
+            });
+        }
+        distances
+    }
+}
+
+/// Element of the priority queue, the nearer the greater.
+#[derive(Clone, Copy, Debug)]
+struct Front(f64, usize);
+
+impl PartialEq for Front {
+    fn eq(&self, other: &Self) -> bool { self.cmp(other) == Ordering::Equal }
+}
+
+impl Eq for Front {}
+
+impl PartialOrd for Front {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+
+impl Ord for Front {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.total_cmp(&self.0).then(other.1.cmp(&self.1))
+    }
+}
+
+/// Returns the distance at `c` from the virtual planar source whose distances from `a` and `b` are given.
+///
+/// Returns `None` if the virtual source does not exist or the ray from it to `c` does not pass through
+/// the segment `ab`.
+fn unfolding_distance((a, da): (Point3, f64), (b, db): (Point3, f64), c: Point3) -> Option<f64> {
+    let len = a.distance(b);
+    if len.so_small() {
+        return None;
+    }
+    let e = (b - a) / len;
+    let ac = c - a;
+    let (cx, cy) = (ac.dot(e), (ac - e * ac.dot(e)).magnitude());
+    let sx = (da * da - db * db + len * len) / (2.0 * len);
+    let sy2 = da * da - sx * sx;
+    if sy2 < 0.0 || cy.so_small() {
+        return None;
+    }
+    let sy = -f64::sqrt(sy2);
+    let ix = sx + (cx - sx) * (-sy) / (cy - sy);
+    match (0.0..=len).contains(&ix) {
+        true => Some(f64::hypot(cx - sx, cy - sy)),
+        false => None,
+    }
+}
@@ -1,17 +1,37 @@
 use crate::*;
 
+#[cfg(feature = "tessellation")]
+mod cavities;
 mod collision;
+mod comparison;
+mod deviation;
+mod feature_edges;
+mod geodesic;
 mod in_out_judge;
 mod point_cloud;
+mod signed_distance;
 mod splitting;
 mod topology;
+mod triangle_quality;
+mod uv_distortion;
 mod volume;
+mod wall_thickness;
 
+#[cfg(feature = "tessellation")]
+pub use cavities::{solid_with_cavities, CavityError};
 pub use collision::Collision;
+pub use comparison::{MeshComparison, MeshDiff};
+pub use deviation::{Deviation, DeviationStats};
+pub use feature_edges::FeatureEdges;
+pub use geodesic::GeodesicDistance;
 pub use in_out_judge::IncludingPointInDomain;
 pub use point_cloud::WithPointCloud;
+pub use signed_distance::SignedDistance;
 pub use splitting::ExperimentalSplitters;
 pub use splitting::Splitting;
-pub use topology::Topology;
+pub use topology::{MeshTopology, Topology};
+pub use triangle_quality::TriangleQuality;
 pub use truck_topology::shell::ShellCondition;
-pub use volume::CalcVolume;
+pub use uv_distortion::UvDistortion;
+pub use volume::{CalcVolume, MassProperties};
+pub use wall_thickness::WallThickness;
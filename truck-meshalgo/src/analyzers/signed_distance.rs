@@ -0,0 +1,119 @@
+use super::*;
+use crate::common::TriangleGrid;
+
+/// Signed distances from closed meshes.
+pub trait SignedDistance {
+    /// Returns the distance from `point` to the mesh, negative if `point` is inside.
+    ///
+    /// # Details
+    /// The distance is the minimum distance to the triangles of [`Faces::triangle_iter`], and the
+    /// sign is given by [`IncludingPointInDomain::inside`]. So the mesh must be closed and
+    /// oriented outward for the correct signs. The sign of the points on the mesh is unstable,
+    /// but their distances are near zero anyway. Returns `INFINITY` for the mesh without faces.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// let positions = (0..8)
+    ///     .map(|i| Point3::new((i & 1) as f64, ((i >> 1) & 1) as f64, (i >> 2) as f64))
+    ///     .collect();
+    /// let cube = PolygonMesh::new(
+    ///     StandardAttributes { positions, ..Default::default() },
+    ///     Faces::from_iter(&[
+    ///         [0, 2, 3, 1],
+    ///         [4, 5, 7, 6],
+    ///         [0, 1, 5, 4],
+    ///         [1, 3, 7, 5],
+    ///         [3, 2, 6, 7],
+    ///         [2, 0, 4, 6],
+    ///     ]),
+    /// );
+    /// assert_near!(cube.signed_distance(Point3::new(0.5, 0.5, 0.5)), -0.5);
+    /// assert_near!(cube.signed_distance(Point3::new(0.5, 0.5, 0.8)), -0.2);
+    /// assert_near!(cube.signed_distance(Point3::new(0.5, 0.5, 3.0)), 2.0);
+    /// assert_near!(cube.signed_distance(Point3::new(2.0, 2.0, 0.5)), f64::sqrt(2.0));
+    /// ```
+    fn signed_distance(&self, point: Point3) -> f64;
+    /// Returns the signed distances at the nodes of the grid on `bounds`.
+    ///
+    /// # Details
+    /// The grid has `resolution[i]` nodes along the `i`-th axis, which divide the range of
+    /// `bounds` evenly, including the both ends. The only node along an axis with the resolution
+    /// `1` is on the minimum. The value at the node `(i, j, k)` is [`SignedDistance::signed_distance`]
+    /// at the node, and it is stored at the index `i + resolution[0] * (j + resolution[1] * k)`.
+    /// The triangles are hashed by a uniform grid for the closest points and the crossings of the
+    /// rays, and the nodes are computed in parallel except on WASM. The degenerate triangles are
+    /// ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// let simplex = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///             Point3::new(0.0, 0.0, 1.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[0, 2, 1], [0, 1, 3], [0, 3, 2], [1, 2, 3]]),
+    /// );
+    /// let bounds = BoundingBox::from_iter([Point3::new(-1.0, -1.0, -1.0), Point3::new(1.0, 1.0, 1.0)]);
+    /// let grid = simplex.to_sdf_grid([5, 5, 5], bounds);
+    /// assert_eq!(grid.len(), 125);
+    /// // the node (-1, -1, -1)
+    /// assert_near!(grid[0], f64::sqrt(3.0));
+    /// // the node (0.5, 0, 0), on an edge of the simplex
+    /// assert_near!(grid[3 + 5 * (2 + 5 * 2)], 0.0);
+    /// ```
+    fn to_sdf_grid(&self, resolution: [usize; 3], bounds: BoundingBox<Point3>) -> Vec<f64>;
+}
+
+impl SignedDistance for PolygonMesh {
+    fn signed_distance(&self, point: Point3) -> f64 {
+        let positions = self.positions();
+        let distance2 = self
+            .faces()
+            .triangle_iter()
+            .map(|tri| point_cloud::distance2_point_triangle(point, tri.map(|v| positions[v.pos])))
+            .fold(f64::INFINITY, f64::min);
+        match self.inside(point) {
+            true => -distance2.sqrt(),
+            false => distance2.sqrt(),
+        }
+    }
+    fn to_sdf_grid(&self, resolution: [usize; 3], bounds: BoundingBox<Point3>) -> Vec<f64> {
+        let (min, diag) = (bounds.min(), bounds.max() - bounds.min());
+        let coord = |axis: usize, i: usize| match resolution[axis] {
+            0 | 1 => min[axis],
+            n => min[axis] + diag[axis] * i as f64 / (n - 1) as f64,
+        };
+        let [nx, ny, nz] = resolution;
+        let grid = TriangleGrid::from_mesh(self);
+        let signed_distance = |n: usize| {
+            let (i, j, k) = (n % nx, n / nx % ny, n / (nx * ny));
+            let point = Point3::new(coord(0, i), coord(1, j), coord(2, k));
+            let Some((_, closest)) = grid.closest(point) else {
+                return f64::INFINITY;
+            };
+            let distance = point.distance(closest);
+            match grid.signed_crossings(point, hash::take_one_unit(point)) >= 1 {
+                true => -distance,
+                false => distance,
+            }
+        };
+        #[cfg(not(target_arch = "wasm32"))]
+        let res = {
+            use rayon::prelude::*;
+            (0..nx * ny * nz)
+                .into_par_iter()
+                .map(signed_distance)
+                .collect()
+        };
+        #[cfg(target_arch = "wasm32")]
+        let res = (0..nx * ny * nz).map(signed_distance).collect();
+        res
+    }
+}
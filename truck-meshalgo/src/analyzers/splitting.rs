@@ -128,6 +128,65 @@ pub trait Splitting {
     /// assert_eq!(components.len(), 1);
     /// ```
     fn components(&self, use_normal: bool) -> Vec<Vec<usize>>;
+    /// Returns the bounding boxes of the components.
+    /// # Details
+    /// The components are the ones of [`Splitting::components`] without normals, and the boxes
+    /// are in the same order. The components whose boxes are empty are skipped.
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// use truck_meshalgo::analyzers::*;
+    /// // the two triangles apart
+    /// let mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///             Point3::new(5.0, 0.0, 1.0),
+    ///             Point3::new(6.0, 0.0, 1.0),
+    ///             Point3::new(5.0, 2.0, 1.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[0, 1, 2], [3, 4, 5]]),
+    /// );
+    /// let boxes = mesh.component_bounding_boxes();
+    /// assert_eq!(boxes.len(), 2);
+    /// assert_eq!(boxes[1].min(), Point3::new(5.0, 0.0, 1.0));
+    /// assert_eq!(boxes[1].max(), Point3::new(6.0, 2.0, 1.0));
+    /// ```
+    fn component_bounding_boxes(&self) -> Vec<BoundingBox<Point3>>;
+    /// Returns the components sorted by comparing their bounding boxes with `compare`.
+    /// # Details
+    /// The components are the ones of [`Splitting::component_bounding_boxes`], and the sort is
+    /// stable. Each component is returned with its bounding box.
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// use truck_meshalgo::analyzers::*;
+    /// let mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(5.0, 0.0, 0.0),
+    ///             Point3::new(6.0, 0.0, 0.0),
+    ///             Point3::new(5.0, 1.0, 0.0),
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[0, 1, 2], [3, 4, 5]]),
+    /// );
+    /// // sort along the x-axis
+    /// let sorted = mesh.sort_components_by(|a, b| a.min().x.total_cmp(&b.min().x));
+    /// assert_eq!(sorted[0].0, vec![1]);
+    /// assert_eq!(sorted[1].0, vec![0]);
+    /// assert_eq!(sorted[1].1.min(), Point3::new(5.0, 0.0, 0.0));
+    /// ```
+    fn sort_components_by<F>(&self, compare: F) -> Vec<(Vec<usize>, BoundingBox<Point3>)>
+    where F: FnMut(&BoundingBox<Point3>, &BoundingBox<Point3>) -> std::cmp::Ordering;
 }
 
 impl Splitting for PolygonMesh {
@@ -157,6 +216,37 @@ impl Splitting for PolygonMesh {
         let face_adjacency = self.faces().face_adjacency(use_normal);
         get_components(&face_adjacency)
     }
+
+    fn component_bounding_boxes(&self) -> Vec<BoundingBox<Point3>> {
+        components_with_bounding_boxes(self)
+            .map(|(_, bdb)| bdb)
+            .collect()
+    }
+
+    fn sort_components_by<F>(&self, mut compare: F) -> Vec<(Vec<usize>, BoundingBox<Point3>)>
+    where F: FnMut(&BoundingBox<Point3>, &BoundingBox<Point3>) -> std::cmp::Ordering {
+        let mut components = components_with_bounding_boxes(self).collect::<Vec<_>>();
+        components.sort_by(|(_, a), (_, b)| compare(a, b));
+        components
+    }
+}
+
+/// the components without normals and their bounding boxes, except the empty ones
+fn components_with_bounding_boxes(
+    mesh: &PolygonMesh,
+) -> impl Iterator<Item = (Vec<usize>, BoundingBox<Point3>)> + '_ {
+    let faces = mesh.faces();
+    mesh.components(false)
+        .into_iter()
+        .map(move |component| {
+            let bdb = component
+                .iter()
+                .flat_map(|i| faces[*i].iter())
+                .map(|v| mesh.positions()[v.pos])
+                .collect::<BoundingBox<_>>();
+            (component, bdb)
+        })
+        .filter(|(_, bdb)| !bdb.is_empty())
 }
 
 #[doc(hidden)]
@@ -269,7 +359,7 @@ fn get_components(adjacency: &[Vec<usize>]) -> Vec<Vec<usize>> {
 }
 
 fn is_in_the_plane(positions: &[Point3], normals: &[Vector3], face: &[Vertex], tol2: f64) -> bool {
-    let n = FaceNormal::new(positions, face, 0).normal;
+    let n = face_normal(positions, face);
     for v in face {
         if let Some(nor) = v.nor {
             if n.distance2(normals[nor]) < tol2 {
@@ -1,6 +1,7 @@
 use super::*;
 use itertools::Itertools;
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use std::iter::successors;
 use truck_topology::shell::ShellCondition;
 
 /// Extracts boundaries, and check shell condition: closed or orientation.
@@ -13,6 +14,74 @@ pub trait Topology {
     /// Examples for each condition can be found on the page of
     /// [`ShellCondition`](https://docs.rs/truck-topology/0.2.0/truck_topology/shell/enum.ShellCondition.html).
     fn shell_condition(&self) -> ShellCondition;
+    /// Returns the number of edges incident to each vertex, indexed by the position index.
+    ///
+    /// The length of the returned vector is the number of positions, so the unused positions
+    /// have valence `0`.
+    fn vertex_valences(&self) -> Vec<usize>;
+    /// Returns the position indices of the vertices with irregular valence: not `6` for
+    /// interior vertices and not `4` for boundary vertices.
+    ///
+    /// This is the regularity with respect to triangle meshes, which is useful to evaluate
+    /// meshes before subdivision or after remeshing. The unused positions are not returned.
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// // a fan of six triangles around the position 0
+    /// let faces = Faces::from_iter(&[
+    ///     [0, 1, 2], [0, 2, 3], [0, 3, 4], [0, 4, 5], [0, 5, 6], [0, 6, 1],
+    /// ]);
+    /// assert_eq!(faces.vertex_valences(), vec![6, 3, 3, 3, 3, 3, 3]);
+    /// // The center is regular, and the boundary vertices are not.
+    /// assert_eq!(faces.irregular_vertices(), vec![1, 2, 3, 4, 5, 6]);
+    /// ```
+    fn irregular_vertices(&self) -> Vec<usize>;
+    /// Returns the topological invariants: the Euler characteristic, the numbers of connected
+    /// components and boundary loops, and the genus.
+    ///
+    /// The genus is the sum of the genera of the components, each given by
+    /// `2 - 2 * genus - boundary_loops = euler_characteristic`, and is `None` if the mesh is not
+    /// an oriented manifold. The genus of a closed component counts its handles, e.g. `0` for
+    /// spheres and `1` for tori.
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// // a torus of 4 x 3 quadrangles
+    /// let faces = Faces::from_iter((0..4).flat_map(|i| {
+    ///     (0..3).map(move |j| {
+    ///         let idx = |i: usize, j: usize| 3 * (i % 4) + j % 3;
+    ///         [idx(i, j), idx(i + 1, j), idx(i + 1, j + 1), idx(i, j + 1)]
+    ///     })
+    /// }));
+    /// let info = faces.topology_info();
+    /// assert_eq!(info.euler_characteristic, 0);
+    /// assert_eq!(info.components, 1);
+    /// assert_eq!(info.boundary_loops, 0);
+    /// assert_eq!(info.genus, Some(1));
+    ///
+    /// // two triangles sharing only one vertex
+    /// let faces = Faces::from_iter(&[[0, 1, 2], [0, 3, 4]]);
+    /// let info = faces.topology_info();
+    /// assert!(!info.manifold);
+    /// assert_eq!(info.genus, None);
+    /// ```
+    fn topology_info(&self) -> MeshTopology;
+}
+
+/// The topological invariants of a mesh, returned by [`Topology::topology_info`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MeshTopology {
+    /// the number of vertices minus the number of edges plus the number of faces
+    pub euler_characteristic: isize,
+    /// the number of components connected by faces
+    pub components: usize,
+    /// the number of boundary loops
+    pub boundary_loops: usize,
+    /// whether each edge is shared by at most two faces and the faces around each vertex form
+    /// one fan
+    pub manifold: bool,
+    /// the sum of the genera of the components, or `None` if the mesh is not an oriented manifold
+    pub genus: Option<usize>,
 }
 
 #[derive(Clone, Debug)]
@@ -109,9 +178,150 @@ impl Topology for Faces {
             .collect::<Boundaries>()
             .condition()
     }
+    fn vertex_valences(&self) -> Vec<usize> { valences(self, num_of_positions(self)) }
+    fn irregular_vertices(&self) -> Vec<usize> { irregular_vertices(self, &self.vertex_valences()) }
+    fn topology_info(&self) -> MeshTopology { topology_info(self, num_of_positions(self)) }
 }
 
 impl Topology for PolygonMesh {
     fn extract_boundaries(&self) -> Vec<Vec<usize>> { self.faces().extract_boundaries() }
     fn shell_condition(&self) -> ShellCondition { self.faces().shell_condition() }
+    fn vertex_valences(&self) -> Vec<usize> {
+        let len = usize::max(self.positions().len(), num_of_positions(self.faces()));
+        valences(self.faces(), len)
+    }
+    fn irregular_vertices(&self) -> Vec<usize> {
+        irregular_vertices(self.faces(), &self.vertex_valences())
+    }
+    fn topology_info(&self) -> MeshTopology {
+        let len = usize::max(self.positions().len(), num_of_positions(self.faces()));
+        topology_info(self.faces(), len)
+    }
+}
+
+fn num_of_positions(faces: &Faces) -> usize {
+    faces
+        .face_iter()
+        .flatten()
+        .map(|v| v.pos + 1)
+        .max()
+        .unwrap_or(0)
+}
+
+fn valences(faces: &Faces, num_of_positions: usize) -> Vec<usize> {
+    faces
+        .vertex_adjacency(num_of_positions)
+        .into_iter()
+        .map(|adjacency| adjacency.len())
+        .collect()
+}
+
+fn irregular_vertices(faces: &Faces, valences: &[usize]) -> Vec<usize> {
+    let boundaries: Boundaries = faces.face_iter().flat_map(face_edge_iter).collect();
+    let on_boundary: HashSet<usize> = boundaries.boundary.keys().flatten().copied().collect();
+    valences
+        .iter()
+        .enumerate()
+        .filter(|(i, &valence)| {
+            let regular = match on_boundary.contains(i) {
+                true => 4,
+                false => 6,
+            };
+            valence != 0 && valence != regular
+        })
+        .map(|(i, _)| i)
+        .collect()
+}
+
+fn topology_info(faces: &Faces, num_of_positions: usize) -> MeshTopology {
+    let mut parents = (0..num_of_positions).collect::<Vec<_>>();
+    let mut used = vec![false; num_of_positions];
+    let mut edges = HashSet::<[usize; 2]>::default();
+    // the edges of the link of each vertex, to check that the faces form one fan
+    let mut links = HashMap::<usize, Vec<[usize; 2]>>::default();
+    faces.face_iter().for_each(|face| {
+        let n = face.len();
+        (0..n).for_each(|i| {
+            let (prev, v, next) = (
+                face[(i + n - 1) % n].pos,
+                face[i].pos,
+                face[(i + 1) % n].pos,
+            );
+            used[v] = true;
+            if v != next {
+                edges.insert([usize::min(v, next), usize::max(v, next)]);
+                let (r0, r1) = (root(&mut parents, v), root(&mut parents, next));
+                parents[r0] = r1;
+            }
+            links.entry(v).or_default().push([prev, next]);
+        });
+    });
+    let boundaries: Boundaries = faces.face_iter().flat_map(face_edge_iter).collect();
+    let condition = boundaries.condition();
+    let manifold = condition != ShellCondition::Irregular
+        && links.into_values().all(|link| link_is_connected(&link));
+    let loops = faces.extract_boundaries();
+
+    // (vertices - edges + faces, boundary loops) of each component
+    let mut invariants = HashMap::<usize, (isize, usize)>::default();
+    (0..num_of_positions).filter(|i| used[*i]).for_each(|i| {
+        invariants.entry(root(&mut parents, i)).or_default().0 += 1;
+    });
+    edges.iter().for_each(|edge| {
+        invariants.entry(root(&mut parents, edge[0])).or_default().0 -= 1;
+    });
+    faces
+        .face_iter()
+        .filter(|face| !face.is_empty())
+        .for_each(|face| {
+            invariants
+                .entry(root(&mut parents, face[0].pos))
+                .or_default()
+                .0 += 1;
+        });
+    loops.iter().for_each(|boundary| {
+        invariants
+            .entry(root(&mut parents, boundary[0]))
+            .or_default()
+            .1 += 1;
+    });
+    let oriented = matches!(condition, ShellCondition::Oriented | ShellCondition::Closed);
+    let genus = (manifold && oriented).then(|| {
+        invariants
+            .values()
+            .map(|(chi, loops)| (2 - chi - *loops as isize) as usize / 2)
+            .sum()
+    });
+    MeshTopology {
+        euler_characteristic: invariants.values().map(|(chi, _)| chi).sum(),
+        components: invariants.len(),
+        boundary_loops: loops.len(),
+        manifold,
+        genus,
+    }
+}
+
+/// Returns `true` if the link of a vertex, given by the edges opposite to the vertex, is
+/// connected.
+fn link_is_connected(link: &[[usize; 2]]) -> bool {
+    let mut adjacency = HashMap::<usize, Vec<usize>>::default();
+    link.iter().for_each(|[v, w]| {
+        adjacency.entry(*v).or_default().push(*w);
+        adjacency.entry(*w).or_default().push(*v);
+    });
+    link.first().is_none_or(|&[start, _]| {
+        // the breadth-first search by the fronts of the newly visited vertices
+        let mut visited = HashSet::from_iter([start]);
+        successors(Some(vec![start]), |front: &Vec<usize>| {
+            let front = front
+                .iter()
+                .flat_map(|v| &adjacency[v])
+                .copied()
+                .filter(|w| visited.insert(*w))
+                .collect::<Vec<_>>();
+            (!front.is_empty()).then_some(front)
+        })
+        .for_each(drop);
+        visited.len() == adjacency.len()
+    })
 }
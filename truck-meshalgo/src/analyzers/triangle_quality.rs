@@ -0,0 +1,87 @@
+use super::*;
+
+/// Measures the shapes of the triangles for the quality assurance of meshes.
+pub trait TriangleQuality {
+    /// Returns the radius ratio of each triangle, in the order of [`Faces::triangle_iter`].
+    ///
+    /// # Details
+    /// The radius ratio is the ratio of the inradius to the circumradius, normalized so that it is
+    /// `1` for the equilateral triangles. It decreases to `0` as the triangle degenerates, and the
+    /// triangles with zero-length edges get `0`. The quadrangles and the other polygons are
+    /// reported by their fan triangles, as in [`Faces::triangle_iter`].
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// // a unit square and an equilateral triangle
+    /// let mut faces = Faces::from_iter(&[[0, 1, 2, 3]]);
+    /// faces.push([4, 5, 6]);
+    /// let mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 1.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///             Point3::new(2.0, 0.0, 0.0),
+    ///             Point3::new(3.0, 0.0, 0.0),
+    ///             Point3::new(2.5, f64::sqrt(3.0) / 2.0, 0.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     faces,
+    /// );
+    /// // The triangles come first, and the square is split into two right isosceles triangles.
+    /// let qualities = mesh.triangle_qualities();
+    /// assert_eq!(qualities.len(), 3);
+    /// assert_near!(qualities[0], 1.0);
+    /// assert_near!(qualities[1], 2.0 * (f64::sqrt(2.0) - 1.0));
+    /// assert_near!(qualities[2], 2.0 * (f64::sqrt(2.0) - 1.0));
+    /// ```
+    fn triangle_qualities(&self) -> Vec<f64>;
+
+    /// Returns the minimum of [`TriangleQuality::triangle_qualities`], or `None` if the mesh has
+    /// no faces.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// // the sliver triangle
+    /// let mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(0.5, 0.001, 0.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[0, 1, 2]]),
+    /// );
+    /// assert!(mesh.worst_triangle_quality().unwrap() < 0.01);
+    /// assert_eq!(PolygonMesh::default().worst_triangle_quality(), None);
+    /// ```
+    fn worst_triangle_quality(&self) -> Option<f64> {
+        self.triangle_qualities().into_iter().reduce(f64::min)
+    }
+}
+
+impl TriangleQuality for PolygonMesh {
+    fn triangle_qualities(&self) -> Vec<f64> {
+        let positions = self.positions();
+        self.faces()
+            .triangle_iter()
+            .map(|tri| radius_ratio(tri.map(|v| positions[v.pos])))
+            .collect()
+    }
+}
+
+/// Returns `2 r / R` for the inradius `r` and the circumradius `R`.
+fn radius_ratio([p0, p1, p2]: [Point3; 3]) -> f64 {
+    let (a, b, c) = (p1.distance(p2), p2.distance(p0), p0.distance(p1));
+    let denom = a * b * c;
+    match denom > 0.0 {
+        true => f64::max((b + c - a) * (c + a - b) * (a + b - c) / denom, 0.0),
+        false => 0.0,
+    }
+}
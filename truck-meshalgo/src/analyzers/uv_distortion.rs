@@ -0,0 +1,82 @@
+use super::*;
+
+/// Measures how the texture coordinates stretch the mesh.
+pub trait UvDistortion {
+    /// Returns the L2 texture stretch of Sander et al. for each face, in the order of
+    /// [`Faces::face_iter`].
+    ///
+    /// # Details
+    /// The stretch of a triangle is the root mean square of the singular values of the Jacobian
+    /// from the texture space to 3D, so it is `1` for isometric texture coordinates, greater
+    /// for the faces whose texture is scaled up, and less for the scaled down ones. The stretch
+    /// of a polygon is the combination of the stretches of its fan triangles, weighted by the
+    /// areas in the texture space.
+    ///
+    /// The faces some of whose vertices do not have texture coordinates get `NaN`, and the faces
+    /// with degenerate texture coordinates get `INFINITY`.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// let mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(2.0, 0.0, 0.0),
+    ///             Point3::new(0.0, 2.0, 0.0),
+    ///         ],
+    ///         uv_coords: vec![
+    ///             Vector2::new(0.0, 0.0),
+    ///             Vector2::new(1.0, 0.0),
+    ///             Vector2::new(0.0, 1.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[(0, Some(0), None), (1, Some(1), None), (2, Some(2), None)]]),
+    /// );
+    /// // The triangle in 3D is twice as large as the one in the texture space.
+    /// assert_near!(mesh.uv_distortion()[0], 2.0);
+    /// ```
+    fn uv_distortion(&self) -> Vec<f64>;
+}
+
+impl UvDistortion for PolygonMesh {
+    fn uv_distortion(&self) -> Vec<f64> {
+        let (positions, uv_coords) = (self.positions(), self.uv_coords());
+        self.face_iter()
+            .map(|face| {
+                let uvs = face
+                    .iter()
+                    .map(|v| v.uv.and_then(|i| uv_coords.get(i)))
+                    .collect::<Option<Vec<_>>>();
+                let Some(uvs) = uvs else {
+                    return f64::NAN;
+                };
+                let (sum, area) = (2..face.len()).fold((0.0, 0.0), |(sum, area), k| {
+                    let q = [0, k - 1, k].map(|i| positions[face[i].pos]);
+                    let (stretch2, uv_area) = triangle_stretch2(q, [uvs[0], uvs[k - 1], uvs[k]]);
+                    (sum + stretch2 * uv_area, area + uv_area)
+                });
+                match area > 0.0 {
+                    true => f64::sqrt(sum / area),
+                    false => f64::INFINITY,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Returns the square of the L2 stretch and the area in the texture space.
+fn triangle_stretch2([q0, q1, q2]: [Point3; 3], [p0, p1, p2]: [Vector2; 3]) -> (f64, f64) {
+    let (a, b) = (p1 - p0, p2 - p0);
+    let det = a.x * b.y - a.y * b.x;
+    let uv_area = det.abs() / 2.0;
+    if uv_area < TOLERANCE2 {
+        return (0.0, 0.0);
+    }
+    let (d1, d2) = (q1 - q0, q2 - q0);
+    // the partial derivatives of the affine map from the texture space to 3D
+    let ss = (d1 * b.y - d2 * a.y) / det;
+    let st = (d2 * a.x - d1 * b.x) / det;
+    ((ss.magnitude2() + st.magnitude2()) / 2.0, uv_area)
+}
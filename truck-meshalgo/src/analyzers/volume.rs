@@ -1,6 +1,20 @@
 use super::*;
 use array_macro::array;
 
+/// The mass properties of a solid, returned by [`CalcVolume::mass_properties`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MassProperties {
+    /// The mass, i.e. the volume multiplied by the density.
+    pub mass: f64,
+    /// The center of mass.
+    pub center_of_mass: Point3,
+    /// The inertia tensor about the center of mass, in the coordinates of the mesh.
+    ///
+    /// The diagonal elements are the moments of inertia, and the off-diagonal elements are the
+    /// products of inertia with the negative signs, e.g. `inertia[0][1]` is `-∫ xy dm`.
+    pub inertia: Matrix3,
+}
+
 /// Calculate the volume and the center of gravity of mesh.
 pub trait CalcVolume {
     /// Returns the volume of the mesh if the mesh is closed.
@@ -123,6 +137,92 @@ pub trait CalcVolume {
     /// assert_near!(homog.to_point(), Point3::new(-1.0, 2.0, 1.0));
     /// ```
     fn center_of_gravity(&self) -> Vector4;
+    /// Returns the area of the cross section of the mesh by the plane through `plane_origin`
+    /// perpendicular to `plane_normal`, if the mesh is closed.
+    ///
+    /// # Details
+    /// The triangles are cut by the plane, and the signed areas enclosed by the section segments
+    /// are summed up, seen from `plane_normal`. The segments are oriented by the faces, so the
+    /// areas of the holes are subtracted for the outward meshes. Same as [`CalcVolume::volume`],
+    /// the value is linear, so the area of the solid with multiple boundaries is the sum of the
+    /// areas of the boundaries.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// // the unit cube, oriented outward
+    /// let corners = (0..8)
+    ///     .map(|i| Point3::new((i & 1) as f64, ((i >> 1) & 1) as f64, (i >> 2) as f64))
+    ///     .collect::<Vec<_>>();
+    /// let cube = PolygonMesh::convex_hull(&corners);
+    /// let origin = Point3::new(0.5, 0.5, 0.5);
+    /// assert_near!(cube.cross_section_area(origin, Vector3::unit_z()), 1.0);
+    /// // the diagonal section is a rectangle
+    /// let normal = Vector3::new(1.0, -1.0, 0.0);
+    /// assert_near!(cube.cross_section_area(origin, normal), f64::sqrt(2.0));
+    /// // out of the cube
+    /// let origin = Point3::new(0.5, 0.5, 2.0);
+    /// assert_near!(cube.cross_section_area(origin, Vector3::unit_z()), 0.0);
+    /// ```
+    fn cross_section_area(&self, plane_origin: Point3, plane_normal: Vector3) -> f64;
+    /// Returns the contributions of the faces to [`CalcVolume::volume`].
+    ///
+    /// # Details
+    /// Each value is the signed volume of the cone from the origin to the face, i.e. the sum of the
+    /// signed volumes of the tetrahedra spanned by the origin and the triangles of the face. If the
+    /// mesh is closed, the sum of them is the volume. The flipped faces contribute with the
+    /// opposite signs, so they are found by comparing the contributions of the faces. The faces
+    /// of [`PolygonMesh`] are ordered by [`Faces::face_iter`], and the ones of the solids by
+    /// [`Solid::face_iter`](truck_topology::Solid::face_iter).
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// // the unit cube, oriented outward
+    /// let corners = (0..8)
+    ///     .map(|i| Point3::new((i & 1) as f64, ((i >> 1) & 1) as f64, (i >> 2) as f64))
+    ///     .collect::<Vec<_>>();
+    /// let cube = PolygonMesh::convex_hull(&corners);
+    /// let contributions = cube.face_volume_contributions();
+    /// assert_eq!(contributions.len(), 12);
+    /// // The six triangles through the origin do not contribute, and the others are the cones
+    /// // with the volume `1 / 6`.
+    /// let zeros = contributions.iter().filter(|x| x.so_small()).count();
+    /// assert_eq!(zeros, 6);
+    /// contributions
+    ///     .iter()
+    ///     .filter(|x| !x.so_small())
+    ///     .for_each(|x| assert_near!(*x, 1.0 / 6.0));
+    /// assert_near!(contributions.iter().sum::<f64>(), cube.volume());
+    /// ```
+    fn face_volume_contributions(&self) -> Vec<f64>;
+    /// Returns the mass properties of the solid with the uniform density `density`, if the mesh
+    /// is closed and oriented outward.
+    ///
+    /// # Details
+    /// The integrals of `1`, `x`, `y`, `z` and the quadratic monomials over the solid are reduced
+    /// to the integrals over the triangles by the divergence theorem, which are exact for the
+    /// triangulated faces same as the polyhedral mass algorithm of Mirtich. The integrals are
+    /// taken relative to the center of the bounding box for the numerical stability. Same as
+    /// [`CalcVolume::volume`], the integrals are linear, so the solid with multiple boundaries is
+    /// also supported by the implementation for [`Solid`](truck_topology::Solid). The volume must
+    /// not be zero, otherwise the center and the inertia are not determined.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// // the unit cube, oriented outward
+    /// let corners = (0..8)
+    ///     .map(|i| Point3::new((i & 1) as f64, ((i >> 1) & 1) as f64, (i >> 2) as f64))
+    ///     .collect::<Vec<_>>();
+    /// let cube = PolygonMesh::convex_hull(&corners);
+    /// let properties = cube.mass_properties(2.0);
+    /// assert_near!(properties.mass, 2.0);
+    /// assert_near!(properties.center_of_mass, Point3::new(0.5, 0.5, 0.5));
+    /// // the moment of inertia of the cube is `m a^2 / 6`
+    /// assert_near!(properties.inertia, Matrix3::identity() / 3.0);
+    /// ```
+    fn mass_properties(&self, density: f64) -> MassProperties;
 }
 
 impl CalcVolume for PolygonMesh {
@@ -147,6 +247,50 @@ impl CalcVolume for PolygonMesh {
         });
         Vector4::new(arr[0] / 24.0, arr[1] / 24.0, arr[2] / 24.0, arr[3] / 6.0)
     }
+    fn cross_section_area(&self, plane_origin: Point3, plane_normal: Vector3) -> f64 {
+        let n = plane_normal.normalize();
+        point_triangles(self).fold(0.0, |sum, tri| {
+            let vecs = tri.map(|p| p - plane_origin);
+            let dists = vecs.map(|v| v.dot(n));
+            // The vertices on the plane are regarded as above, so that the adjacent triangles
+            // share the section points on their common edges.
+            let mut section = (0..3).filter_map(|i| {
+                let j = (i + 1) % 3;
+                let (a, b) = (dists[i], dists[j]);
+                let t = a / (a - b);
+                ((a < 0.0) != (b < 0.0)).then(|| vecs[i] + (vecs[j] - vecs[i]) * t)
+            });
+            let (Some(s0), Some(s1)) = (section.next(), section.next()) else {
+                return sum;
+            };
+            let normal = (vecs[1] - vecs[0]).cross(vecs[2] - vecs[0]);
+            let area = n.dot(s0.cross(s1)) / 2.0;
+            match (s1 - s0).dot(n.cross(normal)) < 0.0 {
+                true => sum - area,
+                false => sum + area,
+            }
+        })
+    }
+    fn face_volume_contributions(&self) -> Vec<f64> {
+        let positions = self.positions();
+        self.face_iter()
+            .map(|face| {
+                let p = positions[face[0].pos].to_vec();
+                face[1..]
+                    .windows(2)
+                    .map(|v| {
+                        let (q, r) = (positions[v[0].pos].to_vec(), positions[v[1].pos].to_vec());
+                        p.dot(q.cross(r)) / 6.0
+                    })
+                    .sum::<f64>()
+            })
+            .collect()
+    }
+    fn mass_properties(&self, density: f64) -> MassProperties {
+        let reference = self.positions().iter().collect::<BoundingBox<_>>().center();
+        let integrals = volume_integrals(point_triangles(self), reference);
+        to_mass_properties(integrals, reference, density)
+    }
 }
 
 fn point_triangles(poly: &PolygonMesh) -> impl Iterator<Item = [Point3; 3]> + '_ {
@@ -155,6 +299,61 @@ fn point_triangles(poly: &PolygonMesh) -> impl Iterator<Item = [Point3; 3]> + '_
         .map(|faces| array![i => poly.positions()[faces[i].pos]; 3])
 }
 
+/// Returns the integrals of `1`, `x`, `y`, `z`, `x^2`, `y^2`, `z^2`, `xy`, `yz` and `zx` over the
+/// solid bounded by `triangles`, in the coordinates relative to `reference`.
+///
+/// Each triangle contributes the integrals over the signed tetrahedron spanned by `reference` and
+/// the triangle.
+fn volume_integrals(triangles: impl Iterator<Item = [Point3; 3]>, reference: Point3) -> [f64; 10] {
+    triangles.fold([0.0; 10], |sum, tri| {
+        let [p, q, r] = tri.map(|p| p - reference);
+        let det = p.dot(q.cross(r));
+        let s = p + q + r;
+        // `∫ x_i x_j` over the tetrahedron is `det / 120 * (Σ a_i a_j + s_i s_j)`.
+        let quadratic = |i: usize, j: usize| p[i] * p[j] + q[i] * q[j] + r[i] * r[j] + s[i] * s[j];
+        let values = [
+            det / 6.0,
+            det * s.x / 24.0,
+            det * s.y / 24.0,
+            det * s.z / 24.0,
+            det * quadratic(0, 0) / 120.0,
+            det * quadratic(1, 1) / 120.0,
+            det * quadratic(2, 2) / 120.0,
+            det * quadratic(0, 1) / 120.0,
+            det * quadratic(1, 2) / 120.0,
+            det * quadratic(2, 0) / 120.0,
+        ];
+        array![i => sum[i] + values[i]; 10]
+    })
+}
+
+/// Returns the mass properties from the integrals given by [`volume_integrals`].
+fn to_mass_properties(integrals: [f64; 10], reference: Point3, density: f64) -> MassProperties {
+    let [volume, x, y, z, xx, yy, zz, xy, yz, zx] = integrals;
+    let c = Vector3::new(x, y, z) / volume;
+    // the second moments about the center of mass by the parallel axis theorem
+    let (xx, yy, zz) = (
+        xx - volume * c.x * c.x,
+        yy - volume * c.y * c.y,
+        zz - volume * c.z * c.z,
+    );
+    let (xy, yz, zx) = (
+        xy - volume * c.x * c.y,
+        yz - volume * c.y * c.z,
+        zx - volume * c.z * c.x,
+    );
+    let inertia = Matrix3::from_cols(
+        Vector3::new(yy + zz, -xy, -zx),
+        Vector3::new(-xy, zz + xx, -yz),
+        Vector3::new(-zx, -yz, xx + yy),
+    );
+    MassProperties {
+        mass: density * volume,
+        center_of_mass: reference + c,
+        inertia: inertia * density,
+    }
+}
+
 impl CalcVolume for truck_topology::Solid<Point3, PolylineCurve<Point3>, PolygonMesh> {
     fn volume(&self) -> f64 {
         self.face_iter()
@@ -172,4 +371,51 @@ impl CalcVolume for truck_topology::Solid<Point3, PolylineCurve<Point3>, Polygon
             })
             .sum::<Vector4>()
     }
+    fn cross_section_area(&self, plane_origin: Point3, plane_normal: Vector3) -> f64 {
+        self.face_iter()
+            .map(|face| {
+                let area = face
+                    .surface()
+                    .cross_section_area(plane_origin, plane_normal);
+                match face.orientation() {
+                    true => area,
+                    false => -area,
+                }
+            })
+            .sum::<f64>()
+    }
+    fn face_volume_contributions(&self) -> Vec<f64> {
+        self.face_iter()
+            .map(|face| {
+                let volume = face
+                    .surface()
+                    .face_volume_contributions()
+                    .iter()
+                    .sum::<f64>();
+                match face.orientation() {
+                    true => volume,
+                    false => -volume,
+                }
+            })
+            .collect()
+    }
+    fn mass_properties(&self, density: f64) -> MassProperties {
+        let meshes = self
+            .face_iter()
+            .map(|face| (face.surface(), face.orientation()))
+            .collect::<Vec<_>>();
+        let reference = meshes
+            .iter()
+            .flat_map(|(mesh, _)| mesh.positions())
+            .collect::<BoundingBox<_>>()
+            .center();
+        let integrals = meshes.iter().fold([0.0; 10], |sum, (mesh, orientation)| {
+            let integrals = volume_integrals(point_triangles(mesh), reference);
+            match orientation {
+                true => array![i => sum[i] + integrals[i]; 10],
+                false => array![i => sum[i] - integrals[i]; 10],
+            }
+        });
+        to_mass_properties(integrals, reference, density)
+    }
 }
@@ -0,0 +1,91 @@
+use super::*;
+
+/// Measures the thickness of the walls of closed meshes.
+pub trait WallThickness {
+    /// Returns the local wall thickness at `samples` points on the surface.
+    ///
+    /// # Details
+    /// The sample points are distributed over the faces in proportion to their areas, in the order
+    /// of [`Faces::triangle_iter`]. From each sample point, a ray is cast opposite to the normal
+    /// of the face, and the distance to the nearest other face hit by the ray is the thickness.
+    /// The faces must be oriented outward, e.g. the meshes tessellated from solids. The samples
+    /// whose rays hit nothing get `INFINITY`. The rays are cast through a uniform grid of the
+    /// triangles, in parallel except on `wasm32`.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// // a plate with thickness 0.1
+    /// let positions = (0..8)
+    ///     .map(|i| Point3::new((i & 1) as f64, ((i >> 1) & 1) as f64, ((i >> 2) & 1) as f64 * 0.1))
+    ///     .collect();
+    /// let faces = Faces::from_iter(&[
+    ///     [0, 2, 3, 1],
+    ///     [4, 5, 7, 6],
+    ///     [0, 1, 5, 4],
+    ///     [2, 6, 7, 3],
+    ///     [0, 4, 6, 2],
+    ///     [1, 3, 7, 5],
+    /// ]);
+    /// let mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions,
+    ///         ..Default::default()
+    ///     },
+    ///     faces,
+    /// );
+    /// let thickness = mesh.wall_thickness(100);
+    /// assert_eq!(thickness.len(), 100);
+    /// let thin = thickness.iter().filter(|t| f64::abs(*t - 0.1) < 1.0e-6).count();
+    /// // Almost all samples are on the large faces.
+    /// assert!(thin > 80);
+    /// ```
+    fn wall_thickness(&self, samples: usize) -> Vec<f64>;
+}
+
+impl WallThickness for PolygonMesh {
+    fn wall_thickness(&self, samples: usize) -> Vec<f64> {
+        let grid = TriangleGrid::from_mesh(self);
+        let triangles = grid.triangles();
+        let areas = triangles
+            .iter()
+            .map(|[p, q, r]| (q - p).cross(r - p).magnitude() / 2.0)
+            .collect::<Vec<_>>();
+        let total = areas.iter().sum::<f64>();
+        if samples == 0 || total <= 0.0 {
+            return vec![f64::INFINITY; samples];
+        }
+        let cumulative = areas
+            .iter()
+            .scan(0.0, |sum, area| {
+                *sum += area;
+                Some(*sum)
+            })
+            .collect::<Vec<_>>();
+        let thickness = |i: usize| {
+            let a = total * (i as f64 + 0.5) / samples as f64;
+            let k = cumulative
+                .partition_point(|c| *c <= a)
+                .min(triangles.len() - 1);
+            let [p, q, r] = triangles[k];
+            // The first coordinate is from the position in the cumulative area, and the second
+            // from the golden ratio sequence.
+            let s = ((a - (cumulative[k] - areas[k])) / areas[k])
+                .clamp(0.0, 1.0)
+                .sqrt();
+            let t = (i as f64 * 0.618_033_988_749_895).fract();
+            let origin = p + (q - p) * (s * (1.0 - t)) + (r - p) * (s * t);
+            let direction = -(q - p).cross(r - p).normalize();
+            grid.ray_cast(origin, direction, k)
+                .map_or(f64::INFINITY, |(_, t)| t)
+        };
+        #[cfg(not(target_arch = "wasm32"))]
+        let res = {
+            use rayon::prelude::*;
+            (0..samples).into_par_iter().map(thickness).collect()
+        };
+        #[cfg(target_arch = "wasm32")]
+        let res = (0..samples).map(thickness).collect();
+        res
+    }
+}
@@ -1,7 +1,71 @@
 use super::*;
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 
+/// Returns the key of the undirected edge between the positions `a` and `b`.
+pub fn edge_key(a: usize, b: usize) -> [usize; 2] { [usize::min(a, b), usize::max(a, b)] }
+
+/// Vertices of faces, which refer to positions.
+pub trait PositionIndex: Copy {
+    /// Returns the index of the position.
+    fn position_index(self) -> usize;
+}
+
+impl PositionIndex for usize {
+    fn position_index(self) -> usize { self }
+}
+
+impl PositionIndex for Vertex {
+    fn position_index(self) -> usize { self.pos }
+}
+
+/// Rotates `tri` so that its edge between `a` and `b`, in either direction, comes first, and
+/// returns `None` if there is no such edge.
+#[cfg(feature = "filters")]
+pub fn rotate_to_edge<T: PositionIndex>(tri: [T; 3], a: usize, b: usize) -> Option<[T; 3]> {
+    (0..3)
+        .map(|k| [tri[k], tri[(k + 1) % 3], tri[(k + 2) % 3]])
+        .find(|[p, q, _]| edge_key(p.position_index(), q.position_index()) == edge_key(a, b))
+}
+
+/// Returns the edges of `face` with their indices in the face, keyed by [`edge_key`].
+fn face_edge_keys<T: PositionIndex>(face: &[T]) -> impl Iterator<Item = (usize, [usize; 2])> + '_ {
+    (0..face.len()).map(move |k| {
+        let (a, b) = (face[k], face[(k + 1) % face.len()]);
+        (k, edge_key(a.position_index(), b.position_index()))
+    })
+}
+
+/// Returns the faces incident to each undirected edge, keyed by [`edge_key`]. Each face is given by
+/// its index and the index of the edge in the face.
+pub fn edge_faces<F, T>(
+    faces: impl IntoIterator<Item = F>,
+) -> HashMap<[usize; 2], Vec<(usize, usize)>>
+where
+    F: AsRef<[T]>,
+    T: PositionIndex, {
+    let mut res = HashMap::<[usize; 2], Vec<(usize, usize)>>::default();
+    faces.into_iter().enumerate().for_each(|(i, face)| {
+        face_edge_keys(face.as_ref())
+            .for_each(|(k, edge)| res.entry(edge).or_default().push((i, k)))
+    });
+    res
+}
+
+/// Returns the number of faces incident to each undirected edge, keyed by [`edge_key`].
+#[cfg(feature = "filters")]
+pub fn edge_face_counts<F, T>(faces: impl IntoIterator<Item = F>) -> HashMap<[usize; 2], usize>
+where
+    F: AsRef<[T]>,
+    T: PositionIndex, {
+    let mut res = HashMap::<[usize; 2], usize>::default();
+    faces.into_iter().for_each(|face| {
+        face_edge_keys(face.as_ref()).for_each(|(_, edge)| *res.entry(edge).or_default() += 1)
+    });
+    res
+}
+
 pub trait Adjacency {
+    /// create the adjacency list of the vertices
     #[allow(dead_code)]
     fn vertex_adjacency(&self, num_of_vertices: usize) -> Vec<Vec<usize>>;
     /// create the adjacency list of the faces
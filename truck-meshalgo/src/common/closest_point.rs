@@ -0,0 +1,281 @@
+use super::*;
+#[cfg(feature = "analyzers")]
+use algo::surface::ray_triangle;
+use itertools::Either;
+use rustc_hash::FxHashMap as HashMap;
+#[cfg(feature = "analyzers")]
+use rustc_hash::FxHashSet as HashSet;
+#[cfg(feature = "analyzers")]
+use std::iter::successors;
+use std::{array::from_fn, ops::ControlFlow};
+
+/// The triangles hashed by a uniform grid, for searching the closest points on them.
+#[derive(Clone, Debug)]
+pub(crate) struct TriangleGrid {
+    triangles: Vec<[Point3; 3]>,
+    cells: HashMap<[i64; 3], Vec<usize>>,
+    cell_size: f64,
+    range: [[i64; 3]; 2],
+}
+
+impl TriangleGrid {
+    pub(crate) fn new(triangles: Vec<[Point3; 3]>, cell_size: f64) -> Self {
+        let mut cells = HashMap::<[i64; 3], Vec<usize>>::default();
+        let key = |p: Point3| [p.x, p.y, p.z].map(|x| (x / cell_size).floor() as i64);
+        triangles.iter().enumerate().for_each(|(i, tri)| {
+            let bdd = tri.iter().collect::<BoundingBox<_>>();
+            let (min, max) = (key(bdd.min()), key(bdd.max()));
+            itertools::iproduct!(min[0]..=max[0], min[1]..=max[1], min[2]..=max[2])
+                .for_each(|(x, y, z)| cells.entry([x, y, z]).or_default().push(i));
+        });
+        let range = cells
+            .keys()
+            .fold([[i64::MAX; 3], [i64::MIN; 3]], |[min, max], key| {
+                [
+                    from_fn(|i| min[i].min(key[i])),
+                    from_fn(|i| max[i].max(key[i])),
+                ]
+            });
+        Self {
+            triangles,
+            cells,
+            cell_size,
+            range,
+        }
+    }
+
+    /// Hashes the non-degenerate triangles of `mesh`, with cells of the size for which each cell
+    /// has about one triangle if they are uniformly distributed in the bounding box.
+    pub(crate) fn from_mesh(mesh: &PolygonMesh) -> Self {
+        let positions = mesh.positions();
+        let triangles = mesh
+            .faces()
+            .triangle_iter()
+            .map(|tri| tri.map(|v| positions[v.pos]))
+            .filter(|[p, q, r]| !(q - p).cross(r - p).so_small2())
+            .collect::<Vec<_>>();
+        let bdd = triangles.iter().flatten().collect::<BoundingBox<_>>();
+        let cell_size = match triangles.is_empty() {
+            true => 1.0,
+            false => bdd.diameter() / f64::cbrt(triangles.len() as f64),
+        };
+        Self::new(triangles, f64::max(cell_size, TOLERANCE))
+    }
+
+    #[inline(always)]
+    pub(crate) fn triangles(&self) -> &[[Point3; 3]] { &self.triangles }
+
+    /// Returns the index of the closest triangle and the closest point on it.
+    ///
+    /// The cells are searched by the rings around the cell of `point` from the inside, until the
+    /// closest point found is nearer than the cells out of the searched rings. Returns `None` if
+    /// there is no triangle.
+    pub(crate) fn closest(&self, point: Point3) -> Option<(usize, Point3)> {
+        if self.triangles.is_empty() {
+            return None;
+        }
+        let [min, max] = self.range;
+        let center = [point.x, point.y, point.z].map(|x| (x / self.cell_size).floor() as i64);
+        // the rings from the first one meeting the range to the first one covering it
+        let first = (0..3)
+            .map(|i| i64::max(min[i] - center[i], center[i] - max[i]))
+            .fold(0, i64::max);
+        let last = (0..3)
+            .map(|i| i64::max(center[i] - min[i], max[i] - center[i]))
+            .fold(0, i64::max);
+        let flow = (first..=last).try_fold(None, |best: Option<(usize, Point3)>, r| {
+            let best = self
+                .ring(center, r)
+                .filter_map(|key| self.cells.get(&key))
+                .flatten()
+                .map(|i| (*i, closest_point_on_triangle(point, self.triangles[*i])))
+                .chain(best)
+                .min_by(|(_, p), (_, q)| point.distance2(*p).total_cmp(&point.distance2(*q)));
+            // The cells out of the ring `r` are farther than `r` cells from `point`.
+            let reach = r as f64 * self.cell_size;
+            match best {
+                Some((_, p)) if point.distance2(p) <= reach * reach => ControlFlow::Break(best),
+                _ => ControlFlow::Continue(best),
+            }
+        });
+        match flow {
+            ControlFlow::Break(best) | ControlFlow::Continue(best) => best,
+        }
+    }
+
+    /// Returns the keys of the cells in the range whose Chebyshev distances from `center` are `r`.
+    fn ring(&self, center: [i64; 3], r: i64) -> impl Iterator<Item = [i64; 3]> {
+        let [min, max] = self.range;
+        let bound = |i: usize| i64::max(center[i] - r, min[i])..=i64::min(center[i] + r, max[i]);
+        let (x0, x1) = bound(0).into_inner();
+        itertools::iproduct!(bound(1), bound(2)).flat_map(move |(y, z)| {
+            let on_ring = (y - center[1]).abs() == r || (z - center[2]).abs() == r;
+            // Only the ends of the rows are on the ring, except for the rows on its faces.
+            let x = match on_ring {
+                true => Either::Left(x0..=x1),
+                false => Either::Right(
+                    [center[0] - r, center[0] + r]
+                        .into_iter()
+                        .filter(move |x| (x0..=x1).contains(x)),
+                ),
+            };
+            x.map(move |x| [x, y, z])
+        })
+    }
+
+    /// Returns the closest point by [`TriangleGrid::closest`], or `point` itself if there is no
+    /// triangle.
+    #[cfg(feature = "filters")]
+    #[inline(always)]
+    pub(crate) fn project(&self, point: Point3) -> Point3 {
+        self.closest(point).map_or(point, |(_, p)| p)
+    }
+
+    /// Returns the index of the first triangle hit by the ray `origin + t * direction` with
+    /// `t > TOLERANCE`, and `t`. The triangle `except` is not hit.
+    ///
+    /// # Details
+    /// The cells on the ray are traversed in order by [`TriangleGrid::cells_on_ray`], and the
+    /// traversal stops at the first cell containing a hit that is not beyond the cell.
+    #[cfg(feature = "analyzers")]
+    pub(crate) fn ray_cast(
+        &self,
+        origin: Point3,
+        direction: Vector3,
+        except: usize,
+    ) -> Option<(usize, f64)> {
+        let hit = |i: usize| {
+            let (_, _, t) = ray_triangle(origin, direction, self.triangles[i], 0.0)?;
+            (t > TOLERANCE).then_some((i, t))
+        };
+        let flow = self.cells_on_ray(origin, direction).try_fold(
+            None,
+            |best: Option<(usize, f64)>, (key, cell_exit)| {
+                let best = self
+                    .cells
+                    .get(&key)
+                    .into_iter()
+                    .flatten()
+                    .filter(|i| **i != except)
+                    .filter_map(|i| hit(*i))
+                    .chain(best)
+                    .min_by(|(_, s), (_, t)| s.total_cmp(t));
+                match best {
+                    Some((_, t)) if t <= cell_exit => ControlFlow::Break(best),
+                    _ => ControlFlow::Continue(best),
+                }
+            },
+        );
+        match flow {
+            ControlFlow::Break(best) | ControlFlow::Continue(best) => best,
+        }
+    }
+
+    /// Returns the signed number of the triangles crossed by the ray `origin + t * direction`
+    /// with `t > 0`, counted up if the ray goes along the normal of the triangle and down
+    /// otherwise, same as [`IncludingPointInDomain::signed_crossing_faces`].
+    ///
+    /// [`IncludingPointInDomain::signed_crossing_faces`]: crate::analyzers::IncludingPointInDomain::signed_crossing_faces
+    #[cfg(feature = "analyzers")]
+    pub(crate) fn signed_crossings(&self, origin: Point3, direction: Vector3) -> isize {
+        // The triangles over several cells are counted only once.
+        let mut visited = HashSet::default();
+        self.cells_on_ray(origin, direction)
+            .filter_map(|(key, _)| self.cells.get(&key))
+            .flatten()
+            .filter(|i| visited.insert(**i))
+            .filter_map(|i| {
+                let [p, q, r] = self.triangles[*i];
+                let (_, _, t) = ray_triangle(origin, direction, [p, q, r], 0.0)?;
+                let sign = (q - p).cross(r - p).dot(direction).signum() as isize;
+                (t > 0.0).then_some(sign)
+            })
+            .sum()
+    }
+
+    /// Returns the keys of the cells on the ray `origin + t * direction` with `t >= 0` in order,
+    /// by the 3D DDA, with the parameters `t` at which the ray exits the cells.
+    #[cfg(feature = "analyzers")]
+    fn cells_on_ray(
+        &self,
+        origin: Point3,
+        direction: Vector3,
+    ) -> impl Iterator<Item = ([i64; 3], f64)> {
+        let [min, max] = self.range;
+        let (o, d) = (origin.to_vec() / self.cell_size, direction / self.cell_size);
+        let (o, d) = ([o.x, o.y, o.z], [d.x, d.y, d.z]);
+        let slab = |i: usize| match d[i] == 0.0 {
+            true => match (min[i] as f64..=(max[i] + 1) as f64).contains(&o[i]) {
+                true => (f64::NEG_INFINITY, f64::INFINITY),
+                false => (f64::INFINITY, f64::NEG_INFINITY),
+            },
+            false => {
+                let t0 = (min[i] as f64 - o[i]) / d[i];
+                let t1 = ((max[i] + 1) as f64 - o[i]) / d[i];
+                (f64::min(t0, t1), f64::max(t0, t1))
+            }
+        };
+        let (enter, exit) = (0..3)
+            .map(slab)
+            .fold((0.0, f64::INFINITY), |(t0, t1), (s0, s1)| {
+                (f64::max(t0, s0), f64::min(t1, s1))
+            });
+        let empty = min[0] > max[0] || direction.so_small() || enter > exit;
+        let start: [i64; 3] =
+            from_fn(|i| ((o[i] + enter * d[i]).floor() as i64).clamp(min[i], max[i]));
+        let step: [i64; 3] = from_fn(|i| d[i].signum() as i64);
+        let t_delta: [f64; 3] = from_fn(|i| 1.0 / d[i].abs());
+        let t_max: [f64; 3] = from_fn(|i| match d[i] == 0.0 {
+            true => f64::INFINITY,
+            false => {
+                let next = start[i] + (d[i] > 0.0) as i64;
+                (next as f64 - o[i]) / d[i]
+            }
+        });
+        let in_range = move |key: &[i64; 3]| (0..3).all(|i| (min[i]..=max[i]).contains(&key[i]));
+        let first = (!empty).then_some((start, t_max));
+        successors(first, move |(key, t_max)| {
+            let axis = (0..3).min_by(|i, j| t_max[*i].total_cmp(&t_max[*j]))?;
+            let (mut key, mut t_max) = (*key, *t_max);
+            key[axis] += step[axis];
+            t_max[axis] += t_delta[axis];
+            in_range(&key).then_some((key, t_max))
+        })
+        .map(|(key, t_max)| (key, t_max.into_iter().fold(f64::INFINITY, f64::min)))
+    }
+}
+
+fn closest_point_on_triangle(point: Point3, [a, b, c]: [Point3; 3]) -> Point3 {
+    let (ab, ac, ap) = (b - a, c - a, point - a);
+    let (d1, d2) = (ab.dot(ap), ac.dot(ap));
+    if d1 <= 0.0 && d2 <= 0.0 {
+        return a;
+    }
+    let bp = point - b;
+    let (d3, d4) = (ab.dot(bp), ac.dot(bp));
+    if d3 >= 0.0 && d4 <= d3 {
+        return b;
+    }
+    let vc = d1 * d4 - d3 * d2;
+    if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+        return a + ab * (d1 / (d1 - d3));
+    }
+    let cp = point - c;
+    let (d5, d6) = (ab.dot(cp), ac.dot(cp));
+    if d6 >= 0.0 && d5 <= d6 {
+        return c;
+    }
+    let vb = d5 * d2 - d1 * d6;
+    if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+        return a + ac * (d2 / (d2 - d6));
+    }
+    let va = d3 * d6 - d5 * d4;
+    if va <= 0.0 && d4 - d3 >= 0.0 && d5 - d6 >= 0.0 {
+        return b + (c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+    }
+    let denom = va + vb + vc;
+    if denom.so_small2() {
+        return a;
+    }
+    a + ab * (vb / denom) + ac * (vc / denom)
+}
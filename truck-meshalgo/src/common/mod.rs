@@ -1,6 +1,17 @@
 use crate::*;
 
 mod adjacency;
+mod closest_point;
 mod face_normal;
-pub(super) use adjacency::Adjacency;
+#[cfg(any(feature = "analyzers", feature = "filters"))]
+mod union_find;
+#[cfg(feature = "filters")]
+pub(super) use adjacency::{edge_face_counts, edge_key, rotate_to_edge, PositionIndex};
+pub(super) use adjacency::{edge_faces, Adjacency};
+pub(super) use closest_point::TriangleGrid;
+#[cfg(feature = "analyzers")]
+pub(super) use face_normal::face_normal;
+#[cfg(feature = "filters")]
 pub(super) use face_normal::FaceNormal;
+#[cfg(any(feature = "analyzers", feature = "filters"))]
+pub(super) use union_find::root;
@@ -0,0 +1,14 @@
+use std::iter::successors;
+
+/// Returns the root of `i` in the union-find forest `parents`, halving the path on the way.
+pub fn root(parents: &mut [usize], i: usize) -> usize {
+    let path = successors(Some(i), |j| {
+        let j = *j;
+        (parents[j] != j).then(|| {
+            parents[j] = parents[parents[j]];
+            parents[j]
+        })
+    });
+    // SAFETY: the path begins with `i`.
+    path.last().unwrap()
+}
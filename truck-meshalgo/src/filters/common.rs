@@ -0,0 +1,67 @@
+use super::*;
+use rustc_hash::FxHashSet as HashSet;
+
+/// Returns whether each of the `len` positions is on a boundary or a non-manifold edge, i.e. an
+/// edge whose number of faces is not `2`, from the edges with their numbers of faces.
+pub(super) fn fixed_vertices(
+    edges: impl IntoIterator<Item = ([usize; 2], usize)>,
+    len: usize,
+) -> Vec<bool> {
+    let mut fixed = vec![false; len];
+    edges
+        .into_iter()
+        .filter(|(_, count)| *count != 2)
+        .flat_map(|(edge, _)| edge)
+        .for_each(|i| fixed[i] = true);
+    fixed
+}
+
+/// Non-normalized normal, whose length is twice the area.
+pub(super) fn triangle_normal([p, q, r]: [Point3; 3]) -> Vector3 { (q - p).cross(r - p) }
+
+/// Runs one pass of the flips of the edges in the order of the keys, and returns `true` if some
+/// edge has been flipped.
+///
+/// An edge is a candidate if it is shared by exactly two consistently oriented triangles
+/// `[p, q, c]` and `[q, p, d]` which are not changed in this pass, and the new diagonal `[c, d]`
+/// does not exist yet. The candidate is rejected if the flipped triangles `[c, p, d]` and
+/// `[d, q, c]` fold over at the points `points([p, q, c, d])`. Otherwise, `flip` is called with the
+/// quadrangle `[p, q, c, d]` and its points, and the edge is flipped if it returns `true`.
+pub(super) fn flip_pass<T: PositionIndex + PartialEq>(
+    tris: &mut [[T; 3]],
+    points: impl Fn([T; 4]) -> [Point3; 4],
+    mut flip: impl FnMut([T; 4], [Point3; 4]) -> bool,
+) -> bool {
+    let edges = edge_faces(&*tris);
+    let mut keys = edges.keys().copied().collect::<Vec<_>>();
+    keys.sort();
+    let mut touched = vec![false; tris.len()];
+    let mut new_edges = HashSet::<[usize; 2]>::default();
+    keys.into_iter().fold(false, |flipped, [a, b]| {
+        let candidate = || match edges[&[a, b]][..] {
+            [(f0, _), (f1, _)] if !touched[f0] && !touched[f1] => {
+                let [p, q, c] = rotate_to_edge(tris[f0], a, b)?;
+                let [q1, p1, d] = rotate_to_edge(tris[f1], a, b)?;
+                let diagonal = edge_key(c.position_index(), d.position_index());
+                let valid = p == p1 && q == q1 && diagonal[0] != diagonal[1];
+                let new = !edges.contains_key(&diagonal) && !new_edges.contains(&diagonal);
+                let quad = [p, q, c, d];
+                let [pp, pq, pc, pd] = points(quad);
+                let normal = triangle_normal([pp, pq, pc]) + triangle_normal([pq, pp, pd]);
+                let (n0, n1) = (triangle_normal([pc, pp, pd]), triangle_normal([pd, pq, pc]));
+                let unfolded = n0.dot(normal) > 0.0 && n1.dot(normal) > 0.0 && n0.dot(n1) > 0.0;
+                (valid && new && unfolded).then_some(([f0, f1], diagonal, quad, [pp, pq, pc, pd]))
+            }
+            _ => None,
+        };
+        match candidate().filter(|(_, _, quad, points)| flip(*quad, *points)) {
+            Some(([f0, f1], diagonal, [p, q, c, d], _)) => {
+                (tris[f0], tris[f1]) = ([c, p, d], [d, q, c]);
+                (touched[f0], touched[f1]) = (true, true);
+                new_edges.insert(diagonal);
+                true
+            }
+            None => flipped,
+        }
+    })
+}
@@ -0,0 +1,107 @@
+use super::*;
+use common::flip_pass;
+
+/// The upper bound of the number of passes of flipping.
+const MAX_PASSES: usize = 64;
+
+/// Filters improving the shape of triangles by edge flips.
+pub trait EdgeFlipping {
+    /// Flips the diagonals of adjacent triangle pairs so that the minimum angle of the pairs increases.
+    ///
+    /// # Details
+    /// The angles are measured in 3D if `surface_aware` is `true`, and in the texture coordinate
+    /// space, i.e. the parameter space of a tessellated surface, otherwise. The pairs some of whose
+    /// vertices do not have texture coordinates are always measured in 3D.
+    ///
+    /// Only the edges shared by exactly two triangles with consistent orientation and the same
+    /// attributes are flipped, so the boundaries, the non-manifold edges, and the seams of normals
+    /// or texture coordinates are kept as constraints. Quadrangles and other polygons are not
+    /// changed. A flip is rejected if the flipped triangles fold over in the measuring space, or
+    /// if the new diagonal already exists.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// // a rhombus split along its long diagonal
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(-1.0, 0.0, 0.0),
+    ///             Point3::new(0.0, -0.2, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(0.0, 0.2, 0.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[0, 1, 2], [0, 2, 3]]),
+    /// );
+    /// mesh.optimize_triangulation(true);
+    /// // The diagonal is flipped to the short one.
+    /// let mut diagonal = mesh.faces().tri_faces()[0].map(|v| v.pos).to_vec();
+    /// diagonal.retain(|i| mesh.faces().tri_faces()[1].iter().any(|v| v.pos == *i));
+    /// diagonal.sort();
+    /// assert_eq!(diagonal, vec![1, 3]);
+    /// ```
+    fn optimize_triangulation(&mut self, surface_aware: bool) -> &mut Self;
+}
+
+impl EdgeFlipping for PolygonMesh {
+    fn optimize_triangulation(&mut self, surface_aware: bool) -> &mut Self {
+        let mut mesh = self.debug_editor();
+        let PolygonMeshEditor {
+            attributes:
+                StandardAttributes {
+                    positions,
+                    uv_coords,
+                    ..
+                },
+            faces,
+            ..
+        } = &mut mesh;
+        let tris = faces.tri_faces_mut();
+        let measure = Measure {
+            positions,
+            uv_coords: match surface_aware {
+                true => &[],
+                false => uv_coords,
+            },
+        };
+        (0..MAX_PASSES)
+            .take_while(|_| flip_pass(tris, |quad| measure.points(quad), improves_angles))
+            .for_each(drop);
+        drop(mesh);
+        self
+    }
+}
+
+struct Measure<'a> {
+    positions: &'a [Point3],
+    uv_coords: &'a [Vector2],
+}
+
+impl Measure<'_> {
+    /// Returns the positions in the measuring space, embedded in 3D.
+    fn points<const N: usize>(&self, vertices: [Vertex; N]) -> [Point3; N] {
+        let uvs = vertices.map(|v| v.uv.and_then(|i| self.uv_coords.get(i)));
+        match uvs.iter().all(Option::is_some) {
+            true => uvs.map(|uv| {
+                // SAFETY: all texture coordinates have been checked to exist.
+                let uv = uv.unwrap();
+                Point3::new(uv.x, uv.y, 0.0)
+            }),
+            false => vertices.map(|v| self.positions[v.pos]),
+        }
+    }
+}
+
+fn min_angle([a, b, c]: [Point3; 3]) -> f64 {
+    let angle = |p: Point3, q: Point3, r: Point3| (q - p).angle(r - p).0;
+    f64::min(angle(a, b, c), f64::min(angle(b, c, a), angle(c, a, b)))
+}
+
+/// Returns whether the flip of the quadrangle `[p, q, c, d]` increases the minimum angle.
+fn improves_angles(_: [Vertex; 4], [p, q, c, d]: [Point3; 4]) -> bool {
+    let current = f64::min(min_angle([p, q, c]), min_angle([q, p, d]));
+    let candidate = f64::min(min_angle([c, p, d]), min_angle([d, q, c]));
+    candidate > current + TOLERANCE
+}
@@ -1,12 +1,43 @@
 use crate::*;
 use polygon_mesh::PolygonMeshEditor;
 
+mod common;
+mod edge_flipping;
 mod normal_filters;
+mod offsetting;
 mod optimizing;
+pub mod pipeline;
+#[cfg(feature = "analyzers")]
+mod printing;
+mod registration;
+mod remeshing;
+mod simplification;
+mod smoothing;
+mod stitching;
 mod structuring;
 mod subdivision;
+mod uv_generation;
 
+pub use edge_flipping::EdgeFlipping;
 pub use normal_filters::NormalFilters;
+pub use offsetting::Offsetting;
 pub use optimizing::OptimizingFilter;
-pub use structuring::StructuringFilter;
+pub use pipeline::MeshFilter;
+#[cfg(feature = "analyzers")]
+pub use printing::{MeshReport, PrintPreparation};
+pub use registration::Registration;
+pub use remeshing::Remeshing;
+pub use simplification::Simplification;
+pub use smoothing::Smoothing;
+pub use stitching::Stitching;
+pub use structuring::{NgonTriangulation, StructuringFilter};
 pub use subdivision::Subdivision;
+pub use uv_generation::{UvGeneration, UvProjection};
+
+/// The number of positions and the position indices of the faces, which are kept by the filters
+/// only moving positions.
+#[cfg(debug_assertions)]
+fn position_indices(mesh: &PolygonMesh) -> (usize, Vec<usize>) {
+    let indices = mesh.face_iter().flatten().map(|v| v.pos).collect();
+    (mesh.positions().len(), indices)
+}
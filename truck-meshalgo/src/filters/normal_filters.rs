@@ -1,5 +1,6 @@
 use super::*;
 use rustc_hash::FxHashMap as HashMap;
+use std::iter::repeat_n;
 
 /// Filters for adding normals
 pub trait NormalFilters {
@@ -123,6 +124,98 @@ pub trait NormalFilters {
     /// assert!(mesh.normals()[v1.nor.unwrap()].near(&Vector3::new(2.0, 5.0, 0.0).normalize()));
     /// ```
     fn add_smooth_normals(&mut self, tol_ang: f64, overwrite: bool) -> &mut Self;
+    /// Recomputes the smooth normals across the soft edges, keeping the existing normals at the
+    /// hard corners.
+    /// # Details
+    /// An edge is soft if it is shared by exactly two faces and the angle between their normals
+    /// is less than or equal to `angle_tol`. For each vertex, the faces around it are grouped by
+    /// the soft edges incident to the vertex.
+    /// - If a group has more than one face, the corners of the group get the average of the face
+    ///   normals of the group. The existing normals nearly equal to the average are kept.
+    /// - If a face is isolated at the vertex, i.e. both incident edges are hard or boundary,
+    ///   the existing normal of the corner is kept. The face normal is added if there is none.
+    ///
+    /// So the authored normals of hard regions survive, unlike [`NormalFilters::add_smooth_normals`]
+    /// with `overwrite == true`. The neighbors are determined by position indices, same as
+    /// [`FeatureEdges::feature_edges`](crate::analyzers::FeatureEdges::feature_edges).
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///             Point3::new(0.0, 0.0, 1.0),
+    ///             Point3::new(1.0, 1.0, 0.2),
+    ///         ],
+    ///         normals: vec![Vector3::unit_y()],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[
+    ///         &[(0, None, None), (1, None, None), (2, None, None)],
+    ///         // folded at a right angle, with the authored normal
+    ///         &[(1, None, Some(0)), (0, None, Some(0)), (3, None, Some(0))],
+    ///         // folded slightly
+    ///         &[(1, None, None), (4, None, None), (2, None, None)],
+    ///     ]),
+    /// );
+    /// mesh.recompute_smooth_preserving(Rad(0.5));
+    ///
+    /// // the authored normals are kept
+    /// assert!(mesh.faces()[1].iter().all(|v| v.nor == Some(0)));
+    /// assert_eq!(mesh.normals()[0], Vector3::unit_y());
+    ///
+    /// // the normals across the soft edge are averaged
+    /// let (v0, v1) = (mesh.faces()[0][1], mesh.faces()[2][0]);
+    /// assert_eq!(v0.pos, 1);
+    /// assert_eq!(v0.nor, v1.nor);
+    /// let normal = Vector3::unit_z() + Vector3::new(-0.2, -0.2, 1.0).normalize();
+    /// assert_near!(mesh.normals()[v0.nor.unwrap()], normal.normalize());
+    ///
+    /// // the isolated corner gets the face normal
+    /// let v = mesh.faces()[0][0];
+    /// assert_near!(mesh.normals()[v.nor.unwrap()], Vector3::unit_z());
+    /// ```
+    fn recompute_smooth_preserving(&mut self, angle_tol: Rad<f64>) -> &mut Self;
+    /// Replaces the normals by the smooth normals within the smoothing groups.
+    /// # Details
+    /// The `i`-th value of `groups` is the smoothing group of the `i`-th face, e.g. read by
+    /// [`obj::read_with_smoothing_groups`](truck_polymesh::obj::read_with_smoothing_groups).
+    /// The corners at one position of the faces in the same group get the average of the face
+    /// normals, so the faces in different groups do not share normals across their edges.
+    /// The faces in the group `0`, which means the smoothing is off, get their own face normals.
+    /// If `groups` is shorter than the number of faces, the remaining faces are in the group `0`.
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// let obj = b"v 0 0 0
+    /// v 1 0 0
+    /// v 0 1 0
+    /// v 1 1 0.2
+    /// v 0 0 1
+    /// s 1
+    /// f 1 2 3
+    /// f 2 4 3
+    /// s 2
+    /// f 2 1 5
+    /// ";
+    /// let (mut mesh, groups) = obj::read_with_smoothing_groups(&obj[..]).unwrap();
+    /// mesh.add_smoothing_group_normals(&groups);
+    ///
+    /// // the normals are shared in the group 1
+    /// let (v0, v1) = (mesh.faces()[0][1], mesh.faces()[1][0]);
+    /// assert_eq!(v0.nor, v1.nor);
+    /// let normal = Vector3::unit_z() + Vector3::new(-0.2, -0.2, 1.0).normalize();
+    /// assert_near!(mesh.normals()[v0.nor.unwrap()], normal.normalize());
+    ///
+    /// // but not across the edge to the group 2
+    /// let v2 = mesh.faces()[2][0];
+    /// assert_eq!(v2.pos, 1);
+    /// assert_near!(mesh.normals()[v2.nor.unwrap()], Vector3::unit_y());
+    /// ```
+    fn add_smoothing_group_normals(&mut self, groups: &[u32]) -> &mut Self;
     /// Makes the orientation of faces compatible to the normal vectors.
     /// # Examples
     /// ```
@@ -284,6 +377,131 @@ impl NormalFilters for PolygonMesh {
         self.reflect_normal_clusters(vnmap, overwrite);
         self
     }
+    fn recompute_smooth_preserving(&mut self, angle_tol: Rad<f64>) -> &mut Self {
+        let positions = self.positions();
+        let face_normals = self
+            .face_iter()
+            .enumerate()
+            .map(|(i, face)| FaceNormal::new(positions, face, i).normal)
+            .collect::<Vec<_>>();
+        let faces = self.face_iter().collect::<Vec<_>>();
+        let corner_faces = faces
+            .iter()
+            .enumerate()
+            .flat_map(|(i, face)| repeat_n(i, face.len()))
+            .collect::<Vec<_>>();
+        let offsets = faces
+            .iter()
+            .scan(0, |offset, face| {
+                *offset += face.len();
+                Some(*offset - face.len())
+            })
+            .collect::<Vec<_>>();
+        // the corners at the ends of the edge, ordered by the position indices
+        let edge_corners = |i: usize, k: usize| {
+            let face = faces[i];
+            let l = (k + 1) % face.len();
+            match face[k].pos < face[l].pos {
+                true => [offsets[i] + k, offsets[i] + l],
+                false => [offsets[i] + l, offsets[i] + k],
+            }
+        };
+
+        // union-find of the corners grouped by the soft edges
+        let mut parents = (0..corner_faces.len()).collect::<Vec<_>>();
+        edge_faces(&faces).into_values().for_each(|corners| {
+            let [(i0, k0), (i1, k1)] = corners[..] else {
+                return;
+            };
+            let (c0, c1) = (edge_corners(i0, k0), edge_corners(i1, k1));
+            let (n0, n1) = (face_normals[i0], face_normals[i1]);
+            if n0.angle(n1) <= angle_tol {
+                (0..2).for_each(|k| {
+                    let (r0, r1) = (root(&mut parents, c0[k]), root(&mut parents, c1[k]));
+                    parents[r0] = r1;
+                });
+            }
+        });
+        let mut sums = vec![Vector3::zero(); corner_faces.len()];
+        let mut counts = vec![0_usize; corner_faces.len()];
+        (0..corner_faces.len()).for_each(|c| {
+            let r = root(&mut parents, c);
+            sums[r] += face_normals[corner_faces[c]];
+            counts[r] += 1;
+        });
+
+        let mut mesh = self.debug_editor();
+        let PolygonMeshEditor {
+            attributes: StandardAttributes { normals, .. },
+            faces,
+            ..
+        } = &mut mesh;
+        let mut indices = vec![None; corner_faces.len()];
+        let mut corner = 0;
+        faces.face_iter_mut().flatten().for_each(|v| {
+            let r = root(&mut parents, corner);
+            corner += 1;
+            let normal = sums[r].normalize();
+            let keep = match v.nor {
+                Some(idx) => counts[r] == 1 || normals[idx].near(&normal),
+                None => false,
+            };
+            if !keep {
+                let idx = *indices[r].get_or_insert_with(|| {
+                    normals.push(normal);
+                    normals.len() - 1
+                });
+                v.nor = Some(idx);
+            }
+        });
+        drop(mesh);
+        self
+    }
+    fn add_smoothing_group_normals(&mut self, groups: &[u32]) -> &mut Self {
+        let positions = self.positions();
+        let face_normals = self
+            .face_iter()
+            .enumerate()
+            .map(|(i, face)| FaceNormal::new(positions, face, i).normal)
+            .collect::<Vec<_>>();
+        let group = |i: usize| groups.get(i).copied().unwrap_or(0);
+        let mut sums = HashMap::<(usize, u32), Vector3>::default();
+        self.face_iter().enumerate().for_each(|(i, face)| {
+            if group(i) != 0 {
+                face.iter().for_each(|v| {
+                    *sums.entry((v.pos, group(i))).or_insert_with(Vector3::zero) += face_normals[i];
+                });
+            }
+        });
+
+        let mut mesh = self.debug_editor();
+        let PolygonMeshEditor {
+            attributes: StandardAttributes { normals, .. },
+            faces,
+            ..
+        } = &mut mesh;
+        normals.clear();
+        let mut indices = HashMap::<(usize, u32), usize>::default();
+        faces
+            .face_iter_mut()
+            .enumerate()
+            .for_each(|(i, face)| match group(i) {
+                0 => {
+                    normals.push(face_normals[i]);
+                    let idx = normals.len() - 1;
+                    face.iter_mut().for_each(|v| v.nor = Some(idx));
+                }
+                g => face.iter_mut().for_each(|v| {
+                    let idx = *indices.entry((v.pos, g)).or_insert_with(|| {
+                        normals.push(sums[&(v.pos, g)].normalize());
+                        normals.len() - 1
+                    });
+                    v.nor = Some(idx);
+                }),
+            });
+        drop(mesh);
+        self
+    }
 }
 
 trait SubNormalFilter {
@@ -0,0 +1,70 @@
+use super::*;
+use std::f64::consts::PI;
+
+/// Filters for offsetting meshes approximately.
+pub trait Offsetting {
+    /// Moves each position by `distance` times its vertex normal, i.e. the normalized average of
+    /// the normals of the vertices at the position.
+    ///
+    /// # Details
+    /// This is a cheap approximation of the offset, useful for thickening open meshes or
+    /// shrink-wrapping. Unlike the exact offset surfaces, the self-intersections in concave
+    /// regions are not resolved. A negative `distance` moves the positions backward.
+    ///
+    /// If the mesh has no normals, the smooth normals are added in advance by
+    /// [`NormalFilters::add_smooth_normals`]. The normals are kept as they are, since the normals
+    /// of the offset surface are parallel to the original ones. The positions which are not used
+    /// by any vertices with normals are not moved. The positions should be shared in advance by
+    /// [`OptimizingFilter::put_together_same_attrs`], otherwise the mesh is torn along the
+    /// unshared positions. The number and the order of the positions and the position indices of
+    /// the faces are kept.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 1.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[0, 1, 2, 3]]),
+    /// );
+    /// mesh.offset_along_normals(0.5);
+    /// assert!(mesh.positions().iter().all(|p| p.z.near(&0.5)));
+    /// assert_eq!(mesh.normals().len(), 1);
+    /// ```
+    fn offset_along_normals(&mut self, distance: f64) -> &mut Self;
+}
+
+impl Offsetting for PolygonMesh {
+    fn offset_along_normals(&mut self, distance: f64) -> &mut Self {
+        #[cfg(debug_assertions)]
+        let indices = position_indices(self);
+        if self.normals().is_empty() {
+            self.add_smooth_normals(PI, true);
+        }
+        let mut sums = vec![Vector3::new(0.0, 0.0, 0.0); self.positions().len()];
+        self.face_iter().flatten().for_each(|v| {
+            if let Some(nor) = v.nor {
+                sums[v.pos] += self.normals()[nor];
+            }
+        });
+        self.positions_mut()
+            .iter_mut()
+            .zip(sums)
+            .filter(|(_, sum)| !sum.magnitude2().so_small2())
+            .for_each(|(p, sum)| *p += sum.normalize() * distance);
+        #[cfg(debug_assertions)]
+        assert_eq!(
+            position_indices(self),
+            indices,
+            "the position indices are changed."
+        );
+        self
+    }
+}
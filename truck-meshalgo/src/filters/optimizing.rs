@@ -1,11 +1,14 @@
 use super::*;
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use std::f64::consts::PI;
 use std::iter::Iterator;
 use std::ops::{Div, Mul};
 
 /// Filters for optimizing data
 pub trait OptimizingFilter {
     /// remove all unused position, texture coordinates, and normal vectors.
+    ///
+    /// The remaining attributes are re-indexed, so the indices of the attributes are invalidated.
     /// # Examples
     /// ```
     /// use truck_polymesh::*;
@@ -60,6 +63,7 @@ pub trait OptimizingFilter {
     /// # Remarks
     /// No longer needed attributes are NOT autoremoved.
     /// One can remove such attributes by running [`remove_unused_attrs`] manually.
+    /// The indices in the faces are changed, so the buffers indexed by the faces are invalidated.
     ///
     /// [`remove_unused_attrs`]: ./trait.WasteEliminatingFilter.html#tymethod.remove_unused_attrs
     ///
@@ -94,6 +98,129 @@ pub trait OptimizingFilter {
     /// assert_eq!(mesh.positions().len(), 4);
     /// ```
     fn put_together_same_attrs(&mut self, tol: f64) -> &mut Self;
+    /// Returns the mesh welded by [`put_together_same_attrs`](OptimizingFilter::put_together_same_attrs)
+    /// with `tol`, whose degenerate faces and unused attributes are removed, leaving `self`
+    /// untouched.
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// let mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///             Point3::new(1.0, 1.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[&[0, 1, 2], &[3, 4, 5]]),
+    /// );
+    /// let welded = mesh.welded(TOLERANCE);
+    /// assert_eq!(welded.positions().len(), 4);
+    /// assert_eq!(mesh.positions().len(), 6);
+    /// ```
+    fn welded(&self, tol: f64) -> Self
+    where Self: Clone {
+        let mut mesh = self.clone();
+        mesh.put_together_same_attrs(tol)
+            .remove_degenerate_faces()
+            .remove_unused_attrs();
+        mesh
+    }
+    /// Removes faces whose positions coincide with the ones of a preceding face within `tol`,
+    /// even if they refer to different attribute indices or have the opposite orientation.
+    /// Returns the number of removed faces.
+    /// # Details
+    /// Faces are bucketed by their centroids snapped to the grid of `tol`, and each face is compared
+    /// with the faces in the neighboring buckets. Two faces are duplicate if they have the same
+    /// number of vertices and their vertices coincide within `tol` in the same cyclic order, in
+    /// either winding. Running [`put_together_same_attrs`] in advance is recommended
+    /// but not required.
+    ///
+    /// [`put_together_same_attrs`]: ./trait.OptimizingFilter.html#tymethod.put_together_same_attrs
+    ///
+    /// # Panics
+    /// `tol` must be no less than `TOLERANCE`.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[0, 1, 2], [3, 4, 5]]),
+    /// );
+    ///
+    /// // The two triangles are the same, in spite of the indices and the orientation.
+    /// assert_eq!(mesh.remove_geometric_duplicate_faces(TOLERANCE), 1);
+    /// assert_eq!(mesh.faces().len(), 1);
+    /// ```
+    fn remove_geometric_duplicate_faces(&mut self, tol: f64) -> usize;
+    /// Welds the positions for the smooth geometry, keeping the texture seams split.
+    ///
+    /// # Details
+    /// The coincident positions and the coincident texture coordinates are put together within
+    /// `tol` as [`put_together_same_attrs`], and the normals are replaced by the smooth normals
+    /// by [`NormalFilters::add_smooth_normals`] with the angle tolerance `PI`. Hence each position
+    /// has exactly one normal, and two vertices at the same position differ only if their
+    /// texture coordinates differ, i.e. on the texture seams.
+    ///
+    /// The render-ready vertices are given by [`PolygonMesh::expands`]: it creates one vertex for
+    /// each distinct pair of the position and the texture coordinate. No longer needed attributes
+    /// are not removed, same as [`put_together_same_attrs`].
+    ///
+    /// [`put_together_same_attrs`]: OptimizingFilter::put_together_same_attrs
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// // two triangles sharing an edge, whose texture coordinates are split on the edge
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 1.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///         ],
+    ///         uv_coords: vec![
+    ///             Vector2::new(0.0, 0.0),
+    ///             Vector2::new(0.5, 0.0),
+    ///             Vector2::new(0.0, 1.0),
+    ///             Vector2::new(0.6, 0.0),
+    ///             Vector2::new(1.0, 1.0),
+    ///             Vector2::new(0.0, 1.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[
+    ///         [(0, Some(0), None), (1, Some(1), None), (2, Some(2), None)],
+    ///         [(3, Some(3), None), (4, Some(4), None), (5, Some(5), None)],
+    ///     ]),
+    /// );
+    /// mesh.weld_positions_keep_uv_seams(TOLERANCE).remove_unused_attrs();
+    /// assert_eq!(mesh.positions().len(), 4);
+    /// assert_eq!(mesh.normals().len(), 1);
+    ///
+    /// // The vertex at (0, 1) is shared, but the one at (1, 0) is not.
+    /// let render = mesh.expands(|attr| (attr.position, attr.uv_coord));
+    /// assert_eq!(render.attributes().len(), 5);
+    /// ```
+    fn weld_positions_keep_uv_seams(&mut self, tol: f64) -> &mut Self;
 }
 
 fn all_pos_mut(faces: &mut Faces) -> impl Iterator<Item = &mut usize> {
@@ -163,34 +290,90 @@ impl OptimizingFilter for PolygonMesh {
         self
     }
 
-    fn put_together_same_attrs(&mut self, tol: f64) -> &mut Self {
+    fn remove_geometric_duplicate_faces(&mut self, tol: f64) -> usize {
+        nonpositive_tolerance!(tol);
         let mut mesh = self.debug_editor();
         let PolygonMeshEditor {
-            attributes:
-                StandardAttributes {
-                    positions,
-                    uv_coords,
-                    normals,
-                },
+            attributes: StandardAttributes { positions, .. },
             faces,
             ..
         } = &mut mesh;
-        let bnd_box: BoundingBox<_> = positions.iter().collect();
-        let center = bnd_box.center();
-        let diag = bnd_box.diagonal().map(|a| f64::max(a.abs(), 1.0));
-        let normalized_positions = positions
-            .iter()
-            .map(move |position| 2.0 * (position - center).zip(diag, |a, b| a / b))
-            .collect::<Vec<_>>();
-        let pos_map = sub_put_together_same_attrs(&normalized_positions, tol);
-        all_pos_mut(faces).for_each(|idx| *idx = pos_map[*idx]);
-        let uv_map = sub_put_together_same_attrs(uv_coords, tol);
-        all_uv_mut(faces).for_each(|idx| *idx = uv_map[*idx]);
-        let nor_map = sub_put_together_same_attrs(normals, tol);
-        all_nor_mut(faces).for_each(|idx| *idx = nor_map[*idx]);
+        let mut buckets = HashMap::<[i64; 3], Vec<Vec<Point3>>>::default();
+        let mut new_faces = Faces::default();
+        faces.face_iter().for_each(|face| {
+            let points = face.iter().map(|v| positions[v.pos]).collect::<Vec<_>>();
+            let centroid = points
+                .iter()
+                .fold(Vector3::new(0.0, 0.0, 0.0), |sum, p| sum + p.to_vec());
+            // The centroids of the duplicate faces are within `tol`, so they are in the
+            // neighborhoods of each other.
+            let key = (centroid / points.len() as f64).round(tol);
+            let duplicate = key.neighborhood().into_iter().any(|idx| {
+                buckets.get(&idx).is_some_and(|bucket| {
+                    bucket.iter().any(|other| same_polygon(other, &points, tol))
+                })
+            });
+            if !duplicate {
+                buckets.entry(key).or_default().push(points);
+                new_faces.push(face);
+            }
+        });
+        let removed = faces.len() - new_faces.len();
+        **faces = new_faces;
         drop(mesh);
+        removed
+    }
+
+    fn put_together_same_attrs(&mut self, tol: f64) -> &mut Self {
+        put_together_same_attrs(self, tol, true);
         self
     }
+
+    fn weld_positions_keep_uv_seams(&mut self, tol: f64) -> &mut Self {
+        put_together_same_attrs(self, tol, false);
+        self.add_smooth_normals(PI, true)
+    }
+}
+
+fn put_together_same_attrs(mesh: &mut PolygonMesh, tol: f64, with_normals: bool) {
+    let mut mesh = mesh.debug_editor();
+    let PolygonMeshEditor {
+        attributes:
+            StandardAttributes {
+                positions,
+                uv_coords,
+                normals,
+            },
+        faces,
+        ..
+    } = &mut mesh;
+    let bnd_box: BoundingBox<_> = positions.iter().collect();
+    let center = bnd_box.center();
+    let diag = bnd_box.diagonal().map(|a| f64::max(a.abs(), 1.0));
+    let normalized_positions = positions
+        .iter()
+        .map(move |position| 2.0 * (position - center).zip(diag, |a, b| a / b))
+        .collect::<Vec<_>>();
+    let pos_map = sub_put_together_same_attrs(&normalized_positions, tol);
+    all_pos_mut(faces).for_each(|idx| *idx = pos_map[*idx]);
+    let uv_map = sub_put_together_same_attrs(uv_coords, tol);
+    all_uv_mut(faces).for_each(|idx| *idx = uv_map[*idx]);
+    if with_normals {
+        let nor_map = sub_put_together_same_attrs(normals, tol);
+        all_nor_mut(faces).for_each(|idx| *idx = nor_map[*idx]);
+    }
+}
+
+/// Returns whether the vertices of `face1` coincide with the ones of `face0` within `tol`, up to
+/// the cyclic shifts in both windings.
+fn same_polygon(face0: &[Point3], face1: &[Point3], tol: f64) -> bool {
+    let len = face0.len();
+    let near = |i: usize, j: usize| face0[i].distance2(face1[j]) < tol * tol;
+    len == face1.len()
+        && (0..len).any(|shift| {
+            (0..len).all(|i| near(i, (shift + i) % len))
+                || (0..len).all(|i| near(i, (shift + len - i) % len))
+        })
 }
 
 fn sub_remove_unused_attrs<'a, I: Iterator<Item = &'a mut usize>>(
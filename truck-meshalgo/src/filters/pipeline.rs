@@ -0,0 +1,148 @@
+//! Filters as values, for assembling mesh processing pipelines at runtime.
+//!
+//! Each struct holds the parameters of a filter method and runs it by [`MeshFilter::apply`].
+//! A slice or a vector of filters is also a filter which applies them in order.
+//!
+//! # Examples
+//! ```
+//! use truck_meshalgo::prelude::*;
+//! use truck_meshalgo::filters::pipeline::*;
+//! // a square whose corners are duplicated
+//! let mut mesh = PolygonMesh::new(
+//!     StandardAttributes {
+//!         positions: vec![
+//!             Point3::new(0.0, 0.0, 0.0),
+//!             Point3::new(1.0, 0.0, 0.0),
+//!             Point3::new(1.0, 1.0, 0.0),
+//!             Point3::new(0.0, 0.0, 0.0),
+//!             Point3::new(1.0, 1.0, 0.0),
+//!             Point3::new(0.0, 1.0, 0.0),
+//!         ],
+//!         ..Default::default()
+//!     },
+//!     Faces::from_iter(&[[0, 1, 2], [3, 4, 5]]),
+//! );
+//! let pipeline: Vec<Box<dyn MeshFilter>> = vec![
+//!     Box::new(Weld { tol: TOLERANCE }),
+//!     Box::new(SmoothNormals {
+//!         tol_ang: std::f64::consts::PI / 3.0,
+//!         overwrite: true,
+//!     }),
+//! ];
+//! pipeline.apply(&mut mesh);
+//! assert_eq!(mesh.positions().len(), 4);
+//! assert_eq!(mesh.normals().len(), 1);
+//! ```
+
+use super::*;
+
+/// A mesh filter applied through a trait object.
+pub trait MeshFilter {
+    /// Applies the filter to `mesh` in place.
+    fn apply(&self, mesh: &mut PolygonMesh);
+}
+
+impl<T: MeshFilter + ?Sized> MeshFilter for &T {
+    #[inline(always)]
+    fn apply(&self, mesh: &mut PolygonMesh) { T::apply(*self, mesh) }
+}
+
+impl<T: MeshFilter + ?Sized> MeshFilter for Box<T> {
+    #[inline(always)]
+    fn apply(&self, mesh: &mut PolygonMesh) { T::apply(&**self, mesh) }
+}
+
+impl<T: MeshFilter> MeshFilter for [T] {
+    #[inline(always)]
+    fn apply(&self, mesh: &mut PolygonMesh) { self.iter().for_each(|filter| filter.apply(mesh)) }
+}
+
+impl<T: MeshFilter> MeshFilter for Vec<T> {
+    #[inline(always)]
+    fn apply(&self, mesh: &mut PolygonMesh) { self.as_slice().apply(mesh) }
+}
+
+/// Merges the coincident attributes by [`OptimizingFilter::put_together_same_attrs`], and
+/// removes the degenerate faces and the unused attributes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Weld {
+    /// the tolerance of merging
+    pub tol: f64,
+}
+
+impl MeshFilter for Weld {
+    fn apply(&self, mesh: &mut PolygonMesh) {
+        mesh.put_together_same_attrs(self.tol)
+            .remove_degenerate_faces()
+            .remove_unused_attrs();
+    }
+}
+
+/// Adds smooth normals by [`NormalFilters::add_smooth_normals`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SmoothNormals {
+    /// the angle tolerance of the normals to be shared
+    pub tol_ang: f64,
+    /// whether the existing normals are overwritten
+    pub overwrite: bool,
+}
+
+impl MeshFilter for SmoothNormals {
+    fn apply(&self, mesh: &mut PolygonMesh) {
+        mesh.add_smooth_normals(self.tol_ang, self.overwrite);
+    }
+}
+
+/// Smooths positions by [`Smoothing::taubin_smoothing`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TaubinSmoothing {
+    /// the number of iterations
+    pub iterations: usize,
+    /// the factor of the shrinking step
+    pub lambda: f64,
+    /// the factor of the inflating step
+    pub mu: f64,
+}
+
+impl MeshFilter for TaubinSmoothing {
+    fn apply(&self, mesh: &mut PolygonMesh) {
+        mesh.taubin_smoothing(self.iterations, self.lambda, self.mu);
+    }
+}
+
+/// Simplifies the mesh by [`Simplification::cluster_simplify`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ClusterSimplify {
+    /// the size of the cells of clusters
+    pub cell_size: f64,
+}
+
+impl MeshFilter for ClusterSimplify {
+    fn apply(&self, mesh: &mut PolygonMesh) { mesh.cluster_simplify(self.cell_size); }
+}
+
+/// Decimates the mesh by [`Simplification::quadric_decimate_to_error`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct QuadricDecimation {
+    /// the maximum geometric error
+    pub max_error: f64,
+}
+
+impl MeshFilter for QuadricDecimation {
+    fn apply(&self, mesh: &mut PolygonMesh) { mesh.quadric_decimate_to_error(self.max_error); }
+}
+
+/// Remeshes the mesh by [`Remeshing::remesh_isotropic`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IsotropicRemeshing {
+    /// the target length of edges
+    pub target_edge_length: f64,
+    /// the number of iterations
+    pub iterations: usize,
+}
+
+impl MeshFilter for IsotropicRemeshing {
+    fn apply(&self, mesh: &mut PolygonMesh) {
+        mesh.remesh_isotropic(self.target_edge_length, self.iterations);
+    }
+}
@@ -0,0 +1,232 @@
+use super::*;
+use crate::analyzers::{CalcVolume, Topology};
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use std::{f64::consts::PI, iter::successors};
+use truck_topology::shell::ShellCondition;
+
+/// The changes made by [`PrintPreparation::prepare_for_printing`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MeshReport {
+    /// The number of positions merged into other coincident ones.
+    pub merged_vertices: usize,
+    /// The number of degenerate or duplicated faces removed.
+    pub removed_faces: usize,
+    /// The number of original faces whose orientations are reversed.
+    pub reoriented_faces: usize,
+    /// The number of holes filled.
+    pub filled_holes: usize,
+    /// The shell condition of the resulting mesh.
+    pub shell_condition: ShellCondition,
+}
+
+impl MeshReport {
+    /// Returns `true` if the resulting mesh is closed, i.e. watertight and oriented.
+    #[inline(always)]
+    pub fn is_closed(&self) -> bool { self.shell_condition == ShellCondition::Closed }
+}
+
+/// Repairs meshes to be watertight and outward-oriented for 3D printing.
+pub trait PrintPreparation {
+    /// Repairs the mesh in place for 3D printing, and returns the report of the changes.
+    ///
+    /// # Details
+    /// The following steps are run in order:
+    /// 1. The coincident positions, normals and texture coordinates are merged by
+    ///    [`OptimizingFilter::put_together_same_attrs`] with `TOLERANCE`.
+    /// 2. The degenerate faces and the geometrically duplicated faces are removed.
+    /// 3. The faces are reversed so that the adjacent faces have the consistent orientation.
+    ///    The orientation is not propagated over the edges shared by more than two faces.
+    /// 4. Every hole, a boundary loop without branches, is filled by a polygon.
+    /// 5. Each connected component is reversed if its signed volume is negative.
+    ///
+    /// If the mesh has normals, they are recomputed by [`NormalFilters::add_smooth_normals`].
+    /// The holes are filled regardless of their size, so the result is watertight unless the
+    /// mesh has non-manifold edges or is not orientable, e.g. a Möbius strip. Check
+    /// [`MeshReport::is_closed`] for the result.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// // an inward-oriented tetrahedron without the bottom
+    /// let positions = vec![
+    ///     Point3::new(0.0, 0.0, 0.0),
+    ///     Point3::new(1.0, 0.0, 0.0),
+    ///     Point3::new(0.0, 1.0, 0.0),
+    ///     Point3::new(0.0, 0.0, 1.0),
+    /// ];
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions,
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[0, 3, 1], [1, 3, 2], [2, 3, 0]]),
+    /// );
+    /// let report = mesh.prepare_for_printing();
+    /// assert!(report.is_closed());
+    /// assert_eq!(report.filled_holes, 1);
+    /// assert_eq!(report.reoriented_faces, 3);
+    /// assert_near!(mesh.volume(), 1.0 / 6.0);
+    /// ```
+    fn prepare_for_printing(&mut self) -> MeshReport;
+}
+
+impl PrintPreparation for PolygonMesh {
+    fn prepare_for_printing(&mut self) -> MeshReport {
+        let has_normals = !self.normals().is_empty();
+        let (position_len, face_len) = (self.positions().len(), self.faces().len());
+        self.put_together_same_attrs(TOLERANCE)
+            .remove_degenerate_faces()
+            .remove_unused_attrs();
+        let merged_vertices = position_len - self.positions().len();
+        let without_degenerate = self.faces().len();
+        let removed_faces = face_len.saturating_sub(without_degenerate)
+            + self.remove_geometric_duplicate_faces(TOLERANCE);
+
+        let mut faces = self
+            .face_iter()
+            .map(|face| face.to_vec())
+            .collect::<Vec<_>>();
+        let original_len = faces.len();
+        let (flips, _, _) = consistent_orientation(&faces);
+        faces
+            .iter_mut()
+            .zip(&flips)
+            .filter(|(_, flip)| **flip)
+            .for_each(|(face, _)| face.reverse());
+        let holes = hole_loops(&faces);
+        let filled_holes = holes.len();
+        faces.extend(holes);
+        let (_, components, component_len) = consistent_orientation(&faces);
+        let mut component_faces = vec![Vec::new(); component_len];
+        faces
+            .iter()
+            .zip(&components)
+            .for_each(|(face, c)| component_faces[*c].push(face.as_slice()));
+        let volumes = component_faces
+            .into_iter()
+            .map(|faces| {
+                let attrs = StandardAttributes {
+                    positions: self.positions().to_vec(),
+                    ..Default::default()
+                };
+                PolygonMesh::new(attrs, Faces::from_iter(faces)).volume()
+            })
+            .collect::<Vec<_>>();
+        let mut reversed = flips;
+        faces.iter_mut().enumerate().for_each(|(i, face)| {
+            if volumes[components[i]] < 0.0 {
+                face.reverse();
+                if i < original_len {
+                    reversed[i] = !reversed[i];
+                }
+            }
+        });
+        let reoriented_faces = reversed.into_iter().filter(|x| *x).count();
+
+        let mesh = self.debug_editor();
+        *mesh.faces = faces.iter().collect();
+        drop(mesh);
+        if has_normals {
+            self.add_smooth_normals(PI / 3.0, true);
+        }
+        MeshReport {
+            merged_vertices,
+            removed_faces,
+            reoriented_faces,
+            filled_holes,
+            shell_condition: self.shell_condition(),
+        }
+    }
+}
+
+/// Returns the faces to be reversed for the consistent orientation, the component indices and
+/// the number of the components.
+fn consistent_orientation(faces: &[Vec<Vertex>]) -> (Vec<bool>, Vec<usize>, usize) {
+    let edges = &edge_faces(faces);
+    let forward = |i: usize, k: usize| {
+        let face = &faces[i];
+        face[k].pos < face[(k + 1) % face.len()].pos
+    };
+    let mut flips = vec![false; faces.len()];
+    let mut components = vec![usize::MAX; faces.len()];
+    let component_len = (0..faces.len()).fold(0, |component, start| match components[start] {
+        usize::MAX => {
+            components[start] = component;
+            // the breadth-first search over the manifold edges, by the fronts of the faces
+            successors(Some(vec![start]), |front: &Vec<usize>| {
+                let adjacents = front
+                    .iter()
+                    .flat_map(|&i| {
+                        let flip = flips[i];
+                        face_edges(&faces[i])
+                            .map(move |(a, b)| (&edges[&edge_key(a, b)], (a < b) ^ flip))
+                            .filter(|(adjacent, _)| adjacent.len() == 2)
+                            .flat_map(|(adjacent, direction)| {
+                                adjacent.iter().map(move |&(j, k)| (j, k, direction))
+                            })
+                    })
+                    .collect::<Vec<_>>();
+                let front = adjacents
+                    .into_iter()
+                    .filter_map(|(j, k, direction)| match components[j] {
+                        usize::MAX => {
+                            components[j] = component;
+                            flips[j] = forward(j, k) == direction;
+                            Some(j)
+                        }
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>();
+                (!front.is_empty()).then_some(front)
+            })
+            .for_each(drop);
+            component + 1
+        }
+        _ => component,
+    });
+    (flips, components, component_len)
+}
+
+/// Returns the polygons filling the holes of the consistently oriented faces.
+fn hole_loops(faces: &[Vec<Vertex>]) -> Vec<Vec<Vertex>> {
+    let counts = edge_face_counts(faces);
+    // the boundary edges reversed, so that the filling polygons have the same orientation
+    let mut next = HashMap::<usize, Vec<usize>>::default();
+    faces
+        .iter()
+        .flat_map(|face| face_edges(face))
+        .filter(|(a, b)| counts[&edge_key(*a, *b)] == 1)
+        .for_each(|(a, b)| next.entry(b).or_default().push(a));
+    let mut starts = next
+        .iter()
+        .filter(|(_, ends)| ends.len() == 1)
+        .map(|(start, _)| *start)
+        .collect::<Vec<_>>();
+    starts.sort();
+    starts
+        .into_iter()
+        .filter_map(|start| {
+            let next_of = |v: &usize| match next.get(v).map(Vec::as_slice) {
+                Some([end]) => Some(*end),
+                _ => None,
+            };
+            let mut visited = HashSet::default();
+            let hole = successors(Some(start), next_of)
+                .take_while(|v| visited.insert(*v))
+                .collect::<Vec<_>>();
+            // SAFETY: `hole` has at least `start`.
+            let closed = next_of(hole.last().unwrap()) == Some(start);
+            let hole = (closed && hole.len() >= 3).then_some(hole)?;
+            hole.iter().for_each(|v| {
+                next.remove(v);
+            });
+            Some(hole.into_iter().map(Vertex::from).collect())
+        })
+        .collect()
+}
+
+fn face_edges(face: &[Vertex]) -> impl Iterator<Item = (usize, usize)> + '_ {
+    face.iter()
+        .zip(face.iter().cycle().skip(1))
+        .map(|(v, w)| (v.pos, w.pos))
+}
@@ -0,0 +1,156 @@
+use super::*;
+
+/// Rigid registration of meshes.
+pub trait Registration {
+    /// Aligns `self` to `target` by the point-to-plane iterative closest point method, and returns
+    /// the cumulative rigid transformation applied to `self`.
+    ///
+    /// # Details
+    /// The samples are the positions of `self` used by the faces. In each of `iterations`
+    /// iterations, the closest points of the samples on the triangles of `target` are searched,
+    /// and the rigid transformation minimizing the squared distances from the moved samples to the
+    /// planes of the closest triangles is solved with the rotation linearized. The transformation
+    /// is applied to `self` by [`Transformed::transform_by`], so the indices of `self` are kept.
+    /// The iterations stop early if the transformation is negligible.
+    ///
+    /// The method converges to the alignment near the initial position, so `self` should be
+    /// roughly aligned to `target` beforehand. The directions in which `target` is invariant, e.g.
+    /// the translations along a plane, are not moved. Returns the identity if `target` has no
+    /// triangles.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// // the wavy surface
+    /// let positions = (0..=10)
+    ///     .flat_map(|i| (0..=10).map(move |j| (i as f64 / 10.0, j as f64 / 10.0)))
+    ///     .map(|(x, y)| Point3::new(x, y, 0.2 * f64::sin(4.0 * x) * f64::cos(3.0 * y)))
+    ///     .collect::<Vec<_>>();
+    /// let faces = (0..10)
+    ///     .flat_map(|i| (0..10).map(move |j| 11 * i + j))
+    ///     .map(|k| [k, k + 11, k + 12, k + 1])
+    ///     .collect::<Vec<_>>();
+    /// let target = PolygonMesh::new(
+    ///     StandardAttributes { positions, ..Default::default() },
+    ///     Faces::from_iter(&faces),
+    /// );
+    ///
+    /// let moved = Matrix4::from_translation(Vector3::new(0.01, -0.02, 0.03));
+    /// let mut mesh = target.clone();
+    /// mesh.transform_by(moved);
+    ///
+    /// let alignment = mesh.align_to(&target, 20);
+    /// assert!((alignment * moved).near(&Matrix4::identity()));
+    /// assert!(mesh.positions().iter().zip(target.positions()).all(|(p, q)| p.near(q)));
+    /// ```
+    fn align_to(&mut self, target: &PolygonMesh, iterations: usize) -> Matrix4;
+}
+
+impl Registration for PolygonMesh {
+    fn align_to(&mut self, target: &PolygonMesh, iterations: usize) -> Matrix4 {
+        let grid = TriangleGrid::from_mesh(target);
+        if grid.triangles().is_empty() {
+            return Matrix4::identity();
+        }
+        let normals = grid
+            .triangles()
+            .iter()
+            .map(|[p, q, r]| (q - p).cross(r - p).normalize())
+            .collect::<Vec<_>>();
+        let mut indices = self
+            .face_iter()
+            .flatten()
+            .map(|v| v.pos)
+            .collect::<Vec<_>>();
+        indices.sort();
+        indices.dedup();
+        (0..iterations)
+            .map_while(|_| {
+                let samples = indices.iter().map(|i| self.positions()[*i]);
+                let step = point_to_plane_step(&grid, &normals, samples.collect())?;
+                self.transform_by(step);
+                Some(step)
+            })
+            .fold(Matrix4::identity(), |total, step| step * total)
+    }
+}
+
+/// Returns the rigid transformation of one iteration moving `samples` to `grid`, or `None` if the
+/// transformation is negligible.
+fn point_to_plane_step(
+    grid: &TriangleGrid,
+    normals: &[Vector3],
+    samples: Vec<Point3>,
+) -> Option<Matrix4> {
+    if samples.is_empty() {
+        return None;
+    }
+    // The samples are centered for the conditioning of the linearized rotation.
+    let center = samples.iter().map(|p| p.to_vec()).sum::<Vector3>() / samples.len() as f64;
+    // The unknowns are the rotation vector and the translation. Each sample gives the equation
+    // `(p - q + omega x p + t) . n = 0` for the closest point `q` and the normal `n`.
+    let (ata, atb) = samples
+        .iter()
+        .filter_map(|p| grid.closest(*p).map(|(i, q)| (*p, q, normals[i])))
+        .fold(
+            ([[0.0; 6]; 6], [0.0; 6]),
+            |(mut ata, mut atb), (p, q, n)| {
+                let c = (p - center).to_vec().cross(n);
+                let a = [c.x, c.y, c.z, n.x, n.y, n.z];
+                let b = (q - p).dot(n);
+                (0..6).for_each(|i| {
+                    (0..6).for_each(|j| ata[i][j] += a[i] * a[j]);
+                    atb[i] += a[i] * b;
+                });
+                (ata, atb)
+            },
+        );
+    let x = solve_damped(ata, atb)?;
+    let (omega, translation) = (
+        Vector3::new(x[0], x[1], x[2]),
+        Vector3::new(x[3], x[4], x[5]),
+    );
+    if omega.so_small2() && translation.so_small2() {
+        return None;
+    }
+    let rotation = match omega.so_small2() {
+        true => Matrix3::identity(),
+        false => Matrix3::from_axis_angle(omega.normalize(), Rad(omega.magnitude())),
+    };
+    Some(
+        Matrix4::from_translation(center + translation)
+            * Matrix4::from(rotation)
+            * Matrix4::from_translation(-center),
+    )
+}
+
+/// Solves the symmetric positive semidefinite system `a x = b` by the Gaussian elimination.
+///
+/// The diagonal is slightly damped, so the components in the kernel of `a` are zero. Returns
+/// `None` if `a` is zero.
+fn solve_damped(mut a: [[f64; 6]; 6], mut b: [f64; 6]) -> Option<[f64; 6]> {
+    let trace = (0..6).map(|i| a[i][i]).sum::<f64>();
+    if trace.so_small2() {
+        return None;
+    }
+    (0..6).for_each(|i| a[i][i] += trace * 1.0e-12);
+    (0..6).for_each(|k| {
+        // SAFETY: the range `k..6` is not empty since `k < 6`.
+        let pivot = (k..6)
+            .max_by(|i, j| a[*i][k].abs().total_cmp(&a[*j][k].abs()))
+            .unwrap();
+        a.swap(k, pivot);
+        b.swap(k, pivot);
+        (k + 1..6).for_each(|i| {
+            let ratio = a[i][k] / a[k][k];
+            (k..6).for_each(|j| a[i][j] -= ratio * a[k][j]);
+            b[i] -= ratio * b[k];
+        });
+    });
+    let mut x = [0.0; 6];
+    (0..6).rev().for_each(|i| {
+        let sum = (i + 1..6).map(|j| a[i][j] * x[j]).sum::<f64>();
+        x[i] = (b[i] - sum) / a[i][i];
+    });
+    Some(x)
+}
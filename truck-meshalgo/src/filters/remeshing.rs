@@ -0,0 +1,360 @@
+use super::*;
+use common::{fixed_vertices, flip_pass, triangle_normal};
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use std::{f64::consts::PI, mem::take};
+
+/// Remeshing algorithms
+pub trait Remeshing {
+    /// Remeshes the surface into triangles whose edges are close to `target_edge_length`.
+    ///
+    /// # Details
+    /// The standard isotropic remeshing pipeline is repeated `iterations` times:
+    /// 1. edges longer than `4/3 * target_edge_length` are split at their midpoints,
+    /// 2. edges shorter than `4/5 * target_edge_length` are collapsed,
+    /// 3. edges are flipped if the flip makes the valences closer to `6` (`4` on boundaries),
+    /// 4. the vertices are relaxed in the tangent planes, and projected back to the closest points
+    ///    on the original mesh.
+    ///
+    /// The boundary vertices are neither collapsed nor moved, so the boundary is preserved as a
+    /// polyline. The same holds for the vertices on non-manifold edges.
+    ///
+    /// All faces are triangulated, and uv coordinates are removed. If the mesh has normals, they
+    /// are recomputed by [`NormalFilters::add_smooth_normals`]. All indices of positions and faces
+    /// are invalidated.
+    ///
+    /// # Panics
+    /// `target_edge_length` must be no less than `TOLERANCE`.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 1.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[0, 1, 2, 3]]),
+    /// );
+    /// mesh.remesh_isotropic(0.1, 5);
+    ///
+    /// assert!(mesh.faces().len() > 100);
+    /// assert!(mesh.positions().iter().all(|p| p.z.so_small()));
+    /// assert_eq!(mesh.shell_condition(), ShellCondition::Oriented);
+    /// ```
+    fn remesh_isotropic(&mut self, target_edge_length: f64, iterations: usize) -> &mut Self;
+}
+
+impl Remeshing for PolygonMesh {
+    fn remesh_isotropic(&mut self, target_edge_length: f64, iterations: usize) -> &mut Self {
+        nonpositive_tolerance!(target_edge_length);
+        let has_normals = !self.normals().is_empty();
+        let mut mesh = TriMesh::new(self);
+        let triangles = mesh
+            .tris
+            .iter()
+            .map(|tri| tri.map(|v| mesh.positions[v]))
+            .collect();
+        let reference = TriangleGrid::new(triangles, target_edge_length);
+        let (max, min) = (
+            target_edge_length * 4.0 / 3.0,
+            target_edge_length * 4.0 / 5.0,
+        );
+        (0..iterations).for_each(|_| {
+            mesh.split_long_edges(max);
+            mesh.collapse_short_edges(min, max);
+            mesh.flip_edges();
+            mesh.relax(&reference);
+        });
+        *self = mesh.into_polygon();
+        if has_normals {
+            self.add_smooth_normals(PI / 3.0, true);
+        }
+        self
+    }
+}
+
+/// Upper bound of the number of passes of splitting and collapsing in one iteration.
+const MAX_PASSES: usize = 16;
+
+type EdgeFaces = HashMap<[usize; 2], Vec<usize>>;
+
+#[derive(Clone, Debug)]
+struct TriMesh {
+    positions: Vec<Point3>,
+    tris: Vec<[usize; 3]>,
+}
+
+impl TriMesh {
+    fn new(mesh: &PolygonMesh) -> Self {
+        Self {
+            positions: mesh.positions().clone(),
+            tris: mesh
+                .faces()
+                .triangle_iter()
+                .map(|tri| tri.map(|v| v.pos))
+                .collect(),
+        }
+    }
+
+    fn into_polygon(self) -> PolygonMesh {
+        let mut map = vec![None; self.positions.len()];
+        let mut positions = Vec::new();
+        let faces = self
+            .tris
+            .into_iter()
+            .map(|tri| {
+                tri.map(|i| {
+                    *map[i].get_or_insert_with(|| {
+                        positions.push(self.positions[i]);
+                        positions.len() - 1
+                    })
+                })
+            })
+            .collect::<Vec<_>>();
+        PolygonMesh::new(
+            StandardAttributes {
+                positions,
+                ..Default::default()
+            },
+            Faces::from_iter(faces),
+        )
+    }
+
+    fn edge_faces(&self) -> EdgeFaces {
+        edge_faces(&self.tris)
+            .into_iter()
+            .map(|(edge, faces)| (edge, faces.into_iter().map(|(i, _)| i).collect()))
+            .collect()
+    }
+
+    fn length2(&self, [a, b]: [usize; 2]) -> f64 { self.positions[a].distance2(self.positions[b]) }
+
+    /// Edges whose squared lengths satisfy `pred`, sorted by the lengths and then by the keys.
+    fn sorted_edges<'a>(
+        &self,
+        edges: &'a EdgeFaces,
+        pred: impl Fn(f64) -> bool,
+        longest_first: bool,
+    ) -> Vec<(&'a [usize; 2], &'a Vec<usize>)> {
+        let mut res = edges
+            .iter()
+            .filter(|(edge, _)| pred(self.length2(**edge)))
+            .collect::<Vec<_>>();
+        res.sort_by(|(e0, _), (e1, _)| {
+            let (l0, l1) = (self.length2(**e0), self.length2(**e1));
+            match longest_first {
+                true => l1.total_cmp(&l0),
+                false => l0.total_cmp(&l1),
+            }
+            .then(e0.cmp(e1))
+        });
+        res
+    }
+
+    fn split_long_edges(&mut self, max: f64) {
+        (0..MAX_PASSES)
+            .take_while(|_| self.split_pass(max))
+            .for_each(drop);
+    }
+
+    /// Splits the edges longer than `max`, and returns `true` if some edge has been split.
+    fn split_pass(&mut self, max: f64) -> bool {
+        let edges = self.edge_faces();
+        let long = self.sorted_edges(&edges, |l2| l2 > max * max, true);
+        let split = !long.is_empty();
+        let mut touched = vec![false; self.tris.len()];
+        let mut new_tris = Vec::new();
+        long.into_iter().for_each(|(&[a, b], faces)| {
+            if faces.iter().any(|&f| touched[f]) {
+                return;
+            }
+            let m = self.positions.len();
+            self.positions
+                .push(self.positions[a].midpoint(self.positions[b]));
+            faces.iter().for_each(|&f| {
+                touched[f] = true;
+                if let Some([p, q, r]) = rotate_to_edge(self.tris[f], a, b) {
+                    new_tris.push([p, m, r]);
+                    new_tris.push([m, q, r]);
+                }
+            });
+        });
+        self.tris = take(&mut self.tris)
+            .into_iter()
+            .zip(touched)
+            .filter_map(|(tri, touched)| (!touched).then_some(tri))
+            .chain(new_tris)
+            .collect();
+        split
+    }
+
+    fn collapse_short_edges(&mut self, min: f64, max: f64) {
+        (0..MAX_PASSES)
+            .take_while(|_| self.collapse_pass(min, max))
+            .for_each(drop);
+    }
+
+    /// Collapses the edges shorter than `min` into their midpoints, unless the collapse makes an
+    /// edge longer than `max` or folds a triangle, and returns `true` if some edge has been
+    /// collapsed.
+    fn collapse_pass(&mut self, min: f64, max: f64) -> bool {
+        let edges = self.edge_faces();
+        let fixed = fixed_vertices(
+            edges.iter().map(|(e, f)| (*e, f.len())),
+            self.positions.len(),
+        );
+        let mut vertex_tris = vec![Vec::new(); self.positions.len()];
+        self.tris.iter().enumerate().for_each(|(i, tri)| {
+            tri.iter().for_each(|&v| vertex_tris[v].push(i));
+        });
+        let mut locked = vec![false; self.positions.len()];
+        let mut removed = vec![false; self.tris.len()];
+        let mut collapsed = false;
+        let short = self.sorted_edges(&edges, |l2| l2 < min * min, false);
+        short.into_iter().for_each(|(&[a, b], faces)| {
+            if locked[a] || locked[b] || fixed[a] || fixed[b] || faces.len() != 2 {
+                return;
+            }
+            let neighbors = |v: usize| -> HashSet<usize> {
+                vertex_tris[v]
+                    .iter()
+                    .flat_map(|&t| self.tris[t])
+                    .filter(|&w| w != v)
+                    .collect()
+            };
+            let (na, nb) = (neighbors(a), neighbors(b));
+            let opposite = faces
+                .iter()
+                .flat_map(|&f| self.tris[f])
+                .filter(|&v| v != a && v != b)
+                .collect::<HashSet<_>>();
+            if na.intersection(&nb).copied().collect::<HashSet<_>>() != opposite {
+                return;
+            }
+            let mid = self.positions[a].midpoint(self.positions[b]);
+            let too_long = na
+                .iter()
+                .chain(&nb)
+                .any(|&v| v != a && v != b && mid.distance2(self.positions[v]) > max * max);
+            let around = || {
+                vertex_tris[a]
+                    .iter()
+                    .chain(&vertex_tris[b])
+                    .filter(|t| !faces.contains(t))
+            };
+            let folded = around().any(|&t| {
+                let old = self.tris[t].map(|v| self.positions[v]);
+                let new = self.tris[t].map(|v| match v == a || v == b {
+                    true => mid,
+                    false => self.positions[v],
+                });
+                triangle_normal(old).dot(triangle_normal(new)) <= 0.0
+            });
+            if too_long || folded {
+                return;
+            }
+            self.positions[b] = mid;
+            faces.iter().for_each(|&f| removed[f] = true);
+            vertex_tris[a].iter().for_each(|&t| {
+                self.tris[t]
+                    .iter_mut()
+                    .filter(|v| **v == a)
+                    .for_each(|v| *v = b);
+            });
+            na.iter().chain(&nb).for_each(|&v| locked[v] = true);
+            (locked[a], locked[b]) = (true, true);
+            collapsed = true;
+        });
+        self.tris = take(&mut self.tris)
+            .into_iter()
+            .zip(removed)
+            .filter_map(|(tri, removed)| (!removed).then_some(tri))
+            .collect();
+        collapsed
+    }
+
+    fn flip_edges(&mut self) {
+        let edges = self.edge_faces();
+        let fixed = fixed_vertices(
+            edges.iter().map(|(e, f)| (*e, f.len())),
+            self.positions.len(),
+        );
+        let mut valence = vec![0_i64; self.positions.len()];
+        edges.keys().for_each(|[a, b]| {
+            valence[*a] += 1;
+            valence[*b] += 1;
+        });
+        let target = |v: usize| match fixed[v] {
+            true => 4,
+            false => 6,
+        };
+        let positions = &self.positions;
+        flip_pass(
+            &mut self.tris,
+            |quad| quad.map(|v| positions[v]),
+            |[p, q, c, d], _| {
+                let deviation = |dp: i64, dc: i64| {
+                    (valence[p] + dp - target(p)).abs()
+                        + (valence[q] + dp - target(q)).abs()
+                        + (valence[c] + dc - target(c)).abs()
+                        + (valence[d] + dc - target(d)).abs()
+                };
+                let improved = deviation(-1, 1) < deviation(0, 0);
+                if improved {
+                    valence[p] -= 1;
+                    valence[q] -= 1;
+                    valence[c] += 1;
+                    valence[d] += 1;
+                }
+                improved
+            },
+        );
+    }
+
+    fn relax(&mut self, reference: &TriangleGrid) {
+        let edges = self.edge_faces();
+        let fixed = fixed_vertices(
+            edges.iter().map(|(e, f)| (*e, f.len())),
+            self.positions.len(),
+        );
+        let mut neighbors = vec![Vec::new(); self.positions.len()];
+        edges.keys().for_each(|[a, b]| {
+            neighbors[*a].push(*b);
+            neighbors[*b].push(*a);
+        });
+        let mut normals = vec![Vector3::new(0.0, 0.0, 0.0); self.positions.len()];
+        self.tris.iter().for_each(|tri| {
+            let normal = triangle_normal(tri.map(|v| self.positions[v]));
+            tri.iter().for_each(|&v| normals[v] += normal);
+        });
+        self.positions = self
+            .positions
+            .iter()
+            .enumerate()
+            .map(|(i, p)| {
+                if fixed[i] || neighbors[i].is_empty() {
+                    return *p;
+                }
+                let sum = neighbors[i]
+                    .iter()
+                    .fold(Vector3::new(0.0, 0.0, 0.0), |sum, &v| {
+                        sum + self.positions[v].to_vec()
+                    });
+                let q = Point3::from_vec(sum / neighbors[i].len() as f64);
+                let moved = match normals[i].so_small() {
+                    true => q,
+                    false => {
+                        let n = normals[i].normalize();
+                        q + n * n.dot(p - q)
+                    }
+                };
+                reference.project(moved)
+            })
+            .collect();
+    }
+}
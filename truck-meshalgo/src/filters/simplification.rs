@@ -0,0 +1,758 @@
+use super::*;
+use common::fixed_vertices;
+use rustc_hash::FxHashMap as HashMap;
+use std::{
+    cmp::Ordering,
+    collections::BinaryHeap,
+    f64::consts::PI,
+    iter::successors,
+    mem::{replace, take},
+    ops::Add,
+};
+
+/// Filters for reducing the number of polygons.
+pub trait Simplification {
+    /// Simplifies the mesh by vertex clustering on the uniform grid.
+    ///
+    /// # Details
+    /// The space is divided into cubic cells of `cell_size`, and all positions in the same cell are
+    /// snapped to the centroid of them. Faces which degenerate by the snapping are removed, and
+    /// unused attributes are cleaned up. If the mesh has normals, they are recomputed by
+    /// [`NormalFilters::add_smooth_normals`].
+    ///
+    /// This runs in linear time, so it is suitable for huge meshes where exact decimation is too slow.
+    /// On the other hand, the topology of the mesh is not preserved in general. All indices of
+    /// positions and faces are invalidated.
+    ///
+    /// # Panics
+    /// `cell_size` must be no less than `TOLERANCE`.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// // a strip of four triangles whose middle positions are near each other
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.1, 0.1, 0.0),
+    ///             Point3::new(1.1, 0.1, 0.0),
+    ///             Point3::new(1.2, 0.2, 0.0),
+    ///             Point3::new(2.1, 0.1, 0.0),
+    ///             Point3::new(2.1, 1.1, 0.0),
+    ///             Point3::new(1.1, 1.1, 0.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[0, 1, 5], [1, 2, 5], [2, 3, 4], [2, 4, 5]]),
+    /// );
+    /// mesh.cluster_simplify(1.0);
+    /// // The positions 1 and 2 are snapped together, and the triangle `[1, 2, 5]` is removed.
+    /// assert_eq!(mesh.positions().len(), 5);
+    /// assert_eq!(mesh.faces().len(), 3);
+    /// ```
+    fn cluster_simplify(&mut self, cell_size: f64) -> &mut Self;
+
+    /// Simplifies the mesh by quadric error edge collapses as much as possible within `max_error`.
+    ///
+    /// # Details
+    /// Each vertex holds the quadric of the planes of the original faces merged into it. The edges
+    /// are collapsed in the increasing order of the quadric error, and the decimation stops when
+    /// the error of the next collapse would exceed `max_error`. Here, the error is the square root
+    /// of the sum of the squared distances to the planes, so every vertex of the simplified mesh
+    /// is within `max_error` of all planes of the original faces merged into it.
+    ///
+    /// - The faces are triangulated, and the result consists of triangles only.
+    /// - The vertices on the boundary or on the non-manifold edges are not moved, and the edges
+    ///   between two such vertices are not collapsed.
+    /// - The collapses which would change the topology or flip faces are skipped.
+    /// - Unused attributes are cleaned up, and all indices of positions and faces are
+    ///   invalidated. If the mesh has normals, they are recomputed by
+    ///   [`NormalFilters::add_smooth_normals`].
+    ///
+    /// The positions should be put together by [`OptimizingFilter::put_together_same_attrs`]
+    /// in advance, since edges are connected by the indices of positions.
+    ///
+    /// # Panics
+    /// `max_error` must be non-negative.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// // a flat 3x3 grid of squares
+    /// let positions = (0..16)
+    ///     .map(|i| Point3::new((i % 4) as f64, (i / 4) as f64, 0.0))
+    ///     .collect::<Vec<_>>();
+    /// let faces = (0..9)
+    ///     .map(|i| {
+    ///         let j = i / 3 * 4 + i % 3;
+    ///         [j, j + 1, j + 5, j + 4]
+    ///     })
+    ///     .collect::<Faces>();
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions,
+    ///         ..Default::default()
+    ///     },
+    ///     faces,
+    /// );
+    /// mesh.quadric_decimate_to_error(TOLERANCE);
+    /// // Only the four interior vertices can be removed without moving the boundary.
+    /// assert_eq!(mesh.positions().len(), 12);
+    /// assert!(mesh.positions().iter().all(|p| p.z.so_small()));
+    /// ```
+    fn quadric_decimate_to_error(&mut self, max_error: f64) -> &mut Self;
+
+    /// Simplifies the mesh by quadric error edge collapses until the number of faces is at most
+    /// `target_face_count`.
+    ///
+    /// The collapses are the same as [`Simplification::quadric_decimate_to_error`] without the
+    /// bound of the error, so the mesh may have more faces than `target_face_count` if no more
+    /// collapses are valid.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// // a flat 3x3 grid of squares, each split into two triangles
+    /// let positions = (0..16)
+    ///     .map(|i| Point3::new((i % 4) as f64, (i / 4) as f64, 0.0))
+    ///     .collect::<Vec<_>>();
+    /// let faces = (0..9)
+    ///     .flat_map(|i| {
+    ///         let j = i / 3 * 4 + i % 3;
+    ///         [[j, j + 1, j + 5], [j, j + 5, j + 4]]
+    ///     })
+    ///     .collect::<Faces>();
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions,
+    ///         ..Default::default()
+    ///     },
+    ///     faces,
+    /// );
+    /// assert_eq!(mesh.faces().len(), 18);
+    /// mesh.quadric_decimate(14);
+    /// assert!(mesh.faces().len() <= 14);
+    /// ```
+    fn quadric_decimate(&mut self, target_face_count: usize) -> &mut Self;
+
+    /// Simplifies the mesh by [`Simplification::quadric_decimate`], preserving the scalar field
+    /// `scalars` on the positions.
+    ///
+    /// # Details
+    /// The vertices are regarded as the points `(x, y, z, weight * s)` in four dimensions, where
+    /// `s` is the value of the field, and each vertex holds the generalized quadric of Garland and
+    /// Heckbert, the sum of the squared distances to the planes of the original triangles in the
+    /// four dimensional space. Hence, the collapses which distort the field are penalized as well
+    /// as the ones which distort the shape, and the field is also optimized at the collapsed
+    /// vertices. `weight` balances the errors of the field and the positions.
+    ///
+    /// `scalars` is indexed by the positions, and is updated with the positions so that
+    /// `scalars[i]` is the value on `self.positions()[i]` after the decimation. The other
+    /// properties are the same as [`Simplification::quadric_decimate`].
+    ///
+    /// # Panics
+    /// - The length of `scalars` must be the same as the one of the positions.
+    /// - `weight` must be positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// // a flat 3x3 grid of squares with the linear field
+    /// let positions = (0..16)
+    ///     .map(|i| Point3::new((i % 4) as f64, (i / 4) as f64, 0.0))
+    ///     .collect::<Vec<_>>();
+    /// let mut scalars = positions.iter().map(|p| p.x - 2.0 * p.y).collect::<Vec<_>>();
+    /// let faces = (0..9)
+    ///     .map(|i| {
+    ///         let j = i / 3 * 4 + i % 3;
+    ///         [j, j + 1, j + 5, j + 4]
+    ///     })
+    ///     .collect::<Faces>();
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions,
+    ///         ..Default::default()
+    ///     },
+    ///     faces,
+    /// );
+    /// mesh.quadric_decimate_with_scalar(&mut scalars, 1.0, 14);
+    /// assert!(mesh.faces().len() <= 14);
+    /// assert_eq!(scalars.len(), mesh.positions().len());
+    /// // the field is still linear
+    /// mesh.positions().iter().zip(&scalars).for_each(|(p, s)| {
+    ///     assert!(f64::abs(p.x - 2.0 * p.y - s) < 1.0e-6);
+    /// });
+    /// ```
+    fn quadric_decimate_with_scalar(
+        &mut self,
+        scalars: &mut Vec<f64>,
+        weight: f64,
+        target_face_count: usize,
+    ) -> &mut Self;
+
+    /// Returns the mesh decimated by [`Simplification::quadric_decimate`], leaving `self`
+    /// untouched.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// let positions = (0..16)
+    ///     .map(|i| Point3::new((i % 4) as f64, (i / 4) as f64, 0.0))
+    ///     .collect::<Vec<_>>();
+    /// let faces = (0..9)
+    ///     .map(|i| {
+    ///         let j = i / 3 * 4 + i % 3;
+    ///         [j, j + 1, j + 5, j + 4]
+    ///     })
+    ///     .collect::<Faces>();
+    /// let mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions,
+    ///         ..Default::default()
+    ///     },
+    ///     faces,
+    /// );
+    /// let preview = mesh.quadric_decimated(14);
+    /// assert!(preview.faces().len() <= 14);
+    /// assert_eq!(mesh.faces().len(), 9);
+    /// ```
+    fn quadric_decimated(&self, target_face_count: usize) -> Self
+    where Self: Clone {
+        let mut mesh = self.clone();
+        mesh.quadric_decimate(target_face_count);
+        mesh
+    }
+}
+
+impl Simplification for PolygonMesh {
+    fn cluster_simplify(&mut self, cell_size: f64) -> &mut Self {
+        nonpositive_tolerance!(cell_size);
+        let has_normals = !self.normals().is_empty();
+        let mut mesh = self.debug_editor();
+        let PolygonMeshEditor {
+            attributes: StandardAttributes {
+                positions, normals, ..
+            },
+            faces,
+            ..
+        } = &mut mesh;
+        let mut cells = HashMap::<[i64; 3], usize>::default();
+        let mut sums = Vec::<(Vector3, usize)>::new();
+        let pos_map = positions
+            .iter()
+            .map(|p| {
+                let key = p.to_vec().map(|x| (x / cell_size).floor() as i64).into();
+                let idx = *cells.entry(key).or_insert_with(|| {
+                    sums.push((Vector3::zero(), 0));
+                    sums.len() - 1
+                });
+                sums[idx].0 += p.to_vec();
+                sums[idx].1 += 1;
+                idx
+            })
+            .collect::<Vec<_>>();
+        *positions = sums
+            .into_iter()
+            .map(|(sum, count)| Point3::from_vec(sum / count as f64))
+            .collect();
+        faces.face_iter_mut().flatten().for_each(|v| {
+            v.pos = pos_map[v.pos];
+            v.nor = None;
+        });
+        normals.clear();
+        drop(mesh);
+        self.remove_degenerate_faces().remove_unused_attrs();
+        if has_normals {
+            self.add_smooth_normals(PI / 3.0, true);
+        }
+        self
+    }
+
+    fn quadric_decimate_to_error(&mut self, max_error: f64) -> &mut Self {
+        nonpositive_tolerance!(max_error, 0.0);
+        quadric_decimation(self, max_error, 0, None)
+    }
+
+    fn quadric_decimate(&mut self, target_face_count: usize) -> &mut Self {
+        quadric_decimation(self, f64::INFINITY, target_face_count, None)
+    }
+
+    fn quadric_decimate_with_scalar(
+        &mut self,
+        scalars: &mut Vec<f64>,
+        weight: f64,
+        target_face_count: usize,
+    ) -> &mut Self {
+        assert_eq!(
+            scalars.len(),
+            self.positions().len(),
+            "the scalar field must be given on all positions."
+        );
+        assert!(weight > 0.0, "the weight of the field must be positive.");
+        quadric_decimation(
+            self,
+            f64::INFINITY,
+            target_face_count,
+            Some((scalars, weight)),
+        )
+    }
+}
+
+/// Decimates `mesh` until the error exceeds `max_error` or the number of faces reaches
+/// `target_face_count`. If `field` is given, the scalar field with the weight is also preserved.
+fn quadric_decimation<'a>(
+    this: &'a mut PolygonMesh,
+    max_error: f64,
+    target_face_count: usize,
+    field: Option<(&mut Vec<f64>, f64)>,
+) -> &'a mut PolygonMesh {
+    let has_normals = !this.normals().is_empty();
+    let mut mesh = this.debug_editor();
+    let PolygonMeshEditor {
+        attributes:
+            StandardAttributes {
+                positions,
+                uv_coords,
+                normals,
+            },
+        faces,
+        ..
+    } = &mut mesh;
+    let triangles = faces.triangle_iter().collect::<Vec<_>>();
+    let attribute = field
+        .as_ref()
+        .map(|(scalars, weight)| AttributeField::new(positions, &triangles, scalars, *weight));
+    let mut decimator = Decimator::new(positions.clone(), &triangles, attribute);
+    decimator.decimate(max_error, target_face_count);
+    let Decimator {
+        positions: new_positions,
+        faces: new_faces,
+        alive,
+        attribute,
+        ..
+    } = decimator;
+    // the positions, the scalars and the uv coordinates are compacted to the used ones
+    let live = triangles
+        .into_iter()
+        .zip(new_faces)
+        .zip(alive)
+        .filter_map(|((tri, face), alive)| alive.then_some((tri.map(|v| v.uv), face)))
+        .collect::<Vec<_>>();
+    let position_indices = live.iter().flat_map(|(_, face)| *face);
+    let (kept_positions, position_map) = compaction(position_indices, new_positions.len());
+    let uv_indices = live
+        .iter()
+        .flat_map(|(uvs, _)| uvs.iter().flatten().copied());
+    let (kept_uvs, uv_map) = compaction(uv_indices, uv_coords.len());
+    *positions = kept_positions.iter().map(|i| new_positions[*i]).collect();
+    *uv_coords = kept_uvs.iter().map(|i| uv_coords[*i]).collect();
+    **faces = live
+        .into_iter()
+        .map(|(uvs, face)| {
+            [0, 1, 2].map(|i| Vertex {
+                pos: position_map[face[i]],
+                uv: uvs[i].map(|j| uv_map[j]),
+                nor: None,
+            })
+        })
+        .collect();
+    normals.clear();
+    if let (Some((scalars, _)), Some(attribute)) = (field, attribute) {
+        *scalars = kept_positions
+            .iter()
+            .map(|i| attribute.values[*i])
+            .collect();
+    }
+    drop(mesh);
+    if has_normals {
+        this.add_smooth_normals(PI / 3.0, true);
+    }
+    this
+}
+
+/// Returns the used indices in the order of their first appearances in `indices`, and the map
+/// from the old indices to the new ones.
+fn compaction(indices: impl Iterator<Item = usize>, len: usize) -> (Vec<usize>, Vec<usize>) {
+    let mut appeared = vec![false; len];
+    let kept = indices
+        .filter(|i| !replace(&mut appeared[*i], true))
+        .collect::<Vec<_>>();
+    let mut map = vec![0; len];
+    kept.iter().enumerate().for_each(|(j, i)| map[*i] = j);
+    (kept, map)
+}
+
+/// the state of the quadric error edge collapses
+#[derive(Debug)]
+struct Decimator {
+    positions: Vec<Point3>,
+    quadrics: Vec<Matrix4>,
+    faces: Vec<[usize; 3]>,
+    alive: Vec<bool>,
+    /// the indices of faces incident to each vertex, including dead ones
+    incidence: Vec<Vec<usize>>,
+    locked: Vec<bool>,
+    /// incremented each time the vertex is changed, in order to detect outdated collapses
+    stamps: Vec<usize>,
+    heap: BinaryHeap<Collapse>,
+    attribute: Option<AttributeField>,
+}
+
+/// the scalar field and the generalized quadrics of the vertices
+#[derive(Debug)]
+struct AttributeField {
+    weight: f64,
+    values: Vec<f64>,
+    quadrics: Vec<AttributeQuadric>,
+}
+
+/// the quadric `v.dot(a * v) + 2 b.dot(v) + c` on the points `v` in four dimensions
+#[derive(Clone, Copy, Debug)]
+struct AttributeQuadric {
+    a: Matrix4,
+    b: Vector4,
+    c: f64,
+}
+
+/// a candidate of the collapse of the edge `(a, b)` into `point`
+#[derive(Clone, Copy, Debug)]
+struct Collapse {
+    error: f64,
+    edge: [usize; 2],
+    stamps: [usize; 2],
+    point: Point3,
+    /// the value of the field at `point`, used only if the field is given
+    scalar: f64,
+}
+
+impl PartialEq for Collapse {
+    fn eq(&self, other: &Self) -> bool { self.cmp(other) == Ordering::Equal }
+}
+
+impl Eq for Collapse {}
+
+impl PartialOrd for Collapse {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+
+impl Ord for Collapse {
+    // reversed so that `BinaryHeap` pops the minimum error
+    fn cmp(&self, other: &Self) -> Ordering { other.error.total_cmp(&self.error) }
+}
+
+impl Decimator {
+    fn new(
+        positions: Vec<Point3>,
+        triangles: &[[Vertex; 3]],
+        attribute: Option<AttributeField>,
+    ) -> Self {
+        let len = positions.len();
+        let faces = triangles
+            .iter()
+            .map(|tri| tri.map(|v| v.pos))
+            .collect::<Vec<_>>();
+        let alive = faces
+            .iter()
+            .map(|f| f[0] != f[1] && f[1] != f[2] && f[2] != f[0])
+            .collect::<Vec<_>>();
+        let mut quadrics = vec![Matrix4::zero(); len];
+        let mut incidence = vec![Vec::new(); len];
+        faces
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| alive[*i])
+            .for_each(|(i, face)| {
+                let quadric = face_quadric(face.map(|j| positions[j]));
+                (0..3).for_each(|k| {
+                    quadrics[face[k]] += quadric;
+                    incidence[face[k]].push(i);
+                });
+            });
+        let edge_count = edge_face_counts(
+            faces
+                .iter()
+                .zip(&alive)
+                .filter_map(|(face, alive)| alive.then_some(face)),
+        );
+        let locked = fixed_vertices(edge_count.iter().map(|(e, c)| (*e, *c)), len);
+        let mut decimator = Self {
+            positions,
+            quadrics,
+            faces,
+            alive,
+            incidence,
+            locked,
+            stamps: vec![0; len],
+            heap: BinaryHeap::new(),
+            attribute,
+        };
+        edge_count
+            .into_keys()
+            .for_each(|[a, b]| decimator.push_collapse(a, b));
+        decimator
+    }
+
+    fn push_collapse(&mut self, a: usize, b: usize) {
+        let fixed = match (self.locked[a], self.locked[b]) {
+            (true, true) => return,
+            (true, false) => Some(a),
+            (false, true) => Some(b),
+            (false, false) => None,
+        };
+        let (error, point, scalar) = match &self.attribute {
+            Some(attribute) => attribute.optimal_collapse(&self.positions, [a, b], fixed),
+            None => {
+                let quadric = self.quadrics[a] + self.quadrics[b];
+                let (pa, pb) = (self.positions[a], self.positions[b]);
+                let point = match fixed {
+                    Some(i) => self.positions[i],
+                    None => least_error([pa, pb, pa.midpoint(pb)], optimal_point(&quadric), |p| {
+                        quadric_error(&quadric, p)
+                    }),
+                };
+                (quadric_error(&quadric, point), point, 0.0)
+            }
+        };
+        self.heap.push(Collapse {
+            error,
+            edge: [a, b],
+            stamps: [self.stamps[a], self.stamps[b]],
+            point,
+            scalar,
+        });
+    }
+
+    fn neighbors(&self, a: usize) -> Vec<usize> {
+        let mut res = self.incidence[a]
+            .iter()
+            .filter(|i| self.alive[**i])
+            .flat_map(|i| self.faces[*i])
+            .filter(|i| *i != a)
+            .collect::<Vec<_>>();
+        res.sort();
+        res.dedup();
+        res
+    }
+
+    fn is_valid(&self, collapse: &Collapse) -> bool {
+        let [a, b] = collapse.edge;
+        if collapse.stamps != [self.stamps[a], self.stamps[b]] {
+            return false;
+        }
+        let shared = self.incidence[a]
+            .iter()
+            .filter(|i| self.alive[**i] && self.faces[**i].contains(&b))
+            .count();
+        let (na, nb) = (self.neighbors(a), self.neighbors(b));
+        let common = na.iter().filter(|i| nb.contains(i)).count();
+        if shared == 0 || common != shared {
+            return false;
+        }
+        self.incidence[a]
+            .iter()
+            .chain(&self.incidence[b])
+            .filter(|i| self.alive[**i])
+            .map(|i| self.faces[*i])
+            .filter(|face| !(face.contains(&a) && face.contains(&b)))
+            .all(|face| {
+                let old = face.map(|i| self.positions[i]);
+                let new = face.map(|i| match i == a || i == b {
+                    true => collapse.point,
+                    false => self.positions[i],
+                });
+                let old_normal = (old[1] - old[0]).cross(old[2] - old[0]);
+                let new_normal = (new[1] - new[0]).cross(new[2] - new[0]);
+                !new_normal.magnitude2().so_small2() && old_normal.dot(new_normal) > 0.0
+            })
+    }
+
+    /// Returns the number of removed faces.
+    fn collapse(&mut self, collapse: &Collapse) -> usize {
+        let [a, b] = collapse.edge;
+        self.positions[a] = collapse.point;
+        self.quadrics[a] = self.quadrics[a] + self.quadrics[b];
+        self.locked[a] |= self.locked[b];
+        if let Some(attribute) = &mut self.attribute {
+            attribute.values[a] = collapse.scalar;
+            attribute.quadrics[a] = attribute.quadrics[a] + attribute.quadrics[b];
+        }
+        let (removed, moved): (Vec<_>, Vec<_>) = take(&mut self.incidence[b])
+            .into_iter()
+            .filter(|i| self.alive[*i])
+            .partition(|i| self.faces[*i].contains(&a));
+        removed.iter().for_each(|i| self.alive[*i] = false);
+        moved.iter().for_each(|i| {
+            self.faces[*i]
+                .iter_mut()
+                .filter(|j| **j == b)
+                .for_each(|j| *j = a)
+        });
+        self.incidence[a].extend(moved);
+        let alive = &self.alive;
+        self.incidence[a].retain(|i| alive[*i]);
+        self.stamps[a] += 1;
+        self.stamps[b] += 1;
+        self.neighbors(a)
+            .into_iter()
+            .for_each(|c| self.push_collapse(a, c));
+        removed.len()
+    }
+
+    fn decimate(&mut self, max_error: f64, target_face_count: usize) {
+        let face_count = self.alive.iter().filter(|alive| **alive).count();
+        // the collapses with the least errors are popped until the face count reaches the target
+        let step = |&face_count: &usize| match face_count > target_face_count {
+            true => self
+                .heap
+                .pop()
+                .filter(|collapse| collapse.error <= max_error)
+                .map(|collapse| match self.is_valid(&collapse) {
+                    true => face_count - self.collapse(&collapse),
+                    false => face_count,
+                }),
+            false => None,
+        };
+        successors(Some(face_count), step).for_each(drop);
+    }
+}
+
+impl AttributeField {
+    fn new(positions: &[Point3], triangles: &[[Vertex; 3]], values: &[f64], weight: f64) -> Self {
+        let mut quadrics = vec![AttributeQuadric::zero(); positions.len()];
+        triangles.iter().for_each(|tri| {
+            let quadric = AttributeQuadric::from_triangle(
+                tri.map(|v| positions[v.pos].to_vec().extend(weight * values[v.pos])),
+            );
+            tri.iter()
+                .for_each(|v| quadrics[v.pos] = quadrics[v.pos] + quadric);
+        });
+        Self {
+            weight,
+            values: values.to_vec(),
+            quadrics,
+        }
+    }
+
+    /// Returns the error, the position and the value of the collapse of `edge`.
+    fn optimal_collapse(
+        &self,
+        positions: &[Point3],
+        [a, b]: [usize; 2],
+        fixed: Option<usize>,
+    ) -> (f64, Point3, f64) {
+        let quadric = self.quadrics[a] + self.quadrics[b];
+        let point = |i: usize| positions[i].to_vec().extend(self.weight * self.values[i]);
+        let v = match fixed {
+            Some(i) => point(i),
+            None => {
+                let (va, vb) = (point(a), point(b));
+                least_error([va, vb, (va + vb) / 2.0], quadric.optimal_point(), |v| {
+                    quadric.error(v)
+                })
+            }
+        };
+        (
+            quadric.error(v),
+            Point3::from_vec(v.truncate()),
+            v.w / self.weight,
+        )
+    }
+}
+
+impl AttributeQuadric {
+    fn zero() -> Self {
+        Self {
+            a: Matrix4::zero(),
+            b: Vector4::zero(),
+            c: 0.0,
+        }
+    }
+
+    /// the sum of the squared distances to the plane of the triangle in four dimensions
+    fn from_triangle([p, q, r]: [Vector4; 3]) -> Self {
+        let e1 = q - p;
+        if e1.magnitude2().so_small2() {
+            return Self::zero();
+        }
+        let e1 = e1.normalize();
+        let e2 = (r - p) - e1 * e1.dot(r - p);
+        if e2.magnitude2().so_small2() {
+            return Self::zero();
+        }
+        let e2 = e2.normalize();
+        let outer = |e: Vector4| Matrix4::from_cols(e * e.x, e * e.y, e * e.z, e * e.w);
+        let (d1, d2) = (p.dot(e1), p.dot(e2));
+        Self {
+            a: Matrix4::identity() - outer(e1) - outer(e2),
+            b: e1 * d1 + e2 * d2 - p,
+            c: p.dot(p) - d1 * d1 - d2 * d2,
+        }
+    }
+
+    fn error(&self, v: Vector4) -> f64 {
+        let value = v.dot(self.a * v) + 2.0 * self.b.dot(v) + self.c;
+        f64::sqrt(f64::max(value, 0.0))
+    }
+
+    /// the point minimizing the quadric, if it is unique
+    fn optimal_point(&self) -> Option<Vector4> {
+        if self.a.determinant().abs() < TOLERANCE2 {
+            return None;
+        }
+        Some(-(self.a.invert()? * self.b))
+    }
+}
+
+impl Add for AttributeQuadric {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Self {
+            a: self.a + other.a,
+            b: self.b + other.b,
+            c: self.c + other.c,
+        }
+    }
+}
+
+/// Returns the candidate of the collapsed point with the least `error`, among the ends and the
+/// midpoint of the edge, and the optimal point if any.
+fn least_error<V: Copy>(candidates: [V; 3], optimal: Option<V>, error: impl Fn(V) -> f64) -> V {
+    let candidates = candidates.into_iter().chain(optimal);
+    let least = candidates.min_by(|v, w| error(*v).total_cmp(&error(*w)));
+    // SAFETY: there are at least three candidates.
+    least.unwrap()
+}
+
+/// the quadric of the plane of the triangle, with the unit normal
+fn face_quadric(tri: [Point3; 3]) -> Matrix4 {
+    let normal = (tri[1] - tri[0]).cross(tri[2] - tri[0]);
+    if normal.magnitude2().so_small2() {
+        return Matrix4::zero();
+    }
+    let normal = normal.normalize();
+    let plane = normal.extend(-normal.dot(tri[0].to_vec()));
+    Matrix4::from_cols(
+        plane * plane.x,
+        plane * plane.y,
+        plane * plane.z,
+        plane * plane.w,
+    )
+}
+
+fn quadric_error(quadric: &Matrix4, point: Point3) -> f64 {
+    let v = point.to_homogeneous();
+    f64::sqrt(f64::max(v.dot(quadric * v), 0.0))
+}
+
+/// the point minimizing the quadric, if it is unique
+fn optimal_point(quadric: &Matrix4) -> Option<Point3> {
+    let mat = Matrix3::from_cols(
+        quadric[0].truncate(),
+        quadric[1].truncate(),
+        quadric[2].truncate(),
+    );
+    if mat.determinant().abs() < TOLERANCE2 {
+        return None;
+    }
+    Some(Point3::from_vec(-(mat.invert()? * quadric[3].truncate())))
+}
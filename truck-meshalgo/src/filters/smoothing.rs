@@ -0,0 +1,107 @@
+use super::*;
+use common::fixed_vertices;
+use std::f64::consts::PI;
+
+/// Filters for smoothing the positions of meshes.
+pub trait Smoothing {
+    /// Smooths the mesh by Taubin's λ|μ scheme, which does not shrink the mesh unlike the
+    /// plain Laplacian smoothing.
+    ///
+    /// # Details
+    /// Each iteration runs two steps of the umbrella operator: the shrinking step moves each
+    /// position toward the centroid of its neighbors by the factor `lambda`, and the inflating
+    /// step moves it by the negative factor `mu`. The vertices on boundaries and non-manifold
+    /// edges are fixed. The neighbors are determined by position indices, so the positions
+    /// should be shared in advance by [`OptimizingFilter::put_together_same_attrs`]. If the mesh
+    /// has normals, they are recomputed by [`NormalFilters::add_smooth_normals`].
+    ///
+    /// The positions are moved in place, so the number and the order of the positions and the
+    /// position indices of the faces are kept.
+    ///
+    /// The typical parameters are `lambda = 0.5` and `mu = -0.53`.
+    ///
+    /// # Panics
+    /// The parameters must satisfy `0 < lambda < -mu`.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// // a fan of four triangles whose center is displaced
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 1.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///             Point3::new(-1.0, 0.0, 0.0),
+    ///             Point3::new(0.0, -1.0, 0.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[0, 1, 2], [0, 2, 3], [0, 3, 4], [0, 4, 1]]),
+    /// );
+    /// mesh.taubin_smoothing(10, 0.5, -0.53);
+    /// // The boundary is fixed, and the center gets close to the plane.
+    /// assert_eq!(mesh.positions()[1], Point3::new(1.0, 0.0, 0.0));
+    /// assert!(mesh.positions()[0].z.abs() < 0.1);
+    /// ```
+    fn taubin_smoothing(&mut self, iterations: usize, lambda: f64, mu: f64) -> &mut Self;
+
+    /// Returns the mesh smoothed by [`Smoothing::taubin_smoothing`], leaving `self` untouched.
+    fn taubin_smoothed(&self, iterations: usize, lambda: f64, mu: f64) -> Self
+    where Self: Clone {
+        let mut mesh = self.clone();
+        mesh.taubin_smoothing(iterations, lambda, mu);
+        mesh
+    }
+}
+
+impl Smoothing for PolygonMesh {
+    fn taubin_smoothing(&mut self, iterations: usize, lambda: f64, mu: f64) -> &mut Self {
+        assert!(
+            0.0 < lambda && lambda < -mu,
+            "the parameters must satisfy 0 < lambda < -mu: lambda = {lambda}, mu = {mu}"
+        );
+        #[cfg(debug_assertions)]
+        let indices = position_indices(self);
+        let has_normals = !self.normals().is_empty();
+        let adjacency = self.faces().vertex_adjacency(self.positions().len());
+        let fixed = fixed_vertices(
+            edge_face_counts(self.faces().face_iter()),
+            self.positions().len(),
+        );
+        let umbrella = |positions: &mut [Point3], factor: f64| {
+            let moved = positions
+                .iter()
+                .enumerate()
+                .map(|(i, p)| {
+                    if fixed[i] || adjacency[i].is_empty() {
+                        return *p;
+                    }
+                    let sum = adjacency[i]
+                        .iter()
+                        .fold(Vector3::new(0.0, 0.0, 0.0), |sum, j| {
+                            sum + positions[*j].to_vec()
+                        });
+                    let centroid = Point3::from_vec(sum / adjacency[i].len() as f64);
+                    p + (centroid - p) * factor
+                })
+                .collect::<Vec<_>>();
+            positions.copy_from_slice(&moved);
+        };
+        (0..iterations).for_each(|_| {
+            umbrella(self.positions_mut(), lambda);
+            umbrella(self.positions_mut(), mu);
+        });
+        if has_normals {
+            self.add_smooth_normals(PI / 3.0, true);
+        }
+        #[cfg(debug_assertions)]
+        assert_eq!(
+            position_indices(self),
+            indices,
+            "the position indices are changed."
+        );
+        self
+    }
+}
@@ -0,0 +1,280 @@
+use super::*;
+use itertools::iproduct;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use std::iter::{repeat_with, successors};
+
+/// Filters for joining meshes.
+pub trait Stitching {
+    /// Merges `other` into `self`, and joins the boundary loops of both meshes which coincide.
+    ///
+    /// # Details
+    /// A boundary loop of `other` is stitched to a boundary loop of `self` if they have the same
+    /// number of vertices and the vertices along the loops correspond one-to-one within `tol`, up
+    /// to the cyclic shift and the direction. If there are several such correspondences, the one
+    /// with the least sum of the squared distances is taken, and each loop of `self` is stitched
+    /// at most once. The positions of the stitched loop of `other` are replaced by the
+    /// corresponding positions of `self`, so the boundary edges become interior edges.
+    /// The boundaries which do not match stay as boundaries. Unused attributes are removed after
+    /// stitching.
+    ///
+    /// This is the manual counterpart of the watertight tessellation of shells, for meshes created
+    /// separately.
+    ///
+    /// # Panics
+    /// `tol` must be no less than `TOLERANCE`.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// let mut mesh0 = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[0, 1, 2]]),
+    /// );
+    /// // the same triangle with the opposite winding
+    /// let mesh1 = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[0, 1, 2]]),
+    /// );
+    /// mesh0.stitch(&mesh1, TOLERANCE);
+    /// assert_eq!(mesh0.positions().len(), 3);
+    /// assert_eq!(mesh0.shell_condition(), ShellCondition::Closed);
+    /// ```
+    fn stitch(&mut self, other: &Self, tol: f64) -> &mut Self;
+    /// Splits the edges on which the vertices of the other faces lie, so that the faces are
+    /// connected there, and returns the number of the resolved pairs of the vertices and edges.
+    ///
+    /// # Details
+    /// A T-junction is a position within `tol` of the interior of an edge of a face which does
+    /// not contain the position, and farther than `tol` from the end points of the edge. Each face
+    /// having such an edge is replaced by the fan of triangles from the position, so the position
+    /// becomes a vertex of the face. The texture coordinates and the normals of the new vertices
+    /// are interpolated along the edges. The faces are split repeatedly until no T-junction is
+    /// left, so several positions on one edge are all resolved.
+    ///
+    /// The faces are assumed to be convex, and the positions are not welded by this method; apply
+    /// [`OptimizingFilter::put_together_same_attrs`] beforehand if needed.
+    ///
+    /// # Panics
+    /// `tol` must be no less than `TOLERANCE`.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// // the square whose lower half is one triangle and upper half is two triangles
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(2.0, 0.0, 0.0),
+    ///             Point3::new(2.0, 2.0, 0.0),
+    ///             Point3::new(0.0, 2.0, 0.0),
+    ///             Point3::new(1.0, 1.0, 0.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[0, 1, 2], [0, 4, 3], [4, 2, 3]]),
+    /// );
+    /// // the vertex 4 is on the diagonal of the lower triangle, so the diagonal is a boundary
+    /// assert_eq!(mesh.extract_boundaries().len(), 2);
+    ///
+    /// assert_eq!(mesh.resolve_t_junctions(TOLERANCE), 1);
+    /// assert_eq!(mesh.tri_faces().len(), 4);
+    /// assert_eq!(mesh.extract_boundaries().len(), 1);
+    /// assert_eq!(mesh.shell_condition(), ShellCondition::Oriented);
+    /// ```
+    fn resolve_t_junctions(&mut self, tol: f64) -> usize;
+}
+
+impl Stitching for PolygonMesh {
+    fn stitch(&mut self, other: &Self, tol: f64) -> &mut Self {
+        nonpositive_tolerance!(tol);
+        let offset = self.positions().len();
+        let loops0 = boundary_loops(self.faces());
+        let loops1 = boundary_loops(other.faces());
+        let (positions0, positions1) = (self.positions(), other.positions());
+        // the vertices of the loops of `self` hashed by a uniform grid with the cell size `tol`
+        let key = |p: Point3| [p.x, p.y, p.z].map(|x| (x / tol).floor() as i64);
+        let mut cells = HashMap::<[i64; 3], Vec<(usize, usize)>>::default();
+        loops0.iter().enumerate().for_each(|(i, loop0)| {
+            loop0
+                .iter()
+                .enumerate()
+                .for_each(|(k, &v)| cells.entry(key(positions0[v])).or_default().push((i, k)))
+        });
+        let distance2 = |(j, k): (usize, usize)| positions0[k].distance2(positions1[j]);
+        let mut stitched = vec![false; loops0.len()];
+        let pos_map = loops1
+            .iter()
+            .filter_map(|loop1| {
+                let n = loop1.len();
+                // the vertices of `self` near the front of `loop1` in the neighbour cells
+                let [x, y, z] = key(positions1[loop1[0]]);
+                // the cyclic alignment from the vertex in either direction, whose pairs are all
+                // within `tol`, with the least sum of the squared distances
+                let (i, pairs, _) = iproduct!(x - 1..=x + 1, y - 1..=y + 1, z - 1..=z + 1)
+                    .filter_map(|(x, y, z)| cells.get(&[x, y, z]))
+                    .flatten()
+                    .filter(|(i, _)| !stitched[*i] && loops0[*i].len() == n)
+                    .flat_map(|&(i, k)| [(i, k, true), (i, k, false)])
+                    .filter_map(|(i, k, reversed)| {
+                        let loop0 = &loops0[i];
+                        let pairs = (0..n)
+                            .map(|m| match reversed {
+                                true => (loop1[m], loop0[(k + n - m) % n]),
+                                false => (loop1[m], loop0[(k + m) % n]),
+                            })
+                            .collect::<Vec<_>>();
+                        let aligned = pairs.iter().all(|pair| distance2(*pair) <= tol * tol);
+                        let cost = pairs.iter().map(|pair| distance2(*pair)).sum::<f64>();
+                        aligned.then_some((i, pairs, cost))
+                    })
+                    .min_by(|(i, _, x), (j, _, y)| x.total_cmp(y).then(i.cmp(j)))?;
+                stitched[i] = true;
+                Some(pairs)
+            })
+            .flatten()
+            .map(|(j, k)| (j + offset, k))
+            .collect::<HashMap<_, _>>();
+        self.merge(other.clone());
+        let mesh = self.debug_editor();
+        mesh.faces.face_iter_mut().flatten().for_each(|v| {
+            if let Some(&pos) = pos_map.get(&v.pos) {
+                v.pos = pos;
+            }
+        });
+        drop(mesh);
+        self.remove_unused_attrs()
+    }
+    fn resolve_t_junctions(&mut self, tol: f64) -> usize {
+        nonpositive_tolerance!(tol);
+        repeat_with(|| split_t_junctions(self, tol))
+            .take_while(|resolved| *resolved > 0)
+            .sum()
+    }
+}
+
+/// Splits each face at the first T-junction on its edges, and returns the number of the pairs of
+/// the positions and the edges.
+fn split_t_junctions(mesh: &mut PolygonMesh, tol: f64) -> usize {
+    let positions = mesh.positions();
+    let mut used = mesh
+        .face_iter()
+        .flatten()
+        .map(|v| v.pos)
+        .collect::<Vec<_>>();
+    used.sort_unstable();
+    used.dedup();
+    // the used positions hashed by a uniform grid, with about one position in each cell
+    let bdd = used
+        .iter()
+        .map(|i| positions[*i])
+        .collect::<BoundingBox<_>>();
+    let cell_size = match used.is_empty() {
+        true => tol,
+        false => f64::max(bdd.diameter() / f64::cbrt(used.len() as f64), tol),
+    };
+    let key = |p: Point3| [p.x, p.y, p.z].map(|x| (x / cell_size).floor() as i64);
+    let mut cells = HashMap::<[i64; 3], Vec<usize>>::default();
+    used.iter()
+        .for_each(|i| cells.entry(key(positions[*i])).or_default().push(*i));
+    let junction = |face: &[StandardVertex], a: usize, b: usize| {
+        let (p, q) = (positions[a], positions[b]);
+        // the cells of the bounding box of the edge, expanded by `tol`
+        let bdd = BoundingBox::from_iter([p, q]);
+        let margin = Vector3::new(tol, tol, tol);
+        let (min, max) = (key(bdd.min() - margin), key(bdd.max() + margin));
+        let dir = q - p;
+        iproduct!(min[0]..=max[0], min[1]..=max[1], min[2]..=max[2])
+            .filter_map(|(x, y, z)| cells.get(&[x, y, z]))
+            .flatten()
+            .filter(|i| face.iter().all(|v| v.pos != **i))
+            .filter_map(|&i| {
+                let r = positions[i];
+                let t = (r - p).dot(dir) / dir.magnitude2();
+                let on_edge = (p + dir * t).distance2(r) <= tol * tol
+                    && r.distance2(p) > tol * tol
+                    && r.distance2(q) > tol * tol;
+                (on_edge && 0.0 < t && t < 1.0).then_some((i, t))
+            })
+            .min_by(|(i, t), (j, s)| t.total_cmp(s).then(i.cmp(j)))
+    };
+    let (uv_len, nor_len) = (mesh.uv_coords().len(), mesh.normals().len());
+    let (mut uv_coords, mut normals) = (Vec::new(), Vec::new());
+    let mut resolved = HashSet::<[usize; 3]>::default();
+    let faces = mesh
+        .face_iter()
+        .flat_map(|face| {
+            let len = face.len();
+            let found = (0..len).find_map(|k| {
+                let (a, b) = (face[k], face[(k + 1) % len]);
+                junction(face, a.pos, b.pos).map(|(i, t)| (k, a, b, i, t))
+            });
+            let Some((k, a, b, i, t)) = found else {
+                return vec![face.to_vec()];
+            };
+            resolved.insert([usize::min(a.pos, b.pos), usize::max(a.pos, b.pos), i]);
+            let uv = a.uv.zip(b.uv).map(|(uv0, uv1)| {
+                let (uv0, uv1) = (mesh.uv_coords()[uv0], mesh.uv_coords()[uv1]);
+                uv_coords.push(uv0 + (uv1 - uv0) * t);
+                uv_len + uv_coords.len() - 1
+            });
+            let nor = a.nor.zip(b.nor).map(|(n0, n1)| {
+                let (n0, n1) = (mesh.normals()[n0], mesh.normals()[n1]);
+                let n = n0 + (n1 - n0) * t;
+                normals.push(match n.so_small() {
+                    true => n0,
+                    false => n.normalize(),
+                });
+                nor_len + normals.len() - 1
+            });
+            let v = StandardVertex { pos: i, uv, nor };
+            // the fan from the new vertex, starting from the back of the split edge
+            (1..len)
+                .map(|j| vec![v, face[(k + j) % len], face[(k + j + 1) % len]])
+                .collect()
+        })
+        .collect::<Faces>();
+    let editor = mesh.debug_editor();
+    editor.attributes.uv_coords.extend(uv_coords);
+    editor.attributes.normals.extend(normals);
+    *editor.faces = faces;
+    resolved.len()
+}
+
+/// Returns the position indices of each boundary loop, ordered along its boundary half-edges.
+fn boundary_loops(faces: &Faces) -> Vec<Vec<usize>> {
+    let counts = edge_face_counts(faces.face_iter());
+    let next = faces
+        .face_iter()
+        .flat_map(|face| {
+            (0..face.len()).map(move |k| (face[k].pos, face[(k + 1) % face.len()].pos))
+        })
+        .filter(|(a, b)| counts[&edge_key(*a, *b)] == 1)
+        .collect::<HashMap<usize, usize>>();
+    let mut starts = next.keys().copied().collect::<Vec<_>>();
+    starts.sort();
+    let mut visited = HashSet::<usize>::default();
+    starts
+        .into_iter()
+        .filter_map(|start| {
+            let boundary = successors(Some(start), |v| next.get(v).copied())
+                .take_while(|v| visited.insert(*v))
+                .collect::<Vec<_>>();
+            (!boundary.is_empty()).then_some(boundary)
+        })
+        .collect()
+}
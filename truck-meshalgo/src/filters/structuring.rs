@@ -1,8 +1,23 @@
 use super::*;
+use std::iter::{once, successors};
+use truck_polymesh::planarity;
+
+/// The ways to triangulate n-gons by [`StructuringFilter::triangulate_with`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum NgonTriangulation {
+    /// the fan from the first vertex, fast and good for convex polygons
+    #[default]
+    Fan,
+    /// the fan from a new vertex at the centroid, good for convex and nearly planar polygons
+    Centroid,
+    /// the ear clipping on the best-fit plane, correct for non-convex planar polygons
+    EarClip,
+}
 
 /// triangulation, quadrangulation, give a structure
 pub trait StructuringFilter {
-    /// triangulate all n-gons
+    /// triangulate all n-gons by the fans from the first vertices,
+    /// the same as [`StructuringFilter::triangulate_with`] with [`NgonTriangulation::Fan`].
     /// # Examples
     /// ```
     /// use truck_polymesh::*;
@@ -35,6 +50,67 @@ pub trait StructuringFilter {
     /// assert_eq!(mesh.faces().len(), 12);
     /// ```
     fn triangulate(&mut self) -> &mut Self;
+    /// triangulate all n-gons, including quadrangles, by `method`
+    /// # Details
+    /// - [`NgonTriangulation::Centroid`] adds a new vertex for each n-gon. The position is the
+    ///   average of the positions. The texture coordinate and the normal are also averaged if all
+    ///   vertices of the n-gon have them.
+    /// - [`NgonTriangulation::EarClip`] projects each n-gon onto its best-fit plane by
+    ///   [`planarity::best_fit_plane`], and clips the convex vertices whose triangles contain no
+    ///   other vertices. If no ear is found, e.g. for self-intersecting polygons, the rest is
+    ///   triangulated by a fan.
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// // an L-shaped hexagon, not convex at the vertex 4
+    /// let positions = vec![
+    ///     Point3::new(0.0, 0.0, 0.0),
+    ///     Point3::new(2.0, 0.0, 0.0),
+    ///     Point3::new(2.0, 1.0, 0.0),
+    ///     Point3::new(1.0, 1.0, 0.0),
+    ///     Point3::new(1.0, 2.0, 0.0),
+    ///     Point3::new(0.0, 2.0, 0.0),
+    /// ];
+    /// let mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions,
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[1, 2, 3, 4, 5, 0]]),
+    /// );
+    /// let area = |mesh: &PolygonMesh| {
+    ///     mesh.faces().triangle_iter().fold(0.0, |sum, [a, b, c]| {
+    ///         let p = |v: StandardVertex| mesh.positions()[v.pos];
+    ///         sum + (p(b) - p(a)).cross(p(c) - p(a)).z / 2.0
+    ///     })
+    /// };
+    ///
+    /// // the fan from the vertex 1 goes outside
+    /// let mut fan = mesh.clone();
+    /// fan.triangulate_with(NgonTriangulation::Fan);
+    /// let signed_areas = fan.tri_faces().iter().map(|tri| {
+    ///     let p = tri.map(|v| fan.positions()[v.pos]);
+    ///     (p[1] - p[0]).cross(p[2] - p[0]).z
+    /// });
+    /// assert!(signed_areas.into_iter().any(|area| area < 0.0));
+    ///
+    /// // the ear clipping makes only the triangles inside
+    /// let mut ear_clip = mesh.clone();
+    /// ear_clip.triangulate_with(NgonTriangulation::EarClip);
+    /// assert_eq!(ear_clip.tri_faces().len(), 4);
+    /// assert!(ear_clip.tri_faces().iter().all(|tri| {
+    ///     let p = tri.map(|v| ear_clip.positions()[v.pos]);
+    ///     (p[1] - p[0]).cross(p[2] - p[0]).z > 0.0
+    /// }));
+    /// assert_near!(area(&ear_clip), 3.0);
+    ///
+    /// // the centroid adds one vertex
+    /// let mut centroid = mesh.clone();
+    /// centroid.triangulate_with(NgonTriangulation::Centroid);
+    /// assert_eq!(centroid.positions().len(), 7);
+    /// assert_eq!(centroid.tri_faces().len(), 6);
+    /// ```
+    fn triangulate_with(&mut self, method: NgonTriangulation) -> &mut Self;
     /// join two triangles into one quadrangle.
     /// # Arguments
     /// * `plane_tol` - the tolerance for determining that four points are in the same plane
@@ -87,8 +163,20 @@ pub trait StructuringFilter {
 }
 
 impl StructuringFilter for PolygonMesh {
-    fn triangulate(&mut self) -> &mut Self {
-        let tri_faces = self.faces().triangle_iter().collect::<Vec<_>>();
+    fn triangulate(&mut self) -> &mut Self { self.triangulate_with(NgonTriangulation::Fan) }
+    fn triangulate_with(&mut self, method: NgonTriangulation) -> &mut Self {
+        let tri_faces = match method {
+            NgonTriangulation::Fan => self.faces().triangle_iter().collect::<Vec<_>>(),
+            NgonTriangulation::Centroid => centroid_triangulation(self),
+            NgonTriangulation::EarClip => {
+                let mut tri_faces = self.faces().tri_faces().clone();
+                self.faces()
+                    .face_iter()
+                    .filter(|face| face.len() > 3)
+                    .for_each(|face| tri_faces.extend(ear_clip(self.positions(), face)));
+                tri_faces
+            }
+        };
         *self.debug_editor().faces = Faces::from_tri_and_quad_faces(tri_faces, Vec::new());
         self
     }
@@ -101,6 +189,115 @@ impl StructuringFilter for PolygonMesh {
     }
 }
 
+/// Triangulates the n-gons of `mesh` by the fans from the new vertices at the centroids.
+fn centroid_triangulation(mesh: &mut PolygonMesh) -> Vec<[Vertex; 3]> {
+    let faces = mesh.faces().clone();
+    let mut tri_faces = faces.tri_faces().clone();
+    let mut editor = mesh.debug_editor();
+    let attributes = &mut editor.attributes;
+    faces
+        .face_iter()
+        .filter(|face| face.len() > 3)
+        .for_each(|face| {
+            let n = face.len() as f64;
+            let position = face.iter().fold(Point3::origin(), |sum, v| {
+                sum + attributes.positions[v.pos].to_vec() / n
+            });
+            let uv = face.iter().try_fold(Vector2::zero(), |sum, v| {
+                v.uv.map(|uv| sum + attributes.uv_coords[uv] / n)
+            });
+            let normal = face.iter().try_fold(Vector3::zero(), |sum, v| {
+                v.nor.map(|nor| sum + attributes.normals[nor])
+            });
+            let center = Vertex {
+                pos: push(&mut attributes.positions, position),
+                uv: uv.map(|uv| push(&mut attributes.uv_coords, uv)),
+                nor: normal
+                    .filter(|normal| !normal.magnitude2().so_small2())
+                    .map(|normal| push(&mut attributes.normals, normal.normalize())),
+            };
+            tri_faces.extend(
+                face.iter()
+                    .zip(face.iter().cycle().skip(1))
+                    .map(|(v, w)| [center, *v, *w]),
+            );
+        });
+    tri_faces
+}
+
+fn push<T>(vec: &mut Vec<T>, x: T) -> usize {
+    vec.push(x);
+    vec.len() - 1
+}
+
+/// Triangulates `face` by the ear clipping on its best-fit plane.
+fn ear_clip(positions: &[Point3], face: &[Vertex]) -> Vec<[Vertex; 3]> {
+    let points = face.iter().map(|v| positions[v.pos]).collect::<Vec<_>>();
+    let (origin, normal) = planarity::best_fit_plane(&points);
+    if normal == Vector3::zero() {
+        return fan(face, &(0..face.len()).collect::<Vec<_>>());
+    }
+    let axis = match normal.x.abs() < 0.5 {
+        true => Vector3::unit_x(),
+        false => Vector3::unit_y(),
+    };
+    let u = axis.cross(normal).normalize();
+    let v = normal.cross(u);
+    let uv = points
+        .iter()
+        .map(|p| Point2::new((p - origin).dot(u), (p - origin).dot(v)))
+        .collect::<Vec<_>>();
+    let cross = |i: usize, j: usize, k: usize| (uv[j] - uv[i]).perp_dot(uv[k] - uv[i]);
+    let inside = |p: Point2, [i, j, k]: [usize; 3]| {
+        let s = |a: Point2, b: Point2| (b - a).perp_dot(p - a);
+        s(uv[i], uv[j]) >= 0.0 && s(uv[j], uv[k]) >= 0.0 && s(uv[k], uv[i]) >= 0.0
+    };
+    // the remaining polygon as the doubly linked list of the indices
+    let len = face.len();
+    let mut next = (1..len).chain(once(0)).collect::<Vec<_>>();
+    let mut prev = once(len - 1).chain(0..len - 1).collect::<Vec<_>>();
+    let mut res = Vec::with_capacity(len - 2);
+    // `start` is the least remaining index, so the ears are searched in the order of the indices.
+    let clipped = (3..len).try_fold(0, |start, _| {
+        let is_ear = |m: usize| {
+            let tri = [prev[m], m, next[m]];
+            cross(tri[0], tri[1], tri[2]) > 0.0
+                && ring(&next, start)
+                    .filter(|l| !tri.contains(l) && !tri.iter().any(|t| uv[*t].near(&uv[*l])))
+                    .all(|l| !inside(uv[l], tri))
+        };
+        let m = ring(&next, start).find(|m| is_ear(*m)).ok_or(start)?;
+        let (p, q) = (prev[m], next[m]);
+        res.push([face[p], face[m], face[q]]);
+        (next[p], prev[q]) = (q, p);
+        Ok(match m == start {
+            true => q,
+            false => start,
+        })
+    });
+    match clipped {
+        Ok(start) => {
+            let (p, q) = (start, next[start]);
+            res.push([face[p], face[q], face[next[q]]]);
+        }
+        Err(start) => res.extend(fan(face, &ring(&next, start).collect::<Vec<_>>())),
+    }
+    res
+}
+
+/// Returns the indices of the remaining polygon from `start`, following the linked list `next`.
+fn ring(next: &[usize], start: usize) -> impl Iterator<Item = usize> + '_ {
+    successors(Some(start), move |i| Some(next[*i]).filter(|j| *j != start))
+}
+
+fn fan(face: &[Vertex], indices: &[usize]) -> Vec<[Vertex; 3]> {
+    indices
+        .windows(2)
+        .skip(1)
+        .map(|w| [face[indices[0]], face[w[0]], face[w[1]]])
+        .collect()
+}
+
 trait SubStructureFilter {
     fn create_face_edge_list(&self, plane_tol: f64, score_tol: f64) -> Vec<FaceEdge>;
     fn reflect_face_edge_list(&mut self, list: Vec<FaceEdge>);
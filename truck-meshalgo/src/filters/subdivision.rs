@@ -12,6 +12,9 @@ pub trait Subdivision {
     /// - `self.shell_condition()` is `Oriented` or `Closed` before use.
     ///
     /// This method does NOT check these conditions.
+    ///
+    /// The positions of the original vertices are moved, and the new positions and faces are
+    /// added, so the indices of the faces are invalidated.
     fn loop_subdivision(&mut self) -> &mut Self;
 }
 
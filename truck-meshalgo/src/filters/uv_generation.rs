@@ -0,0 +1,421 @@
+use super::*;
+use rustc_hash::FxHashMap as HashMap;
+use std::f64::consts::PI;
+
+/// Projection methods for [`UvGeneration::generate_uv`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum UvProjection {
+    /// Projects all positions onto the plane perpendicular to `axis`.
+    Planar {
+        /// the normal vector of the projection plane
+        axis: Vector3,
+    },
+    /// Projects each face onto the coordinate plane perpendicular to the dominant axis of the face normal.
+    Box,
+    /// Projects all positions onto the sphere centered at the center of the bounding box.
+    Spherical,
+}
+
+/// Generates uv coordinates by simple projections, or by unwrapping patches.
+///
+/// The projections are a pragmatic way to make meshes texturable, not a real unwrapping.
+pub trait UvGeneration {
+    /// Clears all uv coordinates and generates new ones by `projection`.
+    ///
+    /// # Details
+    /// - `Planar`: the uv coordinates are the coordinates on the projection plane,
+    ///   translated and uniformly scaled so that the longer side spans `[0, 1]`.
+    /// - `Box`: the face is projected along the dominant axis of its normal. The uv coordinates
+    ///   are scaled by the bounding box of the mesh, so the texel density is common among faces.
+    /// - `Spherical`: `u` is the longitude and `v` is the latitude, both normalized to `[0, 1]`.
+    ///   The vertices are not split along the seam.
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 1.0),
+    ///             Point3::new(2.0, 0.0, 1.0),
+    ///             Point3::new(2.0, 1.0, 1.0),
+    ///             Point3::new(0.0, 1.0, 1.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[0, 1, 2, 3]]),
+    /// );
+    /// mesh.generate_uv(UvProjection::Planar { axis: Vector3::unit_z() });
+    ///
+    /// let uv = mesh.faces()[0][2].uv.unwrap();
+    /// assert_near!(mesh.uv_coords()[uv], Vector2::new(1.0, 0.5));
+    /// ```
+    fn generate_uv(&mut self, projection: UvProjection) -> &mut Self;
+
+    /// Clears all uv coordinates and reconstructs them by the least-squares conformal maps.
+    ///
+    /// # Details
+    /// The uv coordinates minimize the angle distortion of the triangles, so that a mesh
+    /// tessellated from a parametric surface gets back the uv coordinates close to a conformal
+    /// parameterization. Each position gets its uv coordinate, and the faces refer to the uv
+    /// coordinates by the position indices, so the mesh is not split.
+    ///
+    /// Each connected component is mapped separately, with two pinned vertices far from each
+    /// other on its boundary. The first pin is mapped to the origin, and the second one to the
+    /// `u`-axis. Then the uv coordinates are scaled around the origin so that the area is the
+    /// same as the mesh. Hence the uv coordinates of a developable patch keep the lengths of the
+    /// mesh, and the components may overlap each other.
+    ///
+    /// The mapping is meaningful for the patches with the topology of a disk. A component without
+    /// boundary is pinned at the farthest vertices, and the result is distorted.
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// // a folded strip of two squares, which is developable
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 1.0),
+    ///             Point3::new(0.0, 0.0, 1.0),
+    ///             Point3::new(1.0, 1.0, 0.0),
+    ///             Point3::new(1.0, 1.0, 1.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[0, 1, 2, 3], [1, 4, 5, 2]]),
+    /// );
+    /// mesh.reconstruct_uv_by_lscm();
+    ///
+    /// // the lengths on the strip are kept
+    /// let uv = mesh.uv_coords();
+    /// assert_eq!(uv.len(), 6);
+    /// assert_near!(uv[0].distance(uv[4]), 2.0);
+    /// assert_near!(uv[3].distance(uv[5]), 2.0);
+    /// assert_near!(uv[0].distance(uv[3]), 1.0);
+    /// ```
+    fn reconstruct_uv_by_lscm(&mut self) -> &mut Self;
+}
+
+impl UvGeneration for PolygonMesh {
+    fn generate_uv(&mut self, projection: UvProjection) -> &mut Self {
+        let bdd = self.bounding_box();
+        let mut mesh = self.debug_editor();
+        let PolygonMeshEditor {
+            attributes:
+                StandardAttributes {
+                    positions,
+                    uv_coords,
+                    ..
+                },
+            faces,
+            ..
+        } = &mut mesh;
+        match projection {
+            UvProjection::Planar { axis } => {
+                let (u, v) = orthonormal_basis(axis);
+                let projected = positions
+                    .iter()
+                    .map(|p| Point2::new(p.to_vec().dot(u), p.to_vec().dot(v)))
+                    .collect::<Vec<_>>();
+                let bdd = projected.iter().collect::<BoundingBox<_>>();
+                let scale = positive_or_one(bdd.size());
+                *uv_coords = projected
+                    .into_iter()
+                    .map(|p| (p - bdd.min()) / scale)
+                    .collect();
+                faces
+                    .face_iter_mut()
+                    .flatten()
+                    .for_each(|v| v.uv = Some(v.pos));
+            }
+            UvProjection::Box => {
+                let (min, scale) = (bdd.min(), positive_or_one(bdd.size()));
+                let mut map = HashMap::<(usize, usize), usize>::default();
+                uv_coords.clear();
+                faces.face_iter_mut().for_each(|face| {
+                    let normal = FaceNormal::new(positions, face, 0).normal;
+                    let axis = (0..3)
+                        .max_by(|i, j| normal[*i].abs().total_cmp(&normal[*j].abs()))
+                        .unwrap_or(2);
+                    let (i, j) = ((axis + 1) % 3, (axis + 2) % 3);
+                    face.iter_mut().for_each(|v| {
+                        let idx = *map.entry((v.pos, axis)).or_insert_with(|| {
+                            let p = positions[v.pos] - min;
+                            uv_coords.push(Vector2::new(p[i], p[j]) / scale);
+                            uv_coords.len() - 1
+                        });
+                        v.uv = Some(idx);
+                    });
+                });
+            }
+            UvProjection::Spherical => {
+                let center = bdd.center();
+                *uv_coords = positions.iter().map(|p| spherical_uv(p - center)).collect();
+                faces
+                    .face_iter_mut()
+                    .flatten()
+                    .for_each(|v| v.uv = Some(v.pos));
+            }
+        }
+        drop(mesh);
+        self
+    }
+
+    fn reconstruct_uv_by_lscm(&mut self) -> &mut Self {
+        let lscm_uv = lscm(self.positions(), self.faces());
+        let mut mesh = self.debug_editor();
+        let PolygonMeshEditor {
+            attributes: StandardAttributes { uv_coords, .. },
+            faces,
+            ..
+        } = &mut mesh;
+        *uv_coords = lscm_uv;
+        faces
+            .face_iter_mut()
+            .flatten()
+            .for_each(|v| v.uv = Some(v.pos));
+        drop(mesh);
+        self
+    }
+}
+
+/// The uv coordinates of the positions by the least-squares conformal maps.
+fn lscm(positions: &[Point3], faces: &Faces) -> Vec<Vector2> {
+    let n = positions.len();
+    let mut parents = (0..n).collect::<Vec<_>>();
+    let mut used = vec![false; n];
+    faces.face_iter().for_each(|face| {
+        (0..face.len()).for_each(|i| {
+            let (a, b) = (face[i].pos, face[(i + 1) % face.len()].pos);
+            let (ra, rb) = (root(&mut parents, a), root(&mut parents, b));
+            parents[ra] = rb;
+            used[a] = true;
+        })
+    });
+    let mut boundary = vec![false; n];
+    edge_face_counts(faces.face_iter())
+        .into_iter()
+        .filter(|(_, count)| *count == 1)
+        .for_each(|([a, b], _)| {
+            boundary[a] = true;
+            boundary[b] = true;
+        });
+
+    // two pins of each component, approximately the farthest pair on the boundary
+    let mut components = HashMap::<usize, Vec<usize>>::default();
+    (0..n)
+        .filter(|i| used[*i])
+        .for_each(|i| components.entry(root(&mut parents, i)).or_default().push(i));
+    let mut pins = vec![None; n];
+    components.into_values().for_each(|vertices| {
+        let on_boundary = vertices
+            .iter()
+            .copied()
+            .filter(|i| boundary[*i])
+            .collect::<Vec<_>>();
+        let candidates = match on_boundary.len() >= 2 {
+            true => on_boundary,
+            false => vertices,
+        };
+        let farthest = |from: usize| {
+            let dist2 = |i: &usize| positions[*i].distance2(positions[from]);
+            candidates
+                .iter()
+                .copied()
+                .max_by(|i, j| dist2(i).total_cmp(&dist2(j)))
+                .unwrap_or(from)
+        };
+        let a = farthest(candidates[0]);
+        let b = farthest(a);
+        pins[a] = Some(Vector2::zero());
+        if a != b {
+            pins[b] = Some(Vector2::unit_x());
+        }
+    });
+
+    // the indices of the unknowns, u and v of the free vertices
+    let mut var_len = 0;
+    let vars = (0..n)
+        .map(|i| {
+            (used[i] && pins[i].is_none()).then(|| {
+                var_len += 2;
+                var_len - 2
+            })
+        })
+        .collect::<Vec<_>>();
+
+    // Each triangle gives the complex equation `sum_j W_j U_j = 0`, where `U_j = u_j + i v_j` and
+    // `W_j` is the opposite edge in the local frame of the triangle, divided by the square root
+    // of the double area. The real and imaginary parts are the rows of the least squares.
+    let mut rows = Vec::<(Vec<(usize, f64)>, f64)>::new();
+    faces.triangle_iter().for_each(|tri| {
+        let [p0, p1, p2] = tri.map(|v| positions[v.pos]);
+        let (e1, e2) = (p1 - p0, p2 - p0);
+        let normal = e1.cross(e2);
+        let area2 = normal.magnitude();
+        if area2.so_small2() || e1.so_small() {
+            return;
+        }
+        let x_axis = e1.normalize();
+        let y_axis = (normal / area2).cross(x_axis);
+        let q = [
+            Vector2::zero(),
+            Vector2::new(e1.magnitude(), 0.0),
+            Vector2::new(e2.dot(x_axis), e2.dot(y_axis)),
+        ];
+        let mut real = (Vec::new(), 0.0);
+        let mut imag = (Vec::new(), 0.0);
+        (0..3).for_each(|j| {
+            let w = (q[(j + 2) % 3] - q[(j + 1) % 3]) / area2.sqrt();
+            let idx = tri[j].pos;
+            match (vars[idx], pins[idx]) {
+                (Some(k), _) => {
+                    real.0.extend([(k, w.x), (k + 1, -w.y)]);
+                    imag.0.extend([(k, w.y), (k + 1, w.x)]);
+                }
+                (None, Some(uv)) => {
+                    real.1 -= w.x * uv.x - w.y * uv.y;
+                    imag.1 -= w.y * uv.x + w.x * uv.y;
+                }
+                (None, None) => {}
+            }
+        });
+        rows.extend([real, imag]);
+    });
+
+    let x = least_squares(&rows, var_len);
+    let mut uv_coords = (0..n)
+        .map(|i| match (vars[i], pins[i]) {
+            (Some(k), _) => Vector2::new(x[k], x[k + 1]),
+            (None, Some(uv)) => uv,
+            (None, None) => Vector2::zero(),
+        })
+        .collect::<Vec<_>>();
+
+    // scales each component around its first pin so that the area is kept
+    let mut areas = HashMap::<usize, (f64, f64)>::default();
+    faces.triangle_iter().for_each(|tri| {
+        let [p0, p1, p2] = tri.map(|v| positions[v.pos]);
+        let [w0, w1, w2] = tri.map(|v| uv_coords[v.pos]);
+        let area = areas.entry(root(&mut parents, tri[0].pos)).or_default();
+        area.0 += (p1 - p0).cross(p2 - p0).magnitude();
+        area.1 += (w1 - w0).perp_dot(w2 - w0);
+    });
+    (0..n).filter(|i| used[*i]).for_each(|i| {
+        let (area, uv_area) = areas
+            .get(&root(&mut parents, i))
+            .copied()
+            .unwrap_or_default();
+        if uv_area > 0.0 {
+            uv_coords[i] *= f64::sqrt(area / uv_area);
+        }
+    });
+    uv_coords
+}
+
+/// Solves the sparse linear least squares `A x = b`, given by the rows of `A` and `b`.
+///
+/// The normal equation `A^T A x = A^T b` is assembled into the compressed sparse rows and solved
+/// by the conjugate gradient preconditioned by its diagonal. The iteration stops if the residual
+/// is reduced by `1.0e-10`, and the bound of the iterations, ten times the number of the
+/// unknowns, is only the guard against the stagnation. The products by the matrix are computed
+/// in parallel.
+fn least_squares(rows: &[(Vec<(usize, f64)>, f64)], var_len: usize) -> Vec<f64> {
+    let mut entries = rows
+        .iter()
+        .flat_map(|(row, _)| {
+            row.iter()
+                .flat_map(move |(k, a)| row.iter().map(move |(l, b)| ((*k, *l), a * b)))
+        })
+        .collect::<Vec<_>>();
+    entries.sort_unstable_by_key(|(index, _)| *index);
+    let entries = entries
+        .chunk_by(|(index0, _), (index1, _)| index0 == index1)
+        .map(|chunk| (chunk[0].0, chunk.iter().map(|(_, a)| a).sum::<f64>()))
+        .collect::<Vec<_>>();
+    let offsets = (0..=var_len)
+        .map(|k| entries.partition_point(|((i, _), _)| *i < k))
+        .collect::<Vec<_>>();
+    let matrix_row = |k: usize| &entries[offsets[k]..offsets[k + 1]];
+    let apply = |x: &[f64]| -> Vec<f64> {
+        let product = |k: usize| matrix_row(k).iter().map(|((_, l), a)| a * x[*l]).sum();
+        #[cfg(not(target_arch = "wasm32"))]
+        let res = {
+            use rayon::prelude::*;
+            (0..var_len).into_par_iter().map(product).collect()
+        };
+        #[cfg(target_arch = "wasm32")]
+        let res = (0..var_len).map(product).collect();
+        res
+    };
+    let inverse_diagonal = (0..var_len)
+        .map(|k| {
+            let diagonal = matrix_row(k).iter().find(|((_, l), _)| *l == k);
+            1.0 / positive_or_one(diagonal.map_or(0.0, |(_, a)| *a))
+        })
+        .collect::<Vec<_>>();
+    let precondition = |r: &[f64]| -> Vec<f64> {
+        r.iter()
+            .zip(&inverse_diagonal)
+            .map(|(r, d)| r * d)
+            .collect()
+    };
+    let dot = |a: &[f64], b: &[f64]| a.iter().zip(b).map(|(a, b)| a * b).sum::<f64>();
+
+    let r = rows.iter().fold(vec![0.0; var_len], |mut r, (row, b)| {
+        row.iter().for_each(|(k, a)| r[*k] += a * b);
+        r
+    });
+    let threshold = dot(&r, &r) * 1.0e-20;
+    let z = precondition(&r);
+    let rz = dot(&r, &z);
+    // the preconditioned conjugate gradient method, which stops by `Err` with the solution
+    let step = |(mut x, mut r, mut p, rz): (Vec<f64>, Vec<f64>, Vec<f64>, f64), _| {
+        let converged = dot(&r, &r) <= threshold || rz == 0.0;
+        match (!converged).then(|| apply(&p)).filter(|q| dot(&p, q) > 0.0) {
+            None => Err(x),
+            Some(q) => {
+                let alpha = rz / dot(&p, &q);
+                x.iter_mut().zip(&p).for_each(|(x, p)| *x += alpha * p);
+                r.iter_mut().zip(&q).for_each(|(r, q)| *r -= alpha * q);
+                let z = precondition(&r);
+                let new_rz = dot(&r, &z);
+                let beta = new_rz / rz;
+                p.iter_mut().zip(&z).for_each(|(p, z)| *p = z + beta * *p);
+                Ok((x, r, p, new_rz))
+            }
+        }
+    };
+    let max_iter = 10 * var_len + 100;
+    let (Ok((x, ..)) | Err(x)) = (0..max_iter).try_fold((vec![0.0; var_len], r, z, rz), step);
+    x
+}
+
+fn orthonormal_basis(axis: Vector3) -> (Vector3, Vector3) {
+    let axis = axis.normalize();
+    let helper = match axis.x.abs() < 0.9 {
+        true => Vector3::unit_x(),
+        false => Vector3::unit_y(),
+    };
+    let u = (helper - axis * helper.dot(axis)).normalize();
+    (u, axis.cross(u))
+}
+
+fn positive_or_one(x: f64) -> f64 {
+    match x.so_small() {
+        true => 1.0,
+        false => x,
+    }
+}
+
+fn spherical_uv(dir: Vector3) -> Vector2 {
+    let radius = dir.magnitude();
+    match radius.so_small() {
+        true => Vector2::new(0.5, 0.5),
+        false => Vector2::new(
+            0.5 + f64::atan2(dir.y, dir.x) / (2.0 * PI),
+            0.5 + f64::asin(f64::clamp(dir.z / radius, -1.0, 1.0)) / PI,
+        ),
+    }
+}
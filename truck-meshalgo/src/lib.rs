@@ -30,6 +30,14 @@ use truck_polymesh::{StandardVertex as Vertex, *};
 pub mod analyzers;
 mod common;
 /// Edits meshes. Add normals, optimizing data, and so on.
+///
+/// # Index stability
+/// The filters which only move positions, i.e. [`Smoothing`](filters::Smoothing) and
+/// [`Offsetting`](filters::Offsetting), keep the number and the order of the positions and the
+/// position indices of the faces, so the buffers indexed by positions stay valid. This is checked
+/// by assertions in debug builds. The normals may be recomputed by them. The transformations by
+/// `transform_by` keep all indices as well. The other filters, e.g. welding, simplification,
+/// remeshing and subdivision, may invalidate any indices.
 #[cfg(feature = "filters")]
 pub mod filters;
 /// Tessellates shapes.
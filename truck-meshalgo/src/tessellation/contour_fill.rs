@@ -0,0 +1,136 @@
+use super::*;
+use crate::Point2;
+use itertools::Itertools;
+
+type SPoint2 = spade::Point2<f64>;
+type Contour = truck_polymesh::PolylineCurve<Point2>;
+
+/// The rule deciding which regions of overlapping or nested contours are filled, by the winding
+/// number of the contours around each region.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FillRule {
+    /// Fills the regions whose winding numbers are odd.
+    EvenOdd,
+    /// Fills the regions whose winding numbers are not zero.
+    #[default]
+    NonZero,
+}
+
+/// Triangulates the regions in the plane filled by `contours` under `fill_rule`.
+///
+/// # Details
+/// Each contour is closed, whether or not its last point is the same as the first one, and may
+/// intersect itself or the other contours. The holes are not designated explicitly, but decided
+/// by the winding numbers and `fill_rule`, as the fill rules of fonts and vector graphics.
+///
+/// The contours are inserted into the constrained Delaunay triangulation, and split at their
+/// intersections. Then, the triangles in the filled regions are returned with the positions
+/// `(x, y, 0)`. The triangles are counter-clockwise, i.e. oriented toward `+z`, regardless of the
+/// orientations of the contours.
+///
+/// # Examples
+/// ```
+/// use truck_meshalgo::prelude::*;
+/// // a square with a square hole in the same orientation
+/// let square = |x: f64, size: f64| {
+///     PolylineCurve(vec![
+///         Point2::new(x, x),
+///         Point2::new(x + size, x),
+///         Point2::new(x + size, x + size),
+///         Point2::new(x, x + size),
+///     ])
+/// };
+/// let contours = [square(0.0, 3.0), square(1.0, 1.0)];
+/// let area = |mesh: &PolygonMesh| {
+///     mesh.faces()
+///         .triangle_iter()
+///         .map(|tri| {
+///             let [p, q, r] = tri.map(|v| mesh.positions()[v.pos]);
+///             (q - p).cross(r - p).z / 2.0
+///         })
+///         .sum::<f64>()
+/// };
+///
+/// let mesh = triangulate_contours(&contours, FillRule::EvenOdd);
+/// assert_near!(area(&mesh), 8.0);
+/// // the winding number of the hole is 2
+/// let mesh = triangulate_contours(&contours, FillRule::NonZero);
+/// assert_near!(area(&mesh), 9.0);
+/// ```
+pub fn triangulate_contours(contours: &[Contour], fill_rule: FillRule) -> PolygonMesh {
+    let contours = contours
+        .iter()
+        .map(|contour| {
+            let mut points = contour.0.clone();
+            if points.len() > 1 && points[0] == points[points.len() - 1] {
+                points.pop();
+            }
+            points
+        })
+        .collect::<Vec<_>>();
+    let mut triangulation = ConstrainedDelaunayTriangulation::<SPoint2>::new();
+    contours.iter().for_each(|points| {
+        let handles = points
+            .iter()
+            .filter_map(|p| triangulation.insert(SPoint2::new(p.x, p.y)).ok())
+            .collect::<Vec<_>>();
+        handles
+            .into_iter()
+            .circular_tuple_windows()
+            .for_each(|(v, w)| {
+                if v != w {
+                    triangulation.add_constraint_and_split(v, w, |p| p);
+                }
+            });
+    });
+
+    let positions = triangulation
+        .vertices()
+        .map(|v| Point3::new(v.position().x, v.position().y, 0.0))
+        .collect::<Vec<_>>();
+    let tri_faces = triangulation
+        .inner_faces()
+        .filter_map(|face| {
+            let [p, q, r] = face.positions().map(|p| Point2::new(p.x, p.y));
+            let area = (q - p).perp_dot(r - p);
+            let center = Point2::from_vec((p.to_vec() + q.to_vec() + r.to_vec()) / 3.0);
+            let winding = contours
+                .iter()
+                .map(|points| winding_number(points, center))
+                .sum::<i32>();
+            let filled = match fill_rule {
+                FillRule::EvenOdd => winding % 2 != 0,
+                FillRule::NonZero => winding != 0,
+            };
+            let tri = face.vertices().map(|v| v.fix().index());
+            (filled && area > 0.0).then(|| tri.map(StandardVertex::from))
+        })
+        .collect::<Vec<_>>();
+    let mut mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        Faces::from_tri_and_quad_faces(tri_faces, Vec::new()),
+    );
+    mesh.remove_unused_attrs();
+    mesh
+}
+
+/// Returns the winding number of the closed polyline `points` around `c`.
+fn winding_number(points: &[Point2], c: Point2) -> i32 {
+    points
+        .iter()
+        .circular_tuple_windows()
+        .map(|(p, q)| {
+            let side = (q - p).perp_dot(c - p);
+            if p.y <= c.y && c.y < q.y && side > 0.0 {
+                1
+            } else if q.y <= c.y && c.y < p.y && side < 0.0 {
+                -1
+            } else {
+                0
+            }
+        })
+        .sum()
+}
@@ -0,0 +1,230 @@
+use super::*;
+use array_macro::array;
+use itertools::iproduct;
+use rustc_hash::FxHashMap as HashMap;
+use std::iter::successors;
+
+/// The faces of the unit cube, whose corner `c` is `(c & 1, (c >> 1) & 1, c >> 2)`. The corners of
+/// each face are counter-clockwise seen from the outside.
+const CUBE_FACES: [[usize; 4]; 6] = [
+    [0, 4, 6, 2],
+    [1, 3, 7, 5],
+    [0, 1, 5, 4],
+    [2, 6, 7, 3],
+    [0, 2, 3, 1],
+    [4, 5, 7, 6],
+];
+
+/// Extracts the iso-surface `value == iso` of the scalar field sampled on the grid by marching cubes.
+///
+/// # Details
+/// The layout of `grid` is the same as
+/// [`SignedDistance::to_sdf_grid`](crate::analyzers::SignedDistance::to_sdf_grid): the grid has
+/// `resolution[i]` nodes along the `i`-th axis including both ends of `bounds`, and the value at
+/// the node `(i, j, k)` is `grid[i + resolution[0] * (j + resolution[1] * k)]`. The nodes whose
+/// values are less than `iso` are inside, and the triangles are oriented toward the outside, so
+/// the mesh of a signed distance field is oriented outward.
+///
+/// The vertices are placed on the edges of the cells by the linear interpolation of the values,
+/// and shared by the cells around each edge, so the returned triangle mesh is welded. Instead of
+/// the lookup table of the 256 cases, the polygons of each cell are traced along the faces of the
+/// cell. The ambiguous faces, whose diagonal corners are inside, are resolved by the average of
+/// the four values, which is common to the two cells sharing the face. Each polygon is split into
+/// triangles by a fan, or from its centroid if every fan has a diagonal on a face of the cell.
+/// Hence the mesh is manifold without cracks, and it is closed if the inside does not reach the
+/// border of the grid.
+///
+/// # Panics
+/// Panics if the length of `grid` is not the product of `resolution`.
+///
+/// # Examples
+/// ```
+/// use truck_meshalgo::prelude::*;
+/// // the signed distance field of the unit sphere
+/// let n = 21;
+/// let coord = |i: usize| -1.5 + 3.0 * i as f64 / (n - 1) as f64;
+/// let grid = (0..n * n * n)
+///     .map(|idx| {
+///         let p = Point3::new(coord(idx % n), coord(idx / n % n), coord(idx / (n * n)));
+///         p.to_vec().magnitude() - 1.0
+///     })
+///     .collect::<Vec<_>>();
+/// let bounds = BoundingBox::from_iter([Point3::new(-1.5, -1.5, -1.5), Point3::new(1.5, 1.5, 1.5)]);
+///
+/// let mesh = marching_cubes(&grid, [n, n, n], bounds, 0.0);
+/// assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+/// assert!(mesh
+///     .positions()
+///     .iter()
+///     .all(|p| f64::abs(p.to_vec().magnitude() - 1.0) < 0.01));
+/// // oriented outward
+/// assert!(f64::abs(mesh.volume() - 4.0 * std::f64::consts::PI / 3.0) < 0.1);
+/// ```
+pub fn marching_cubes(
+    grid: &[f64],
+    resolution: [usize; 3],
+    bounds: BoundingBox<Point3>,
+    iso: f64,
+) -> PolygonMesh {
+    let [nx, ny, nz] = resolution;
+    assert_eq!(
+        grid.len(),
+        nx * ny * nz,
+        "the length of grid is not compatible with resolution."
+    );
+    let (min, diag) = (bounds.min(), bounds.max() - bounds.min());
+    let coord = |axis: usize, i: usize| match resolution[axis] {
+        0 | 1 => min[axis],
+        n => min[axis] + diag[axis] * i as f64 / (n - 1) as f64,
+    };
+    let node_index = |[i, j, k]: [usize; 3]| i + nx * (j + ny * k);
+    let node_point = |[i, j, k]: [usize; 3]| Point3::new(coord(0, i), coord(1, j), coord(2, k));
+
+    // the polygons of the cell at the node `[i, j, k]`, traced along the faces of the cell
+    let cell_polygons = |i: usize, j: usize, k: usize| {
+        let node = |c: usize| [i + (c & 1), j + ((c >> 1) & 1), k + (c >> 2)];
+        let values = array![c => grid[node_index(node(c))]; 8];
+        let inside = values.map(|value| value < iso);
+        // the vertex on the edge between the corners `a` and `b`, identified by the lower node
+        // and the axis of the edge, so that it is shared by the cells around the edge
+        let vertex = |a: usize, b: usize| {
+            let (a, b) = (usize::min(a, b), usize::max(a, b));
+            let axis = (a ^ b).trailing_zeros() as usize;
+            let t = (iso - values[a]) / (values[b] - values[a]);
+            let (p, q) = (node_point(node(a)), node_point(node(b)));
+            CellVertex {
+                id: 3 * node_index(node(a)) + axis,
+                point: p + (q - p) * t,
+                edge: [a, b],
+            }
+        };
+        // the segments on the faces, with the inside on the left seen from the outside
+        let segments = CUBE_FACES
+            .iter()
+            .flat_map(|face| {
+                let crossings = (0..4)
+                    .filter_map(|m| {
+                        let (a, b) = (face[m], face[(m + 1) % 4]);
+                        (inside[a] != inside[b]).then(|| (vertex(a, b), inside[a]))
+                    })
+                    .collect::<Vec<_>>();
+                let center_inside = face.iter().map(|c| values[*c]).sum::<f64>() / 4.0 < iso;
+                let n = crossings.len();
+                (0..n)
+                    .filter(|m| crossings[*m].1)
+                    .map(|m| {
+                        let partner = match n == 4 && center_inside {
+                            true => (m + 1) % n,
+                            false => (m + n - 1) % n,
+                        };
+                        [crossings[m].0, crossings[partner].0]
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        // chain the segments into the loops, marking the edges of the cell by the bits
+        let mut visited = 0u64;
+        let next = |v: &CellVertex| segments.iter().find(|s| s[0].id == v.id).map(|s| s[1]);
+        segments
+            .iter()
+            .filter_map(|[start, _]| {
+                let polygon = successors(Some(*start), next)
+                    .take_while(|v| {
+                        let bit = 1 << (8 * v.edge[0] + v.edge[1]);
+                        let new = visited & bit == 0;
+                        visited |= bit;
+                        new
+                    })
+                    .collect::<Vec<_>>();
+                (!polygon.is_empty()).then_some(polygon)
+            })
+            .collect::<Vec<_>>()
+    };
+    let slab_polygons = |k: usize| {
+        iproduct!(0..ny.saturating_sub(1), 0..nx.saturating_sub(1))
+            .flat_map(move |(j, i)| cell_polygons(i, j, k))
+    };
+    #[cfg(not(target_arch = "wasm32"))]
+    let polygons = {
+        use rayon::prelude::*;
+        (0..nz.saturating_sub(1))
+            .into_par_iter()
+            .flat_map_iter(slab_polygons)
+            .collect::<Vec<_>>()
+    };
+    #[cfg(target_arch = "wasm32")]
+    let polygons = (0..nz.saturating_sub(1))
+        .flat_map(slab_polygons)
+        .collect::<Vec<_>>();
+
+    // the vertices shared by the cells are indexed in the order of their ids
+    let mut vertices = polygons
+        .iter()
+        .flatten()
+        .map(|v| (v.id, v.point))
+        .collect::<Vec<_>>();
+    vertices.sort_by_key(|(id, _)| *id);
+    vertices.dedup_by_key(|(id, _)| *id);
+    let indices = vertices
+        .iter()
+        .enumerate()
+        .map(|(idx, (id, _))| (*id, idx))
+        .collect::<HashMap<_, _>>();
+    let mut positions = vertices.into_iter().map(|(_, p)| p).collect::<Vec<_>>();
+    let triangles = polygons
+        .iter()
+        .flat_map(|polygon| triangulate_loop(polygon, &indices, &mut positions))
+        .collect::<Vec<_>>();
+    PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        Faces::from_iter(&triangles),
+    )
+}
+
+/// A vertex of the polygons in a cell, on the edge between two corners.
+#[derive(Clone, Copy, Debug)]
+struct CellVertex {
+    /// the id of the edge of the grid
+    id: usize,
+    point: Point3,
+    edge: [usize; 2],
+}
+
+/// Triangulates the loop in a cell by a fan without the diagonals on the faces of the cell, or by
+/// the fan from the centroid if there is no such fan. The diagonals on the faces would be shared
+/// by the triangles of the adjacent cell.
+///
+/// The indices of the vertices are given by `indices` from the ids, and the centroid is appended
+/// to `positions`.
+fn triangulate_loop(
+    polygon: &[CellVertex],
+    indices: &HashMap<usize, usize>,
+    positions: &mut Vec<Point3>,
+) -> Vec<[usize; 3]> {
+    let n = polygon.len();
+    let idx = |m: usize| indices[&polygon[m % n].id];
+    let on_one_face = |v: CellVertex, w: CellVertex| {
+        CUBE_FACES
+            .iter()
+            .any(|face| v.edge.iter().chain(&w.edge).all(|c| face.contains(c)))
+    };
+    let fan = (0..n).find(|s| {
+        (2..n.saturating_sub(1)).all(|m| !on_one_face(polygon[*s], polygon[(s + m) % n]))
+    });
+    match fan {
+        Some(s) => (2..n)
+            .map(|m| [idx(s), idx(s + m), idx(s + m - 1)])
+            .collect(),
+        None => {
+            let sum = polygon
+                .iter()
+                .fold(Vector3::zero(), |sum, v| sum + v.point.to_vec());
+            positions.push(Point3::from_vec(sum / n as f64));
+            let center = positions.len() - 1;
+            (0..n).map(|m| [center, idx(m + 1), idx(m)]).collect()
+        }
+    }
+}
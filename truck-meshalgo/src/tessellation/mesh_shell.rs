@@ -0,0 +1,116 @@
+use super::*;
+use rustc_hash::FxHashMap as HashMap;
+use truck_topology::{Result, Vertex as TVertex};
+
+type MeshedShell = Shell<Point3, PolylineCurve, Option<PolygonMesh>>;
+
+/// Reconstructs the topological shell from the polygons of `mesh`.
+///
+/// # Details
+/// The positions of `mesh` within `tol` are welded by
+/// [`OptimizingFilter::put_together_same_attrs`], and the degenerate polygons are removed. Then,
+/// each position becomes a [`Vertex`](TVertex), each pair of adjacent positions becomes an
+/// [`Edge`] shared by all polygons including it, and each polygon becomes a [`Face`] whose
+/// boundary is the loop of the edges and whose surface is the mesh of the polygon only. The curves
+/// of the edges are the segments, and the uv coordinates and the normals of the polygons are kept
+/// in the meshes of the faces.
+///
+/// The result has the same type as the tessellated shells, so it is converted back to the mesh by
+/// [`MeshedShape::to_seamless_polygon`]. The polygons are not triangulated, so a quadrangle becomes
+/// one face.
+///
+/// # Examples
+/// ```
+/// use std::collections::HashSet;
+/// use truck_meshalgo::prelude::*;
+/// // the triangle soup of a square, with the unconnected triangles
+/// let mesh = PolygonMesh::new(
+///     StandardAttributes {
+///         positions: vec![
+///             Point3::new(0.0, 0.0, 0.0),
+///             Point3::new(1.0, 0.0, 0.0),
+///             Point3::new(1.0, 1.0, 0.0),
+///             Point3::new(0.0, 0.0, 0.0),
+///             Point3::new(1.0, 1.0, 0.0),
+///             Point3::new(0.0, 1.0, 0.0),
+///         ],
+///         ..Default::default()
+///     },
+///     Faces::from_iter(&[[0, 1, 2], [3, 4, 5]]),
+/// );
+/// let shell = shell_from_mesh(&mesh, TOLERANCE).unwrap();
+/// assert_eq!(shell.len(), 2);
+/// // the diagonal edge is shared by the two faces
+/// let vertices = shell.vertex_iter().map(|v| v.id()).collect::<HashSet<_>>();
+/// let edges = shell.edge_iter().map(|edge| edge.id()).collect::<HashSet<_>>();
+/// assert_eq!(vertices.len(), 4);
+/// assert_eq!(edges.len(), 5);
+/// assert_eq!(shell.shell_condition(), ShellCondition::Oriented);
+/// ```
+///
+/// # Failures
+/// If a polygon passes through the same position twice after welding, then returns
+/// the error of [`Edge::try_new`] or [`Face::try_new`].
+///
+/// # Panics
+/// `tol` must be no less than `TOLERANCE`.
+pub fn shell_from_mesh(mesh: &PolygonMesh, tol: f64) -> Result<MeshedShell> {
+    nonpositive_tolerance!(tol);
+    let mut mesh = mesh.clone();
+    mesh.put_together_same_attrs(tol)
+        .remove_degenerate_faces()
+        .remove_unused_attrs();
+    let vertices = TVertex::news(mesh.positions());
+    let mut edges = HashMap::<(usize, usize), Edge<Point3, PolylineCurve>>::default();
+    let mut edge = |a: usize, b: usize| {
+        if let Some(edge) = edges.get(&(b, a)) {
+            return Ok(edge.inverse());
+        }
+        if let Some(edge) = edges.get(&(a, b)) {
+            return Ok(edge.clone());
+        }
+        let curve = PolylineCurve(vec![mesh.positions()[a], mesh.positions()[b]]);
+        let edge = Edge::try_new(&vertices[a], &vertices[b], curve)?;
+        edges.insert((a, b), edge.clone());
+        Ok(edge)
+    };
+    mesh.face_iter()
+        .map(|face| {
+            let wire = face
+                .iter()
+                .zip(face.iter().cycle().skip(1))
+                .map(|(v, w)| edge(v.pos, w.pos))
+                .collect::<Result<Wire<_, _>>>()?;
+            Face::try_new(vec![wire], Some(polygon_mesh(&mesh, face)))
+        })
+        .collect()
+}
+
+/// the mesh consisting of only one polygon `face` of `mesh`
+fn polygon_mesh(mesh: &PolygonMesh, face: &[StandardVertex]) -> PolygonMesh {
+    let mut uv_coords = Vec::new();
+    let mut normals = Vec::new();
+    let polygon = face
+        .iter()
+        .enumerate()
+        .map(|(i, v)| StandardVertex {
+            pos: i,
+            uv: v.uv.map(|uv| {
+                uv_coords.push(mesh.uv_coords()[uv]);
+                uv_coords.len() - 1
+            }),
+            nor: v.nor.map(|nor| {
+                normals.push(mesh.normals()[nor]);
+                normals.len() - 1
+            }),
+        })
+        .collect::<Vec<_>>();
+    PolygonMesh::new(
+        StandardAttributes {
+            positions: face.iter().map(|v| mesh.positions()[v.pos]).collect(),
+            uv_coords,
+            normals,
+        },
+        Faces::from_iter([polygon]),
+    )
+}
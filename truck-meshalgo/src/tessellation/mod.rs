@@ -1,5 +1,14 @@
+use crate::Point2;
 use crate::*;
-use spade::{iterators::*, *};
+use algo::surface::{DivisionOptions, SsnpVector};
+use filters::{NormalFilters, OptimizingFilter};
+use rustc_hash::FxHashSet as HashSet;
+use spade::*;
+use std::io::Write;
+use std::result::Result;
+use std::sync::atomic::AtomicBool;
+use truck_base::newton;
+use truck_polymesh::errors;
 use truck_topology::{compress::*, *};
 
 /// Tessellation output primitive preference.
@@ -46,15 +55,79 @@ impl Default for QuadOptions {
     }
 }
 
+/// How the triangles of trimmed faces are filtered by the boundaries.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TrimMode {
+    /// Keep the triangles whose centers are inside the boundaries.
+    #[default]
+    Full,
+    /// Keep all triangles of the constrained Delaunay triangulation without the inclusion test.
+    ///
+    /// The triangulation covers the convex hull of the boundary points, so this is valid only
+    /// for the faces whose domains are convex and have no holes. For such faces, this saves the
+    /// inclusion tests and never drops the triangles along the boundaries by the failures of the
+    /// tests. The faces without boundaries are not affected.
+    AssumeConvexNoHoles,
+}
+
+/// How the points in the interiors of trimmed faces are sampled.
+///
+/// The faces without boundaries are not affected, and always sampled by the grid.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum InteriorSampling {
+    /// Inserts the grid points of the parameter division of the surface.
+    #[default]
+    Grid,
+    /// Inserts no interior points, i.e. the triangles are the constrained Delaunay triangulation
+    /// of the boundary points only.
+    ///
+    /// This is fast and avoids the slivers between the grid and the boundaries, so it is
+    /// suitable for the small, thin or nearly flat faces. On the other hand, the distance between
+    /// the mesh and the surface is not bounded by the tolerance in the interiors.
+    None,
+    /// Starts from the boundary points, and inserts the surface points at the centers of the
+    /// triangles whose centers are farther than the tolerance from the surface, repeatedly.
+    ///
+    /// The points are inserted only where the surface is curved, so the flat parts are covered
+    /// by the large triangles.
+    Adaptive,
+}
+
 /// Options for tessellation.
 #[derive(Clone, Copy, Debug)]
 pub struct TessellationOptions {
     /// Geometric tolerance for curve and surface approximation.
     pub tolerance: f64,
     /// Maximum number of Newton iterations per parameter search.
+    ///
+    /// More trials make the search robust on difficult surfaces, and fewer trials make the
+    /// tessellation of easy surfaces fail fast.
     pub search_trials: usize,
+    /// Convergence tolerance of the Newton iterations searching the parameters of the boundary
+    /// points from the parameters of the previous points.
+    ///
+    /// A larger tolerance makes the searches on easy surfaces stop earlier, and the boundary
+    /// points are accepted within the tolerance from the surfaces. `None` keeps the searches by
+    /// [`SearchParameter`], which converge within [`TOLERANCE`].
+    /// cf. [`BySearchParameterWithTolerance`], [`BySearchNearestParameterWithTolerance`]
+    pub search_tolerance: Option<f64>,
     /// Quad generation policy.
     pub quad: QuadOptions,
+    /// Bound of the parameter divisions of surfaces.
+    ///
+    /// The divisions of too small tolerances are truncated at `division.max_division` intervals
+    /// in each direction, so that the grids of surfaces cannot exhaust the memory. The bound is
+    /// not applied to the boundary curves.
+    pub division: DivisionOptions,
+    /// Filtering of the triangles of trimmed faces.
+    pub trim: TrimMode,
+    /// Sampling of the interior points of trimmed faces.
+    pub interior: InteriorSampling,
+    /// Appends the reversed copy of each polygon with the flipped normals to the mesh of each face.
+    ///
+    /// This is a rendering convenience for open shells, e.g. sheet bodies, so that they are
+    /// visible from both sides with backface culling. The meshes are no longer oriented.
+    pub double_sided: bool,
 }
 
 impl Default for TessellationOptions {
@@ -62,11 +135,52 @@ impl Default for TessellationOptions {
         Self {
             tolerance: 0.01,
             search_trials: 100,
+            search_tolerance: None,
             quad: QuadOptions::default(),
+            division: DivisionOptions::default(),
+            trim: TrimMode::default(),
+            interior: InteriorSampling::default(),
+            double_sided: false,
+        }
+    }
+}
+
+impl TessellationOptions {
+    #[inline(always)]
+    fn trimming(&self) -> Trimming {
+        Trimming {
+            mode: self.trim,
+            interior: self.interior,
+            division: self.division,
+            double_sided: self.double_sided,
         }
     }
 }
 
+/// the options of the faces passed to the triangulation
+#[derive(Clone, Copy, Debug, Default)]
+struct Trimming {
+    mode: TrimMode,
+    interior: InteriorSampling,
+    division: DivisionOptions,
+    double_sided: bool,
+}
+
+/// The reasons of failures of tessellating faces.
+#[derive(Clone, Debug, PartialEq, thiserror::Error)]
+pub enum TessellationError {
+    /// The parameter of a boundary point is not found on the surface, e.g. because the boundary
+    /// does not ride on the surface.
+    #[error("The parameter of the boundary point {0:?} is not found on the surface.")]
+    ParameterSearchFailed(Point3),
+    /// A boundary wire has no points, or the face without boundaries has an unbounded surface.
+    #[error("The boundary of the face is degenerate.")]
+    DegenerateBoundary,
+    /// The tessellation has succeeded but the mesh has no faces.
+    #[error("The triangulation has no faces.")]
+    EmptyTriangulation,
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 mod parallelizable {
     /// Parallelizable by `rayon`.
@@ -98,6 +212,11 @@ pub trait PreMeshableSurface: ParametricSurface3D + ParameterDivision2D + Parall
 impl<S: ParametricSurface3D + ParameterDivision2D + Parallelizable> PreMeshableSurface for S {}
 
 /// The generated mesh can be trimmed only if the boundary curves ride strictly on a surface.
+///
+/// This trait is automatically implemented, so a user-defined surface can be tessellated through
+/// [`MeshableShape`] by implementing [`ParametricSurface3D`], [`ParameterDivision2D`] and
+/// [`SearchParameter<D2, Point = Point3>`]. The functions in [`algo::surface`] help to implement them.
+/// See `examples/custom-surface.rs` for a worked example.
 pub trait MeshableSurface: PreMeshableSurface + SearchParameter<D2, Point = Point3> {}
 impl<S: PreMeshableSurface + SearchParameter<D2, Point = Point3>> MeshableSurface for S {}
 
@@ -107,12 +226,236 @@ pub trait RobustMeshableSurface:
 }
 impl<S: MeshableSurface + SearchNearestParameter<D2, Point = Point3>> RobustMeshableSurface for S {}
 
+/// Strategy searching the parameters of the boundary points of faces on their surfaces.
+///
+/// Each face is tessellated with its own clone of the searcher, and [`ParameterSearcher::reset`]
+/// is called on the clone before the face is tessellated, so an implementor can keep per-face
+/// state, e.g. a cache of the previous hits. Since faces are tessellated in parallel, the state
+/// shared by all clones must be thread-safe.
+///
+/// The closures `Fn(&S, Point3, Option<(f64, f64)>) -> Option<(f64, f64)>` are also searchers.
+pub trait ParameterSearcher<S>: Clone + Parallelizable {
+    /// Returns the parameter of `point` on `surface`, or `None` if the search fails.
+    ///
+    /// `hint` is the parameter of the previous boundary point, if any.
+    fn search(
+        &mut self,
+        surface: &S,
+        point: Point3,
+        hint: Option<(f64, f64)>,
+    ) -> Option<(f64, f64)>;
+    /// Resets the per-face state. Called before tessellating each face.
+    #[inline(always)]
+    fn reset(&mut self) {}
+}
+
+impl<S, F> ParameterSearcher<S> for F
+where F: Fn(&S, Point3, Option<(f64, f64)>) -> Option<(f64, f64)> + Clone + Parallelizable
+{
+    #[inline(always)]
+    fn search(
+        &mut self,
+        surface: &S,
+        point: Point3,
+        hint: Option<(f64, f64)>,
+    ) -> Option<(f64, f64)> {
+        self(surface, point, hint)
+    }
+}
+
+/// The searcher by [`SearchParameter`], used in [`MeshableShape::triangulation`].
+///
+/// If the search from the hint fails, the search is retried without the hint.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BySearchParameter {
+    /// Maximum number of Newton iterations per parameter search.
+    pub trials: usize,
+}
+
+impl<S: MeshableSurface> ParameterSearcher<S> for BySearchParameter {
+    fn search(
+        &mut self,
+        surface: &S,
+        point: Point3,
+        hint: Option<(f64, f64)>,
+    ) -> Option<(f64, f64)> {
+        surface
+            .search_parameter(point, hint, self.trials)
+            .or_else(|| surface.search_parameter(point, None, self.trials))
+    }
+}
+
+/// The searcher by [`SearchParameter`] falling back to [`SearchNearestParameter`], used in
+/// [`RobustMeshableShape::robust_triangulation`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BySearchNearestParameter {
+    /// Maximum number of Newton iterations per parameter search.
+    pub trials: usize,
+}
+
+impl<S: RobustMeshableSurface> ParameterSearcher<S> for BySearchNearestParameter {
+    fn search(
+        &mut self,
+        surface: &S,
+        point: Point3,
+        hint: Option<(f64, f64)>,
+    ) -> Option<(f64, f64)> {
+        BySearchParameter {
+            trials: self.trials,
+        }
+        .search(surface, point, hint)
+        .or_else(|| surface.search_nearest_parameter(point, hint, self.trials))
+        .or_else(|| surface.search_nearest_parameter(point, None, self.trials))
+    }
+}
+
+/// The searcher of [`BySearchParameter`] whose Newton iterations from the hints converge within
+/// `tolerance`, used in [`triangulation_with`].
+///
+/// The Newton method from the hint stops if the step is less than `tolerance`, and the found
+/// parameter is accepted if its surface point is within `tolerance` from the point. If there is
+/// no hint or the search from the hint fails, the parameter is searched by [`SearchParameter`]
+/// without the hint. If `tolerance` is `None`, the search is the same as [`BySearchParameter`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BySearchParameterWithTolerance {
+    /// Maximum number of Newton iterations per parameter search.
+    pub trials: usize,
+    /// Convergence tolerance of the Newton iterations from the hints.
+    /// cf. [`TessellationOptions::search_tolerance`]
+    pub tolerance: Option<f64>,
+}
+
+impl<S: MeshableSurface> ParameterSearcher<S> for BySearchParameterWithTolerance {
+    fn search(
+        &mut self,
+        surface: &S,
+        point: Point3,
+        hint: Option<(f64, f64)>,
+    ) -> Option<(f64, f64)> {
+        let Some(tolerance) = self.tolerance else {
+            return BySearchParameter {
+                trials: self.trials,
+            }
+            .search(surface, point, hint);
+        };
+        hint.and_then(|hint| newton_nearest(surface, point, hint, self.trials, tolerance))
+            .filter(|(u, v)| surface.subs(*u, *v).distance(point) <= tolerance)
+            .or_else(|| surface.search_parameter(point, None, self.trials))
+    }
+}
+
+/// The searcher of [`BySearchNearestParameter`] whose Newton iterations from the hints converge
+/// within `tolerance`, used in [`robust_triangulation_with`].
+///
+/// The Newton method from the hint stops if the step is less than `tolerance`. The found
+/// parameter is accepted at any distance, if the search by [`SearchParameter`] without the hint
+/// also fails. The last fallback is the search by [`SearchNearestParameter`] without the hint.
+/// If `tolerance` is `None`, the search is the same as [`BySearchNearestParameter`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BySearchNearestParameterWithTolerance {
+    /// Maximum number of Newton iterations per parameter search.
+    pub trials: usize,
+    /// Convergence tolerance of the Newton iterations from the hints.
+    /// cf. [`TessellationOptions::search_tolerance`]
+    pub tolerance: Option<f64>,
+}
+
+impl<S: RobustMeshableSurface> ParameterSearcher<S> for BySearchNearestParameterWithTolerance {
+    fn search(
+        &mut self,
+        surface: &S,
+        point: Point3,
+        hint: Option<(f64, f64)>,
+    ) -> Option<(f64, f64)> {
+        let Some(tolerance) = self.tolerance else {
+            return BySearchNearestParameter {
+                trials: self.trials,
+            }
+            .search(surface, point, hint);
+        };
+        let nearest =
+            hint.and_then(|hint| newton_nearest(surface, point, hint, self.trials, tolerance));
+        nearest
+            .filter(|(u, v)| surface.subs(*u, *v).distance(point) <= tolerance)
+            .or_else(|| surface.search_parameter(point, None, self.trials))
+            .or(nearest)
+            .or_else(|| surface.search_nearest_parameter(point, None, self.trials))
+    }
+}
+
+/// The parameter of the nearest point on `surface` to `point` by the Newton method from `hint`,
+/// which stops if the step is less than `tolerance`.
+fn newton_nearest<S: ParametricSurface3D>(
+    surface: &S,
+    point: Point3,
+    (u, v): (f64, f64),
+    trials: usize,
+    tolerance: f64,
+) -> Option<(f64, f64)> {
+    let function = |param: Vector3| SsnpVector::subs(surface, point, param);
+    let hint = Vector3::new(u, v, 0.0);
+    let res = newton::solve_with_tolerance(function, hint, trials, tolerance).ok()?;
+    Some(res.into_param())
+}
+
 type PolylineCurve = truck_polymesh::PolylineCurve<Point3>;
 
 /// Trait for converting tessellated shape into polygon.
 pub trait MeshedShape {
     /// Converts tessellated shape into polygon.
     fn to_polygon(&self) -> PolygonMesh;
+    /// Returns the number of the faces which failed to be tessellated, i.e. the faces skipped by
+    /// [`MeshedShape::to_polygon`] and [`MeshedShape::to_seamless_polygon`].
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// use truck_modeling::*;
+    /// let bdd = BoundingBox::from_iter([Point3::origin(), Point3::new(1.0, 1.0, 1.0)]);
+    /// let cube: Solid = primitive::cuboid(bdd);
+    /// let mut meshed = cube.compress().triangulation(0.01);
+    /// assert_eq!(meshed.skipped_face_count(), 0);
+    ///
+    /// // the face whose tessellation failed
+    /// meshed.boundaries[0].faces[0].surface = None;
+    /// assert_eq!(meshed.skipped_face_count(), 1);
+    /// assert_eq!(meshed.to_polygon().faces().len(), 5 * 2);
+    /// ```
+    fn skipped_face_count(&self) -> usize { 0 }
+    /// Converts tessellated shape into a single seamless polygon with smooth normals.
+    ///
+    /// # Details
+    /// The polygons of all faces are merged, and the coincident vertices on the boundaries of faces are welded.
+    /// Then, the normals are recomputed by [`NormalFilters::add_smooth_normals`] with `tol_ang`,
+    /// so the edges whose dihedral angles exceed `tol_ang` are kept sharp.
+    /// Same as [`MeshedShape::to_polygon`], the faces which failed to be tessellated are skipped,
+    /// and the number of them is reported by [`MeshedShape::skipped_face_count`].
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// use truck_modeling::*;
+    /// let bdd = BoundingBox::from_iter([Point3::origin(), Point3::new(1.0, 1.0, 1.0)]);
+    /// let cube: Solid = primitive::cuboid(bdd);
+    /// let mesh = cube.triangulation(0.01).to_seamless_polygon(std::f64::consts::PI / 6.0);
+    /// assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+    /// assert_eq!(mesh.positions().len(), 8);
+    ///
+    /// // The corners are shared by three faces, and each of them keeps its own normal.
+    /// let normals = mesh.normals();
+    /// mesh.face_iter().for_each(|face| {
+    ///     let normal = normals[face[0].nor.unwrap()];
+    ///     assert!(face.iter().all(|v| normals[v.nor.unwrap()].near(&normal)));
+    /// });
+    /// ```
+    fn to_seamless_polygon(&self, tol_ang: f64) -> PolygonMesh {
+        let mut polygon = self.to_polygon();
+        polygon
+            .put_together_same_attrs(TOLERANCE)
+            .remove_degenerate_faces()
+            .remove_unused_attrs()
+            .add_smooth_normals(tol_ang, true);
+        polygon
+    }
 }
 
 impl MeshedShape for Shell<Point3, PolylineCurve, PolygonMesh> {
@@ -138,6 +481,11 @@ impl MeshedShape for Shell<Point3, PolylineCurve, Option<PolygonMesh>> {
         });
         polygon
     }
+    fn skipped_face_count(&self) -> usize {
+        self.face_iter()
+            .filter(|face| face.surface().is_none())
+            .count()
+    }
 }
 
 impl<P, C, S> MeshedShape for Solid<P, C, S>
@@ -150,6 +498,12 @@ where Shell<P, C, S>: MeshedShape
         });
         polygon
     }
+    fn skipped_face_count(&self) -> usize {
+        self.boundaries()
+            .iter()
+            .map(MeshedShape::skipped_face_count)
+            .sum()
+    }
 }
 
 impl MeshedShape for CompressedShell<Point3, PolylineCurve, PolygonMesh> {
@@ -176,6 +530,12 @@ impl MeshedShape for CompressedShell<Point3, PolylineCurve, Option<PolygonMesh>>
         });
         polygon
     }
+    fn skipped_face_count(&self) -> usize {
+        self.faces
+            .iter()
+            .filter(|face| face.surface.is_none())
+            .count()
+    }
 }
 
 impl<P, C, S> MeshedShape for CompressedSolid<P, C, S>
@@ -188,6 +548,12 @@ where CompressedShell<P, C, S>: MeshedShape
         });
         polygon
     }
+    fn skipped_face_count(&self) -> usize {
+        self.boundaries
+            .iter()
+            .map(MeshedShape::skipped_face_count)
+            .sum()
+    }
 }
 
 /// Trait for tessellating `Shell` and `Solid`.
@@ -318,19 +684,57 @@ pub trait RobustMeshableShape {
     fn robust_triangulation(&self, tolerance: f64) -> Self::MeshedShape;
 }
 
+/// Trait for computing the surface areas of `Face`, `Shell` and `Solid` from their geometry.
+pub trait SurfaceArea {
+    /// Returns the surface area by integrating the surface metric `|uder x vder|` over the
+    /// trimmed parameter domains of faces.
+    ///
+    /// This is more accurate than the area of the tessellated mesh for curved faces.
+    /// The domain is bounded by the polylines of the boundaries projected onto the surface.
+    /// The polylines are ten times finer than the ones of [`MeshableShape::triangulation`],
+    /// so that the error by the chords is much less than `tolerance` for moderate sizes.
+    /// If the parameter search of a boundary fails, the area is `NaN`.
+    ///
+    /// # Panics
+    ///
+    /// `tolerance` must be greater than or equal to `TOLERANCE`.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// use truck_modeling::*;
+    /// use std::f64::consts::PI;
+    ///
+    /// // a unit cube
+    /// let v = builder::vertex(Point3::origin());
+    /// let e = builder::tsweep(&v, Vector3::unit_x());
+    /// let f = builder::tsweep(&e, Vector3::unit_y());
+    /// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    /// assert_near!(cube.area(0.01), 6.0);
+    ///
+    /// // a cylinder with radius 1 and height 2
+    /// let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+    /// let circle: Wire = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(2.0 * PI), 4);
+    /// let disk: Face = builder::try_attach_plane(vec![circle]).unwrap();
+    /// assert!(f64::abs(disk.area(0.001) - PI) < 0.001);
+    /// let cylinder: Solid = builder::tsweep(&disk, Vector3::new(0.0, 0.0, 2.0));
+    /// assert!(f64::abs(cylinder.area(0.001) - 6.0 * PI) < 0.001);
+    /// ```
+    fn area(&self, tolerance: f64) -> f64;
+}
+
 /// Tessellates a [`Shell`] with a [`TessellationOptions`].
 pub fn triangulation_with<C: PolylineableCurve, S: MeshableSurface>(
     shell: &Shell<Point3, C, S>,
     options: TessellationOptions,
 ) -> Shell<Point3, PolylineCurve, Option<PolygonMesh>> {
     nonpositive_tolerance!(options.tolerance);
-    let sp = triangulation::search_parameter_sp::<S>(options.search_trials);
-    #[cfg(not(target_arch = "wasm32"))]
-    let res = triangulation::shell_tessellation(shell, options.tolerance, sp, options.quad);
-    #[cfg(target_arch = "wasm32")]
-    let res =
-        triangulation::shell_tessellation_single_thread(shell, options.tolerance, sp, options.quad);
-    res
+    let sp = BySearchParameterWithTolerance {
+        trials: options.search_trials,
+        tolerance: options.search_tolerance,
+    };
+    let never = AtomicBool::new(false);
+    tessellate_shell(shell, &options, sp, &never).expect("tessellation is never canceled")
 }
 
 /// Tessellates a [`Shell`] with robust parameter search and a [`TessellationOptions`].
@@ -339,12 +743,238 @@ pub fn robust_triangulation_with<C: PolylineableCurve, S: RobustMeshableSurface>
     options: TessellationOptions,
 ) -> Shell<Point3, PolylineCurve, Option<PolygonMesh>> {
     nonpositive_tolerance!(options.tolerance);
-    let sp = triangulation::search_nearest_parameter_sp::<S>(options.search_trials);
+    let sp = BySearchNearestParameterWithTolerance {
+        trials: options.search_trials,
+        tolerance: options.search_tolerance,
+    };
+    let never = AtomicBool::new(false);
+    tessellate_shell(shell, &options, sp, &never).expect("tessellation is never canceled")
+}
+
+/// Tessellates a [`Shell`] into a single polygon, and returns the [`FaceID`] of the original face
+/// of each polygon face.
+///
+/// The `i`-th ID corresponds to the `i`-th face of [`PolygonMesh::face_iter`], so a picked
+/// polygon face can be mapped to the face of the shell from which it was tessellated.
+/// Same as [`MeshedShape::to_polygon`], the faces which failed to be tessellated are skipped.
+pub fn triangulation_with_face_ids<C: PolylineableCurve, S: MeshableSurface>(
+    shell: &Shell<Point3, C, S>,
+    options: TessellationOptions,
+) -> (PolygonMesh, Vec<FaceID<S>>) {
+    let meshed = triangulation_with(shell, options);
+    let mut polygon = PolygonMesh::default();
+    let (mut tri_ids, mut quad_ids, mut other_ids) = (Vec::new(), Vec::new(), Vec::new());
+    shell
+        .face_iter()
+        .zip(meshed.face_iter())
+        .for_each(|(face, meshed_face)| {
+            if let Some(mut poly) = meshed_face.surface() {
+                if !meshed_face.orientation() {
+                    poly.invert();
+                }
+                let id = face.id();
+                tri_ids.extend(std::iter::repeat_n(id, poly.tri_faces().len()));
+                quad_ids.extend(std::iter::repeat_n(id, poly.quad_faces().len()));
+                other_ids.extend(std::iter::repeat_n(id, poly.other_faces().len()));
+                polygon.merge(poly);
+            }
+        });
+    tri_ids.extend(quad_ids);
+    tri_ids.extend(other_ids);
+    (polygon, tri_ids)
+}
+
+/// Tessellates a [`Shell`] with a [`TessellationOptions`], and returns the reason of the failure
+/// for each face which fails to be tessellated.
+///
+/// The meshes are the same as [`triangulation_with`], except that the empty meshes are
+/// [`TessellationError::EmptyTriangulation`].
+/// # Examples
+/// ```
+/// use truck_meshalgo::prelude::*;
+/// use truck_modeling::*;
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+/// let shell = &cube.boundaries()[0];
+/// let meshed = try_triangulation_with(shell, Default::default());
+/// assert!(meshed.face_iter().all(|face| face.surface().is_ok()));
+/// ```
+pub fn try_triangulation_with<C: PolylineableCurve, S: MeshableSurface>(
+    shell: &Shell<Point3, C, S>,
+    options: TessellationOptions,
+) -> Shell<Point3, PolylineCurve, Result<PolygonMesh, TessellationError>> {
+    nonpositive_tolerance!(options.tolerance);
+    let sp = BySearchParameterWithTolerance {
+        trials: options.search_trials,
+        tolerance: options.search_tolerance,
+    };
+    let never = AtomicBool::new(false);
+    tessellate_shell(shell, &options, sp, &never).expect("tessellation is never canceled")
+}
+
+/// Tessellates a [`Shell`] with robust parameter search and a [`TessellationOptions`], and
+/// returns the reason of the failure for each face which fails to be tessellated.
+///
+/// The meshes are the same as [`robust_triangulation_with`], except that the empty meshes are
+/// [`TessellationError::EmptyTriangulation`].
+pub fn try_robust_triangulation_with<C: PolylineableCurve, S: RobustMeshableSurface>(
+    shell: &Shell<Point3, C, S>,
+    options: TessellationOptions,
+) -> Shell<Point3, PolylineCurve, Result<PolygonMesh, TessellationError>> {
+    nonpositive_tolerance!(options.tolerance);
+    let sp = BySearchNearestParameterWithTolerance {
+        trials: options.search_trials,
+        tolerance: options.search_tolerance,
+    };
+    let never = AtomicBool::new(false);
+    tessellate_shell(shell, &options, sp, &never).expect("tessellation is never canceled")
+}
+
+/// Tessellates a [`Shell`] with a [`TessellationOptions`] and a custom [`ParameterSearcher`].
+///
+/// `options.search_trials` and `options.search_tolerance` are not used, since the searcher
+/// determines how to search parameters.
+/// With [`BySearchParameter`], the result is the same as [`triangulation_with`] without
+/// `options.search_tolerance`.
+/// # Examples
+/// ```
+/// use truck_meshalgo::prelude::*;
+/// use truck_modeling::*;
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+/// let shell = &cube.boundaries()[0];
+///
+/// let searcher = |surface: &Surface, point: Point3, hint: Option<(f64, f64)>| {
+///     surface.search_parameter(point, hint, 10)
+/// };
+/// let meshed = triangulation_with_searcher(shell, Default::default(), searcher);
+/// assert!(meshed.face_iter().all(|face| face.surface().is_some()));
+/// ```
+pub fn triangulation_with_searcher<C: PolylineableCurve, S: PreMeshableSurface>(
+    shell: &Shell<Point3, C, S>,
+    options: TessellationOptions,
+    searcher: impl ParameterSearcher<S>,
+) -> Shell<Point3, PolylineCurve, Option<PolygonMesh>> {
+    nonpositive_tolerance!(options.tolerance);
+    let never = AtomicBool::new(false);
+    tessellate_shell(shell, &options, searcher, &never).expect("tessellation is never canceled")
+}
+
+/// Tessellates the face of `shell` with `face_id` again, and replaces the corresponding face of
+/// `meshed`, the tessellation of `shell`.
+///
+/// Returns `false` and does nothing if `shell` has no face with `face_id`, or `meshed` does not
+/// have the corresponding face. The faces of `meshed` are regarded as tessellated from the faces
+/// of `shell` in the same order, as the results of [`triangulation_with`] and so on.
+///
+/// The existing polylines of the boundary of the face are reused, so the new mesh is as watertight
+/// with the neighboring faces as before. Hence this is for the changes of the face which keep its
+/// boundary, e.g. an edit of the surface inside the face, or a finer tolerance for the face.
+/// `options.search_trials` and `options.search_tolerance` are not used, since the searcher
+/// determines how to search parameters.
+/// # Examples
+/// ```
+/// use truck_meshalgo::prelude::*;
+/// use truck_modeling::*;
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+/// let shell = &cube.boundaries()[0];
+/// let mut meshed = shell.triangulation(0.01);
+///
+/// let options = TessellationOptions {
+///     quad: QuadOptions {
+///         mode: QuadMode::AllQuads,
+///         ..Default::default()
+///     },
+///     ..Default::default()
+/// };
+/// let searcher = BySearchParameter { trials: 100 };
+/// assert!(retessellate_face(&mut meshed, shell, shell[0].id(), options, searcher));
+/// assert!(!meshed[0].surface().unwrap().quad_faces().is_empty());
+/// assert!(meshed[1].surface().unwrap().quad_faces().is_empty());
+/// ```
+pub fn retessellate_face<C, S: PreMeshableSurface>(
+    meshed: &mut Shell<Point3, PolylineCurve, Option<PolygonMesh>>,
+    shell: &Shell<Point3, C, S>,
+    face_id: FaceID<S>,
+    options: TessellationOptions,
+    searcher: impl ParameterSearcher<S>,
+) -> bool {
+    nonpositive_tolerance!(options.tolerance);
+    triangulation::retessellate_face(
+        meshed,
+        shell,
+        face_id,
+        options.tolerance,
+        searcher,
+        options.quad,
+        options.trimming(),
+    )
+}
+
+/// Tessellates a [`Shell`] with a [`TessellationOptions`], or returns `None` if `cancel` is set.
+///
+/// The flag is checked before and after tessellating each face, so a long tessellation stops soon
+/// after the flag is set, e.g. from another thread. If the flag is never set, the result is the
+/// same as [`triangulation_with`].
+/// # Examples
+/// ```
+/// use std::sync::atomic::{AtomicBool, Ordering};
+/// use truck_meshalgo::prelude::*;
+/// use truck_modeling::*;
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+/// let shell = &cube.boundaries()[0];
+///
+/// let cancel = AtomicBool::new(false);
+/// let meshed = cancelable_triangulation_with(shell, Default::default(), &cancel).unwrap();
+/// assert_eq!(meshed.len(), 6);
+///
+/// cancel.store(true, Ordering::Relaxed);
+/// assert!(cancelable_triangulation_with(shell, Default::default(), &cancel).is_none());
+/// ```
+pub fn cancelable_triangulation_with<C: PolylineableCurve, S: MeshableSurface>(
+    shell: &Shell<Point3, C, S>,
+    options: TessellationOptions,
+    cancel: &AtomicBool,
+) -> Option<Shell<Point3, PolylineCurve, Option<PolygonMesh>>> {
+    nonpositive_tolerance!(options.tolerance);
+    let sp = BySearchParameterWithTolerance {
+        trials: options.search_trials,
+        tolerance: options.search_tolerance,
+    };
+    tessellate_shell(shell, &options, sp, cancel)
+}
+
+/// Tessellates `shell` with `options` and `sp`, in parallel except on wasm, or returns `None` if
+/// `cancel` is set.
+fn tessellate_shell<'a, C, S, T>(
+    shell: &'a Shell<Point3, C, S>,
+    options: &TessellationOptions,
+    sp: impl ParameterSearcher<S>,
+    cancel: &AtomicBool,
+) -> Option<Shell<Point3, PolylineCurve, T>>
+where
+    C: PolylineableCurve + 'a,
+    S: PreMeshableSurface + 'a,
+    T: triangulation::FaceMesh,
+{
+    let (tolerance, quad, trimming) = (options.tolerance, options.quad, options.trimming());
     #[cfg(not(target_arch = "wasm32"))]
-    let res = triangulation::shell_tessellation(shell, options.tolerance, sp, options.quad);
-    #[cfg(target_arch = "wasm32")]
     let res =
-        triangulation::shell_tessellation_single_thread(shell, options.tolerance, sp, options.quad);
+        triangulation::shell_tessellation_cancelable(shell, tolerance, sp, quad, trimming, cancel);
+    #[cfg(target_arch = "wasm32")]
+    let res = triangulation::shell_tessellation_single_thread_cancelable(
+        shell, tolerance, sp, quad, trimming, cancel,
+    );
     res
 }
 
@@ -354,8 +984,17 @@ pub fn cshell_triangulation_with<C: PolylineableCurve, S: MeshableSurface>(
     options: TessellationOptions,
 ) -> CompressedShell<Point3, PolylineCurve, Option<PolygonMesh>> {
     nonpositive_tolerance!(options.tolerance);
-    let sp = triangulation::search_parameter_sp::<S>(options.search_trials);
-    triangulation::cshell_tessellation(shell, options.tolerance, sp, options.quad)
+    let sp = BySearchParameterWithTolerance {
+        trials: options.search_trials,
+        tolerance: options.search_tolerance,
+    };
+    triangulation::cshell_tessellation(
+        shell,
+        options.tolerance,
+        sp,
+        options.quad,
+        options.trimming(),
+    )
 }
 
 /// Tessellates a [`CompressedShell`] with robust parameter search and a [`TessellationOptions`].
@@ -364,8 +1003,389 @@ pub fn robust_cshell_triangulation_with<C: PolylineableCurve, S: RobustMeshableS
     options: TessellationOptions,
 ) -> CompressedShell<Point3, PolylineCurve, Option<PolygonMesh>> {
     nonpositive_tolerance!(options.tolerance);
-    let sp = triangulation::search_nearest_parameter_sp::<S>(options.search_trials);
-    triangulation::cshell_tessellation(shell, options.tolerance, sp, options.quad)
+    let sp = BySearchNearestParameterWithTolerance {
+        trials: options.search_trials,
+        tolerance: options.search_tolerance,
+    };
+    triangulation::cshell_tessellation(
+        shell,
+        options.tolerance,
+        sp,
+        options.quad,
+        options.trimming(),
+    )
+}
+
+/// Tessellates a [`CompressedShell`] with a [`TessellationOptions`] and a custom
+/// [`ParameterSearcher`].
+///
+/// `options.search_trials` and `options.search_tolerance` are not used, since the searcher
+/// determines how to search parameters.
+pub fn cshell_triangulation_with_searcher<C: PolylineableCurve, S: PreMeshableSurface>(
+    shell: &CompressedShell<Point3, C, S>,
+    options: TessellationOptions,
+    searcher: impl ParameterSearcher<S>,
+) -> CompressedShell<Point3, PolylineCurve, Option<PolygonMesh>> {
+    nonpositive_tolerance!(options.tolerance);
+    triangulation::cshell_tessellation(
+        shell,
+        options.tolerance,
+        searcher,
+        options.quad,
+        options.trimming(),
+    )
+}
+
+/// Tessellates the solids with the shared [`TessellationOptions`].
+///
+/// The `i`-th result is the tessellation of the `i`-th solid, same as [`triangulation_with`] for
+/// each boundary shell. The solids are tessellated in parallel, and so are the faces of each
+/// solid. Both run in the same thread pool of `rayon`, so the nested parallelism creates no
+/// extra threads.
+/// # Examples
+/// ```
+/// use truck_meshalgo::prelude::*;
+/// use truck_modeling::*;
+/// let solids: Vec<Solid> = (0..3)
+///     .map(|i| {
+///         let p = Point3::new(2.0 * i as f64, 0.0, 0.0);
+///         primitive::cuboid(BoundingBox::from_iter([p, p + Vector3::new(1.0, 1.0, 1.0)]))
+///     })
+///     .collect();
+/// let meshed = tessellate_solids(&solids, Default::default());
+/// assert_eq!(meshed.len(), 3);
+/// assert!(meshed.iter().all(|solid| solid.boundaries()[0].len() == 6));
+/// ```
+pub fn tessellate_solids<C: PolylineableCurve, S: MeshableSurface>(
+    solids: &[Solid<Point3, C, S>],
+    options: TessellationOptions,
+) -> Vec<Solid<Point3, PolylineCurve, Option<PolygonMesh>>> {
+    nonpositive_tolerance!(options.tolerance);
+    let tessellate = |solid: &Solid<Point3, C, S>| {
+        let boundaries = solid
+            .boundaries()
+            .iter()
+            .map(|shell| triangulation_with(shell, options))
+            .collect::<Vec<_>>();
+        Solid::new(boundaries)
+    };
+    #[cfg(not(target_arch = "wasm32"))]
+    let res = {
+        use rayon::prelude::*;
+        solids.par_iter().map(tessellate).collect()
+    };
+    #[cfg(target_arch = "wasm32")]
+    let res = solids.iter().map(tessellate).collect();
+    res
+}
+
+/// Tessellates `surface` trimmed by the polygons `outer` and `holes` in the parameter space.
+///
+/// This does not need any topological face, so polygons from sketches or external data can be
+/// meshed directly. The loops may or may not repeat the first point at the end, and their
+/// orientations are corrected: the outer loop is made counter-clockwise and the holes clockwise.
+/// Each segment of the loops is subdivided within `tolerance` on the surface. Loops with less
+/// than three points are ignored, and if the outer loop is ignored, the whole domain of the
+/// bounded surface is used instead.
+///
+/// # Panics
+/// `tolerance` must be no less than `TOLERANCE`.
+///
+/// # Examples
+/// ```
+/// use truck_meshalgo::prelude::*;
+/// use truck_modeling::*;
+/// let plane = Plane::new(Point3::origin(), Point3::new(1.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0));
+/// let outer = [
+///     Point2::new(0.0, 0.0),
+///     Point2::new(1.0, 0.0),
+///     Point2::new(1.0, 1.0),
+///     Point2::new(0.0, 1.0),
+/// ];
+/// let mesh = trimmed_surface_mesh(&plane, &outer, &[], 0.01);
+/// let area = mesh.faces().triangle_iter().fold(0.0, |sum, [a, b, c]| {
+///     let p = mesh.positions();
+///     sum + (p[b.pos] - p[a.pos]).cross(p[c.pos] - p[a.pos]).magnitude() / 2.0
+/// });
+/// assert_near!(area, 1.0);
+/// ```
+pub fn trimmed_surface_mesh<S: PreMeshableSurface>(
+    surface: &S,
+    outer: &[Point2],
+    holes: &[Vec<Point2>],
+    tolerance: f64,
+) -> PolygonMesh {
+    nonpositive_tolerance!(tolerance);
+    triangulation::trimmed_surface_tessellation(
+        surface,
+        outer,
+        holes,
+        tolerance,
+        QuadOptions::default(),
+        Default::default(),
+    )
+}
+
+/// Tessellates the part of `surface` on the parameter range `range` without any trimming.
+///
+/// The range does not need to be the whole domain of the surface, so only a portion of a large
+/// surface can be meshed without generating grid points outside. The vertices have the texture
+/// coordinates of their parameters and the normals of the surface.
+///
+/// # Panics
+/// `tolerance` must be no less than `TOLERANCE`.
+///
+/// # Examples
+/// ```
+/// use truck_meshalgo::prelude::*;
+/// use truck_modeling::*;
+/// let plane = Plane::new(Point3::origin(), Point3::new(1.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0));
+/// let mesh = surface_mesh(&plane, ((2.0, 3.0), (-1.0, 0.5)), 0.01);
+/// assert!(mesh.uv_coords().iter().all(|uv| {
+///     (2.0..=3.0).contains(&uv.x) && (-1.0..=0.5).contains(&uv.y)
+/// }));
+/// let area = mesh.faces().triangle_iter().fold(0.0, |sum, [a, b, c]| {
+///     let p = mesh.positions();
+///     sum + (p[b.pos] - p[a.pos]).cross(p[c.pos] - p[a.pos]).magnitude() / 2.0
+/// });
+/// assert_near!(area, 1.5);
+/// ```
+pub fn surface_mesh<S: PreMeshableSurface>(
+    surface: &S,
+    range: ((f64, f64), (f64, f64)),
+    tolerance: f64,
+) -> PolygonMesh {
+    nonpositive_tolerance!(tolerance);
+    let division = DivisionOptions::default();
+    triangulation::untrimmed_tessellation(surface, range, tolerance, QuadMode::Triangles, division)
+}
+
+/// Evaluates `surface` on the triangulation of the parameter space given by `uv_points` and
+/// `triangles`.
+///
+/// No triangulation is run, so the triangles made by the other meshers are kept as they are. The
+/// `i`-th vertex of the mesh is the point of `surface` at `uv_points[i]`, with the texture
+/// coordinate of the parameter and the normal of the surface.
+///
+/// # Panics
+/// Panics if an index of `triangles` is out of range of `uv_points`.
+///
+/// # Examples
+/// ```
+/// use truck_meshalgo::prelude::*;
+/// use truck_modeling::*;
+/// let plane = Plane::new(Point3::origin(), Point3::new(2.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0));
+/// let uv_points = [
+///     Point2::new(0.0, 0.0),
+///     Point2::new(1.0, 0.0),
+///     Point2::new(0.5, 1.0),
+/// ];
+/// let mesh = evaluate_param_triangulation(&plane, &uv_points, &[[0, 1, 2]]);
+/// assert_eq!(mesh.tri_faces().len(), 1);
+/// assert_near!(mesh.positions()[2], Point3::new(1.0, 1.0, 0.0));
+/// assert_near!(mesh.normals()[2], Vector3::unit_z());
+/// ```
+pub fn evaluate_param_triangulation<S: ParametricSurface3D>(
+    surface: &S,
+    uv_points: &[Point2],
+    triangles: &[[usize; 3]],
+) -> PolygonMesh {
+    let attributes = StandardAttributes {
+        positions: uv_points
+            .iter()
+            .map(|uv| surface.subs(uv.x, uv.y))
+            .collect(),
+        uv_coords: uv_points.iter().map(|uv| uv.to_vec()).collect(),
+        normals: uv_points
+            .iter()
+            .map(|uv| surface.normal(uv.x, uv.y))
+            .collect(),
+    };
+    let faces = triangles
+        .iter()
+        .map(|tri| tri.map(|i| [i, i, i]))
+        .collect::<Faces>();
+    PolygonMesh::new(attributes, faces)
+}
+
+/// Tessellates only the edges of `shell`, and returns one polyline for each edge.
+///
+/// No face is meshed, so this is much cheaper than [`MeshableShape::triangulation`] for the
+/// wireframe rendering. The polylines are the same as the ones of the edges of the meshed shell:
+/// each edge shared by some faces is tessellated once, along the absolute orientation of the
+/// edge. The polylines are ordered by the first appearances of the edges in the shell.
+///
+/// # Panics
+/// `tolerance` must be no less than `TOLERANCE`.
+///
+/// # Examples
+/// ```
+/// use truck_meshalgo::prelude::*;
+/// use truck_modeling::*;
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+///
+/// let wireframe = wireframe(&cube.boundaries()[0], 0.01);
+/// assert_eq!(wireframe.len(), 12);
+/// // straight edges are not divided
+/// assert!(wireframe.iter().all(|polyline| polyline.len() == 2));
+/// ```
+pub fn wireframe<C: PolylineableCurve, S>(
+    shell: &Shell<Point3, C, S>,
+    tolerance: f64,
+) -> Vec<PolylineCurve> {
+    nonpositive_tolerance!(tolerance);
+    let mut visited = HashSet::default();
+    shell
+        .edge_iter()
+        .filter(|edge| visited.insert(edge.id()))
+        .map(|edge| {
+            let curve = edge.curve();
+            PolylineCurve::from_curve(&curve, curve.range_tuple(), tolerance)
+        })
+        .collect()
+}
+
+/// Tessellates the boundaries of `face` into the closed polylines, without meshing its interior.
+///
+/// The polylines are in the order of [`Face::boundaries`], i.e. the outer boundary first and
+/// then the holes, and they are oriented as the boundaries of the face. Each edge is divided by
+/// [`PolylineCurve::from_curve`] as [`wireframe`], and the polylines of the edges are
+/// concatenated along the orientations of the edges in the wire. The last point of each
+/// polyline is the same as the first one.
+///
+/// # Panics
+/// `tolerance` must be no less than `TOLERANCE`.
+///
+/// # Examples
+/// ```
+/// use truck_meshalgo::prelude::*;
+/// use truck_modeling::*;
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let square: Face = builder::tsweep(&e, Vector3::unit_y());
+///
+/// let loops = face_boundary_loops(&square, 0.01);
+/// assert_eq!(loops.len(), 1);
+/// // four straight edges are not divided
+/// assert_eq!(loops[0].len(), 5);
+/// assert_eq!(loops[0].front(), loops[0].back());
+/// ```
+pub fn face_boundary_loops<C: PolylineableCurve, S>(
+    face: &Face<Point3, C, S>,
+    tolerance: f64,
+) -> Vec<PolylineCurve> {
+    nonpositive_tolerance!(tolerance);
+    face.boundaries()
+        .iter()
+        .map(|wire| {
+            let mut points = Vec::new();
+            wire.edge_iter().for_each(|edge| {
+                let curve = edge.curve();
+                let mut polyline =
+                    PolylineCurve::from_curve(&curve, curve.range_tuple(), tolerance);
+                if !edge.orientation() {
+                    polyline.0.reverse();
+                }
+                // the first point of the edge is the last point of the previous edge
+                points.pop();
+                points.extend(polyline.0);
+            });
+            if let (Some(first), Some(last)) = (points.first().copied(), points.last_mut()) {
+                *last = first;
+            }
+            PolylineCurve(points)
+        })
+        .collect()
+}
+
+/// Tessellates `shape` by [`MeshableShape::triangulation`] into one welded polygon mesh.
+///
+/// The polygons of all faces are merged by [`MeshedShape::to_polygon`], so they are oriented
+/// along the faces, i.e. outward for solids. Then, the coincident vertices on the boundaries of
+/// faces are welded, and the degenerate faces and the unused attributes are removed. The normals
+/// of the faces are kept, use [`MeshedShape::to_seamless_polygon`] for smooth normals.
+/// Same as [`MeshedShape::to_polygon`], the faces which failed to be tessellated are skipped.
+///
+/// # Panics
+/// `tolerance` must be greater than or equal to `TOLERANCE`.
+///
+/// # Examples
+/// ```
+/// use truck_meshalgo::prelude::*;
+/// use truck_modeling::*;
+/// let bdd = BoundingBox::from_iter([Point3::origin(), Point3::new(1.0, 1.0, 1.0)]);
+/// let cube: Solid = primitive::cuboid(bdd);
+/// let mesh = tessellate_to_polygon(&cube, 0.01);
+/// assert_eq!(mesh.faces().triangle_iter().len(), 12);
+/// assert_eq!(mesh.positions().len(), 8);
+/// assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+/// // outward: the volume is positive
+/// assert_near!(mesh.volume(), 1.0);
+/// ```
+pub fn tessellate_to_polygon<T: MeshableShape>(shape: &T, tolerance: f64) -> PolygonMesh {
+    let mut polygon = shape.triangulation(tolerance).to_polygon();
+    polygon
+        .put_together_same_attrs(TOLERANCE)
+        .remove_degenerate_faces()
+        .remove_unused_attrs();
+    polygon
+}
+
+/// Tessellates `shape` by [`MeshableShape::triangulation`] and writes the merged mesh in OBJ format.
+///
+/// Same as [`MeshedShape::to_polygon`], the faces which failed to be tessellated are skipped.
+/// # Examples
+/// ```
+/// use truck_meshalgo::prelude::*;
+/// use truck_modeling::*;
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+///
+/// let mut obj = Vec::new();
+/// write_obj(&cube, 0.01, &mut obj).unwrap();
+/// let mesh = obj::read(obj.as_slice()).unwrap();
+/// assert_eq!(mesh.faces().triangle_iter().len(), 12);
+/// ```
+pub fn write_obj<T, W>(shape: &T, tolerance: f64, writer: W) -> Result<(), errors::Error>
+where
+    T: MeshableShape,
+    W: Write, {
+    obj::write(&shape.triangulation(tolerance).to_polygon(), writer)
+}
+
+/// Tessellates `shape` by [`MeshableShape::triangulation`] and writes the merged mesh in STL format.
+///
+/// Same as [`MeshedShape::to_polygon`], the faces which failed to be tessellated are skipped.
+/// # Examples
+/// ```
+/// use truck_meshalgo::prelude::*;
+/// use truck_modeling::*;
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+///
+/// let mut stl = Vec::new();
+/// write_stl(&cube, 0.01, &mut stl, stl::StlType::Binary).unwrap();
+/// let mesh = stl::read(stl.as_slice(), stl::StlType::Binary).unwrap();
+/// assert_eq!(mesh.faces().len(), 12);
+/// ```
+pub fn write_stl<T: MeshableShape, W: Write>(
+    shape: &T,
+    tolerance: f64,
+    mut writer: W,
+    stl_type: stl::StlType,
+) -> Result<(), errors::Error> {
+    stl::write(
+        &shape.triangulation(tolerance).to_polygon(),
+        &mut writer,
+        stl_type,
+    )
 }
 
 impl<C: PolylineableCurve, S: MeshableSurface> MeshableShape for Shell<Point3, C, S> {
@@ -472,4 +1492,70 @@ impl<C: PolylineableCurve, S: RobustMeshableSurface> RobustMeshableShape
     }
 }
 
+impl<C: PolylineableCurve, S: MeshableSurface> SurfaceArea for Face<Point3, C, S> {
+    fn area(&self, tolerance: f64) -> f64 {
+        nonpositive_tolerance!(tolerance);
+        let sp = BySearchParameter {
+            trials: TessellationOptions::default().search_trials,
+        };
+        triangulation::face_area(self, tolerance, sp).unwrap_or(f64::NAN)
+    }
+}
+
+impl<C: PolylineableCurve, S: MeshableSurface> SurfaceArea for Shell<Point3, C, S> {
+    fn area(&self, tolerance: f64) -> f64 {
+        #[cfg(not(target_arch = "wasm32"))]
+        let res = {
+            use rayon::prelude::*;
+            self.face_par_iter().map(|face| face.area(tolerance)).sum()
+        };
+        #[cfg(target_arch = "wasm32")]
+        let res = self.face_iter().map(|face| face.area(tolerance)).sum();
+        res
+    }
+}
+
+impl<C: PolylineableCurve, S: MeshableSurface> SurfaceArea for Solid<Point3, C, S> {
+    fn area(&self, tolerance: f64) -> f64 {
+        self.boundaries()
+            .iter()
+            .map(|shell| shell.area(tolerance))
+            .sum()
+    }
+}
+
+/// Returns the signed volume enclosed by `shell`, i.e. the integral of `p / 3` dotted with the
+/// oriented normals over the faces, or `None` if the parameter search of a boundary fails.
+///
+/// The volume is positive if the shell is oriented outward.
+#[cfg(feature = "analyzers")]
+pub(crate) fn shell_volume<C: PolylineableCurve, S: MeshableSurface>(
+    shell: &Shell<Point3, C, S>,
+    tolerance: f64,
+) -> Option<f64> {
+    let sp = BySearchParameter {
+        trials: TessellationOptions::default().search_trials,
+    };
+    let integrand = |surface: &S, u: f64, v: f64| {
+        let normal = surface.uder(u, v).cross(surface.vder(u, v));
+        surface.subs(u, v).to_vec().dot(normal) / 3.0
+    };
+    shell
+        .face_iter()
+        .map(|face| {
+            let volume = triangulation::face_integral(face, tolerance, sp, integrand)?;
+            match face.orientation() {
+                true => Some(volume),
+                false => Some(-volume),
+            }
+        })
+        .sum()
+}
+
+mod contour_fill;
+pub use contour_fill::{triangulate_contours, FillRule};
+mod marching_cubes;
+pub use marching_cubes::marching_cubes;
+mod mesh_shell;
+pub use mesh_shell::shell_from_mesh;
 mod triangulation;
@@ -4,38 +4,38 @@ use super::*;
 use crate::filters::{NormalFilters, StructuringFilter};
 use crate::Point2;
 use array_macro::array;
-use handles::FixedVertexHandle;
+use handles::{FaceHandle, FixedVertexHandle, InnerTag, PossiblyOuterTag};
 use itertools::Itertools;
-use rustc_hash::FxHashMap as HashMap;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::{iter::once, result::Result};
 
 #[cfg(not(target_arch = "wasm32"))]
 use rayon::prelude::*;
 
 type SPoint2 = spade::Point2<f64>;
 type Cdt = ConstrainedDelaunayTriangulation<SPoint2>;
-type MeshedShell = Shell<Point3, PolylineCurve, Option<PolygonMesh>>;
-type MeshedCShell = CompressedShell<Point3, PolylineCurve, Option<PolygonMesh>>;
+type MeshedShell<T> = Shell<Point3, PolylineCurve, T>;
+type MeshedCShell<T> = CompressedShell<Point3, PolylineCurve, T>;
 
-pub(super) trait SP<S>:
-    Fn(&S, Point3, Option<(f64, f64)>) -> Option<(f64, f64)> + Parallelizable {
+/// The per-face output of tessellations.
+pub(super) trait FaceMesh: Clone + Parallelizable {
+    fn from_result(result: Result<PolygonMesh, TessellationError>) -> Self;
 }
-impl<S, F> SP<S> for F where F: Fn(&S, Point3, Option<(f64, f64)>) -> Option<(f64, f64)> + Parallelizable {}
 
-pub(super) fn search_parameter_sp<S: MeshableSurface>(trials: usize) -> impl SP<S> {
-    move |surface: &S, point: Point3, hint: Option<(f64, f64)>| {
-        surface
-            .search_parameter(point, hint, trials)
-            .or_else(|| surface.search_parameter(point, None, trials))
-    }
+/// The reasons of failures are ignored, and empty meshes are accepted.
+impl FaceMesh for Option<PolygonMesh> {
+    #[inline(always)]
+    fn from_result(result: Result<PolygonMesh, TessellationError>) -> Self { result.ok() }
 }
 
-pub(super) fn search_nearest_parameter_sp<S: RobustMeshableSurface>(trials: usize) -> impl SP<S> {
-    move |surface: &S, point: Point3, hint: Option<(f64, f64)>| {
-        surface
-            .search_parameter(point, hint, trials)
-            .or_else(|| surface.search_parameter(point, None, trials))
-            .or_else(|| surface.search_nearest_parameter(point, hint, trials))
-            .or_else(|| surface.search_nearest_parameter(point, None, trials))
+impl FaceMesh for Result<PolygonMesh, TessellationError> {
+    #[inline(always)]
+    fn from_result(result: Result<PolygonMesh, TessellationError>) -> Self {
+        result.and_then(|mesh| match mesh.faces().is_empty() {
+            true => Err(TessellationError::EmptyTriangulation),
+            false => Ok(mesh),
+        })
     }
 }
 
@@ -46,20 +46,42 @@ pub(super) fn by_search_parameter<S: MeshableSurface>(
     point: Point3,
     hint: Option<(f64, f64)>,
 ) -> Option<(f64, f64)> {
-    search_parameter_sp::<S>(100)(surface, point, hint)
+    BySearchParameter { trials: 100 }.search(surface, point, hint)
 }
 
 /// Tessellates faces
+#[cfg(all(not(target_arch = "wasm32"), test))]
+pub(super) fn shell_tessellation<'a, C, S, T>(
+    shell: &Shell<Point3, C, S>,
+    tolerance: f64,
+    sp: impl ParameterSearcher<S>,
+    quad_config: QuadOptions,
+    trimming: Trimming,
+) -> MeshedShell<T>
+where
+    C: PolylineableCurve + 'a,
+    S: PreMeshableSurface + 'a,
+    T: FaceMesh,
+{
+    let never = AtomicBool::new(false);
+    shell_tessellation_cancelable(shell, tolerance, sp, quad_config, trimming, &never)
+        .expect("tessellation is never canceled")
+}
+
+/// Tessellates faces, or returns `None` if `cancel` is set before or after tessellating a face.
 #[cfg(not(target_arch = "wasm32"))]
-pub(super) fn shell_tessellation<'a, C, S>(
+pub(super) fn shell_tessellation_cancelable<'a, C, S, T>(
     shell: &Shell<Point3, C, S>,
     tolerance: f64,
-    sp: impl SP<S>,
+    sp: impl ParameterSearcher<S>,
     quad_config: QuadOptions,
-) -> MeshedShell
+    trimming: Trimming,
+    cancel: &AtomicBool,
+) -> Option<MeshedShell<T>>
 where
     C: PolylineableCurve + 'a,
     S: PreMeshableSurface + 'a,
+    T: FaceMesh,
 {
     let vmap: HashMap<_, _> = shell
         .vertex_par_iter()
@@ -96,24 +118,50 @@ where
             wires,
             face.orientation(),
             tolerance,
-            &sp,
+            sp.clone(),
             quad_config,
+            trimming,
         )
     };
-    shell.face_par_iter().map(create_face).collect()
+    shell
+        .face_par_iter()
+        .map(|face| canceled_or(cancel, || create_face(face)))
+        .collect()
 }
 
 /// Tessellates faces
+#[cfg(test)]
+pub(super) fn shell_tessellation_single_thread<'a, C, S, T>(
+    shell: &'a Shell<Point3, C, S>,
+    tolerance: f64,
+    sp: impl ParameterSearcher<S>,
+    quad_config: QuadOptions,
+    trimming: Trimming,
+) -> MeshedShell<T>
+where
+    C: PolylineableCurve + 'a,
+    S: PreMeshableSurface + 'a,
+    T: FaceMesh,
+{
+    let never = AtomicBool::new(false);
+    shell_tessellation_single_thread_cancelable(shell, tolerance, sp, quad_config, trimming, &never)
+        .expect("tessellation is never canceled")
+}
+
+/// Tessellates faces, or returns `None` if `cancel` is set before or after tessellating a face.
 #[cfg(any(target_arch = "wasm32", test))]
-pub(super) fn shell_tessellation_single_thread<'a, C, S>(
+pub(super) fn shell_tessellation_single_thread_cancelable<'a, C, S, T>(
     shell: &'a Shell<Point3, C, S>,
     tolerance: f64,
-    sp: impl SP<S>,
+    sp: impl ParameterSearcher<S>,
     quad_config: QuadOptions,
-) -> MeshedShell
+    trimming: Trimming,
+    cancel: &AtomicBool,
+) -> Option<MeshedShell<T>>
 where
     C: PolylineableCurve + 'a,
     S: PreMeshableSurface + 'a,
+    T: FaceMesh,
 {
     use truck_base::entry_map::FxEntryMap as EntryMap;
     use truck_topology::Vertex as TVertex;
@@ -143,7 +191,7 @@ where
     let mut create_boundary = move |wire: &'a Wire<Point3, C>| -> Wire<_, _> {
         wire.edge_iter().map(&mut create_edge).collect()
     };
-    let create_face = move |face: &'a Face<Point3, C, S>| -> Face<_, _, _> {
+    let mut create_face = move |face: &'a Face<Point3, C, S>| -> Face<_, _, _> {
         let wires: Vec<_> = face
             .absolute_boundaries()
             .iter()
@@ -154,23 +202,37 @@ where
             wires,
             face.orientation(),
             tolerance,
-            &sp,
+            sp.clone(),
             quad_config,
+            trimming,
         )
     };
-    shell.face_iter().map(create_face).collect()
+    shell
+        .face_iter()
+        .map(|face| canceled_or(cancel, || create_face(face)))
+        .collect()
+}
+
+fn canceled_or<T>(cancel: &AtomicBool, f: impl FnOnce() -> T) -> Option<T> {
+    let is_canceled = || cancel.load(Ordering::Relaxed);
+    match is_canceled() {
+        true => None,
+        false => Some(f()).filter(|_| !is_canceled()),
+    }
 }
 
 /// Tessellates faces
-pub(super) fn cshell_tessellation<'a, C, S>(
+pub(super) fn cshell_tessellation<'a, C, S, T>(
     shell: &CompressedShell<Point3, C, S>,
     tolerance: f64,
-    sp: impl SP<S>,
+    sp: impl ParameterSearcher<S>,
     quad_config: QuadOptions,
-) -> MeshedCShell
+    trimming: Trimming,
+) -> MeshedCShell<T>
 where
     C: PolylineableCurve + 'a,
     S: PreMeshableSurface + 'a,
+    T: FaceMesh,
 {
     let vertices = shell.vertices.clone();
     let tessellate_edge = |edge: &CompressedEdge<C>| {
@@ -187,17 +249,25 @@ where
     let tessellate_face = |face: &CompressedFace<S>| {
         let boundaries = face.boundaries.clone();
         let surface = &face.surface;
+        let mut sp = sp.clone();
+        sp.reset();
 
         // Fast path: untrimmed face with bounded surface domain.
         let is_untrimmed = boundaries.iter().all(|wire| wire.is_empty());
         if is_untrimmed {
             if let (Some(urange), Some(vrange)) = surface.try_range_tuple() {
-                let polygon =
-                    untrimmed_tessellation(surface, (urange, vrange), tolerance, quad_config.mode);
+                let polygon = untrimmed_tessellation(
+                    surface,
+                    (urange, vrange),
+                    tolerance,
+                    quad_config.mode,
+                    trimming.division,
+                );
+                let polygon = double_sided(polygon, trimming);
                 return CompressedFace {
                     boundaries,
                     orientation: face.orientation,
-                    surface: Some(polygon),
+                    surface: T::from_result(Ok(polygon)),
                 };
             }
         }
@@ -208,71 +278,253 @@ where
         };
         let create_boundary = |wire: &Vec<CompressedEdgeIndex>| {
             let wire_iter = wire.iter().filter_map(create_edge);
-            PolyBoundaryPiece::try_new(surface, wire_iter, &sp)
+            PolyBoundaryPiece::try_new(surface, wire_iter, &mut sp)
         };
-        let preboundary: Option<Vec<_>> = boundaries.iter().map(create_boundary).collect();
-        let polygon: Option<PolygonMesh> = preboundary.map(|preboundary| {
+        let preboundary: Result<Vec<_>, _> = boundaries.iter().map(create_boundary).collect();
+        let polygon = preboundary.map(|preboundary| {
             let boundary = PolyBoundary::new(preboundary, &surface, tolerance);
-            trimming_tessellation(&surface, &boundary, tolerance, quad_config)
+            let mesh = trimming_tessellation(&surface, &boundary, tolerance, quad_config, trimming);
+            double_sided(mesh, trimming)
         });
         CompressedFace {
             boundaries,
             orientation: face.orientation,
-            surface: polygon,
+            surface: T::from_result(polygon),
         }
     };
     #[cfg(not(target_arch = "wasm32"))]
     let faces = shell.faces.par_iter().map(tessellate_face).collect();
     #[cfg(target_arch = "wasm32")]
     let faces = shell.faces.iter().map(tessellate_face).collect();
-    MeshedCShell {
+    MeshedCShell::<T> {
         vertices,
         edges,
         faces,
     }
 }
 
-fn shell_create_polygon<S: PreMeshableSurface>(
+/// Tessellates the face of `shell` with `face_id` again, and replaces the corresponding face of
+/// `meshed`. Returns `false` if there is no such face.
+pub(super) fn retessellate_face<C, S, T>(
+    meshed: &mut MeshedShell<T>,
+    shell: &Shell<Point3, C, S>,
+    face_id: FaceID<S>,
+    tolerance: f64,
+    sp: impl ParameterSearcher<S>,
+    quad_config: QuadOptions,
+    trimming: Trimming,
+) -> bool
+where
+    S: PreMeshableSurface,
+    T: FaceMesh,
+{
+    let Some(i) = shell.face_iter().position(|face| face.id() == face_id) else {
+        return false;
+    };
+    let Some(meshed_face) = meshed.get(i) else {
+        return false;
+    };
+    let face = &shell[i];
+    let wires = meshed_face.absolute_boundaries().clone();
+    meshed[i] = shell_create_polygon(
+        &face.surface(),
+        wires,
+        face.orientation(),
+        tolerance,
+        sp,
+        quad_config,
+        trimming,
+    );
+    true
+}
+
+fn shell_create_polygon<S: PreMeshableSurface, T: FaceMesh>(
     surface: &S,
     wires: Vec<Wire<Point3, PolylineCurve>>,
     orientation: bool,
     tolerance: f64,
-    sp: impl SP<S>,
+    mut sp: impl ParameterSearcher<S>,
     quad_config: QuadOptions,
-) -> Face<Point3, PolylineCurve, Option<PolygonMesh>> {
+    trimming: Trimming,
+) -> Face<Point3, PolylineCurve, T> {
+    sp.reset();
     // Fast path: untrimmed face with bounded surface domain.
     let is_untrimmed = wires.iter().all(|w| w.is_empty());
     let polygon = if is_untrimmed {
         if let (Some(urange), Some(vrange)) = surface.try_range_tuple() {
-            Some(untrimmed_tessellation(
+            Ok(untrimmed_tessellation(
                 surface,
                 (urange, vrange),
                 tolerance,
                 quad_config.mode,
+                trimming.division,
             ))
         } else {
-            None
+            Err(TessellationError::DegenerateBoundary)
         }
     } else {
         let preboundary = wires
             .iter()
             .map(|wire: &Wire<_, _>| {
                 let wire_iter = wire.iter().map(Edge::oriented_curve);
-                PolyBoundaryPiece::try_new(surface, wire_iter, &sp)
+                PolyBoundaryPiece::try_new(surface, wire_iter, &mut sp)
             })
-            .collect::<Option<Vec<_>>>();
+            .collect::<Result<Vec<_>, _>>();
         preboundary.map(|preboundary| {
             let boundary = PolyBoundary::new(preboundary, &surface, tolerance);
-            trimming_tessellation(surface, &boundary, tolerance, quad_config)
+            trimming_tessellation(surface, &boundary, tolerance, quad_config, trimming)
         })
     };
-    let mut new_face = Face::debug_new(wires, polygon);
+    let polygon = polygon.map(|mesh| double_sided(mesh, trimming));
+    let mut new_face = Face::debug_new(wires, T::from_result(polygon));
     if !orientation {
         new_face.invert();
     }
     new_face
 }
 
+/// Appends the reversed copies of the faces with the flipped normals if `trimming.double_sided`.
+fn double_sided(mut mesh: PolygonMesh, trimming: Trimming) -> PolygonMesh {
+    if !trimming.double_sided {
+        return mesh;
+    }
+    let offset = mesh.normals().len();
+    let back_faces = mesh
+        .face_iter()
+        .map(|face| {
+            face.iter()
+                .rev()
+                .map(|v| StandardVertex {
+                    nor: v.nor.map(|nor| nor + offset),
+                    ..*v
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let editor = mesh.debug_editor();
+    let flipped = editor
+        .attributes
+        .normals
+        .iter()
+        .map(|n| -*n)
+        .collect::<Vec<_>>();
+    editor.attributes.normals.extend(flipped);
+    editor.faces.extend(back_faces);
+    drop(editor);
+    mesh
+}
+
+/// Returns the area of `face` by integrating the surface metric over the trimmed domain, or
+/// `None` if the parameter search of the boundary fails.
+pub(super) fn face_area<C: PolylineableCurve, S: PreMeshableSurface>(
+    face: &Face<Point3, C, S>,
+    tolerance: f64,
+    sp: impl ParameterSearcher<S>,
+) -> Option<f64> {
+    let metric =
+        |surface: &S, u: f64, v: f64| surface.uder(u, v).cross(surface.vder(u, v)).magnitude();
+    face_integral(face, tolerance, sp, metric).map(f64::abs)
+}
+
+/// Returns the integral of `integrand` over the trimmed domain of `face`, or `None` if the
+/// parameter search of the boundary fails.
+///
+/// The domain is the one of the surface without the orientation of the face, i.e. the outer
+/// boundary is counterclockwise. By Green's theorem, the integral over the domain is the integral
+/// of `G(u, v) dv` along the boundary loops, where `G(u, v)` is the integral of the integrand
+/// from `u_min` to `u`.
+pub(super) fn face_integral<C: PolylineableCurve, S: PreMeshableSurface>(
+    face: &Face<Point3, C, S>,
+    tolerance: f64,
+    mut sp: impl ParameterSearcher<S>,
+    integrand: impl Fn(&S, f64, f64) -> f64,
+) -> Option<f64> {
+    let surface = face.surface();
+    let wires = face.absolute_boundaries();
+    // The area between the chords and the curve is proportional to the sag.
+    let edge_tolerance = f64::max(tolerance / 10.0, TOLERANCE);
+    let loops = if wires.iter().all(|wire| wire.is_empty()) {
+        let (Some((u0, u1)), Some((v0, v1))) = surface.try_range_tuple() else {
+            return None;
+        };
+        vec![vec![
+            Point2::new(u0, v0),
+            Point2::new(u1, v0),
+            Point2::new(u1, v1),
+            Point2::new(u0, v1),
+        ]]
+    } else {
+        let pieces = wires
+            .iter()
+            .map(|wire| {
+                let wire_iter = wire.edge_iter().map(|edge| {
+                    let curve = edge.curve();
+                    let range = curve.range_tuple();
+                    let poly = PolylineCurve::from_curve(&curve, range, edge_tolerance);
+                    match edge.orientation() {
+                        true => poly,
+                        false => poly.inverse(),
+                    }
+                });
+                PolyBoundaryPiece::try_new(&surface, wire_iter, &mut sp)
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .ok()?;
+        let boundary = PolyBoundary::new(pieces, &surface, tolerance);
+        boundary
+            .loops
+            .into_iter()
+            .map(|vec| vec.into_iter().map(|p| p.uv).collect())
+            .collect()
+    };
+    let u_min = loops
+        .iter()
+        .flatten()
+        .fold(f64::INFINITY, |u, p| f64::min(u, p.x));
+    let eps = tolerance * tolerance;
+    let integral = loops
+        .iter()
+        .flat_map(|vec| vec.iter().circular_tuple_windows())
+        .filter(|(p, q)| !p.y.near(&q.y))
+        .map(|(p, q)| {
+            let antiderivative = |t: f64| {
+                let Point2 { x: u, y: v } = p + (q - p) * t;
+                adaptive_quadrature(&|s| integrand(&surface, s, v), u_min, u, eps, 16)
+            };
+            adaptive_quadrature(&antiderivative, 0.0, 1.0, eps, 16) * (q.y - p.y)
+        })
+        .sum::<f64>();
+    Some(integral)
+}
+
+/// Integrates `f` over `[a, b]` by the adaptive 5-point Gauss-Legendre quadrature.
+fn adaptive_quadrature(f: &impl Fn(f64) -> f64, a: f64, b: f64, eps: f64, depth: usize) -> f64 {
+    const NODES: [(f64, f64); 5] = [
+        (0.0, 0.568_888_888_888_888_9),
+        (0.538_469_310_105_683_1, 0.478_628_670_499_366_5),
+        (-0.538_469_310_105_683_1, 0.478_628_670_499_366_5),
+        (0.906_179_845_938_664, 0.236_926_885_056_189_1),
+        (-0.906_179_845_938_664, 0.236_926_885_056_189_1),
+    ];
+    let gauss = |a: f64, b: f64| {
+        let (mid, half) = ((a + b) / 2.0, (b - a) / 2.0);
+        NODES
+            .iter()
+            .map(|(x, w)| w * f(mid + half * x))
+            .sum::<f64>()
+            * half
+    };
+    let half = (a + b) / 2.0;
+    let (whole, left, right) = (gauss(a, b), gauss(a, half), gauss(half, b));
+    match depth == 0 || f64::abs(left + right - whole) <= eps {
+        true => left + right,
+        false => {
+            adaptive_quadrature(f, a, half, eps / 2.0, depth - 1)
+                + adaptive_quadrature(f, half, b, eps / 2.0, depth - 1)
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, derive_more::Deref, derive_more::DerefMut)]
 struct SurfacePoint {
     point: Point3,
@@ -292,8 +544,8 @@ impl PolyBoundaryPiece {
     fn try_new<S: PreMeshableSurface>(
         surface: &S,
         wire: impl Iterator<Item = PolylineCurve>,
-        sp: impl SP<S>,
-    ) -> Option<Self> {
+        sp: &mut impl ParameterSearcher<S>,
+    ) -> Result<Self, TessellationError> {
         let (up, vp) = (surface.u_period(), surface.v_period());
         let (urange, vrange) = surface.try_range_tuple();
         let mut bdry3d: Vec<Point3> = wire
@@ -302,14 +554,17 @@ impl PolyBoundaryPiece {
                 poly_edge.into_iter().take(n)
             })
             .collect();
+        if bdry3d.is_empty() {
+            return Err(TessellationError::DegenerateBoundary);
+        }
         bdry3d.push(bdry3d[0]);
         let mut previous = None;
         let mut vec = bdry3d
             .into_iter()
             .flat_map(|pt| {
-                let (mut u, mut v) = match sp(surface, pt, previous) {
+                let (mut u, mut v) = match sp.search(surface, pt, previous) {
                     Some(hint) => hint,
-                    None => return vec![None],
+                    None => return vec![Err(TessellationError::ParameterSearchFailed(pt))],
                 };
                 if let (Some(up), Some((u0, _))) = (up, previous) {
                     u = get_mindiff(u, u0, up);
@@ -321,22 +576,22 @@ impl PolyBoundaryPiece {
                     if let Some((u0, v0)) = previous {
                         if !u0.near(&u) && surface.uder(u0, v0).so_small() {
                             return vec![
-                                Some((Point2::new(u, v0), pt).into()),
-                                Some((Point2::new(u, v), pt).into()),
+                                Ok((Point2::new(u, v0), pt).into()),
+                                Ok((Point2::new(u, v), pt).into()),
                             ];
                         } else if !v0.near(&v) && surface.vder(u0, v0).so_small() {
                             return vec![
-                                Some((Point2::new(u0, v), pt).into()),
-                                Some((Point2::new(u, v), pt).into()),
+                                Ok((Point2::new(u0, v), pt).into()),
+                                Ok((Point2::new(u, v), pt).into()),
                             ];
                         }
                     }
-                    vec![Some((Point2::new(u, v), pt).into())]
+                    vec![Ok((Point2::new(u, v), pt).into())]
                 })();
                 previous = Some((u, v));
                 res
             })
-            .collect::<Option<Vec<SurfacePoint>>>()?;
+            .collect::<Result<Vec<SurfacePoint>, _>>()?;
         let grav = vec.iter().fold(Point2::origin(), |g, p| g + p.uv.to_vec()) / vec.len() as f64;
         if let (Some(up), Some((u0, _))) = (up, urange) {
             let quot = f64::floor((grav.x - u0) / up);
@@ -353,7 +608,7 @@ impl PolyBoundaryPiece {
                 vec.push(vec[0]);
             }
         }
-        Some(Self(vec))
+        Ok(Self(vec))
     }
 }
 
@@ -598,38 +853,26 @@ impl PolyBoundary {
         {
             return false;
         }
-        let t = 2.0 * std::f64::consts::PI * HashGen::hash1(c);
-        let r = Vector2::new(f64::cos(t), f64::sin(t));
-        self.loops
+        let edges = self
+            .loops
             .iter()
             .flat_map(|vec| vec.iter().circular_tuple_windows())
-            .try_fold(0_i32, move |counter, (p0, p1)| {
-                let a = **p0 - c;
-                let b = **p1 - c;
-                let s0 = r.x * a.y - r.y * a.x; // v times a
-                let s1 = r.x * b.y - r.y * b.x; // v times b
-                let s2 = a.x * b.y - a.y * b.x; // a times b
-                let x = s2 / (s1 - s0);
-                if x.so_small() && s0 * s1 < 0.0 {
-                    None
-                } else if x > 0.0 && s0 <= 0.0 && s1 > 0.0 {
-                    Some(counter + 1)
-                } else if x > 0.0 && s0 >= 0.0 && s1 < 0.0 {
-                    Some(counter - 1)
-                } else {
-                    Some(counter)
-                }
-            })
+            .map(|(p0, p1)| (**p0, **p1));
+        polyline_curve::crossing_counter(edges, c)
             .map(|counter| counter > 0)
             .unwrap_or(false)
     }
 
     /// Inserts points and adds constraint into triangulation.
+    ///
+    /// Returns the boundary segments, the constraint edges between the consecutive inserted points
+    /// of each loop. A segment crossing another one, e.g. of a hole touching the outer loop, is
+    /// split at the intersection, so that no segment is dropped.
     fn insert_to(
         &self,
         triangulation: &mut Cdt,
         boundary_map: &mut HashMap<FixedVertexHandle, Point3>,
-    ) {
+    ) -> Vec<[FixedVertexHandle; 2]> {
         let poly2tri: Vec<_> = self
             .loops
             .iter()
@@ -645,36 +888,54 @@ impl PolyBoundary {
                 }
             })
             .collect();
-        let mut prev: Option<usize> = None;
         let mut counter = 0;
-        self.loops
+        let pairs = self
+            .loops
             .iter()
-            .map(Vec::len)
-            .flat_map(|len| {
-                let range = counter..counter + len;
-                counter += len;
-                range.circular_tuple_windows()
-            })
-            .for_each(|(i, j)| {
-                let Some(vj) = poly2tri[j] else { return };
-                if let Some(p) = prev {
-                    let Some(v) = poly2tri[p] else { return };
-                    if triangulation.can_add_constraint(v, vj) {
-                        triangulation.add_constraint(v, vj);
-                        prev = None;
-                    }
-                } else {
-                    let Some(vi) = poly2tri[i] else { return };
-                    if triangulation.can_add_constraint(vi, vj) {
-                        triangulation.add_constraint(vi, vj);
-                    } else {
-                        prev = Some(i);
-                    }
+            .flat_map(|vec| {
+                let mut handles = poly2tri[counter..counter + vec.len()]
+                    .iter()
+                    .flatten()
+                    .copied()
+                    .collect::<Vec<_>>();
+                counter += vec.len();
+                handles.dedup();
+                if handles.len() > 1 && handles[0] == handles[handles.len() - 1] {
+                    handles.pop();
                 }
-            });
+                handles
+                    .into_iter()
+                    .circular_tuple_windows()
+                    .filter(|(v, w)| v != w)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        pairs
+            .into_iter()
+            .flat_map(|(v, w)| constrain_segment(triangulation, v, w))
+            .collect()
     }
 }
 
+/// Adds the constraint from `v` to `w`, splitting it at the crossings with the existing
+/// constraints, and returns the pairs of the end points of the resulting constraint edges.
+fn constrain_segment(
+    triangulation: &mut Cdt,
+    v: FixedVertexHandle,
+    w: FixedVertexHandle,
+) -> Vec<[FixedVertexHandle; 2]> {
+    triangulation
+        .add_constraint_and_split(v, w, |p| p)
+        .into_iter()
+        .map(|edge| {
+            triangulation
+                .directed_edge(edge)
+                .vertices()
+                .map(|v| v.fix())
+        })
+        .collect()
+}
+
 fn spade_round(x: f64) -> f64 {
     match f64::abs(x) < MIN_ALLOWED_VALUE {
         true => 0.0,
@@ -686,16 +947,17 @@ fn spade_round(x: f64) -> f64 {
 ///
 /// Generates a structured grid from parameter division, then triangulates
 /// each quad cell into two triangles. Skips CDT and inclusion tests entirely.
-fn untrimmed_tessellation<S>(
+pub(super) fn untrimmed_tessellation<S>(
     surface: &S,
     range: ((f64, f64), (f64, f64)),
     tolerance: f64,
     quad_mode: QuadMode,
+    division: DivisionOptions,
 ) -> PolygonMesh
 where
     S: PreMeshableSurface,
 {
-    let (udiv, vdiv) = surface.parameter_division(range, tolerance);
+    let (udiv, vdiv) = surface.parameter_division_with_options(range, tolerance, division);
     let nu = udiv.len();
     let nv = vdiv.len();
     let mut positions = Vec::with_capacity(nu * nv);
@@ -755,45 +1017,116 @@ fn trimming_tessellation<S>(
     polyboundary: &PolyBoundary,
     tolerance: f64,
     quad_config: QuadOptions,
+    trimming: Trimming,
 ) -> PolygonMesh
 where
     S: PreMeshableSurface,
 {
     if quad_config.mode == QuadMode::IsoQuads {
-        if let Some(mut mesh) = iso_quad_trimmed_tessellation(surface, polyboundary, tolerance) {
+        let mesh =
+            iso_quad_trimmed_tessellation(surface, polyboundary, tolerance, trimming.division);
+        if let Some(mut mesh) = mesh {
             mesh.make_face_compatible_to_normal();
             mesh
         } else {
-            let mut mesh = cdt_trimming_tessellation(surface, polyboundary, tolerance);
+            let mut mesh = cdt_trimming_tessellation(surface, polyboundary, tolerance, trimming);
             mesh.make_face_compatible_to_normal();
             mesh
         }
     } else {
-        let mut mesh = cdt_trimming_tessellation(surface, polyboundary, tolerance);
+        let mut mesh = cdt_trimming_tessellation(surface, polyboundary, tolerance, trimming);
         mesh.make_face_compatible_to_normal();
         apply_quad_mode(&mut mesh, quad_config);
         mesh
     }
 }
 
+/// Tessellates one surface trimmed by the loops given in the parameter space.
+pub(super) fn trimmed_surface_tessellation<S: PreMeshableSurface>(
+    surface: &S,
+    outer: &[Point2],
+    holes: &[Vec<Point2>],
+    tolerance: f64,
+    quad_config: QuadOptions,
+    trimming: Trimming,
+) -> PolygonMesh {
+    let mut point_cache = HashMap::<UvKey, Point3>::default();
+    let loops = once((outer, true))
+        .chain(holes.iter().map(|hole| (&hole[..], false)))
+        .filter_map(|(uvs, ccw)| {
+            let mut points = uvs
+                .iter()
+                .map(|uv| surface_point_with_cache(surface, *uv, &mut point_cache))
+                .collect::<Vec<_>>();
+            if points.len() > 1 && points[0].uv.near(&points[points.len() - 1].uv) {
+                points.pop();
+            }
+            (points.len() >= 3).then_some((points, ccw))
+        })
+        .collect::<Vec<_>>();
+    let pieces = loops
+        .into_iter()
+        .map(|(mut points, ccw)| {
+            if loop_orientation(&points) != ccw {
+                points.reverse();
+            }
+            let mut vec = points
+                .iter()
+                .circular_tuple_windows()
+                .flat_map(|(p, q)| {
+                    let segment = polyline_on_surface(surface, *p, *q, tolerance, &mut point_cache);
+                    let len = segment.len();
+                    segment.into_iter().take(len - 1)
+                })
+                .collect::<Vec<_>>();
+            vec.push(vec[0]);
+            PolyBoundaryPiece(vec)
+        })
+        .collect();
+    let boundary = PolyBoundary::new(pieces, surface, tolerance);
+    trimming_tessellation(surface, &boundary, tolerance, quad_config, trimming)
+}
+
 fn cdt_trimming_tessellation<S>(
     surface: &S,
     polyboundary: &PolyBoundary,
     tolerance: f64,
+    trimming: Trimming,
 ) -> PolygonMesh
 where
     S: PreMeshableSurface,
 {
     let mut triangulation = Cdt::new();
     let mut boundary_map = HashMap::<FixedVertexHandle, Point3>::default();
-    polyboundary.insert_to(&mut triangulation, &mut boundary_map);
-    insert_surface(&mut triangulation, surface, polyboundary, tolerance);
+    let segments = polyboundary.insert_to(&mut triangulation, &mut boundary_map);
+    match trimming.interior {
+        InteriorSampling::Grid => insert_surface(
+            &mut triangulation,
+            surface,
+            polyboundary,
+            tolerance,
+            trimming.division,
+        ),
+        InteriorSampling::None => {}
+        InteriorSampling::Adaptive => {
+            insert_adaptive(&mut triangulation, surface, polyboundary, tolerance)
+        }
+    }
+    // The segments split by the inserted points are replaced by their pieces.
+    let segments = segments
+        .into_iter()
+        .flat_map(|[v, w]| match triangulation.get_edge_from_neighbors(v, w) {
+            Some(_) => vec![[v, w]],
+            None => constrain_segment(&mut triangulation, v, w),
+        })
+        .collect::<Vec<_>>();
     triangulation_into_polymesh(
-        triangulation.vertices(),
-        triangulation.inner_faces(),
+        &triangulation,
         surface,
         polyboundary,
         &boundary_map,
+        &segments,
+        trimming.mode,
     )
 }
 
@@ -801,6 +1134,7 @@ fn iso_quad_trimmed_tessellation<S>(
     surface: &S,
     polyboundary: &PolyBoundary,
     tolerance: f64,
+    division: DivisionOptions,
 ) -> Option<PolygonMesh>
 where
     S: PreMeshableSurface,
@@ -809,7 +1143,7 @@ where
         (polyboundary.uv_min.x, polyboundary.uv_max.x),
         (polyboundary.uv_min.y, polyboundary.uv_max.y),
     );
-    let (udiv, vdiv) = surface.parameter_division(range, tolerance);
+    let (udiv, vdiv) = surface.parameter_division_with_options(range, tolerance, division);
     let (nu, nv) = (udiv.len(), vdiv.len());
     if nu < 2 || nv < 2 {
         return None;
@@ -890,7 +1224,8 @@ where
             },
             Faces::from_tri_and_quad_faces(Vec::new(), interior_quads),
         );
-        let mut boundary_mesh = cdt_trimming_tessellation(surface, polyboundary, tolerance);
+        let mut boundary_mesh =
+            cdt_trimming_tessellation(surface, polyboundary, tolerance, Trimming::default());
         let boundary_triangles = boundary_mesh
             .tri_faces()
             .iter()
@@ -1276,24 +1611,36 @@ fn blend_normals(normal0: Vector3, normal1: Vector3, normal_blend_angle: f64) ->
 }
 
 /// Inserts parameter divisions into triangulation.
+///
+/// The grid lines are added as constraints, except for the ones crossing the boundary
+/// constraints, which take priority.
+///
+/// The grid points within `tolerance` of a boundary point on the surface are welded to the
+/// boundary point, i.e. they are not inserted, so that no near-duplicate vertices and no
+/// slivers are created along the boundary.
 fn insert_surface(
     triangulation: &mut Cdt,
     surface: impl PreMeshableSurface,
     polyline: &PolyBoundary,
     tolerance: f64,
+    division: DivisionOptions,
 ) {
     let range = (
         (polyline.uv_min.x, polyline.uv_max.x),
         (polyline.uv_min.y, polyline.uv_max.y),
     );
-    let (udiv, vdiv) = surface.parameter_division(range, tolerance);
+    let (udiv, vdiv) = surface.parameter_division_with_options(range, tolerance, division);
+    let welder = BoundaryWelder::new(polyline, tolerance);
     let insert_res: Vec<Vec<Option<_>>> = udiv
         .into_iter()
         .map(|u| {
             vdiv.iter()
-                .map(|v| match polyline.include(Point2::new(u, *v)) {
-                    true => triangulation.insert(SPoint2::new(u, *v)).ok(),
-                    false => None,
+                .map(|v| {
+                    let uv = Point2::new(u, *v);
+                    match polyline.include(uv) && !welder.welded(surface.subs(u, *v)) {
+                        true => triangulation.insert(SPoint2::new(u, *v)).ok(),
+                        false => None,
+                    }
                 })
                 .collect()
         })
@@ -1322,20 +1669,95 @@ fn insert_surface(
     });
 }
 
+/// Inserts the surface points at the centers of the triangles deviating from the surface.
+///
+/// The centers of the triangles in the domain are compared with the surface points at the
+/// centers of the parameters, and the ones farther than `tolerance` are inserted. This is
+/// repeated until all triangles are within `tolerance`, at most `MAX_ROUNDS` times.
+fn insert_adaptive(
+    triangulation: &mut Cdt,
+    surface: impl PreMeshableSurface,
+    polyline: &PolyBoundary,
+    tolerance: f64,
+) {
+    const MAX_ROUNDS: usize = 16;
+    let welder = BoundaryWelder::new(polyline, tolerance);
+    (0..MAX_ROUNDS).try_for_each(|_| {
+        let centers = triangulation
+            .inner_faces()
+            .filter_map(|tri| {
+                let [p, q, r] = tri.vertices().map(|v| *v.as_ref());
+                let uv = Point2::new((p.x + q.x + r.x) / 3.0, (p.y + q.y + r.y) / 3.0);
+                let deviated = || {
+                    let [p, q, r] = [p, q, r].map(|p| surface.subs(p.x, p.y).to_vec());
+                    let center = Point3::from_vec((p + q + r) / 3.0);
+                    let point = surface.subs(uv.x, uv.y);
+                    point.distance(center) > tolerance && !welder.welded(point)
+                };
+                (polyline.include(uv) && deviated()).then_some(uv)
+            })
+            .collect::<Vec<_>>();
+        centers.iter().for_each(|uv| {
+            let _ = triangulation.insert(SPoint2::new(uv.x, uv.y));
+        });
+        // stops at the round without the deviated triangles
+        (!centers.is_empty()).then_some(())
+    });
+}
+
+/// The boundary points registered to the cells of the grid whose size is the weld radius.
+struct BoundaryWelder {
+    radius: f64,
+    cells: HashMap<[i64; 3], Vec<Point3>>,
+}
+
+impl BoundaryWelder {
+    fn new(polyline: &PolyBoundary, radius: f64) -> Self {
+        let mut welder = Self {
+            radius,
+            cells: HashMap::default(),
+        };
+        polyline.loops.iter().flatten().for_each(|pt| {
+            let cell = welder.cell(pt.point);
+            welder.cells.entry(cell).or_default().push(pt.point);
+        });
+        welder
+    }
+
+    fn cell(&self, point: Point3) -> [i64; 3] {
+        array![i => (point[i] / self.radius).floor() as i64; 3]
+    }
+
+    /// whether `point` is within the radius of some boundary point.
+    fn welded(&self, point: Point3) -> bool {
+        let [i, j, k] = self.cell(point);
+        itertools::iproduct!(i - 1..=i + 1, j - 1..=j + 1, k - 1..=k + 1)
+            .filter_map(|(i, j, k)| self.cells.get(&[i, j, k]))
+            .flatten()
+            .any(|q| point.distance2(*q) < self.radius * self.radius)
+    }
+}
+
 /// Converts triangulation into `PolygonMesh`.
-fn triangulation_into_polymesh<'a>(
-    vertices: VertexIterator<'a, SPoint2, (), CdtEdge<()>, ()>,
-    triangles: InnerFaceIterator<'a, SPoint2, (), CdtEdge<()>, ()>,
+///
+/// The triangles are filtered by the trimming, but every boundary segment in `segments` is kept
+/// as an edge of the mesh: if both triangles adjacent to a segment are dropped, e.g. a thin
+/// triangle whose center is misjudged near the boundary, the one on the inner side is restored.
+fn triangulation_into_polymesh(
+    triangulation: &Cdt,
     surface: &impl ParametricSurface3D,
     polyline: &PolyBoundary,
     boundary_map: &HashMap<FixedVertexHandle, Point3>,
+    segments: &[[FixedVertexHandle; 2]],
+    trim: TrimMode,
 ) -> PolygonMesh {
     let mut positions = Vec::<Point3>::new();
     let mut uv_coords = Vec::<Vector2>::new();
     let mut normals = Vec::<Vector3>::new();
     let mut surface_point_cache = HashMap::<UvKey, Point3>::default();
     let mut normal_cache = HashMap::<UvKey, Vector3>::default();
-    let vmap: HashMap<_, _> = vertices
+    let vmap: HashMap<_, _> = triangulation
+        .vertices()
         .enumerate()
         .map(|(i, v)| {
             let p = *v.as_ref();
@@ -1357,16 +1779,47 @@ fn triangulation_into_polymesh<'a>(
             (idx, i)
         })
         .collect();
-    let tri_faces: Vec<[StandardVertex; 3]> = triangles
-        .map(|tri| tri.vertices())
+    let is_degenerate = |tri: &FaceHandle<'_, InnerTag, SPoint2, (), CdtEdge<()>, ()>| {
+        fn sp2cg(p: SPoint2) -> Point2 { Point2::new(p.x, p.y) }
+        let tri = array![i => sp2cg(*tri.vertices()[i].as_ref()); 3];
+        let (a, b) = (tri[1] - tri[0], tri[2] - tri[0]);
+        let area = a.x * b.y - a.y * b.x;
+        area.so_small2()
+    };
+    let mut kept: HashSet<_> = triangulation
+        .inner_faces()
         .filter(|tri| {
-            fn sp2cg(p: SPoint2) -> Point2 { Point2::new(p.x, p.y) }
-            let tri = array![i => sp2cg(*tri[i].as_ref()); 3];
-            let (a, b) = (tri[1] - tri[0], tri[2] - tri[0]);
-            let c = tri[0] + (a + b) / 3.0;
-            let area = a.x * b.y - a.y * b.x;
-            polyline.include(c) && !area.so_small2()
+            let included = match trim {
+                TrimMode::Full => {
+                    let [p, q, r] = tri.vertices().map(|v| *v.as_ref());
+                    polyline.include(Point2::new(
+                        (p.x + q.x + r.x) / 3.0,
+                        (p.y + q.y + r.y) / 3.0,
+                    ))
+                }
+                TrimMode::AssumeConvexNoHoles => true,
+            };
+            included && !is_degenerate(tri)
         })
+        .map(|tri| tri.fix())
+        .collect();
+    segments.iter().for_each(|[v, w]| {
+        // SAFETY: the segments are constraint edges of the triangulation.
+        let edge = triangulation.get_edge_from_neighbors(*v, *w).unwrap();
+        let is_kept = |face: FaceHandle<'_, PossiblyOuterTag, _, _, _, _>| {
+            face.as_inner().is_some_and(|tri| kept.contains(&tri.fix()))
+        };
+        if is_kept(edge.face()) || is_kept(edge.rev().face()) {
+            return;
+        }
+        if let Some(tri) = edge.face().as_inner().filter(|tri| !is_degenerate(tri)) {
+            kept.insert(tri.fix());
+        }
+    });
+    let tri_faces: Vec<[StandardVertex; 3]> = triangulation
+        .inner_faces()
+        .filter(|tri| kept.contains(&tri.fix()))
+        .map(|tri| tri.vertices())
         .map(|tri| {
             let idcs = array![i => vmap[&tri[i].fix()]; 3];
             array![i => [idcs[i], idcs[i], idcs[i]].into(); 3]
@@ -1416,17 +1869,24 @@ fn par_bench() {
 
     let instant = Instant::now();
     (0..100).for_each(|_| {
-        let _shell = shell_tessellation(&shell, 0.01, by_search_parameter, QuadOptions::default());
+        let _shell: MeshedShell<Option<PolygonMesh>> = shell_tessellation(
+            &shell,
+            0.01,
+            by_search_parameter,
+            QuadOptions::default(),
+            Trimming::default(),
+        );
     });
     println!("{}ms", instant.elapsed().as_millis());
 
     let instant = Instant::now();
     (0..100).for_each(|_| {
-        let _shell = shell_tessellation_single_thread(
+        let _shell: MeshedShell<Option<PolygonMesh>> = shell_tessellation_single_thread(
             &shell,
             0.01,
             by_search_parameter,
             QuadOptions::default(),
+            Trimming::default(),
         );
     });
     println!("{}ms", instant.elapsed().as_millis());
@@ -220,12 +220,11 @@ impl ToDataSet for Edge<Point3, PolylineCurve<Point3>> {
     fn to_data_set(&self) -> DataSet {
         let curve = self.oriented_curve();
         let points = curve
-            .0
             .iter()
             .copied()
             .flat_map(Into::<[f64; 3]>::into)
             .collect::<Vec<_>>();
-        let len = curve.0.len() as u64;
+        let len = curve.len() as u64;
         let connectivity = (0..len).chain(vec![0, len - 1]).collect();
         DataSet::UnstructuredGrid {
             meta: None,
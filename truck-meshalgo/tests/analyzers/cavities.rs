@@ -0,0 +1,72 @@
+use super::*;
+use common::shapes::{cube_shell, sphere_shell};
+use std::f64::consts::PI;
+use truck_modeling::{Face, Shell, Solid};
+
+fn volume(solid: &Solid) -> f64 { solid.triangulation(1.0e-3).to_polygon().volume() }
+
+#[test]
+fn cube_with_spherical_cavity() {
+    let expected = 8.0 - 4.0 / 3.0 * PI * 0.125;
+    let outer = cube_shell(Point3::origin(), 2.0);
+    let cavity = sphere_shell(Point3::new(1.0, 1.0, 1.0), 0.5);
+
+    let solid = solid_with_cavities(outer.clone(), vec![cavity.clone()]).unwrap();
+    assert!(solid.is_geometric_consistent());
+    let inner = &solid.boundaries()[1];
+    assert!(inner
+        .iter()
+        .zip(&cavity)
+        .all(|(f, g)| f.orientation() != g.orientation()));
+    assert!(f64::abs(volume(&solid) - expected) < 1.0e-2);
+
+    // the inward outer shell and the inward cavity are kept
+    let inverse = |shell: &Shell| shell.iter().map(Face::inverse).collect::<Shell>();
+    let solid = solid_with_cavities(inverse(&outer), vec![inverse(&cavity)]).unwrap();
+    assert!(f64::abs(volume(&solid) - expected) < 1.0e-2);
+}
+
+#[test]
+fn several_cavities() {
+    let outer = cube_shell(Point3::origin(), 3.0);
+    let cavities = vec![
+        sphere_shell(Point3::new(0.75, 1.5, 1.5), 0.5),
+        cube_shell(Point3::new(1.75, 1.0, 1.0), 1.0),
+    ];
+    let solid = solid_with_cavities(outer, cavities).unwrap();
+    let expected = 27.0 - 4.0 / 3.0 * PI * 0.125 - 1.0;
+    assert!(f64::abs(volume(&solid) - expected) < 1.0e-2);
+}
+
+#[test]
+fn thin_walls() {
+    [0.02, 0.005].into_iter().for_each(|wall| {
+        let outer = cube_shell(Point3::origin(), 3.0);
+        let cavity = cube_shell(Point3::new(wall, wall, wall), 3.0 - 2.0 * wall);
+        let solid = solid_with_cavities(outer, vec![cavity]).unwrap();
+        let expected = 27.0 - f64::powi(3.0 - 2.0 * wall, 3);
+        assert_near!(volume(&solid), expected);
+    });
+}
+
+#[test]
+fn invalid_cavities() {
+    let outer = cube_shell(Point3::origin(), 2.0);
+    let outside = sphere_shell(Point3::new(3.0, 1.0, 1.0), 0.5);
+    assert_eq!(
+        solid_with_cavities(outer.clone(), vec![outside]).unwrap_err(),
+        CavityError::CavityNotInside,
+    );
+    // the outer shell inside the cavity
+    let larger = cube_shell(Point3::new(-1.0, -1.0, -1.0), 4.0);
+    assert_eq!(
+        solid_with_cavities(outer.clone(), vec![larger]).unwrap_err(),
+        CavityError::CavityNotInside,
+    );
+    let mut open = sphere_shell(Point3::new(1.0, 1.0, 1.0), 0.5);
+    open.pop();
+    assert_eq!(
+        solid_with_cavities(outer, vec![open]).unwrap_err(),
+        CavityError::FromTopology(truck_topology::errors::Error::NotClosedShell),
+    );
+}
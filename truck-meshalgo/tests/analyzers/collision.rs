@@ -79,3 +79,72 @@ fn collision_sphere() {
         instant.elapsed().as_secs_f64()
     );
 }
+
+fn tetrahedron(origin: Point3) -> PolygonMesh {
+    let positions = vec![
+        origin,
+        origin + Vector3::unit_x(),
+        origin + Vector3::unit_y(),
+        origin + Vector3::unit_z(),
+    ];
+    PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        Faces::from_iter(&[[0, 2, 1], [0, 1, 3], [0, 3, 2], [1, 2, 3]]),
+    )
+}
+
+#[test]
+fn self_intersections_of_tetrahedra() {
+    let mut mesh = tetrahedron(Point3::origin());
+    assert!(mesh.self_intersections().is_empty());
+    mesh.merge(tetrahedron(Point3::new(0.3, 0.2, 0.1)));
+    let pairs = mesh.self_intersections();
+    assert!(!pairs.is_empty());
+    assert!(pairs.iter().all(|(i, j)| *i < 4 && *j >= 4));
+    // the slanted face of the first one is pierced by the corner of the second one
+    assert!(pairs.contains(&(3, 5)));
+    // consistent with the interference of the separated meshes
+    let interference = tetrahedron(Point3::origin())
+        .extract_interference(&tetrahedron(Point3::new(0.3, 0.2, 0.1)));
+    assert!(!interference.is_empty());
+
+    // the neighbors on the closed sphere are not reported
+    let sphere = common::shapes::sphere(Point3::origin(), 1.0, 16, 8);
+    assert!(sphere.self_intersections().is_empty());
+}
+
+#[test]
+fn self_intersections_at_shared_vertex() {
+    // two triangles sharing the vertex 0, the second one passes through the first one
+    let positions = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(2.0, 0.0, 0.0),
+        Point3::new(0.0, 2.0, 0.0),
+        Point3::new(1.0, 1.0, -1.0),
+        Point3::new(1.0, 1.0, 1.0),
+    ];
+    let mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions: positions.clone(),
+            ..Default::default()
+        },
+        Faces::from_iter(&[[0, 1, 2], [0, 3, 4]]),
+    );
+    assert_eq!(mesh.self_intersections(), vec![(0, 1)]);
+
+    // the second one only touches at the vertex
+    let mut positions = positions;
+    positions[3] = Point3::new(-1.0, -1.0, -1.0);
+    positions[4] = Point3::new(-1.0, -1.0, 1.0);
+    let mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        Faces::from_iter(&[[0, 1, 2], [0, 3, 4]]),
+    );
+    assert!(mesh.self_intersections().is_empty());
+}
@@ -0,0 +1,34 @@
+use super::*;
+
+#[test]
+fn perturbed_sphere() {
+    let mesh = common::shapes::sphere(Point3::new(0.0, 0.0, 0.0), 1.0, 30, 30);
+    let mut perturbed = mesh.clone();
+    perturbed
+        .positions_mut()
+        .iter_mut()
+        .enumerate()
+        .for_each(|(i, p)| p.x += 1.0e-4 * f64::sin(i as f64));
+    assert!(mesh.approx_eq(&perturbed, 1.0e-3));
+    assert!(!mesh.approx_eq(&perturbed, 1.0e-6));
+
+    let diff = mesh.diff(&perturbed, 1.0e-6);
+    assert!(diff.removed.is_empty() && diff.added.is_empty());
+    assert!(!diff.moved.is_empty());
+
+    // shuffling faces and flipping the orientation does not matter
+    let mut shuffled = perturbed.clone();
+    shuffled.invert();
+    let mut faces = shuffled.faces().face_iter().collect::<Vec<_>>();
+    faces.reverse();
+    let faces = faces.into_iter().collect::<Faces>();
+    let shuffled = PolygonMesh::new(shuffled.attributes().clone(), faces);
+    assert!(mesh.approx_eq(&shuffled, 1.0e-3));
+
+    // a removed face is detected
+    let faces = mesh.faces().face_iter().skip(1).collect::<Faces>();
+    let fewer = PolygonMesh::new(mesh.attributes().clone(), faces);
+    let diff = mesh.diff(&fewer, 1.0e-3);
+    assert_eq!(diff.removed.len(), 1);
+    assert!(diff.added.is_empty() && diff.moved.is_empty());
+}
@@ -0,0 +1,78 @@
+use super::*;
+
+fn closed_sphere(radius: f64) -> PolygonMesh {
+    let mut sphere = common::shapes::sphere(Point3::origin(), radius, 64, 32);
+    sphere
+        .put_together_same_attrs(TOLERANCE)
+        .remove_degenerate_faces()
+        .remove_unused_attrs();
+    assert_eq!(sphere.shell_condition(), ShellCondition::Closed);
+    sphere
+}
+
+#[test]
+fn deviation_between_spheres() {
+    let (sphere, larger) = (closed_sphere(1.0), closed_sphere(1.05));
+
+    // `sphere` is inside `larger`
+    let stats = sphere.deviation_from(&larger);
+    assert_eq!(stats.distances.len(), sphere.positions().len());
+    assert!(stats.distances.iter().all(|d| *d < 0.0));
+    assert!(f64::abs(stats.mean + 0.05) < 2.0e-3, "{}", stats.mean);
+    assert!(f64::abs(stats.rms - 0.05) < 2.0e-3, "{}", stats.rms);
+    assert!(f64::abs(stats.max - 0.05) < 2.0e-3, "{}", stats.max);
+
+    // `larger` is outside `sphere`
+    let stats = larger.deviation_from(&sphere);
+    assert!(stats.distances.iter().all(|d| *d > 0.0));
+    assert!(f64::abs(stats.mean - 0.05) < 2.0e-3, "{}", stats.mean);
+}
+
+#[test]
+fn deviation_from_open_reference() {
+    let sphere = closed_sphere(1.0);
+    // the upper hemisphere of the larger sphere
+    let larger = closed_sphere(1.05);
+    let upper = larger
+        .face_iter()
+        .filter(|face| {
+            face.iter()
+                .all(|v| larger.positions()[v.pos].z > -TOLERANCE)
+        })
+        .collect::<Faces>();
+    let hemisphere = PolygonMesh::new(larger.attributes().clone(), upper);
+    assert_ne!(hemisphere.shell_condition(), ShellCondition::Closed);
+
+    let stats = sphere.deviation_from(&hemisphere);
+    assert!(stats.distances.iter().all(|d| *d >= 0.0));
+    sphere
+        .positions()
+        .iter()
+        .zip(&stats.distances)
+        .filter(|(p, _)| p.z > 0.1)
+        .for_each(|(_, d)| assert!(f64::abs(d - 0.05) < 2.0e-3, "{d}"));
+    assert!(stats.max > 1.0);
+}
+
+#[test]
+fn deviation_from_empty() {
+    let sphere = closed_sphere(1.0);
+    let stats = sphere.deviation_from(&PolygonMesh::default());
+    assert!(stats.distances.iter().all(|d| *d == f64::INFINITY));
+    let stats = PolygonMesh::default().deviation_from(&sphere);
+    assert_eq!(stats, DeviationStats::default());
+}
+
+#[test]
+fn deviation_of_far_samples() {
+    let sphere = closed_sphere(1.0);
+    let mut far = closed_sphere(0.5);
+    far.transform_by(Matrix4::from_translation(Vector3::new(4.0, 1.0, -2.0)));
+    let stats = far.deviation_from(&sphere);
+    // the same as the brute force over all the triangles
+    far.positions()
+        .iter()
+        .zip(&stats.distances)
+        .step_by(16)
+        .for_each(|(p, d)| assert_near!(*d, sphere.signed_distance(*p)));
+}
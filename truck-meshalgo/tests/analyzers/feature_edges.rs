@@ -0,0 +1,56 @@
+use super::*;
+
+fn cube() -> PolygonMesh {
+    let mut mesh = common::shapes::cube(Point3::origin(), 1.0);
+    mesh.triangulate();
+    mesh
+}
+
+#[test]
+fn cube_feature_edges() {
+    let mesh = cube();
+    assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+    let edges = mesh.feature_edges(Rad(std::f64::consts::PI / 4.0));
+    assert_eq!(edges.len(), 12);
+    // The diagonals of the square faces are not features.
+    edges.iter().for_each(|[a, b]| {
+        let diff = mesh.positions()[*a] - mesh.positions()[*b];
+        assert_near!(diff.magnitude(), 1.0);
+    });
+}
+
+#[test]
+fn smooth_sphere_has_no_feature_edges() {
+    let mut mesh = common::shapes::sphere(Point3::origin(), 1.0, 32, 32);
+    mesh.put_together_same_attrs(TOLERANCE)
+        .remove_degenerate_faces()
+        .remove_unused_attrs();
+    assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+    assert!(mesh
+        .feature_edges(Rad(std::f64::consts::PI / 4.0))
+        .is_empty());
+}
+
+#[test]
+fn cube_dihedral_angles() {
+    let mesh = cube();
+    let angles = mesh.dihedral_angles();
+    assert_eq!(angles.len(), 18);
+    let tol = Rad(std::f64::consts::PI / 4.0);
+    let mut features = angles
+        .iter()
+        .filter(|(_, angle)| **angle > tol)
+        .map(|(edge, angle)| {
+            assert_near!(angle.0, std::f64::consts::PI / 2.0);
+            *edge
+        })
+        .collect::<Vec<_>>();
+    features.sort();
+    assert_eq!(features, mesh.feature_edges(tol));
+
+    // the boundary edges are omitted
+    let mut open = mesh.clone();
+    let faces = open.faces().triangle_iter().skip(1).collect::<Vec<_>>();
+    *open.debug_editor().faces = Faces::from_tri_and_quad_faces(faces, Vec::new());
+    assert_eq!(open.dihedral_angles().len(), 15);
+}
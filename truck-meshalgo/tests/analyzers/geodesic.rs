@@ -0,0 +1,30 @@
+use super::*;
+
+fn planar_grid(n: usize) -> PolygonMesh {
+    common::shapes::triangulated_grid(n, n, |i, j| {
+        Point3::new(i as f64 / n as f64, j as f64 / n as f64, 0.0)
+    })
+}
+
+#[test]
+fn planar_grid_geodesic() {
+    let mesh = planar_grid(20);
+    let source = 10 * 21 + 10;
+    let distances = mesh.geodesic_distances(source);
+    let center = mesh.positions()[source];
+    mesh.positions().iter().zip(&distances).for_each(|(p, d)| {
+        let exact = p.distance(center);
+        assert!((d - exact).abs() < 0.01 * exact + 1.0e-10, "{d} {exact}");
+    });
+}
+
+#[test]
+fn disconnected_geodesic() {
+    let mut mesh = planar_grid(4);
+    mesh.push_position(Point3::new(0.0, 0.0, 1.0));
+    let distances = mesh.geodesic_distances(0);
+    assert_eq!(distances[0], 0.0);
+    assert_eq!(distances[25], f64::INFINITY);
+    assert!(distances[..25].iter().all(|d| d.is_finite()));
+    assert!(mesh.geodesic_distances(100).iter().all(|d| d.is_infinite()));
+}
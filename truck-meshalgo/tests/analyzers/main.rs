@@ -1,9 +1,18 @@
 use truck_meshalgo::prelude::*;
 
+mod cavities;
 mod collision;
 #[path = "../common/mod.rs"]
 mod common;
+mod comparison;
+mod deviation;
+mod feature_edges;
+mod geodesic;
 mod point_cloud;
+mod signed_distance;
 mod splitting;
 mod topology;
+mod triangle_quality;
+mod uv_distortion;
 mod volume;
+mod wall_thickness;
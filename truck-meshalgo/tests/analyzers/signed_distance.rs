@@ -0,0 +1,33 @@
+use super::*;
+
+#[test]
+fn sphere_sdf_grid() {
+    let mut sphere = common::shapes::sphere(Point3::origin(), 1.0, 32, 16);
+    sphere
+        .put_together_same_attrs(TOLERANCE)
+        .remove_degenerate_faces()
+        .remove_unused_attrs();
+    assert_eq!(sphere.shell_condition(), ShellCondition::Closed);
+
+    // on the surface
+    (0..16).for_each(|i| {
+        let (theta, phi) = (0.4 * i as f64, 0.2 * i as f64 - 1.5);
+        let p = Point3::new(phi.cos() * theta.cos(), phi.cos() * theta.sin(), phi.sin());
+        assert!(sphere.signed_distance(p).abs() < 0.02);
+    });
+
+    let bounds =
+        BoundingBox::from_iter([Point3::new(-1.5, -1.5, -1.5), Point3::new(1.5, 1.5, 1.5)]);
+    let grid = sphere.to_sdf_grid([7, 7, 7], bounds);
+    assert_eq!(grid.len(), 343);
+    // the center
+    assert!((grid[3 + 7 * (3 + 7 * 3)] + 1.0).abs() < 0.02);
+    grid.iter().enumerate().for_each(|(idx, value)| {
+        let (i, j, k) = (idx % 7, idx / 7 % 7, idx / 49);
+        let p = Point3::new(i as f64, j as f64, k as f64) * 0.5 + Vector3::from_value(-1.5);
+        let exact = p.to_vec().magnitude() - 1.0;
+        assert!((value - exact).abs() < 0.02, "{p:?}: {value} {exact}");
+        // the same as the value without the grid of the triangles
+        assert_near!(*value, sphere.signed_distance(p));
+    });
+}
@@ -91,3 +91,29 @@ fn into_component_test() {
     let components = mesh.components(false);
     assert_eq!(components.len(), 1);
 }
+
+#[test]
+fn bounding_boxes_of_two_cubes() {
+    let cube = common::shapes::cube;
+    let mut mesh = cube(Point3::new(3.0, 0.0, 0.0), 2.0);
+    mesh.merge(cube(Point3::new(-1.0, -1.0, -1.0), 1.0));
+
+    let boxes = mesh.component_bounding_boxes();
+    assert_eq!(boxes.len(), 2);
+    assert_near!(boxes[0].min(), Point3::new(3.0, 0.0, 0.0));
+    assert_near!(boxes[0].max(), Point3::new(5.0, 2.0, 2.0));
+    assert_near!(boxes[1].min(), Point3::new(-1.0, -1.0, -1.0));
+    assert_near!(boxes[1].max(), Point3::new(0.0, 0.0, 0.0));
+
+    let sorted = mesh.sort_components_by(|a, b| a.diameter().total_cmp(&b.diameter()));
+    assert_eq!(sorted.len(), 2);
+    // The faces of a component are in the order of the traversal.
+    let sorted_faces = |i: usize| {
+        let mut faces = sorted[i].0.clone();
+        faces.sort();
+        faces
+    };
+    assert_eq!(sorted_faces(0), (6..12).collect::<Vec<_>>());
+    assert_eq!(sorted_faces(1), (0..6).collect::<Vec<_>>());
+    assert_near!(sorted[0].1.max(), Point3::new(0.0, 0.0, 0.0));
+}
@@ -69,3 +69,96 @@ fn shell_condition() {
     ]);
     assert_eq!(faces.shell_condition(), ShellCondition::Closed);
 }
+
+#[test]
+fn irregular_vertices_of_grid() {
+    const N: usize = 4;
+    let idx = |i: usize, j: usize| i * (N + 1) + j;
+    let mesh = common::shapes::triangulated_grid(N, N, |i, j| Point3::new(i as f64, j as f64, 0.0));
+    let faces = mesh.faces();
+    let valences = faces.vertex_valences();
+    assert_eq!(valences.len(), (N + 1) * (N + 1));
+    assert_eq!(valences[idx(2, 2)], 6);
+    assert_eq!(valences[idx(0, 2)], 4);
+    assert_eq!(valences[idx(0, 0)], 3);
+    assert_eq!(valences[idx(N, 0)], 2);
+
+    let mut irregular = faces.irregular_vertices();
+    irregular.sort();
+    assert_eq!(irregular, vec![idx(0, 0), idx(0, N), idx(N, 0), idx(N, N)]);
+}
+
+fn torus(udiv: usize, vdiv: usize) -> PolygonMesh {
+    let positions = (0..udiv)
+        .flat_map(|i| {
+            (0..vdiv).map(move |j| {
+                let u = 2.0 * std::f64::consts::PI * i as f64 / udiv as f64;
+                let v = 2.0 * std::f64::consts::PI * j as f64 / vdiv as f64;
+                let r = 2.0 + v.cos();
+                Point3::new(r * u.cos(), r * u.sin(), v.sin())
+            })
+        })
+        .collect::<Vec<_>>();
+    let idx = |i: usize, j: usize| i % udiv * vdiv + j % vdiv;
+    let faces = Faces::from_iter((0..udiv).flat_map(|i| {
+        (0..vdiv).map(move |j| [idx(i, j), idx(i + 1, j), idx(i + 1, j + 1), idx(i, j + 1)])
+    }));
+    PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        faces,
+    )
+}
+
+#[test]
+fn topology_info_of_sphere_and_torus() {
+    let mut sphere = common::shapes::sphere(Point3::origin(), 1.0, 12, 8);
+    sphere
+        .put_together_same_attrs(TOLERANCE)
+        .remove_degenerate_faces()
+        .remove_unused_attrs();
+    let info = sphere.topology_info();
+    assert_eq!(info.euler_characteristic, 2);
+    assert_eq!(info.components, 1);
+    assert_eq!(info.boundary_loops, 0);
+    assert!(info.manifold);
+    assert_eq!(info.genus, Some(0));
+
+    let info = torus(8, 6).topology_info();
+    assert_eq!(info.euler_characteristic, 0);
+    assert_eq!(info.components, 1);
+    assert_eq!(info.genus, Some(1));
+
+    // the genera are summed over the components
+    let mut mesh = torus(8, 6);
+    mesh.merge(torus(5, 4));
+    mesh.merge(sphere);
+    let info = mesh.topology_info();
+    assert_eq!(info.euler_characteristic, 2);
+    assert_eq!(info.components, 3);
+    assert_eq!(info.genus, Some(2));
+}
+
+#[test]
+fn topology_info_with_boundary() {
+    // an annulus: the torus cut along a meridian
+    let torus = torus(8, 6);
+    let quads = torus
+        .face_iter()
+        .skip(6)
+        .map(|face| [face[0].pos, face[1].pos, face[2].pos, face[3].pos])
+        .collect::<Vec<_>>();
+    let info = Faces::from_iter(&quads).topology_info();
+    assert_eq!(info.euler_characteristic, 0);
+    assert_eq!(info.boundary_loops, 2);
+    assert_eq!(info.genus, Some(0));
+
+    // three faces sharing one edge
+    let mut faces = torus.faces().clone();
+    faces.push([0, 6, 3]);
+    let info = faces.topology_info();
+    assert!(!info.manifold);
+    assert_eq!(info.genus, None);
+}
@@ -0,0 +1,32 @@
+use super::*;
+
+#[test]
+fn equilateral_lattice() {
+    let mesh = common::shapes::triangular_lattice(5, f64::sqrt(3.0) / 2.0);
+    let qualities = mesh.triangle_qualities();
+    assert_eq!(qualities.len(), 25);
+    qualities.iter().for_each(|q| assert_near!(*q, 1.0));
+    assert_near!(mesh.worst_triangle_quality().unwrap(), 1.0);
+
+    // the quality does not depend on the scale
+    let mut scaled = mesh.clone();
+    scaled.transform_by(Matrix4::from_scale(100.0));
+    assert_near!(scaled.worst_triangle_quality().unwrap(), 1.0);
+}
+
+#[test]
+fn sliver_lattice() {
+    let mesh = common::shapes::triangular_lattice(5, 0.001);
+    assert!(mesh.triangle_qualities().iter().all(|q| *q < 0.01));
+    assert!(mesh.worst_triangle_quality().unwrap() < 0.01);
+
+    // a degenerate triangle with a zero-length edge
+    let mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions: vec![Point3::origin(), Point3::new(1.0, 0.0, 0.0)],
+            ..Default::default()
+        },
+        Faces::from_iter(&[[0, 1, 1]]),
+    );
+    assert_eq!(mesh.triangle_qualities(), vec![0.0]);
+}
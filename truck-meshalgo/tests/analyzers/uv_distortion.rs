@@ -0,0 +1,37 @@
+use super::*;
+
+fn square(uv_scale: f64, with_uv: bool) -> PolygonMesh {
+    let positions = vec![
+        Point3::new(0.0, 0.0, 1.0),
+        Point3::new(1.0, 0.0, 1.0),
+        Point3::new(1.0, 1.0, 1.0),
+        Point3::new(0.0, 1.0, 1.0),
+    ];
+    let uv_coords = positions
+        .iter()
+        .map(|p| Vector2::new(p.x, p.y) * uv_scale)
+        .collect();
+    let uv = |i: usize| (i, Some(i).filter(|_| with_uv), None);
+    PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            uv_coords,
+            ..Default::default()
+        },
+        Faces::from_iter(&[[uv(0), uv(1), uv(2), uv(3)], [uv(0), uv(2), uv(3), uv(1)]]),
+    )
+}
+
+#[test]
+fn isometric_uv() {
+    let distortion = square(1.0, true).uv_distortion();
+    assert_eq!(distortion.len(), 2);
+    assert_near!(distortion[0], 1.0);
+}
+
+#[test]
+fn scaled_and_missing_uv() {
+    assert_near!(square(0.5, true).uv_distortion()[0], 2.0);
+    assert_near!(square(4.0, true).uv_distortion()[0], 0.25);
+    assert!(square(1.0, false).uv_distortion()[0].is_nan());
+}
@@ -42,3 +42,138 @@ proptest! {
         prop_assert_near!(msolid.center_of_gravity().to_point(), trans.transform_point(grav));
     }
 }
+
+proptest! {
+    #[test]
+    fn convex_hull_of_cube(
+        interior in prop::collection::vec(prop::array::uniform3(0.01f64..0.99f64), 0..50),
+    ) {
+        let mut points = interior
+            .iter()
+            .map(|[x, y, z]| Point3::new(*x, *y, *z))
+            .collect::<Vec<_>>();
+        points.extend((0..8).map(|i| {
+            Point3::new((i & 1) as f64, ((i >> 1) & 1) as f64, (i >> 2) as f64)
+        }));
+        let hull = PolygonMesh::convex_hull(&points);
+        prop_assert_eq!(hull.tri_faces().len(), 12);
+        prop_assert_eq!(hull.shell_condition(), ShellCondition::Closed);
+        prop_assert!(f64::abs(hull.volume() - 1.0) < 1.0e-10);
+    }
+}
+
+#[test]
+fn cross_section_of_cylinders() {
+    let circle = |radius: f64| -> Wire {
+        let v = builder::vertex(Point3::new(radius, 0.0, 0.0));
+        builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(2.0 * PI), 4)
+    };
+    let height = Vector3::new(0.0, 0.0, 2.0);
+    let disk: Face = builder::try_attach_plane(vec![circle(1.0)]).unwrap();
+    let cylinder: Solid = builder::tsweep(&disk, height);
+    let annulus: Face =
+        builder::try_attach_plane(vec![circle(1.0), circle(0.5).inverse()]).unwrap();
+    let tube: Solid = builder::tsweep(&annulus, height);
+
+    let origin = Point3::new(0.0, 0.0, 1.0);
+    [(cylinder, PI), (tube, 0.75 * PI)]
+        .into_iter()
+        .for_each(|(solid, answer)| {
+            let msolid = solid.triangulation(0.001).collect_option().unwrap();
+            let area = msolid.cross_section_area(origin, Vector3::unit_z());
+            assert!(f64::abs(area - answer) < 0.01, "{area} {answer}");
+            let mesh = msolid.boundaries()[0].to_polygon();
+            let mesh_area = mesh.cross_section_area(origin, Vector3::unit_z());
+            assert_near!(mesh_area, area);
+            // the orientation of the plane does not matter
+            let inverse = mesh.cross_section_area(origin, -Vector3::unit_z());
+            assert_near!(inverse, area);
+        });
+}
+
+#[test]
+fn face_volume_contributions_of_flipped_cube() {
+    let oriented = common::shapes::cube(Point3::new(1.0, 2.0, 3.0), 1.0);
+    let contributions = oriented.face_volume_contributions();
+    assert_eq!(contributions.len(), 6);
+    assert_near!(contributions.iter().sum::<f64>(), 1.0);
+    assert_near!(contributions.iter().sum::<f64>(), oriented.volume());
+
+    // the top face is flipped
+    let mut flipped = oriented.clone();
+    flipped.face_iter_mut().nth(1).unwrap().reverse();
+    let flipped_contributions = flipped.face_volume_contributions();
+    assert!(flipped_contributions[1] < 0.0);
+    assert_near!(flipped_contributions[1], -contributions[1]);
+    flipped_contributions
+        .iter()
+        .zip(&contributions)
+        .enumerate()
+        .filter(|(i, _)| *i != 1)
+        .for_each(|(_, (x, y))| assert_near!(x, y));
+    assert_near!(
+        flipped_contributions.iter().sum::<f64>(),
+        1.0 - 2.0 * contributions[1]
+    );
+}
+
+#[test]
+fn mass_properties_of_unit_cube() {
+    let cube = common::shapes::cube(Point3::new(1.0, 2.0, 3.0), 1.0);
+    let properties = cube.mass_properties(1.0);
+    assert_near!(properties.mass, 1.0);
+    assert_near!(properties.center_of_mass, Point3::new(1.5, 2.5, 3.5));
+    assert_near!(properties.inertia, Matrix3::identity() / 6.0);
+}
+
+proptest! {
+    #[test]
+    fn mass_properties_of_cuboid(
+        size in prop::array::uniform3(0.1f64..10.0f64),
+        density in 0.1f64..10.0f64,
+        dir_array in prop::array::uniform2(0.0f64..1.0f64),
+        angle in 0.0..PI * 2.0,
+        vec in prop::array::uniform3(-100.0f64..100.0f64),
+    ) {
+        let [a, b, c] = size;
+        let v = builder::vertex(Point3::new(-a / 2.0, -b / 2.0, -c / 2.0));
+        let e = builder::tsweep(&v, a * Vector3::unit_x());
+        let f = builder::tsweep(&e, b * Vector3::unit_y());
+        let cuboid: Solid = builder::tsweep(&f, c * Vector3::unit_z());
+        let rotation = Matrix3::from_axis_angle(dir_from_array(dir_array), Rad(angle));
+        let trans = Matrix4::from_translation(vec.into()) * Matrix4::from(rotation);
+        let cuboid = builder::transformed(&cuboid, trans);
+        let mcuboid = cuboid.triangulation(0.05).collect_option().unwrap();
+
+        let mass = density * a * b * c;
+        let diagonal = Matrix3::from_diagonal(Vector3::new(
+            b * b + c * c,
+            c * c + a * a,
+            a * a + b * b,
+        )) * (mass / 12.0);
+        let inertia = rotation * diagonal * rotation.transpose();
+        let properties = mcuboid.mass_properties(density);
+        prop_assert!(f64::abs(properties.mass - mass) < 1.0e-8 * mass);
+        prop_assert_near!(properties.center_of_mass, Point3::from(vec));
+        let error = (0..3)
+            .flat_map(|i| (0..3).map(move |j| (i, j)))
+            .map(|(i, j)| f64::abs(properties.inertia[i][j] - inertia[i][j]))
+            .fold(0.0, f64::max);
+        prop_assert!(error < 1.0e-8 * mass * (a * a + b * b + c * c), "{error}");
+    }
+}
+
+#[test]
+fn mass_properties_of_hollow_cube() {
+    let cube = |size: f64| {
+        common::shapes::cube_shell(Point3::new(-size / 2.0, -size / 2.0, -size / 2.0), size)
+    };
+    let solid = solid_with_cavities(cube(3.0), vec![cube(1.0)]).unwrap();
+    let msolid = solid.triangulation(0.05).collect_option().unwrap();
+    let properties = msolid.mass_properties(1.0);
+    assert_near!(properties.mass, 26.0);
+    assert_near!(properties.center_of_mass, Point3::origin());
+    // the moment of inertia of the cube with the side `a` is `a^5 / 6`
+    let moment = (243.0 - 1.0) / 6.0;
+    assert_near!(properties.inertia, Matrix3::identity() * moment);
+}
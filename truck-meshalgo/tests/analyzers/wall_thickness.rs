@@ -0,0 +1,49 @@
+use super::*;
+
+#[test]
+fn hollow_box() {
+    // a box with walls of thickness 1
+    let mut mesh = common::shapes::cube(Point3::origin(), 3.0);
+    let mut cavity = common::shapes::cube(Point3::new(1.0, 1.0, 1.0), 1.0);
+    cavity.invert();
+    mesh.merge(cavity);
+    let thickness = mesh.wall_thickness(600);
+    assert_eq!(thickness.len(), 600);
+    assert!(thickness.iter().all(|t| *t > 1.0 - TOLERANCE));
+    // The inner faces and the middle of the outer faces have the thickness of the wall.
+    let walls = thickness
+        .iter()
+        .filter(|t| f64::abs(*t - 1.0) < TOLERANCE)
+        .count();
+    assert!(walls > 100, "{walls}");
+    // The others hit the opposite sides of the outer box.
+    assert!(thickness.iter().all(|t| t.near(&1.0) || t.near(&3.0)));
+}
+
+#[test]
+fn open_mesh() {
+    let mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions: vec![
+                Point3::new(0.0, 0.0, 0.0),
+                Point3::new(1.0, 0.0, 0.0),
+                Point3::new(0.0, 1.0, 0.0),
+            ],
+            ..Default::default()
+        },
+        Faces::from_iter(&[[0, 1, 2]]),
+    );
+    assert!(mesh.wall_thickness(5).iter().all(|t| t.is_infinite()));
+    assert!(PolygonMesh::default()
+        .wall_thickness(3)
+        .iter()
+        .all(|t| t.is_infinite()));
+}
+
+#[test]
+fn fine_sphere() {
+    // The rays from the sample points pass near the center.
+    let mesh = common::shapes::sphere(Point3::origin(), 1.0, 128, 65);
+    let thickness = mesh.wall_thickness(1000);
+    assert!(thickness.iter().all(|t| f64::abs(t - 2.0) < 0.01));
+}
@@ -1,3 +1,6 @@
+//! The shapes shared by the test targets, each of which uses only some of them.
+#![allow(dead_code)]
+
 use truck_meshalgo::prelude::*;
 
 pub mod shapes;
@@ -1,5 +1,139 @@
 use super::*;
 use std::f64::consts::PI;
+use truck_modeling::{builder, Face, Shell, Solid, Wire};
+
+/// the quadrangles of a cube by the indices of the corners, oriented outward, where the bits of
+/// the index of a corner are its coordinates
+const CUBE_QUADS: [[usize; 4]; 6] = [
+    [0, 2, 3, 1],
+    [4, 5, 7, 6],
+    [0, 1, 5, 4],
+    [2, 6, 7, 3],
+    [0, 4, 6, 2],
+    [1, 3, 7, 5],
+];
+
+fn cube_corner(origin: Point3, size: f64, i: usize) -> Point3 {
+    origin + size * Vector3::new((i & 1) as f64, ((i >> 1) & 1) as f64, (i >> 2) as f64)
+}
+
+/// the cube `origin + [0, size]^3` by six quadrangles, oriented outward
+pub fn cube(origin: Point3, size: f64) -> PolygonMesh {
+    PolygonMesh::new(
+        StandardAttributes {
+            positions: (0..8).map(|i| cube_corner(origin, size, i)).collect(),
+            ..Default::default()
+        },
+        Faces::from_iter(&CUBE_QUADS),
+    )
+}
+
+/// the cube `[0, 1]^3` by six unconnected quadrangles, oriented outward, like the meshes of
+/// triangle soups
+pub fn cube_soup() -> PolygonMesh {
+    let positions = CUBE_QUADS
+        .iter()
+        .flatten()
+        .map(|i| cube_corner(Point3::origin(), 1.0, *i))
+        .collect();
+    let faces = (0..6)
+        .map(|i| [4 * i, 4 * i + 1, 4 * i + 2, 4 * i + 3])
+        .collect();
+    PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        faces,
+    )
+}
+
+/// the grid of `udiv x vdiv` quadrangles whose `(i, j)`-th point is `point(i, j)`, with the index
+/// `i * (vdiv + 1) + j`
+pub fn grid(udiv: usize, vdiv: usize, point: impl Fn(usize, usize) -> Point3) -> PolygonMesh {
+    let idx = |i: usize, j: usize| i * (vdiv + 1) + j;
+    let faces = (0..udiv)
+        .flat_map(|i| (0..vdiv).map(move |j| (i, j)))
+        .map(|(i, j)| [idx(i, j), idx(i + 1, j), idx(i + 1, j + 1), idx(i, j + 1)])
+        .collect::<Vec<_>>();
+    PolygonMesh::new(
+        StandardAttributes {
+            positions: grid_points(udiv, vdiv, point),
+            ..Default::default()
+        },
+        Faces::from_iter(&faces),
+    )
+}
+
+/// [`grid`] whose quadrangles are split into two triangles by the diagonals from the `(i, j)`-th
+/// points
+pub fn triangulated_grid(
+    udiv: usize,
+    vdiv: usize,
+    point: impl Fn(usize, usize) -> Point3,
+) -> PolygonMesh {
+    let idx = |i: usize, j: usize| i * (vdiv + 1) + j;
+    let faces = (0..udiv)
+        .flat_map(|i| (0..vdiv).map(move |j| (i, j)))
+        .flat_map(|(i, j)| {
+            [
+                [idx(i, j), idx(i + 1, j), idx(i + 1, j + 1)],
+                [idx(i, j), idx(i + 1, j + 1), idx(i, j + 1)],
+            ]
+        })
+        .collect::<Vec<_>>();
+    PolygonMesh::new(
+        StandardAttributes {
+            positions: grid_points(udiv, vdiv, point),
+            ..Default::default()
+        },
+        Faces::from_iter(&faces),
+    )
+}
+
+/// the triangular lattice with `n` rows of triangles, whose triangles are equilateral if
+/// `height` is `sqrt(3) / 2`
+pub fn triangular_lattice(n: usize, height: f64) -> PolygonMesh {
+    let rows = (0..=n)
+        .map(|j| (0..=n - j).map(move |i| (i, j)).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let offsets = rows
+        .iter()
+        .scan(0, |sum, row| {
+            let offset = *sum;
+            *sum += row.len();
+            Some(offset)
+        })
+        .collect::<Vec<_>>();
+    let positions = rows
+        .iter()
+        .flatten()
+        .map(|(i, j)| Point3::new(*i as f64 + 0.5 * *j as f64, height * *j as f64, 0.0))
+        .collect();
+    let idx = |i: usize, j: usize| offsets[j] + i;
+    let faces = (0..n)
+        .flat_map(|j| {
+            let up = (0..n - j).map(move |i| [idx(i, j), idx(i + 1, j), idx(i, j + 1)]);
+            let down =
+                (0..n - j - 1).map(move |i| [idx(i + 1, j), idx(i + 1, j + 1), idx(i, j + 1)]);
+            up.chain(down).collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        Faces::from_iter(&faces),
+    )
+}
+
+fn grid_points(udiv: usize, vdiv: usize, point: impl Fn(usize, usize) -> Point3) -> Vec<Point3> {
+    (0..=udiv)
+        .flat_map(|i| (0..=vdiv).map(move |j| (i, j)))
+        .map(|(i, j)| point(i, j))
+        .collect()
+}
 
 pub fn sphere(center: Point3, radius: f64, udiv: usize, vdiv: usize) -> PolygonMesh {
     let positions = (0..udiv)
@@ -29,3 +163,28 @@ pub fn sphere(center: Point3, radius: f64, udiv: usize, vdiv: usize) -> PolygonM
         faces,
     )
 }
+
+/// the closed cylinder with the radius `1` and the height `2`, the body of a bottle
+pub fn cylinder() -> Shell {
+    let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+    let circle: Wire = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(2.0 * PI), 4);
+    let bottom: Face = builder::try_attach_plane(vec![circle]).unwrap();
+    let body: Solid = builder::tsweep(&bottom.inverse(), Vector3::new(0.0, 0.0, 2.0));
+    body.into_boundaries().pop().unwrap()
+}
+
+/// the cube `origin + [0, size]^3` by six planar faces, oriented outward
+pub fn cube_shell(origin: Point3, size: f64) -> Shell {
+    let v = builder::vertex(origin);
+    let e = builder::tsweep(&v, size * Vector3::unit_x());
+    let f = builder::tsweep(&e, size * Vector3::unit_y());
+    let s: Solid = builder::tsweep(&f, size * Vector3::unit_z());
+    s.into_boundaries().pop().unwrap()
+}
+
+/// the sphere by revolving a half circle, oriented outward
+pub fn sphere_shell(center: Point3, radius: f64) -> Shell {
+    let v = builder::vertex(center + radius * Vector3::unit_y());
+    let wire: Wire = builder::rsweep(&v, center, Vector3::unit_x(), Rad(PI), 3);
+    builder::cone(&wire, Vector3::unit_y(), Rad(2.0 * PI), 4)
+}
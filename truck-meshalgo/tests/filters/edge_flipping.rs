@@ -0,0 +1,119 @@
+use super::common;
+use truck_meshalgo::prelude::*;
+
+fn min_angle(mesh: &PolygonMesh, uv: bool) -> f64 {
+    mesh.faces()
+        .tri_faces()
+        .iter()
+        .flat_map(|tri| {
+            let p = tri.map(|v| match uv {
+                true => {
+                    let uv = mesh.uv_coords()[v.uv.unwrap()];
+                    Point3::new(uv.x, uv.y, 0.0)
+                }
+                false => mesh.positions()[v.pos],
+            });
+            (0..3).map(move |k| {
+                let (a, b, c) = (p[k], p[(k + 1) % 3], p[(k + 2) % 3]);
+                (b - a).angle(c - a).0
+            })
+        })
+        .fold(f64::INFINITY, f64::min)
+}
+
+/// A rhombus split along its long diagonal in 3D.
+/// In the parameter space, it is the unit square, whose both diagonals are equally good.
+fn bad_pair() -> PolygonMesh {
+    let uv_coords = vec![
+        Vector2::new(0.0, 0.0),
+        Vector2::new(1.0, 0.0),
+        Vector2::new(1.0, 1.0),
+        Vector2::new(0.0, 1.0),
+    ];
+    let positions = vec![
+        Point3::new(-1.0, 0.0, 0.0),
+        Point3::new(0.0, -0.2, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 0.2, 0.0),
+    ];
+    let faces = Faces::from_iter(&[
+        [(0, Some(0), None), (1, Some(1), None), (2, Some(2), None)],
+        [(0, Some(0), None), (2, Some(2), None), (3, Some(3), None)],
+    ]);
+    PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            uv_coords,
+            ..Default::default()
+        },
+        faces,
+    )
+}
+
+#[test]
+fn surface_aware_flip() {
+    let mut mesh = bad_pair();
+    let before = min_angle(&mesh, false);
+    mesh.optimize_triangulation(true);
+    let after = min_angle(&mesh, false);
+    assert!(after > before + 0.1, "before: {before}, after: {after}");
+    assert_eq!(mesh.shell_condition(), ShellCondition::Oriented);
+
+    // In the parameter space, the both splits are equally good, so nothing is flipped.
+    let mut mesh = bad_pair();
+    mesh.optimize_triangulation(false);
+    assert_eq!(mesh, bad_pair());
+}
+
+#[test]
+fn parameter_space_flip() {
+    // a square split along the long diagonal of the thin rhombus in the parameter space
+    let mut mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions: vec![
+                Point3::new(0.0, 0.0, 0.0),
+                Point3::new(1.0, -1.0, 0.0),
+                Point3::new(2.0, 0.0, 0.0),
+                Point3::new(1.0, 1.0, 0.0),
+            ],
+            uv_coords: vec![
+                Vector2::new(0.0, 0.0),
+                Vector2::new(1.0, -0.2),
+                Vector2::new(2.0, 0.0),
+                Vector2::new(1.0, 0.2),
+            ],
+            ..Default::default()
+        },
+        Faces::from_iter(&[
+            [(0, Some(0), None), (1, Some(1), None), (2, Some(2), None)],
+            [(0, Some(0), None), (2, Some(2), None), (3, Some(3), None)],
+        ]),
+    );
+    let before = min_angle(&mesh, true);
+    mesh.optimize_triangulation(false);
+    assert!(min_angle(&mesh, true) > before + 0.1);
+}
+
+#[test]
+fn seams_are_kept() {
+    // The shared edge is a seam of texture coordinates.
+    let mut mesh = bad_pair();
+    mesh.push_uv_coord(Vector2::new(0.0, 0.0));
+    mesh.editor().faces.tri_faces_mut()[1][0].uv = Some(4);
+    let original = mesh.clone();
+    mesh.optimize_triangulation(true);
+    assert_eq!(mesh, original);
+}
+
+#[test]
+fn closed_shell_is_kept() {
+    let mut mesh = common::shapes::sphere(Point3::origin(), 1.0, 16, 9);
+    mesh.put_together_same_attrs(TOLERANCE)
+        .remove_degenerate_faces()
+        .remove_unused_attrs()
+        .triangulate();
+    let before = min_angle(&mesh, false);
+    mesh.optimize_triangulation(true);
+    assert!(min_angle(&mesh, false) >= before);
+    assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+}
@@ -1,4 +1,16 @@
+#[path = "../common/mod.rs"]
+mod common;
+mod edge_flipping;
 mod normal_filter;
+mod offsetting;
 mod optimizing;
+mod pipeline;
+mod printing;
+mod registration;
+mod remeshing;
+mod simplification;
+mod smoothing;
+mod stitching;
 mod structuring;
 mod subdivision;
+mod uv_generation;
@@ -1,7 +1,6 @@
+use super::common;
 use truck_meshalgo::filters::*;
 use truck_polymesh::*;
-#[path = "../common/mod.rs"]
-mod common;
 
 #[test]
 fn normalize_normals_test() {
@@ -135,3 +134,106 @@ fn add_smooth_normals() {
         assert!(p0.distance(n0) > p1.distance(n1));
     }
 }
+
+#[test]
+fn recompute_smooth_preserving_mixed() {
+    // a cube with the authored flat normals
+    let positions = (0..8)
+        .map(|i| Point3::new(3.0 + (i & 1) as f64, ((i >> 1) & 1) as f64, (i >> 2) as f64))
+        .collect::<Vec<_>>();
+    let mut mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        Faces::from_iter(&[
+            [0, 2, 3, 1],
+            [4, 5, 7, 6],
+            [0, 1, 5, 4],
+            [1, 3, 7, 5],
+            [3, 2, 6, 7],
+            [2, 0, 4, 6],
+        ]),
+    );
+    mesh.add_naive_normals(true);
+    // a sphere with the flat normals, to be smoothed
+    let mut sphere = common::shapes::sphere(Point3::origin(), 1.0, 32, 16);
+    sphere
+        .put_together_same_attrs(TOLERANCE)
+        .remove_degenerate_faces()
+        .remove_unused_attrs()
+        .add_naive_normals(true);
+    mesh.merge(sphere);
+
+    let before = mesh.face_iter().flatten().copied().collect::<Vec<_>>();
+    let cube_normals = mesh.normals()[..6].to_vec();
+    mesh.recompute_smooth_preserving(Rad(0.5));
+    let after = mesh.face_iter().flatten().copied().collect::<Vec<_>>();
+
+    assert_eq!(&mesh.normals()[..6], cube_normals.as_slice());
+    before.iter().zip(&after).for_each(|(v0, v1)| {
+        assert_eq!(v0.pos, v1.pos);
+        let position = mesh.positions()[v1.pos];
+        match v1.pos < 8 {
+            true => assert_eq!(v0.nor, v1.nor),
+            false => {
+                let normal = mesh.normals()[v1.nor.unwrap()];
+                assert!(normal.angle(position.to_vec()) < Rad(0.05));
+            }
+        }
+    });
+}
+
+#[test]
+fn smoothing_groups_through_obj() {
+    // a flat-shaded cube in the group 0 and a sphere in the group 1
+    let positions = (0..8)
+        .map(|i| Point3::new(3.0 + (i & 1) as f64, ((i >> 1) & 1) as f64, (i >> 2) as f64))
+        .collect::<Vec<_>>();
+    let mut mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        Faces::from_iter(&[
+            [0, 2, 3, 1],
+            [4, 5, 7, 6],
+            [0, 1, 5, 4],
+            [1, 3, 7, 5],
+            [3, 2, 6, 7],
+            [2, 0, 4, 6],
+        ]),
+    );
+    let mut sphere = common::shapes::sphere(Point3::origin(), 1.0, 32, 16);
+    sphere
+        .put_together_same_attrs(TOLERANCE)
+        .remove_degenerate_faces()
+        .remove_unused_attrs();
+    mesh.merge(sphere);
+    let groups = mesh
+        .face_iter()
+        .map(|face| (mesh.positions()[face[0].pos].x < 2.0) as u32)
+        .collect::<Vec<_>>();
+
+    let mut obj = Vec::new();
+    obj::write_with_smoothing_groups(&mesh, &groups, &mut obj).unwrap();
+    let (mut mesh, read_groups) = obj::read_with_smoothing_groups(obj.as_slice()).unwrap();
+    assert_eq!(read_groups, groups);
+    mesh.add_smoothing_group_normals(&read_groups);
+
+    mesh.face_iter()
+        .zip(&read_groups)
+        .for_each(|(face, group)| {
+            let normals = face
+                .iter()
+                .map(|v| mesh.normals()[v.nor.unwrap()])
+                .collect::<Vec<_>>();
+            match group {
+                0 => assert!(normals.iter().all(|n| n.near(&normals[0]))),
+                _ => face.iter().zip(&normals).for_each(|(v, n)| {
+                    let radial = mesh.positions()[v.pos].to_vec();
+                    assert!(n.angle(radial) < Rad(0.05));
+                }),
+            }
+        });
+}
@@ -0,0 +1,26 @@
+use super::common;
+use truck_meshalgo::prelude::*;
+
+#[test]
+fn offset_sphere() {
+    let mut mesh = common::shapes::sphere(Point3::origin(), 1.0, 64, 32);
+    mesh.put_together_same_attrs(TOLERANCE)
+        .remove_degenerate_faces()
+        .remove_unused_attrs();
+    assert!(mesh.normals().is_empty());
+    let distance = 0.25;
+    mesh.offset_along_normals(distance);
+
+    assert!(!mesh.normals().is_empty());
+    mesh.positions().iter().for_each(|p| {
+        let radius = p.to_vec().magnitude();
+        assert!(f64::abs(radius - 1.0 - distance) < 0.01, "{radius}");
+    });
+
+    // offsets backward
+    mesh.offset_along_normals(-2.0 * distance);
+    mesh.positions().iter().for_each(|p| {
+        let radius = p.to_vec().magnitude();
+        assert!(f64::abs(radius - 1.0 + distance) < 0.01, "{radius}");
+    });
+}
@@ -190,3 +190,146 @@ fn put_together_same_attrs_test() {
     assert_eq!(mesh.uv_coords().len(), 18);
     assert_eq!(mesh.normals().len(), 17);
 }
+
+#[test]
+fn remove_geometric_duplicate_faces_test() {
+    let positions = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(1.0, 1.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        // copies of the positions with small errors
+        Point3::new(1.0e-4, 0.0, 0.0),
+        Point3::new(1.0, 1.0e-4, 0.0),
+        Point3::new(1.0, 1.0, 1.0e-4),
+    ];
+    let mut mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        Faces::from_iter(&[
+            [0, 1, 2].as_ref(),
+            &[4, 5, 6],
+            &[6, 5, 4],
+            &[0, 2, 3],
+            &[0, 1, 2, 3],
+        ]),
+    );
+    assert_eq!(mesh.clone().remove_geometric_duplicate_faces(1.0e-6), 1);
+    assert_eq!(mesh.remove_geometric_duplicate_faces(1.0e-3), 2);
+    assert_eq!(
+        mesh.tri_faces(),
+        &[
+            [0.into(), 1.into(), 2.into()],
+            [0.into(), 2.into(), 3.into()]
+        ]
+    );
+    assert_eq!(mesh.quad_faces().len(), 1);
+}
+
+#[test]
+fn weld_positions_keep_uv_seams_test() {
+    // the unit cube whose faces have their own positions:
+    // the four sides are unwrapped into a strip with the seam on the edge from corner 0,
+    // and the top and the bottom are the separated islands.
+    let corner = |k: usize, z: f64| match k % 4 {
+        0 => Point3::new(0.0, 0.0, z),
+        1 => Point3::new(1.0, 0.0, z),
+        2 => Point3::new(1.0, 1.0, z),
+        _ => Point3::new(0.0, 1.0, z),
+    };
+    let (mut positions, mut uv_coords, mut faces) = (Vec::new(), Vec::new(), Vec::new());
+    let mut push_face = |vertices: [(Point3, Vector2); 4]| {
+        let idx = positions.len();
+        vertices.into_iter().for_each(|(p, uv)| {
+            positions.push(p);
+            uv_coords.push(uv);
+        });
+        faces.push(
+            (idx..idx + 4)
+                .map(|i| (i, Some(i), None))
+                .collect::<Vec<_>>(),
+        );
+    };
+    (0..4).for_each(|k| {
+        let (u0, u1) = (k as f64 / 4.0, (k + 1) as f64 / 4.0);
+        push_face([
+            (corner(k, 0.0), Vector2::new(u0, 0.0)),
+            (corner(k + 1, 0.0), Vector2::new(u1, 0.0)),
+            (corner(k + 1, 1.0), Vector2::new(u1, 1.0)),
+            (corner(k, 1.0), Vector2::new(u0, 1.0)),
+        ])
+    });
+    let island = |p: Point3, offset: f64| Vector2::new(p.x + offset, p.y + 2.0);
+    push_face([0, 3, 2, 1].map(|k| (corner(k, 0.0), island(corner(k, 0.0), 2.0))));
+    push_face([0, 1, 2, 3].map(|k| (corner(k, 1.0), island(corner(k, 1.0), 4.0))));
+    let faces = faces.iter().collect::<Faces>();
+    let mut mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            uv_coords,
+            normals: Vec::new(),
+        },
+        faces,
+    );
+
+    mesh.weld_positions_keep_uv_seams(TOLERANCE)
+        .remove_unused_attrs();
+    assert_eq!(mesh.positions().len(), 8);
+    // one smooth normal for each position
+    assert_eq!(mesh.normals().len(), 8);
+    mesh.face_iter().flatten().for_each(|v| {
+        let normal = mesh.normals()[v.nor.unwrap()];
+        let outward = mesh.positions()[v.pos] - Point3::new(0.5, 0.5, 0.5);
+        assert_near!(normal, outward.normalize());
+    });
+
+    // the strip has 10 vertices since the seam is split, and the islands have 4 vertices each.
+    let render = mesh.expands(|attr| (attr.position, attr.uv_coord.unwrap()));
+    assert_eq!(render.attributes().len(), 18);
+    let count = |p: Point3| render.attributes().iter().filter(|(q, _)| *q == p).count();
+    // the corner on the seam: two in the strip and one on the bottom
+    assert_eq!(count(corner(0, 0.0)), 3);
+    assert_eq!(count(corner(1, 0.0)), 2);
+}
+
+#[test]
+fn remove_geometric_duplicates_across_buckets() {
+    let tol = 1.0e-3;
+    // The centroids are on both sides of the boundary of the buckets.
+    let offset = Vector3::new(0.9 * tol, 0.0, 0.0);
+    let triangle = [
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+    ]
+    .map(|p| p + Vector3::new(2.0 / 3.0 + 0.05 * tol, 0.0, 0.0));
+    let positions = triangle
+        .into_iter()
+        .chain(triangle.map(|p| p + offset))
+        // the quadrangle and its permutation, which is not the same polygon
+        .chain([
+            Point3::new(0.0, 0.0, 1.0),
+            Point3::new(1.0, 0.0, 1.0),
+            Point3::new(1.0, 1.0, 1.0),
+            Point3::new(0.0, 1.0, 1.0),
+        ])
+        .collect::<Vec<_>>();
+    let mut mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        Faces::from_iter(&[
+            [0, 1, 2].as_ref(),
+            // the reversed copy
+            &[5, 4, 3],
+            &[6, 7, 8, 9],
+            &[6, 8, 7, 9],
+        ]),
+    );
+    assert_eq!(mesh.remove_geometric_duplicate_faces(tol), 1);
+    assert_eq!(mesh.tri_faces().len(), 1);
+    assert_eq!(mesh.quad_faces().len(), 2);
+}
@@ -0,0 +1,46 @@
+use super::common;
+use truck_meshalgo::filters::pipeline::*;
+use truck_meshalgo::prelude::*;
+
+fn radius_deviation(mesh: &PolygonMesh) -> f64 {
+    let radii = mesh
+        .positions()
+        .iter()
+        .map(|p| p.to_vec().magnitude())
+        .collect::<Vec<_>>();
+    let n = radii.len() as f64;
+    let mean = radii.iter().sum::<f64>() / n;
+    let variance = radii.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / n;
+    variance.sqrt()
+}
+
+#[test]
+fn weld_then_smooth() {
+    let mut mesh = common::shapes::sphere(Point3::origin(), 1.0, 64, 32);
+    // deterministic radial noise depending on the position, so that the duplicated points agree
+    mesh.positions_mut().iter_mut().for_each(|p| {
+        let seed = p.x * 12.9898 + p.y * 78.233 + p.z * 37.719;
+        let hash = f64::fract(f64::sin(seed) * 43758.5453).abs();
+        *p = Point3::from_vec(p.to_vec() * (1.0 + 0.04 * (hash - 0.5)));
+    });
+    let position_len = mesh.positions().len();
+    let noisy = radius_deviation(&mesh);
+
+    let pipeline: Vec<Box<dyn MeshFilter>> = vec![
+        Box::new(Weld { tol: TOLERANCE }),
+        Box::new(TaubinSmoothing {
+            iterations: 20,
+            lambda: 0.5,
+            mu: -0.53,
+        }),
+    ];
+    pipeline.apply(&mut mesh);
+
+    assert!(mesh.positions().len() < position_len);
+    assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+    let smoothed = radius_deviation(&mesh);
+    assert!(
+        smoothed < noisy * 0.5,
+        "noisy: {noisy}, smoothed: {smoothed}"
+    );
+}
@@ -0,0 +1,74 @@
+use truck_meshalgo::{analyzers::*, filters::*};
+use truck_polymesh::*;
+use truck_topology::shell::ShellCondition;
+
+/// the unit cube as a triangle soup, whose triangles have their own positions like STL
+fn cube_soup() -> Vec<[Point3; 3]> {
+    let mesh = super::common::shapes::cube_soup();
+    mesh.faces()
+        .triangle_iter()
+        .map(|tri| tri.map(|v| mesh.positions()[v.pos]))
+        .collect()
+}
+
+#[test]
+fn prepare_broken_cube() {
+    let mut triangles = cube_soup();
+    // inward-oriented as a whole
+    triangles.iter_mut().for_each(|tri| tri.reverse());
+    // one triangle flipped
+    triangles[3].reverse();
+    // one triangle missing
+    triangles.remove(7);
+    // a duplicated triangle and a degenerate triangle
+    triangles.push(triangles[0]);
+    triangles.push([triangles[1][0], triangles[1][0], triangles[1][1]]);
+    let positions = triangles.iter().flatten().copied().collect::<Vec<_>>();
+    let faces = (0..triangles.len())
+        .map(|i| [3 * i, 3 * i + 1, 3 * i + 2])
+        .collect::<Faces>();
+    let mut mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        faces,
+    );
+    assert_ne!(mesh.shell_condition(), ShellCondition::Closed);
+
+    let report = mesh.prepare_for_printing();
+    assert_eq!(report.merged_vertices, 3 * 13 - 8);
+    assert_eq!(report.removed_faces, 2);
+    assert_eq!(report.filled_holes, 1);
+    // all triangles but the flipped one are reversed
+    assert_eq!(report.reoriented_faces, 10);
+    assert!(report.is_closed());
+    assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+    assert_eq!(mesh.positions().len(), 8);
+    assert_eq!(mesh.faces().len(), 12);
+    assert!(f64::abs(mesh.volume() - 1.0) < TOLERANCE);
+}
+
+#[test]
+fn prepare_closed_mesh_does_nothing() {
+    let positions = cube_soup().into_iter().flatten().collect::<Vec<_>>();
+    let faces = (0..12)
+        .map(|i| [3 * i, 3 * i + 1, 3 * i + 2])
+        .collect::<Faces>();
+    let mut mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        faces,
+    );
+    mesh.put_together_same_attrs(TOLERANCE)
+        .remove_unused_attrs();
+    let report = mesh.prepare_for_printing();
+    assert_eq!(report.merged_vertices, 0);
+    assert_eq!(report.removed_faces, 0);
+    assert_eq!(report.reoriented_faces, 0);
+    assert_eq!(report.filled_holes, 0);
+    assert!(report.is_closed());
+    assert!(f64::abs(mesh.volume() - 1.0) < TOLERANCE);
+}
@@ -0,0 +1,61 @@
+use truck_meshalgo::prelude::*;
+
+/// the grid of `n x n` quadrangles on the height field `f` over the unit square
+fn height_field(n: usize, f: impl Fn(f64, f64) -> f64) -> PolygonMesh {
+    super::common::shapes::grid(n, n, |i, j| {
+        let (x, y) = (i as f64 / n as f64, j as f64 / n as f64);
+        Point3::new(x, y, f(x, y))
+    })
+}
+
+fn wavy() -> PolygonMesh {
+    height_field(30, |x, y| {
+        0.2 * f64::sin(4.0 * x) * f64::cos(3.0 * y) + 0.1 * x * x
+    })
+}
+
+fn assert_matrix_near(a: Matrix4, b: Matrix4, tol: f64) {
+    (0..4).for_each(|i| {
+        (0..4).for_each(|j| assert!(f64::abs(a[i][j] - b[i][j]) < tol, "{a:?}\n{b:?}"))
+    });
+}
+
+#[test]
+fn align_rotated_copy() {
+    let target = wavy();
+    let rotation = Matrix4::from_translation(Vector3::new(0.5, 0.5, 0.0))
+        * Matrix4::from_axis_angle(Vector3::new(1.0, 2.0, 3.0).normalize(), Deg(3.0))
+        * Matrix4::from_translation(Vector3::new(-0.5, -0.5, 0.0));
+    let mut mesh = target.clone();
+    mesh.transform_by(rotation);
+
+    let alignment = mesh.align_to(&target, 50);
+    assert_matrix_near(alignment, rotation.invert().unwrap(), 1.0e-3);
+    mesh.positions()
+        .iter()
+        .zip(target.positions())
+        .for_each(|(p, q)| assert!(p.distance(*q) < 1.0e-3, "{p:?} {q:?}"));
+    // The indices are kept.
+    assert_eq!(mesh.faces().len(), target.faces().len());
+}
+
+#[test]
+fn align_to_plane() {
+    let target = height_field(4, |_, _| 0.0);
+    let mut mesh = target.clone();
+    mesh.transform_by(Matrix4::from_translation(Vector3::new(0.1, 0.2, 0.3)));
+
+    // Only the translation along the normal is determined.
+    let alignment = mesh.align_to(&target, 10);
+    let translation = Matrix4::from_translation(Vector3::new(0.0, 0.0, -0.3));
+    assert_matrix_near(alignment, translation, 1.0e-6);
+    assert!(mesh.positions().iter().all(|p| p.z.so_small()));
+}
+
+#[test]
+fn align_to_empty() {
+    let mut mesh = wavy();
+    let alignment = mesh.align_to(&PolygonMesh::default(), 10);
+    assert_eq!(alignment, Matrix4::identity());
+    assert_eq!(mesh.positions(), wavy().positions());
+}
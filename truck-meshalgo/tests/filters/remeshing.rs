@@ -0,0 +1,59 @@
+use super::common;
+use truck_meshalgo::prelude::*;
+
+fn edge_lengths(mesh: &PolygonMesh) -> Vec<f64> {
+    mesh.faces()
+        .triangle_iter()
+        .flat_map(|tri| {
+            (0..3).map(move |k| {
+                let (p, q) = (tri[k].pos, tri[(k + 1) % 3].pos);
+                mesh.positions()[p].distance(mesh.positions()[q])
+            })
+        })
+        .collect()
+}
+
+fn coefficient_of_variation(lengths: &[f64]) -> f64 {
+    let n = lengths.len() as f64;
+    let mean = lengths.iter().sum::<f64>() / n;
+    let variance = lengths.iter().map(|l| (l - mean) * (l - mean)).sum::<f64>() / n;
+    variance.sqrt() / mean
+}
+
+#[test]
+fn remesh_distorted_grid() {
+    // a planar grid whose columns are squeezed to the left
+    const N: usize = 10;
+    let mut mesh = common::shapes::triangulated_grid(N, N, |i, j| {
+        let (u, v) = (i as f64 / N as f64, j as f64 / N as f64);
+        Point3::new(u * u, v, 0.0)
+    });
+    let before = coefficient_of_variation(&edge_lengths(&mesh));
+    mesh.remesh_isotropic(0.08, 10);
+    let after = coefficient_of_variation(&edge_lengths(&mesh));
+    assert!(after < before * 0.5, "before: {before}, after: {after}");
+
+    // The mesh stays on the plane and the boundary square is preserved.
+    assert!(mesh.positions().iter().all(|p| p.z.so_small()));
+    let bdd = mesh.bounding_box();
+    assert_near!(bdd.min(), Point3::new(0.0, 0.0, 0.0));
+    assert_near!(bdd.max(), Point3::new(1.0, 1.0, 0.0));
+    assert_eq!(mesh.shell_condition(), ShellCondition::Oriented);
+    assert_eq!(mesh.extract_boundaries().len(), 1);
+}
+
+#[test]
+fn remesh_sphere() {
+    let mut mesh = common::shapes::sphere(Point3::origin(), 1.0, 32, 16);
+    mesh.put_together_same_attrs(TOLERANCE)
+        .remove_degenerate_faces()
+        .remove_unused_attrs();
+    assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+    mesh.remesh_isotropic(0.15, 5);
+    // vertices are projected onto the original mesh, which is inscribed in the sphere
+    assert!(mesh
+        .positions()
+        .iter()
+        .all(|p| (0.9..=1.0 + TOLERANCE).contains(&p.to_vec().magnitude())));
+    assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+}
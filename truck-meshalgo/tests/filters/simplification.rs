@@ -0,0 +1,140 @@
+use super::common;
+use truck_meshalgo::filters::*;
+use truck_polymesh::*;
+
+#[test]
+fn cluster_simplify_sphere() {
+    let mut sphere = common::shapes::sphere(Point3::origin(), 1.0, 200, 100);
+    sphere.add_naive_normals(true);
+    let face_count = sphere.faces().len();
+    sphere.cluster_simplify(0.2);
+
+    assert!(sphere.faces().len() * 20 < face_count);
+    assert!(!sphere.faces().is_empty());
+    sphere.positions().iter().for_each(|p| {
+        let dist = p.to_vec().magnitude();
+        assert!(dist < 1.0 + TOLERANCE && dist > 0.9, "{dist}");
+    });
+    assert!(sphere.face_iter().flatten().all(|v| v.nor.is_some()));
+    sphere.face_iter().for_each(|face| {
+        let mut pos = face.iter().map(|v| v.pos).collect::<Vec<_>>();
+        pos.sort();
+        pos.dedup();
+        assert_eq!(pos.len(), face.len());
+    });
+}
+
+#[test]
+fn quadric_decimate_sphere() {
+    let mut sphere = common::shapes::sphere(Point3::origin(), 1.0, 64, 33);
+    sphere
+        .put_together_same_attrs(TOLERANCE)
+        .remove_degenerate_faces()
+        .remove_unused_attrs()
+        .add_naive_normals(true);
+    let face_count = sphere.faces().triangle_iter().len();
+    // the maximum distance between the original faces and the sphere
+    let sagitta = 1.0 - f64::cos(std::f64::consts::PI / 64.0);
+    let max_error = 0.02;
+    sphere.quadric_decimate_to_error(max_error);
+
+    let faces = sphere.faces();
+    assert!(faces.len() * 4 < face_count, "{} {face_count}", faces.len());
+    assert_eq!(faces.tri_faces().len(), faces.len());
+    sphere.positions().iter().for_each(|p| {
+        let dist = p.to_vec().magnitude();
+        assert!((dist - 1.0).abs() < max_error + sagitta, "{dist}");
+    });
+    assert!(sphere.face_iter().flatten().all(|v| v.nor.is_some()));
+    // the result is still closed
+    let mut edges = std::collections::HashMap::<[usize; 2], i32>::new();
+    sphere.tri_faces().iter().for_each(|tri| {
+        (0..3).for_each(|i| {
+            let (a, b) = (tri[i].pos, tri[(i + 1) % 3].pos);
+            *edges.entry([a.min(b), a.max(b)]).or_default() += 1;
+        })
+    });
+    assert!(edges.values().all(|count| *count == 2));
+}
+
+#[test]
+fn non_mutating_variants() {
+    let sphere = common::shapes::sphere(Point3::origin(), 1.0, 32, 16);
+    let original = sphere.clone();
+
+    let welded = sphere.welded(TOLERANCE);
+    assert!(welded.positions().len() < sphere.positions().len());
+
+    let decimated = welded.quadric_decimated(200);
+    assert!(decimated.faces().len() <= 200);
+    assert!(decimated.faces().len() > 100);
+    assert_eq!(decimated.tri_faces().len(), decimated.faces().len());
+
+    let smoothed = decimated.taubin_smoothed(5, 0.5, -0.53);
+    assert_ne!(smoothed.positions(), decimated.positions());
+    assert_eq!(smoothed.faces().len(), decimated.faces().len());
+
+    assert_eq!(sphere, original);
+    assert_eq!(welded, original.welded(TOLERANCE));
+}
+
+#[test]
+fn quadric_decimate_with_linear_scalar() {
+    let mut sphere = common::shapes::sphere(Point3::origin(), 1.0, 32, 17);
+    sphere
+        .put_together_same_attrs(TOLERANCE)
+        .remove_degenerate_faces()
+        .remove_unused_attrs();
+    let field = |p: Point3| 3.0 * p.x - p.y + 2.0 * p.z + 1.0;
+    let mut scalars = sphere
+        .positions()
+        .iter()
+        .map(|p| field(*p))
+        .collect::<Vec<_>>();
+    let face_count = sphere.faces().triangle_iter().len();
+    sphere.quadric_decimate_with_scalar(&mut scalars, 1.0, face_count / 4);
+
+    assert!(sphere.faces().len() <= face_count / 4);
+    assert_eq!(scalars.len(), sphere.positions().len());
+    sphere
+        .positions()
+        .iter()
+        .zip(&scalars)
+        .for_each(|(p, s)| assert!((field(*p) - s).abs() < 1.0e-6, "{p:?} {s}"));
+}
+
+#[test]
+fn quadric_decimate_with_creased_scalar() {
+    // a flat 8x8 grid whose field has a crease on the line x = 2
+    let positions = (0..81)
+        .map(|i| Point3::new((i % 9) as f64 * 0.5, (i / 9) as f64 * 0.5, 0.0))
+        .collect::<Vec<_>>();
+    let field = |p: Point3| f64::max(p.x - 2.0, 0.0);
+    let mut scalars = positions.iter().map(|p| field(*p)).collect::<Vec<_>>();
+    let faces = (0..64)
+        .map(|i| {
+            let j = i / 8 * 9 + i % 8;
+            [j, j + 1, j + 10, j + 9]
+        })
+        .collect::<Faces>();
+    let mut mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        faces,
+    );
+    mesh.quadric_decimate_with_scalar(&mut scalars, 1.0, 64);
+
+    assert!(mesh.faces().len() <= 64);
+    assert!(mesh.positions().iter().all(|p| p.z.so_small()));
+    // the crease is not collapsed, so the field is the same as the original one
+    mesh.positions()
+        .iter()
+        .zip(&scalars)
+        .for_each(|(p, s)| assert!((field(*p) - s).abs() < 1.0e-6, "{p:?} {s}"));
+    assert!(mesh
+        .positions()
+        .iter()
+        .any(|p| p.x.near(&2.0) && !p.y.so_small() && !p.y.near(&4.0)));
+}
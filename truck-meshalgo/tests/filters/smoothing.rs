@@ -0,0 +1,79 @@
+use super::common;
+use truck_meshalgo::prelude::*;
+
+fn radii(mesh: &PolygonMesh) -> Vec<f64> {
+    mesh.positions()
+        .iter()
+        .map(|p| p.to_vec().magnitude())
+        .collect()
+}
+
+fn mean_and_deviation(values: &[f64]) -> (f64, f64) {
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    let variance = values.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / n;
+    (mean, variance.sqrt())
+}
+
+#[test]
+fn taubin_smoothing_noisy_sphere() {
+    let mut mesh = common::shapes::sphere(Point3::origin(), 1.0, 64, 32);
+    mesh.put_together_same_attrs(TOLERANCE)
+        .remove_degenerate_faces()
+        .remove_unused_attrs()
+        .add_naive_normals(true);
+    // deterministic radial noise
+    mesh.positions_mut()
+        .iter_mut()
+        .enumerate()
+        .for_each(|(i, p)| {
+            let hash = f64::fract(f64::sin(i as f64 * 12.9898) * 43758.5453).abs();
+            let noise = 0.04 * (hash - 0.5);
+            *p = Point3::from_vec(p.to_vec() * (1.0 + noise));
+        });
+    let (_, noisy) = mean_and_deviation(&radii(&mesh));
+
+    mesh.taubin_smoothing(20, 0.5, -0.53);
+    let (radius, deviation) = mean_and_deviation(&radii(&mesh));
+    assert!(
+        deviation < noisy * 0.5,
+        "noisy: {noisy}, smoothed: {deviation}"
+    );
+    assert!(f64::abs(radius - 1.0) < 0.01, "radius: {radius}");
+    assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+    assert!(!mesh.normals().is_empty());
+}
+
+#[test]
+fn taubin_smoothing_keeps_indices() {
+    let mut mesh = common::shapes::sphere(Point3::origin(), 1.0, 16, 8);
+    mesh.put_together_same_attrs(TOLERANCE)
+        .remove_degenerate_faces()
+        .remove_unused_attrs()
+        .add_naive_normals(true);
+    let original = mesh.clone();
+
+    mesh.taubin_smoothing(5, 0.5, -0.53);
+    assert_eq!(mesh.positions().len(), original.positions().len());
+    assert_eq!(mesh.faces().len(), original.faces().len());
+    mesh.face_iter()
+        .zip(original.face_iter())
+        .for_each(|(face, original_face)| {
+            let indices = face.iter().map(|v| v.pos);
+            assert!(indices.eq(original_face.iter().map(|v| v.pos)));
+        });
+    assert_ne!(mesh.positions(), original.positions());
+
+    // offsetting keeps the indices as well
+    let smoothed = mesh.clone();
+    mesh.offset_along_normals(0.1);
+    assert_eq!(mesh.positions().len(), smoothed.positions().len());
+    assert_eq!(mesh.faces(), smoothed.faces());
+}
+
+#[test]
+#[should_panic]
+fn taubin_smoothing_invalid_parameters() {
+    let mut mesh = common::shapes::sphere(Point3::origin(), 1.0, 8, 8);
+    mesh.taubin_smoothing(1, 0.5, -0.4);
+}
@@ -0,0 +1,155 @@
+use truck_meshalgo::prelude::*;
+
+/// The side walls of the box `[0, 1] x [0, 1] x [z0, z1]` and either the bottom or the top.
+fn half_cube(z0: f64, z1: f64, bottom: bool) -> PolygonMesh {
+    let positions = [z0, z1]
+        .into_iter()
+        .flat_map(|z| {
+            [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)].map(|(x, y)| Point3::new(x, y, z))
+        })
+        .collect();
+    let mut faces = Faces::from_iter((0..4).map(|i| [i, (i + 1) % 4, (i + 1) % 4 + 4, i + 4]));
+    match bottom {
+        true => faces.push([3, 2, 1, 0]),
+        false => faces.push([4, 5, 6, 7]),
+    }
+    PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        faces,
+    )
+}
+
+#[test]
+fn stitch_split_cube() {
+    let mut mesh = half_cube(0.0, 0.5, true);
+    assert_eq!(mesh.extract_boundaries().len(), 1);
+    // The seam is perturbed within the tolerance.
+    let mut upper = half_cube(0.5 + 1.0e-4, 1.0, false);
+    mesh.stitch(&upper, 1.0e-3);
+    assert_eq!(mesh.positions().len(), 12);
+    assert_eq!(mesh.faces().len(), 10);
+    assert!(mesh.extract_boundaries().is_empty());
+    assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+
+    // Out of the tolerance, the meshes are just merged.
+    let mut mesh = half_cube(0.0, 0.5, true);
+    upper.positions_mut().iter_mut().for_each(|p| p.z += 0.01);
+    mesh.stitch(&upper, 1.0e-3);
+    assert_eq!(mesh.positions().len(), 16);
+    assert_eq!(mesh.extract_boundaries().len(), 2);
+}
+
+#[test]
+fn resolve_t_junction_of_quadrangles() {
+    // The left quadrangle is adjacent to the two quadrangles on the right.
+    let positions = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(1.0, 2.0, 0.0),
+        Point3::new(0.0, 2.0, 0.0),
+        Point3::new(1.0, 1.0, 0.0),
+        Point3::new(2.0, 0.0, 0.0),
+        Point3::new(2.0, 1.0, 0.0),
+        Point3::new(2.0, 2.0, 0.0),
+    ];
+    let uv_coords = positions
+        .iter()
+        .map(|p| Vector2::new(p.x, p.y) / 2.0)
+        .collect();
+    let faces = Faces::from_iter([[0, 1, 2, 3], [1, 5, 6, 4], [4, 6, 7, 2]].map(|face| {
+        face.map(|i| StandardVertex {
+            pos: i,
+            uv: Some(i),
+            nor: None,
+        })
+    }));
+    let mut mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            uv_coords,
+            normals: Vec::new(),
+        },
+        faces,
+    );
+    // The unconnected edges make the second boundary.
+    assert_eq!(mesh.extract_boundaries().len(), 2);
+
+    assert_eq!(mesh.resolve_t_junctions(TOLERANCE), 1);
+    assert_eq!(mesh.tri_faces().len(), 3);
+    assert_eq!(mesh.quad_faces().len(), 2);
+    let boundaries = mesh.extract_boundaries();
+    assert_eq!(boundaries.len(), 1);
+    assert_eq!(boundaries[0].len(), 7);
+    assert_eq!(mesh.shell_condition(), ShellCondition::Oriented);
+    // The area is kept, and the new vertex has the interpolated texture coordinate.
+    let area = mesh.triangles().fold(0.0, |sum, [p, q, r]| {
+        sum + (q - p).cross(r - p).magnitude() / 2.0
+    });
+    assert_near!(area, 4.0);
+    mesh.tri_faces().iter().flatten().for_each(|v| {
+        let p = mesh.positions()[v.pos];
+        assert_near!(
+            mesh.uv_coords()[v.uv.unwrap()],
+            Vector2::new(p.x, p.y) / 2.0
+        );
+    });
+    // Nothing is left.
+    assert_eq!(mesh.resolve_t_junctions(TOLERANCE), 0);
+}
+
+#[test]
+fn resolve_t_junctions_on_one_edge() {
+    // a large triangle on three small triangles
+    let mut mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions: vec![
+                Point3::new(0.0, 0.0, 0.0),
+                Point3::new(3.0, 0.0, 0.0),
+                Point3::new(1.5, 2.0, 0.0),
+                Point3::new(1.0, 0.0, 0.0),
+                Point3::new(2.0, 0.0, 0.0),
+                Point3::new(0.5, -1.0, 0.0),
+                Point3::new(1.5, -1.0, 0.0),
+                Point3::new(2.5, -1.0, 0.0),
+            ],
+            ..Default::default()
+        },
+        Faces::from_iter(&[
+            [0, 1, 2],
+            [0, 5, 3],
+            [3, 6, 4],
+            [4, 7, 1],
+            [3, 5, 6],
+            [4, 6, 7],
+        ]),
+    );
+    // the points are perturbed within the tolerance
+    mesh.positions_mut()[3].y += 1.0e-4;
+    mesh.positions_mut()[4].y -= 1.0e-4;
+    assert_eq!(mesh.resolve_t_junctions(1.0e-3), 2);
+    assert_eq!(mesh.faces().len(), 8);
+    assert_eq!(mesh.extract_boundaries()[0].len(), 6);
+    assert_eq!(mesh.shell_condition(), ShellCondition::Oriented);
+}
+
+#[test]
+fn stitch_with_large_tolerance() {
+    // The tolerance is larger than the side of the seam, so the vertices of the loops are all
+    // near each other, and the closest alignment is taken.
+    let mut mesh = half_cube(0.0, 0.5, true);
+    let upper = half_cube(0.5 + 1.0e-4, 1.0, false);
+    mesh.stitch(&upper, 1.2);
+    assert_eq!(mesh.positions().len(), 12);
+    assert!(mesh.extract_boundaries().is_empty());
+    assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+    // the seam is kept at the positions of `self`
+    let top = mesh.positions().iter().filter(|p| p.z > 0.75).count();
+    assert_eq!(top, 4);
+    assert!(mesh
+        .positions()
+        .iter()
+        .all(|p| p.z < 0.5 + 1.0e-5 || p.z > 0.75));
+}
@@ -106,3 +106,79 @@ fn quadrangulate_test() {
     tri_mesh.quadrangulate(TOLERANCE, TOLERANCE);
     assert_eq!(tri_mesh.faces(), quad_mesh.faces());
 }
+
+#[test]
+fn ear_clip_non_convex_pentagon() {
+    // a pentagon with the reflex vertex 3, on a tilted plane
+    let rotation = Matrix3::from_axis_angle(Vector3::new(1.0, 2.0, 3.0).normalize(), Rad(0.8));
+    let uv = [
+        Point2::new(0.0, 0.0),
+        Point2::new(2.0, 0.0),
+        Point2::new(2.0, 2.0),
+        Point2::new(1.0, 0.5),
+        Point2::new(0.0, 2.0),
+    ];
+    let positions = uv
+        .iter()
+        .map(|p| Point3::from_vec(rotation * Vector3::new(p.x, p.y, 0.0)))
+        .collect::<Vec<_>>();
+    let normal = rotation * Vector3::unit_z();
+    let mut mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        // starts from the vertex next to the reflex one, where the fan goes outside
+        Faces::from_iter(&[[2, 3, 4, 0, 1]]),
+    );
+    mesh.triangulate_with(NgonTriangulation::EarClip);
+
+    assert_eq!(mesh.tri_faces().len(), 3);
+    let area = mesh.tri_faces().iter().fold(0.0, |sum, tri| {
+        let p = tri.map(|v| mesh.positions()[v.pos]);
+        let cross = (p[1] - p[0]).cross(p[2] - p[0]);
+        // all triangles are on the same side
+        assert!(cross.dot(normal) > 0.0);
+        // and the centers are inside the pentagon
+        let c = rotation.transpose() * ((p[0].to_vec() + p[1].to_vec() + p[2].to_vec()) / 3.0);
+        assert!(c.y < 2.0 - 1.5 * c.x || c.y < 1.5 * c.x - 1.0);
+        sum + cross.magnitude() / 2.0
+    });
+    // the square minus the notch
+    assert_near!(area, 4.0 - 1.5);
+}
+
+#[test]
+fn centroid_triangulation_attributes() {
+    let mut mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions: vec![
+                Point3::new(0.0, 0.0, 0.0),
+                Point3::new(1.0, 0.0, 0.0),
+                Point3::new(1.0, 1.0, 0.0),
+                Point3::new(0.0, 1.0, 0.0),
+            ],
+            uv_coords: vec![
+                Vector2::new(0.0, 0.0),
+                Vector2::new(1.0, 0.0),
+                Vector2::new(1.0, 1.0),
+                Vector2::new(0.0, 1.0),
+            ],
+            normals: vec![Vector3::unit_z()],
+        },
+        Faces::from_iter(&[[
+            (0, Some(0), Some(0)),
+            (1, Some(1), Some(0)),
+            (2, Some(2), Some(0)),
+            (3, Some(3), None),
+        ]]),
+    );
+    mesh.triangulate_with(NgonTriangulation::Centroid);
+    assert_eq!(mesh.tri_faces().len(), 4);
+    let center = mesh.tri_faces()[0][0];
+    assert!(mesh.tri_faces().iter().all(|tri| tri[0] == center));
+    assert_near!(mesh.positions()[center.pos], Point3::new(0.5, 0.5, 0.0));
+    assert_near!(mesh.uv_coords()[center.uv.unwrap()], Vector2::new(0.5, 0.5));
+    // one of the vertices has no normal
+    assert_eq!(center.nor, None);
+}
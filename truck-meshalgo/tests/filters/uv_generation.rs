@@ -0,0 +1,127 @@
+use super::common;
+use std::f64::consts::PI;
+use truck_meshalgo::filters::*;
+use truck_polymesh::*;
+
+#[test]
+fn spherical_uv_test() {
+    let center = Point3::new(1.0, 2.0, 3.0);
+    let mut mesh = common::shapes::sphere(center, 2.0, 16, 9);
+    mesh.generate_uv(UvProjection::Spherical);
+
+    let uv_coords = mesh.uv_coords();
+    assert_eq!(uv_coords.len(), mesh.positions().len());
+    assert!(uv_coords
+        .iter()
+        .all(|uv| (0.0..=1.0).contains(&uv.x) && (0.0..=1.0).contains(&uv.y)));
+    let bdd = uv_coords
+        .iter()
+        .map(|uv| Point2::from_vec(*uv))
+        .collect::<BoundingBox<_>>();
+    assert_near!(bdd.min()[1], 0.0);
+    assert_near!(bdd.max()[1], 1.0);
+    assert!(bdd.max()[0] - bdd.min()[0] > 0.9);
+    assert!(mesh.face_iter().flatten().all(|v| v.uv == Some(v.pos)));
+}
+
+#[test]
+fn box_uv_test() {
+    let positions = (0..8)
+        .map(|i| Point3::new((i & 1) as f64, ((i >> 1) & 1) as f64, (i >> 2) as f64))
+        .collect();
+    let faces = Faces::from_iter(&[
+        [0, 2, 3, 1],
+        [0, 1, 5, 4],
+        [0, 4, 6, 2],
+        [7, 5, 1, 3],
+        [7, 6, 4, 5],
+        [7, 3, 2, 6],
+    ]);
+    let mut mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        faces,
+    );
+    mesh.generate_uv(UvProjection::Box);
+
+    // Each corner is shared by three faces with distinct projection axes.
+    assert_eq!(mesh.uv_coords().len(), 24);
+    mesh.face_iter().for_each(|face| {
+        let fixed = (0..3)
+            .find(|&i| {
+                face.iter()
+                    .all(|v| mesh.positions()[v.pos][i] == mesh.positions()[face[0].pos][i])
+            })
+            .unwrap();
+        let (i, j) = ((fixed + 1) % 3, (fixed + 2) % 3);
+        face.iter().for_each(|v| {
+            let (p, uv) = (mesh.positions()[v.pos], mesh.uv_coords()[v.uv.unwrap()]);
+            assert_near!(uv, Vector2::new(p[i], p[j]));
+        });
+    });
+}
+
+#[test]
+fn lscm_plane_test() {
+    // the jittered grid on a tilted plane, with the coordinates `(s, t)` on the plane
+    let (e0, e1) = (
+        Vector3::new(1.0, 1.0, 0.0).normalize(),
+        Vector3::new(-1.0, 1.0, 1.0).normalize(),
+    );
+    let coord = |i: usize, j: usize| {
+        let jitter = 0.2 * f64::sin((3 * i + 7 * j) as f64);
+        Vector2::new(i as f64 + jitter, 0.5 * j as f64 - 0.5 * jitter)
+    };
+    let (udiv, vdiv) = (8, 5);
+    let mut mesh = common::shapes::grid(udiv, vdiv, |i, j| {
+        let st = coord(i, j);
+        Point3::new(1.0, 2.0, 3.0) + st.x * e0 + st.y * e1
+    });
+    mesh.reconstruct_uv_by_lscm();
+
+    let uv_coords = mesh.uv_coords();
+    assert_eq!(uv_coords.len(), mesh.positions().len());
+    assert!(mesh.face_iter().flatten().all(|v| v.uv == Some(v.pos)));
+    // The uv coordinates are a rotation of `(s, t)`, as the complex number `rot`.
+    let st = (0..=udiv)
+        .flat_map(|i| (0..=vdiv).map(move |j| coord(i, j)))
+        .collect::<Vec<_>>();
+    let (z, w) = (
+        st[st.len() - 1] - st[0],
+        uv_coords[st.len() - 1] - uv_coords[0],
+    );
+    let rot = Vector2::new(w.x * z.x + w.y * z.y, w.y * z.x - w.x * z.y) / z.magnitude2();
+    assert_near!(rot.magnitude(), 1.0);
+    st.iter().zip(uv_coords).for_each(|(p, uv)| {
+        let z = p - st[0];
+        let rotated = Vector2::new(rot.x * z.x - rot.y * z.y, rot.y * z.x + rot.x * z.y);
+        assert_near!(uv - uv_coords[0], rotated);
+    });
+}
+
+#[test]
+fn lscm_cylinder_test() {
+    // the quarter of a cylinder, whose planar quads are developed without distortion
+    let (udiv, vdiv) = (12, 4);
+    let mut mesh = common::shapes::grid(udiv, vdiv, |i, j| {
+        let theta = 0.5 * PI * i as f64 / udiv as f64;
+        Point3::new(f64::cos(theta), f64::sin(theta), 0.5 * j as f64)
+    });
+    mesh.reconstruct_uv_by_lscm();
+
+    let (positions, uv_coords) = (mesh.positions(), mesh.uv_coords());
+    mesh.face_iter().for_each(|face| {
+        let [a, b, c] = [face[0].pos, face[1].pos, face[2].pos];
+        let (w0, w1, w2) = (uv_coords[a], uv_coords[b], uv_coords[c]);
+        assert!((w1 - w0).perp_dot(w2 - w0) > 0.0);
+        (0..face.len()).for_each(|k| {
+            let (a, b) = (face[k].pos, face[(k + 1) % face.len()].pos);
+            assert_near!(
+                uv_coords[a].distance(uv_coords[b]),
+                positions[a].distance(positions[b])
+            );
+        });
+    });
+}
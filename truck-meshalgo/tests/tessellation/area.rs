@@ -0,0 +1,37 @@
+use super::*;
+use std::f64::consts::PI;
+
+#[test]
+fn spherical_cap_area() {
+    const RADIUS: f64 = 2.0;
+    const TOL: f64 = 0.001;
+    // the cap of the polar angle up to 60 degrees
+    let theta = PI / 3.0;
+    let pole = builder::vertex(Point3::new(0.0, 0.0, RADIUS));
+    let rim = builder::vertex(Point3::new(RADIUS * theta.sin(), 0.0, RADIUS * theta.cos()));
+    let transit = Point3::new(
+        RADIUS * f64::sin(theta / 2.0),
+        0.0,
+        RADIUS * f64::cos(theta / 2.0),
+    );
+    let arc: Wire = vec![builder::circle_arc(&pole, &rim, transit)].into();
+    let cap: Shell = builder::cone(&arc, Vector3::unit_z(), Rad(2.0 * PI), 4);
+    let exact = 2.0 * PI * RADIUS * RADIUS * (1.0 - theta.cos());
+
+    assert_eq!(cap.len(), 4);
+    cap.face_iter()
+        .for_each(|face| assert!(f64::abs(face.area(TOL) - exact / 4.0) < TOL));
+    assert!(f64::abs(cap.area(TOL) - exact) < TOL);
+
+    // more accurate than the area of the mesh
+    let mesh = cap.triangulation(TOL).to_polygon();
+    let mesh_area = mesh
+        .tri_faces()
+        .iter()
+        .map(|tri| {
+            let p = tri.map(|v| mesh.positions()[v.pos]);
+            (p[1] - p[0]).cross(p[2] - p[0]).magnitude() / 2.0
+        })
+        .sum::<f64>();
+    assert!(f64::abs(cap.area(TOL) - exact) < f64::abs(mesh_area - exact));
+}
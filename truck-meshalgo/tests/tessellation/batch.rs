@@ -0,0 +1,24 @@
+use super::*;
+use std::f64::consts::PI;
+
+#[test]
+fn tessellate_three_primitives() {
+    let bdd = BoundingBox::from_iter([Point3::origin(), Point3::new(1.0, 1.0, 1.0)]);
+    let cube: Solid = primitive::cuboid(bdd);
+    let torus: Solid = primitive::partial_torus(2.0, 0.5, Rad(2.0 * PI));
+    let half_torus: Solid = primitive::partial_torus(2.0, 0.5, Rad(PI));
+    let solids = [cube, torus, half_torus];
+
+    let options = TessellationOptions::default();
+    let meshed = tessellate_solids(&solids, options);
+    assert_eq!(meshed.len(), 3);
+    solids.iter().zip(&meshed).for_each(|(solid, meshed)| {
+        let each = solid.boundaries()[0].triangulation(options.tolerance);
+        let polygon = meshed.to_polygon();
+        assert_eq!(meshed.boundaries()[0].len(), each.len());
+        assert!(meshed.boundaries()[0]
+            .face_iter()
+            .all(|face| face.surface().is_some()));
+        assert_eq!(polygon.faces().len(), each.to_polygon().faces().len());
+    });
+}
@@ -0,0 +1,41 @@
+use super::*;
+
+#[test]
+fn grid_point_near_boundary_point() {
+    let surface = Sphere::new(Point3::origin(), 1.0);
+    let tol = 0.01;
+    let (umin, umax, vmin, vmax) = (0.5, 2.5, 0.5, 5.5);
+    let (udiv, vdiv) = surface.parameter_division(((umin, umax), (vmin, vmax)), tol);
+    let grid_point = Point2::new(udiv[udiv.len() / 2], vdiv[vdiv.len() / 2]);
+
+    let outer = [
+        Point2::new(umin, vmin),
+        Point2::new(umax, vmin),
+        Point2::new(umax, vmax),
+        Point2::new(umin, vmax),
+    ];
+    // a hole whose corner is slightly apart from the grid point
+    let (p, q) = (
+        grid_point - Vector2::new(0.3, 0.3),
+        grid_point - Vector2::new(1.0e-4, 1.0e-4),
+    );
+    let hole = vec![p, Point2::new(q.x, p.y), q, Point2::new(p.x, q.y)];
+    let mesh = trimmed_surface_mesh(&surface, &outer, &[hole], tol);
+
+    let positions = mesh.positions();
+    assert!(positions
+        .iter()
+        .any(|p| p.distance(surface.subs(q.x, q.y)) < TOLERANCE));
+    positions.iter().enumerate().for_each(|(i, p)| {
+        positions[..i].iter().for_each(|q| {
+            assert!(
+                p.distance(*q) > tol / 2.0,
+                "near-duplicate vertices: {p:?}, {q:?}"
+            );
+        })
+    });
+    mesh.faces().triangle_iter().for_each(|[a, b, c]| {
+        let (p, q, r) = (positions[a.pos], positions[b.pos], positions[c.pos]);
+        assert!(!(q - p).cross(r - p).magnitude().so_small());
+    });
+}
@@ -0,0 +1,56 @@
+use super::*;
+use std::f64::consts::PI;
+
+fn assert_contains_mesh(shell: &Shell, tol: f64) {
+    let bdb = shell.bounding_box(tol);
+    let mesh = shell.triangulation(tol).to_polygon();
+    assert!(!mesh.positions().is_empty());
+    let mesh_box = mesh.bounding_box();
+    assert!(bdb.contains(mesh_box.min()) && bdb.contains(mesh_box.max()));
+    // not too loose
+    assert!(bdb.diameter() < mesh_box.diameter() * 1.1);
+}
+
+#[test]
+fn bounding_box_of_cube() {
+    let v = builder::vertex(Point3::new(-1.0, -1.0, -1.0));
+    let e = builder::tsweep(&v, Vector3::unit_x() * 2.0);
+    let f = builder::tsweep(&e, Vector3::unit_y() * 2.0);
+    let cube: Solid = builder::tsweep(&f, Vector3::unit_z() * 2.0);
+    let shell = &cube.boundaries()[0];
+    assert_contains_mesh(shell, 0.01);
+    let bdb = shell.bounding_box(0.01);
+    assert_near!(bdb.min(), Point3::new(-1.01, -1.01, -1.01));
+    assert_near!(bdb.max(), Point3::new(1.01, 1.01, 1.01));
+}
+
+#[test]
+fn bounding_box_of_curved_shells() {
+    // sphere
+    let v = builder::vertex(Point3::new(0.0, 0.0, 1.0));
+    let semicircle = builder::rsweep(&v, Point3::origin(), Vector3::unit_y(), Rad(PI), 2);
+    let sphere: Shell = builder::rsweep(
+        &semicircle,
+        Point3::origin(),
+        Vector3::unit_z(),
+        Rad(2.0 * PI),
+        4,
+    );
+    assert_contains_mesh(&sphere, 0.01);
+
+    // a quarter of a cylinder, only a part of the parameter range of the surfaces
+    let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+    let arc: Wire = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(PI / 2.0), 1);
+    let v0 = arc.front_vertex().unwrap();
+    let v1 = arc.back_vertex().unwrap();
+    let center = builder::vertex(Point3::origin());
+    let mut wire = arc.clone();
+    wire.push_back(builder::line(v1, &center));
+    wire.push_back(builder::line(&center, v0));
+    let sector: Face = builder::try_attach_plane(vec![wire]).unwrap();
+    let body: Solid = builder::tsweep(&sector, Vector3::new(0.0, 0.0, 2.0));
+    let shell = &body.boundaries()[0];
+    assert_contains_mesh(shell, 0.01);
+    let bdb = shell.bounding_box(0.01);
+    assert!(bdb.min()[0] > -0.1 && bdb.min()[1] > -0.1);
+}
@@ -0,0 +1,63 @@
+use super::*;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static CANCEL: AtomicBool = AtomicBool::new(false);
+
+/// Sets `CANCEL` when the tessellation of the face starts.
+#[derive(Clone, Debug)]
+struct CancelingSurface(Surface);
+
+impl ParametricSurface for CancelingSurface {
+    type Point = Point3;
+    type Vector = Vector3;
+    fn subs(&self, u: f64, v: f64) -> Point3 { self.0.subs(u, v) }
+    fn uder(&self, u: f64, v: f64) -> Vector3 { self.0.uder(u, v) }
+    fn vder(&self, u: f64, v: f64) -> Vector3 { self.0.vder(u, v) }
+    fn uuder(&self, u: f64, v: f64) -> Vector3 { self.0.uuder(u, v) }
+    fn uvder(&self, u: f64, v: f64) -> Vector3 { self.0.uvder(u, v) }
+    fn vvder(&self, u: f64, v: f64) -> Vector3 { self.0.vvder(u, v) }
+    fn der_mn(&self, m: usize, n: usize, u: f64, v: f64) -> Vector3 { self.0.der_mn(m, n, u, v) }
+    fn parameter_range(&self) -> (ParameterRange, ParameterRange) { self.0.parameter_range() }
+}
+
+impl ParametricSurface3D for CancelingSurface {}
+
+impl ParameterDivision2D for CancelingSurface {
+    fn parameter_division(
+        &self,
+        range: ((f64, f64), (f64, f64)),
+        tol: f64,
+    ) -> (Vec<f64>, Vec<f64>) {
+        CANCEL.store(true, Ordering::Relaxed);
+        self.0.parameter_division(range, tol)
+    }
+}
+
+impl SearchParameter<D2> for CancelingSurface {
+    type Point = Point3;
+    fn search_parameter<H: Into<SPHint2D>>(
+        &self,
+        point: Point3,
+        hint: H,
+        trials: usize,
+    ) -> Option<(f64, f64)> {
+        self.0.search_parameter(point, hint, trials)
+    }
+}
+
+#[test]
+fn cancel_after_one_face() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    let shell = &cube.boundaries()[0];
+
+    let meshed =
+        cancelable_triangulation_with(shell, Default::default(), &AtomicBool::new(false)).unwrap();
+    assert_eq!(meshed.to_polygon(), shell.triangulation(0.01).to_polygon());
+
+    let canceling = shell.mapped(|p| *p, |c| c.clone(), |s| CancelingSurface(s.clone()));
+    assert!(cancelable_triangulation_with(&canceling, Default::default(), &CANCEL).is_none());
+    assert!(CANCEL.load(Ordering::Relaxed));
+}
@@ -0,0 +1,68 @@
+use super::*;
+
+fn square(x: f64, y: f64, size: f64) -> PolylineCurve<Point2> {
+    PolylineCurve(vec![
+        Point2::new(x, y),
+        Point2::new(x + size, y),
+        Point2::new(x + size, y + size),
+        Point2::new(x, y + size),
+    ])
+}
+
+fn area(mesh: &PolygonMesh) -> f64 {
+    mesh.faces()
+        .triangle_iter()
+        .map(|tri| {
+            let [p, q, r] = tri.map(|v| mesh.positions()[v.pos]);
+            let normal = (q - p).cross(r - p);
+            assert!(normal.z > 0.0);
+            normal.z / 2.0
+        })
+        .sum()
+}
+
+#[test]
+fn overlapping_squares() {
+    // two squares overlapping in the unit square
+    let contours = [square(0.0, 0.0, 2.0), square(1.0, 1.0, 2.0)];
+    let mesh = triangulate_contours(&contours, FillRule::NonZero);
+    assert_near!(area(&mesh), 7.0);
+    let mesh = triangulate_contours(&contours, FillRule::EvenOdd);
+    assert_near!(area(&mesh), 6.0);
+    // the intersections of the contours are split
+    assert!(mesh
+        .positions()
+        .iter()
+        .any(|p| p.near(&Point3::new(2.0, 1.0, 0.0))));
+
+    // the second square in the opposite orientation
+    let mut inverse = square(1.0, 1.0, 2.0);
+    inverse.invert();
+    let contours = [square(0.0, 0.0, 2.0), inverse];
+    let mesh = triangulate_contours(&contours, FillRule::NonZero);
+    assert_near!(area(&mesh), 6.0);
+    let mesh = triangulate_contours(&contours, FillRule::EvenOdd);
+    assert_near!(area(&mesh), 6.0);
+}
+
+#[test]
+fn self_overlapping_contour() {
+    // a pentagram, whose center has the winding number 2
+    let contour = PolylineCurve(
+        (0..5)
+            .map(|i| {
+                let t = std::f64::consts::PI * (0.5 + 0.8 * i as f64);
+                Point2::new(f64::cos(t), f64::sin(t))
+            })
+            .collect(),
+    );
+    let contours = [contour];
+    let nonzero = triangulate_contours(&contours, FillRule::NonZero);
+    let even_odd = triangulate_contours(&contours, FillRule::EvenOdd);
+    // the inner pentagon of the circumradius r = cos(2 pi / 5) / cos(pi / 5)
+    let r = f64::cos(0.4 * std::f64::consts::PI) / f64::cos(0.2 * std::f64::consts::PI);
+    let pentagon = 2.5 * r * r * f64::sin(0.4 * std::f64::consts::PI);
+    assert!(area(&nonzero) > area(&even_odd));
+    assert_near!(area(&nonzero) - area(&even_odd), pentagon);
+    assert_eq!(nonzero.shell_condition(), ShellCondition::Oriented);
+}
@@ -0,0 +1,95 @@
+use super::*;
+use std::f64::consts::PI;
+
+/// The graph of `amplitude * sin(PI * u) * sin(PI * v)` on `[0, 1] x [0, 1]`.
+#[derive(Clone, Copy, Debug)]
+struct WaveSurface {
+    amplitude: f64,
+}
+
+impl WaveSurface {
+    fn height_der(&self, m: usize, n: usize, u: f64, v: f64) -> f64 {
+        let sin_der = |k: usize, t: f64| PI.powi(k as i32) * f64::sin(PI * t + k as f64 * PI / 2.0);
+        self.amplitude * sin_der(m, u) * sin_der(n, v)
+    }
+}
+
+impl ParametricSurface for WaveSurface {
+    type Point = Point3;
+    type Vector = Vector3;
+    fn subs(&self, u: f64, v: f64) -> Point3 { Point3::new(u, v, self.height_der(0, 0, u, v)) }
+    fn uder(&self, u: f64, v: f64) -> Vector3 { self.der_mn(1, 0, u, v) }
+    fn vder(&self, u: f64, v: f64) -> Vector3 { self.der_mn(0, 1, u, v) }
+    fn uuder(&self, u: f64, v: f64) -> Vector3 { self.der_mn(2, 0, u, v) }
+    fn uvder(&self, u: f64, v: f64) -> Vector3 { self.der_mn(1, 1, u, v) }
+    fn vvder(&self, u: f64, v: f64) -> Vector3 { self.der_mn(0, 2, u, v) }
+    fn der_mn(&self, m: usize, n: usize, u: f64, v: f64) -> Vector3 {
+        let z = self.height_der(m, n, u, v);
+        match (m, n) {
+            (0, 0) => Vector3::new(u, v, z),
+            (1, 0) => Vector3::new(1.0, 0.0, z),
+            (0, 1) => Vector3::new(0.0, 1.0, z),
+            _ => Vector3::new(0.0, 0.0, z),
+        }
+    }
+}
+
+impl ParametricSurface3D for WaveSurface {}
+
+impl ParameterDivision2D for WaveSurface {
+    fn parameter_division(
+        &self,
+        range: ((f64, f64), (f64, f64)),
+        tol: f64,
+    ) -> (Vec<f64>, Vec<f64>) {
+        algo::surface::parameter_division(self, range, tol)
+    }
+}
+
+impl SearchParameter<D2> for WaveSurface {
+    type Point = Point3;
+    fn search_parameter<H: Into<SPHint2D>>(
+        &self,
+        point: Point3,
+        hint: H,
+        trials: usize,
+    ) -> Option<(f64, f64)> {
+        let hint = match hint.into() {
+            SPHint2D::Parameter(u, v) => (u, v),
+            _ => (point.x, point.y),
+        };
+        algo::surface::search_parameter(self, point, hint, trials)
+    }
+}
+
+#[test]
+fn tessellate_custom_surface() {
+    let surface = WaveSurface { amplitude: 0.3 };
+    let v = builder::vertices([
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(1.0, 1.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+    ]);
+    let wire: Wire = (0..4)
+        .map(|i| builder::line(&v[i], &v[(i + 1) % 4]))
+        .collect();
+    let shell: truck_topology::Shell<Point3, Curve, WaveSurface> =
+        vec![truck_topology::Face::new(vec![wire], surface)].into();
+
+    let tol = 0.01;
+    let meshed = shell.triangulation(tol);
+    let poly = meshed[0].surface().unwrap();
+    assert!(poly.faces().len() > 2);
+    poly.positions().iter().for_each(|p| {
+        assert_near!(p.z, surface.height_der(0, 0, p.x, p.y));
+    });
+    let poly = meshed.to_polygon();
+    assert_eq!(poly.extract_boundaries().len(), 1);
+    poly.faces().triangle_iter().for_each(|tri| {
+        let c = tri.iter().fold(Point3::origin(), |c, v| {
+            c + poly.positions()[v.pos].to_vec() / 3.0
+        });
+        assert!((c.z - surface.height_der(0, 0, c.x, c.y)).abs() < 2.0 * tol);
+    });
+}
@@ -0,0 +1,74 @@
+use super::*;
+use algo::surface::DivisionOptions;
+use std::f64::consts::PI;
+
+fn vertex_count(shell: &Shell, options: TessellationOptions) -> usize {
+    let meshed = triangulation_with(shell, options);
+    meshed
+        .face_iter()
+        .map(|face| face.surface().unwrap().positions().len())
+        .sum()
+}
+
+#[test]
+fn division_cap_of_untrimmed_face() {
+    let knot_vec = KnotVec::bezier_knot(2);
+    let control_points = vec![
+        vec![
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(0.5, 0.0, 0.5),
+            Point3::new(1.0, 0.0, 0.0),
+        ],
+        vec![
+            Point3::new(0.0, 0.5, 0.5),
+            Point3::new(0.5, 0.5, 1.0),
+            Point3::new(1.0, 0.5, 0.5),
+        ],
+        vec![
+            Point3::new(0.0, 1.0, 0.0),
+            Point3::new(0.5, 1.0, 0.5),
+            Point3::new(1.0, 1.0, 0.0),
+        ],
+    ];
+    let surface = BSplineSurface::new((knot_vec.clone(), knot_vec), control_points);
+    let shell: Shell = vec![Face::new(Vec::new(), Surface::BSplineSurface(surface))].into();
+    let options = TessellationOptions {
+        tolerance: 1.0e-5,
+        ..Default::default()
+    };
+    assert!(vertex_count(&shell, options) > 17 * 17);
+    let options = TessellationOptions {
+        division: DivisionOptions { max_division: 16 },
+        ..options
+    };
+    assert!(vertex_count(&shell, options) <= 17 * 17);
+}
+
+#[test]
+fn division_cap_of_trimmed_faces() {
+    let v = builder::vertex(Point3::new(0.0, 0.0, 1.0));
+    let semicircle = builder::rsweep(&v, Point3::origin(), Vector3::unit_y(), Rad(PI), 2);
+    let shell: Shell = builder::rsweep(
+        &semicircle,
+        Point3::origin(),
+        Vector3::unit_z(),
+        Rad(2.0 * PI),
+        4,
+    );
+    let options = TessellationOptions {
+        tolerance: 1.0e-4,
+        ..Default::default()
+    };
+    let fine = vertex_count(&shell, options);
+    let options = TessellationOptions {
+        division: DivisionOptions { max_division: 8 },
+        ..options
+    };
+    let capped = vertex_count(&shell, options);
+    assert!(capped < fine / 2, "{capped} {fine}");
+    let mut mesh = triangulation_with(&shell, options).to_polygon();
+    mesh.put_together_same_attrs(TOLERANCE * 2.0)
+        .remove_degenerate_faces()
+        .remove_unused_attrs();
+    assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+}
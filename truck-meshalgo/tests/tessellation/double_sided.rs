@@ -0,0 +1,59 @@
+use super::*;
+
+/// Checks that the second half of the triangles of `mesh` are the reversed first half.
+fn assert_double_sided(single: &PolygonMesh, mesh: &PolygonMesh) {
+    let (tris0, tris1) = (single.tri_faces(), mesh.tri_faces());
+    assert!(!tris0.is_empty());
+    assert_eq!(tris1.len(), 2 * tris0.len());
+    let (front, back) = tris1.split_at(tris0.len());
+    assert_eq!(front, tris0);
+    front.iter().zip(back).for_each(|(tri0, tri1)| {
+        (0..3).for_each(|k| {
+            let (v0, v1) = (tri0[k], tri1[2 - k]);
+            assert_eq!(v0.pos, v1.pos);
+            let (n0, n1) = (
+                mesh.normals()[v0.nor.unwrap()],
+                mesh.normals()[v1.nor.unwrap()],
+            );
+            assert_near!(n0, -n1);
+        });
+        let [p, q, r] = tri0.map(|v| mesh.positions()[v.pos]);
+        let [s, t, u] = tri1.map(|v| mesh.positions()[v.pos]);
+        assert_near!((q - p).cross(r - p), -(t - s).cross(u - s));
+    });
+}
+
+#[test]
+fn double_sided_square() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let face: Face = builder::tsweep(&e, Vector3::unit_y());
+    let shell: Shell = vec![face].into();
+    let single = triangulation_with(&shell, Default::default())[0]
+        .surface()
+        .unwrap();
+    let options = TessellationOptions {
+        double_sided: true,
+        ..Default::default()
+    };
+    let mesh = triangulation_with(&shell, options)[0].surface().unwrap();
+    assert_double_sided(&single, &mesh);
+}
+
+#[test]
+fn double_sided_cylinder_side() {
+    let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+    let e = builder::tsweep(&v, Vector3::unit_z());
+    let shell: Shell = builder::rsweep(&e, Point3::origin(), Vector3::unit_z(), Rad(1.0), 1);
+    let options = TessellationOptions {
+        tolerance: 0.01,
+        ..Default::default()
+    };
+    let single = triangulation_with(&shell, options)[0].surface().unwrap();
+    let options = TessellationOptions {
+        double_sided: true,
+        ..options
+    };
+    let mesh = triangulation_with(&shell, options)[0].surface().unwrap();
+    assert_double_sided(&single, &mesh);
+}
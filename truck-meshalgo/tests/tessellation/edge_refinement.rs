@@ -0,0 +1,42 @@
+use super::*;
+
+#[test]
+fn straight_edge_on_twisted_surface() {
+    // the hyperbolic paraboloid z = uv, whose boundaries are straight lines
+    let surface = BSplineSurface::new(
+        (KnotVec::bezier_knot(1), KnotVec::bezier_knot(1)),
+        vec![
+            vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0)],
+            vec![Point3::new(1.0, 0.0, 0.0), Point3::new(1.0, 1.0, 1.0)],
+        ],
+    );
+    let line = Line(Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0));
+    let tol = 0.01;
+
+    let plain = PolylineCurve::from_curve(line, (0.0, 1.0), tol);
+    assert_eq!(plain.len(), 2);
+
+    let refined = PolylineCurve::from_curve_on_surface(line, (0.0, 1.0), &surface, tol);
+    let (_, vdiv) = surface.parameter_division(((0.0, 1.0), (0.0, 1.0)), tol);
+    assert!(
+        refined.len() >= vdiv.len(),
+        "boundary: {}, interior: {}",
+        refined.len(),
+        vdiv.len()
+    );
+    assert_near!(refined[0], line.0);
+    assert_near!(refined[refined.len() - 1], line.1);
+    refined
+        .iter()
+        .for_each(|p| assert!(p.x.abs() < TOLERANCE && p.z.abs() < TOLERANCE));
+    refined.windows(2).for_each(|w| assert!(w[0].y < w[1].y));
+
+    // The planar surface does not refine the edge.
+    let plane = Plane::new(
+        Point3::origin(),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+    );
+    let flat = PolylineCurve::from_curve_on_surface(line, (0.0, 1.0), &plane, tol);
+    assert_eq!(flat.len(), 2);
+}
@@ -0,0 +1,26 @@
+use super::*;
+
+#[test]
+fn boundary_off_the_surface() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f: Face = builder::tsweep(&e, Vector3::unit_y());
+    // the same boundary on the plane lifted from the boundary
+    let plane = Plane::new(
+        Point3::new(0.0, 0.0, 1.0),
+        Point3::new(1.0, 0.0, 1.0),
+        Point3::new(0.0, 1.0, 1.0),
+    );
+    let face = Face::new(f.absolute_boundaries().clone(), Surface::Plane(plane));
+    let shell: Shell = vec![face].into();
+
+    let meshed = try_triangulation_with(&shell, Default::default());
+    match meshed[0].surface() {
+        Err(TessellationError::ParameterSearchFailed(point)) => assert_eq!(point.z, 0.0),
+        res => panic!("unexpected result: {res:?}"),
+    }
+    assert!(shell.triangulation(0.01)[0].surface().is_none());
+
+    let meshed = try_robust_triangulation_with(&shell, Default::default());
+    assert!(meshed[0].surface().is_ok());
+}
@@ -0,0 +1,36 @@
+use super::*;
+
+#[test]
+fn face_ids_partition_polygon() {
+    let bdd = BoundingBox::from_iter([Point3::origin(), Point3::new(1.0, 1.0, 1.0)]);
+    let cube: Solid = primitive::cuboid(bdd);
+    let shell: Shell = cube.boundaries()[0].iter().take(2).cloned().collect();
+    let options = TessellationOptions {
+        quad: QuadOptions {
+            mode: QuadMode::PreferQuads,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let (polygon, ids) = triangulation_with_face_ids(&shell, options);
+    assert_eq!(ids.len(), polygon.faces().len());
+
+    shell.face_iter().for_each(|face| {
+        let Surface::Plane(plane) = face.oriented_surface() else {
+            panic!("the faces of a cube must be planes");
+        };
+        let (origin, normal) = (plane.origin(), plane.normal());
+        let faces = polygon
+            .face_iter()
+            .zip(&ids)
+            .filter(|(_, id)| **id == face.id())
+            .map(|(poly_face, _)| poly_face)
+            .collect::<Vec<_>>();
+        assert!(!faces.is_empty());
+        // The polygon faces with the ID of `face` lie on `face`.
+        faces.into_iter().flatten().for_each(|v| {
+            let p = polygon.positions()[v.pos];
+            assert!((p - origin).dot(normal).so_small());
+        });
+    });
+}
@@ -0,0 +1,98 @@
+use super::*;
+
+type Patch = BSplineSurface<Point3>;
+type TrimmedShell = truck_topology::Shell<Point3, PCurve<Line<Point2>, Patch>, Patch>;
+
+/// the face on the bump trimmed by the polygon `uvs` in the domain
+fn trimmed_bump(uvs: &[Point2]) -> (Patch, TrimmedShell) {
+    let control_points = (0..3)
+        .map(|i| {
+            (0..3)
+                .map(|j| {
+                    let (x, y) = (i as f64 / 2.0, j as f64 / 2.0);
+                    let z = if i == 1 && j == 1 { 0.5 } else { 0.0 };
+                    Point3::new(x, y, z)
+                })
+                .collect()
+        })
+        .collect();
+    let surface = Patch::new(
+        (KnotVec::bezier_knot(2), KnotVec::bezier_knot(2)),
+        control_points,
+    );
+    let points = uvs.iter().map(|uv| surface.subs(uv.x, uv.y));
+    let vertices = truck_topology::Vertex::news(points.collect::<Vec<_>>());
+    let wire = (0..uvs.len())
+        .map(|i| {
+            let j = (i + 1) % uvs.len();
+            let curve = PCurve::new(Line(uvs[i], uvs[j]), surface.clone());
+            truck_topology::Edge::new(&vertices[i], &vertices[j], curve)
+        })
+        .collect::<truck_topology::Wire<_, _>>();
+    let face = truck_topology::Face::new(vec![wire], surface.clone());
+    (surface, vec![face].into())
+}
+
+/// the maximum distance between the centers of the triangles and the surface
+fn max_deviation(surface: &Patch, mesh: &PolygonMesh) -> f64 {
+    let positions = mesh.positions();
+    mesh.faces()
+        .triangle_iter()
+        .map(|tri| {
+            let [p, q, r] = tri.map(|v| positions[v.pos].to_vec());
+            let center = Point3::from_vec((p + q + r) / 3.0);
+            let (u, v) = surface.search_nearest_parameter(center, None, 100).unwrap();
+            surface.subs(u, v).distance(center)
+        })
+        .fold(0.0, f64::max)
+}
+
+fn tessellate(shell: &TrimmedShell, interior: InteriorSampling) -> PolygonMesh {
+    let options = TessellationOptions {
+        interior,
+        ..Default::default()
+    };
+    triangulation_with(shell, options).to_polygon()
+}
+
+#[test]
+fn thin_trimmed_strip() {
+    let (surface, shell) = trimmed_bump(&[
+        Point2::new(0.0, 0.0),
+        Point2::new(0.05, 0.0),
+        Point2::new(1.0, 0.95),
+        Point2::new(1.0, 1.0),
+        Point2::new(0.95, 1.0),
+        Point2::new(0.0, 0.05),
+    ]);
+    let grid = tessellate(&shell, InteriorSampling::Grid);
+    let none = tessellate(&shell, InteriorSampling::None);
+    let count = |mesh: &PolygonMesh| mesh.faces().triangle_iter().len();
+    assert!(
+        count(&none) < count(&grid),
+        "{} {}",
+        count(&none),
+        count(&grid)
+    );
+    // The strip is thin, so the boundary points are enough.
+    assert!(max_deviation(&surface, &none) < 0.01);
+    [grid, none].iter().for_each(|mesh| {
+        assert_eq!(mesh.shell_condition(), ShellCondition::Oriented);
+    });
+}
+
+#[test]
+fn adaptive_square() {
+    let (surface, shell) = trimmed_bump(&[
+        Point2::new(0.0, 0.0),
+        Point2::new(1.0, 0.0),
+        Point2::new(1.0, 1.0),
+        Point2::new(0.0, 1.0),
+    ]);
+    let none = tessellate(&shell, InteriorSampling::None);
+    let adaptive = tessellate(&shell, InteriorSampling::Adaptive);
+    // the bump is lost without the interior points
+    assert!(max_deviation(&surface, &none) > 0.05);
+    assert!(max_deviation(&surface, &adaptive) < 0.01);
+    assert_eq!(adaptive.shell_condition(), ShellCondition::Oriented);
+}
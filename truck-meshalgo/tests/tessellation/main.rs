@@ -2,4 +2,31 @@ use truck_meshalgo::prelude::*;
 use truck_modeling::*;
 use truck_topology::shell::ShellCondition;
 
+mod area;
+mod batch;
+mod boundary_weld;
+mod bounding_box;
+mod cancel;
+#[path = "../common/mod.rs"]
+mod common;
+mod contour_fill;
+mod custom_surface;
+mod division;
+mod double_sided;
+mod edge_refinement;
+mod errors;
+mod face_ids;
+mod interior_sampling;
+mod marching_cubes;
+mod mesh_shell;
+mod param_triangulation;
+mod retessellation;
+mod search_options;
+mod searcher;
+mod serialization;
+mod surface_range;
+mod sweep;
 mod triangulation;
+mod trim_mode;
+mod trimmed_surface;
+mod wireframe;
@@ -0,0 +1,47 @@
+use super::*;
+use std::f64::consts::PI;
+
+#[test]
+fn sphere_sdf_round_trip() {
+    let v0 = builder::vertex(Point3::new(0.0, 0.0, 1.0));
+    let v1 = builder::vertex(Point3::new(0.0, 0.0, -1.0));
+    let arc = builder::circle_arc(&v0, &v1, Point3::new(1.0, 0.0, 0.0));
+    let shell: Shell = builder::rsweep(&arc, Point3::origin(), Vector3::unit_z(), Rad(2.0 * PI), 4);
+    let sphere = tessellate_to_polygon(&shell, 0.01);
+    assert_eq!(sphere.shell_condition(), ShellCondition::Closed);
+
+    let bounds =
+        BoundingBox::from_iter([Point3::new(-1.3, -1.3, -1.3), Point3::new(1.3, 1.3, 1.3)]);
+    let grid = sphere.to_sdf_grid([14, 14, 14], bounds);
+    let mesh = marching_cubes(&grid, [14, 14, 14], bounds, 0.0);
+    assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+    mesh.positions().iter().for_each(|p| {
+        assert!(f64::abs(p.to_vec().magnitude() - 1.0) < 0.03);
+    });
+    // the polyhedron inscribed in the sphere
+    assert!(f64::abs(mesh.volume() - 4.0 * PI / 3.0) < 0.2);
+}
+
+#[test]
+fn noise_is_closed() {
+    // all the 256 cases and the ambiguous faces appear in the noise
+    let n = 12;
+    let mut seed = 0x2545_f491_u64;
+    let grid = (0..n * n * n)
+        .map(|idx| {
+            let (i, j, k) = (idx % n, idx / n % n, idx / (n * n));
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            match [i, j, k].iter().any(|x| *x == 0 || *x == n - 1) {
+                true => 1.0,
+                false => (seed >> 11) as f64 / (1u64 << 53) as f64 - 0.5,
+            }
+        })
+        .collect::<Vec<_>>();
+    let bounds = BoundingBox::from_iter([Point3::origin(), Point3::new(1.0, 1.0, 1.0)]);
+    let mesh = marching_cubes(&grid, [n, n, n], bounds, 0.0);
+    assert!(!mesh.faces().is_empty());
+    assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+    assert!(mesh.volume() > 0.0);
+}
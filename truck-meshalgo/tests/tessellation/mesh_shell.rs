@@ -0,0 +1,48 @@
+use super::*;
+use itertools::Itertools;
+
+#[test]
+fn cube_from_quadrangles() {
+    let mesh = common::shapes::cube_soup();
+    assert_eq!(mesh.positions().len(), 24);
+    let shell = shell_from_mesh(&mesh, TOLERANCE).unwrap();
+    assert_eq!(shell.len(), 6);
+    assert_eq!(shell.edge_iter().map(|edge| edge.id()).unique().count(), 12);
+    assert_eq!(shell.vertex_iter().map(|v| v.id()).unique().count(), 8);
+    assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+    shell.edge_iter().for_each(|edge| {
+        let curve = edge.oriented_curve();
+        assert_eq!(curve.len(), 2);
+        assert_near!(curve.front(), edge.front().point());
+        assert_near!(curve.back(), edge.back().point());
+    });
+
+    let polygon = shell.to_seamless_polygon(std::f64::consts::PI / 6.0);
+    assert_eq!(polygon.faces().len(), 6);
+    assert_eq!(polygon.shell_condition(), ShellCondition::Closed);
+}
+
+#[test]
+fn cube_from_triangles() {
+    let mesh = common::shapes::cube_soup();
+    let positions = mesh.positions();
+    let triangles = mesh
+        .faces()
+        .triangle_iter()
+        .flat_map(|tri| tri.map(|v| positions[v.pos]))
+        .collect::<Vec<_>>();
+    let faces = (0..triangles.len() / 3)
+        .map(|i| [3 * i, 3 * i + 1, 3 * i + 2])
+        .collect();
+    let mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions: triangles,
+            ..Default::default()
+        },
+        faces,
+    );
+    let shell = shell_from_mesh(&mesh, TOLERANCE).unwrap();
+    assert_eq!(shell.len(), 12);
+    assert_eq!(shell.edge_iter().map(|edge| edge.id()).unique().count(), 18);
+    assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+}
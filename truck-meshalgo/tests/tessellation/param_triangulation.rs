@@ -0,0 +1,71 @@
+use super::*;
+
+#[test]
+fn hand_made_plane_triangulation() {
+    let plane = Plane::new(
+        Point3::new(1.0, 0.0, 2.0),
+        Point3::new(3.0, 0.0, 2.0),
+        Point3::new(1.0, 0.0, 1.0),
+    );
+    // the square with its center, divided into four triangles
+    let uv_points = [
+        Point2::new(0.0, 0.0),
+        Point2::new(1.0, 0.0),
+        Point2::new(1.0, 1.0),
+        Point2::new(0.0, 1.0),
+        Point2::new(0.5, 0.5),
+    ];
+    let triangles = [[0, 1, 4], [1, 2, 4], [2, 3, 4], [3, 0, 4]];
+    let mesh = evaluate_param_triangulation(&plane, &uv_points, &triangles);
+
+    let answers = [
+        Point3::new(1.0, 0.0, 2.0),
+        Point3::new(3.0, 0.0, 2.0),
+        Point3::new(3.0, 0.0, 1.0),
+        Point3::new(1.0, 0.0, 1.0),
+        Point3::new(2.0, 0.0, 1.5),
+    ];
+    assert_eq!(mesh.positions().len(), answers.len());
+    mesh.positions()
+        .iter()
+        .zip(answers)
+        .for_each(|(p, q)| assert_near!(*p, q));
+    let faces = mesh.tri_faces();
+    assert_eq!(faces.len(), triangles.len());
+    faces.iter().zip(triangles).for_each(|(face, tri)| {
+        face.iter().zip(tri).for_each(|(v, i)| {
+            assert_eq!((v.pos, v.uv, v.nor), (i, Some(i), Some(i)));
+            assert_near!(mesh.uv_coords()[i], uv_points[i].to_vec());
+        })
+    });
+    let normal = plane.normal();
+    assert!(mesh.normals().iter().all(|n| n.near(&normal)));
+    let area = mesh.triangles().fold(0.0, |sum, [p, q, r]| {
+        sum + (q - p).cross(r - p).magnitude() / 2.0
+    });
+    assert_near!(area, 2.0);
+}
+
+#[test]
+fn curved_surface_normals() {
+    let surface = BSplineSurface::new(
+        (KnotVec::bezier_knot(2), KnotVec::bezier_knot(1)),
+        vec![
+            vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0)],
+            vec![Point3::new(0.5, 0.0, 1.0), Point3::new(0.5, 1.0, 1.0)],
+            vec![Point3::new(1.0, 0.0, 0.0), Point3::new(1.0, 1.0, 0.0)],
+        ],
+    );
+    let uv_points = (0..=4)
+        .flat_map(|i| (0..=1).map(move |j| Point2::new(i as f64 / 4.0, j as f64)))
+        .collect::<Vec<_>>();
+    let triangles = (0..4)
+        .flat_map(|i| [[2 * i, 2 * i + 2, 2 * i + 3], [2 * i, 2 * i + 3, 2 * i + 1]])
+        .collect::<Vec<_>>();
+    let mesh = evaluate_param_triangulation(&surface, &uv_points, &triangles);
+    uv_points.iter().enumerate().for_each(|(i, uv)| {
+        assert_near!(mesh.positions()[i], surface.subs(uv.x, uv.y));
+        assert_near!(mesh.normals()[i], surface.normal(uv.x, uv.y));
+    });
+    assert_eq!(mesh.shell_condition(), ShellCondition::Oriented);
+}
@@ -0,0 +1,56 @@
+use super::*;
+
+#[test]
+fn retessellate_one_face() {
+    let shell = &common::shapes::cylinder();
+
+    let options = TessellationOptions {
+        tolerance: 0.1,
+        ..Default::default()
+    };
+    let mut meshed = triangulation_with(shell, options);
+    let before = meshed
+        .face_iter()
+        .map(|face| face.surface())
+        .collect::<Vec<_>>();
+
+    // the lateral face, whose mesh is refined by the finer tolerance
+    let index = 2;
+    let options = TessellationOptions {
+        tolerance: 0.01,
+        ..Default::default()
+    };
+    let searcher = BySearchParameter { trials: 100 };
+    let id = shell[index].id();
+    assert!(retessellate_face(&mut meshed, shell, id, options, searcher));
+
+    let after = meshed
+        .face_iter()
+        .map(|face| face.surface())
+        .collect::<Vec<_>>();
+    (0..shell.len()).for_each(|i| match i == index {
+        true => {
+            let (before, after) = (before[i].as_ref().unwrap(), after[i].as_ref().unwrap());
+            assert!(after.tri_faces().len() > before.tri_faces().len());
+        }
+        false => assert_eq!(before[i], after[i]),
+    });
+
+    // the boundary polylines are reused, so the mesh is still watertight
+    let mut mesh = meshed.to_polygon();
+    mesh.put_together_same_attrs(TOLERANCE);
+    assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+
+    // the face of the other shell is not found
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let square: Face = builder::tsweep(&e, Vector3::unit_y());
+    let id = square.id();
+    assert!(!retessellate_face(
+        &mut meshed,
+        shell,
+        id,
+        options,
+        searcher
+    ));
+}
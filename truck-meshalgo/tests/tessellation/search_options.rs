@@ -0,0 +1,134 @@
+use super::*;
+
+/// The plane whose parameter search converges only after [`SlowPlane::REQUIRED_TRIALS`] trials,
+/// imitating a surface difficult for the Newton method.
+#[derive(Clone, Copy, Debug)]
+struct SlowPlane(Plane);
+
+impl SlowPlane {
+    const REQUIRED_TRIALS: usize = 300;
+}
+
+impl ParametricSurface for SlowPlane {
+    type Point = Point3;
+    type Vector = Vector3;
+    fn subs(&self, u: f64, v: f64) -> Point3 { self.0.subs(u, v) }
+    fn uder(&self, u: f64, v: f64) -> Vector3 { self.0.uder(u, v) }
+    fn vder(&self, u: f64, v: f64) -> Vector3 { self.0.vder(u, v) }
+    fn uuder(&self, u: f64, v: f64) -> Vector3 { self.0.uuder(u, v) }
+    fn uvder(&self, u: f64, v: f64) -> Vector3 { self.0.uvder(u, v) }
+    fn vvder(&self, u: f64, v: f64) -> Vector3 { self.0.vvder(u, v) }
+    fn der_mn(&self, m: usize, n: usize, u: f64, v: f64) -> Vector3 { self.0.der_mn(m, n, u, v) }
+}
+
+impl ParametricSurface3D for SlowPlane {}
+
+impl ParameterDivision2D for SlowPlane {
+    fn parameter_division(
+        &self,
+        range: ((f64, f64), (f64, f64)),
+        tol: f64,
+    ) -> (Vec<f64>, Vec<f64>) {
+        self.0.parameter_division(range, tol)
+    }
+}
+
+impl SearchParameter<D2> for SlowPlane {
+    type Point = Point3;
+    fn search_parameter<H: Into<SPHint2D>>(
+        &self,
+        point: Point3,
+        hint: H,
+        trials: usize,
+    ) -> Option<(f64, f64)> {
+        match trials >= Self::REQUIRED_TRIALS {
+            true => self.0.search_parameter(point, hint, trials),
+            false => None,
+        }
+    }
+}
+
+fn square_wire(z: f64) -> Wire {
+    let v = builder::vertices([
+        Point3::new(0.0, 0.0, z),
+        Point3::new(1.0, 0.0, z),
+        Point3::new(1.0, 1.0, z),
+        Point3::new(0.0, 1.0, z),
+    ]);
+    (0..4)
+        .map(|i| builder::line(&v[i], &v[(i + 1) % 4]))
+        .collect()
+}
+
+fn xy_plane() -> Plane {
+    Plane::new(
+        Point3::origin(),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+    )
+}
+
+#[test]
+fn search_trials() {
+    let face = truck_topology::Face::new(vec![square_wire(0.0)], SlowPlane(xy_plane()));
+    let shell: truck_topology::Shell<Point3, Curve, SlowPlane> = vec![face].into();
+
+    let meshed = triangulation_with(&shell, Default::default());
+    assert!(meshed[0].surface().is_none());
+
+    let options = TessellationOptions {
+        search_trials: 500,
+        ..Default::default()
+    };
+    let meshed = triangulation_with(&shell, options);
+    let mesh = meshed[0].surface().unwrap();
+    assert!(!mesh.faces().is_empty());
+}
+
+#[test]
+fn search_tolerance() {
+    // the paraboloid z = x^2 + y^2 over [0, 1]^2 by a bezier surface
+    let control_points = (0..3)
+        .map(|i| {
+            (0..3)
+                .map(|j| {
+                    let (x, y) = (i as f64 / 2.0, j as f64 / 2.0);
+                    let z = match (i, j) {
+                        (2, 2) => 2.0,
+                        (2, _) | (_, 2) => 1.0,
+                        _ => 0.0,
+                    };
+                    Point3::new(x, y, z)
+                })
+                .collect()
+        })
+        .collect();
+    let knot_vecs = (KnotVec::bezier_knot(2), KnotVec::bezier_knot(2));
+    let surface = Surface::BSplineSurface(BSplineSurface::new(knot_vecs, control_points));
+    let point = surface.subs(0.5, 0.5);
+    let search = |tolerance: Option<f64>| {
+        let mut searcher = BySearchParameterWithTolerance {
+            trials: 100,
+            tolerance,
+        };
+        searcher.search(&surface, point, Some((0.1, 0.9))).unwrap()
+    };
+
+    // the same as `BySearchParameter` by default
+    let (u, v) = search(None);
+    assert_near!(Point2::new(u, v), Point2::new(0.5, 0.5));
+
+    // the Newton iterations stop before the convergence within `TOLERANCE`
+    let (u, v) = search(Some(0.1));
+    let distance = surface.subs(u, v).distance(point);
+    assert!(TOLERANCE < distance && distance <= 0.1, "{distance}");
+
+    let face: Face = Face::new(vec![square_wire(0.0)], Surface::Plane(xy_plane()));
+    let shell: Shell = vec![face].into();
+    let options = TessellationOptions {
+        search_tolerance: Some(1.0e-3),
+        ..Default::default()
+    };
+    let meshed = triangulation_with(&shell, options);
+    assert!(meshed[0].surface().is_some());
+}
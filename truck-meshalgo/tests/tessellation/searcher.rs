@@ -0,0 +1,52 @@
+use super::*;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+/// Counts the resets and the searches, and checks that each face starts without hints.
+#[derive(Clone, Debug, Default)]
+struct CountingSearcher {
+    resets: Arc<AtomicUsize>,
+    searches: Arc<AtomicUsize>,
+    searches_in_face: usize,
+}
+
+impl ParameterSearcher<Surface> for CountingSearcher {
+    fn search(
+        &mut self,
+        surface: &Surface,
+        point: Point3,
+        hint: Option<(f64, f64)>,
+    ) -> Option<(f64, f64)> {
+        if self.searches_in_face == 0 {
+            assert!(hint.is_none());
+        }
+        self.searches_in_face += 1;
+        self.searches.fetch_add(1, Ordering::Relaxed);
+        BySearchParameter { trials: 100 }.search(surface, point, hint)
+    }
+    fn reset(&mut self) {
+        self.resets.fetch_add(1, Ordering::Relaxed);
+        self.searches_in_face = 0;
+    }
+}
+
+#[test]
+fn reset_per_face() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    let shell = &cube.boundaries()[0];
+
+    let searcher = CountingSearcher::default();
+    let meshed = triangulation_with_searcher(shell, Default::default(), searcher.clone());
+    assert_eq!(searcher.resets.load(Ordering::Relaxed), 6);
+    assert!(searcher.searches.load(Ordering::Relaxed) > 0);
+    assert_eq!(meshed.to_polygon(), shell.triangulation(0.01).to_polygon());
+
+    let cshell = shell.compress();
+    cshell_triangulation_with_searcher(&cshell, Default::default(), searcher.clone());
+    assert_eq!(searcher.resets.load(Ordering::Relaxed), 12);
+}
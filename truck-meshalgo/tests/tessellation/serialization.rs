@@ -0,0 +1,28 @@
+use super::*;
+use truck_topology::compress::CompressedShell;
+
+type MeshedCShell = CompressedShell<Point3, PolylineCurve<Point3>, Option<PolygonMesh>>;
+
+#[test]
+fn meshed_cshell_round_trip() {
+    let meshed: MeshedCShell = common::shapes::cylinder().triangulation(0.01).compress();
+    assert!(meshed.faces.iter().all(|face| face.surface.is_some()));
+
+    let json = serde_json::to_vec(&meshed).unwrap();
+    // The floating point numbers may differ in the last bits by JSON.
+    let from_json: MeshedCShell = serde_json::from_slice(&json).unwrap();
+    assert_eq!(from_json.faces.len(), meshed.faces.len());
+
+    let binary = meshed.to_bincode().unwrap();
+    let from_binary = MeshedCShell::from_bincode(&binary).unwrap();
+    assert_eq!(from_binary, meshed);
+    assert!(binary.len() < json.len());
+
+    // The restored shell is still closed.
+    let restored: truck_topology::Shell<Point3, PolylineCurve<Point3>, Option<PolygonMesh>> =
+        truck_topology::Shell::extract(from_binary).unwrap();
+    let mut mesh = restored.to_polygon();
+    mesh.put_together_same_attrs(TOLERANCE)
+        .remove_unused_attrs();
+    assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+}
@@ -0,0 +1,72 @@
+use super::*;
+
+/// a B-spline surface on the domain `[0, 10] x [0, 10]`, wavy if `height` is not zero
+fn large_surface(height: f64) -> BSplineSurface<Point3> {
+    let mut knot_vec = KnotVec::uniform_knot(3, 8);
+    knot_vec.transform(10.0, 0.0);
+    let control_points = (0..11)
+        .map(|i| {
+            (0..11)
+                .map(|j| Point3::new(i as f64, j as f64, ((i + 2 * j) % 3) as f64 * height))
+                .collect()
+        })
+        .collect();
+    BSplineSurface::new((knot_vec.clone(), knot_vec), control_points)
+}
+
+#[test]
+fn mesh_sub_range() {
+    let surface = large_surface(0.5);
+    let range = ((2.0, 3.0), (6.5, 7.0));
+    let mesh = surface_mesh(&surface, range, 0.01);
+    let whole = surface_mesh(&surface, surface.range_tuple(), 0.01);
+
+    assert!(!mesh.faces().is_empty());
+    assert!(mesh.positions().len() * 20 < whole.positions().len());
+    mesh.uv_coords().iter().for_each(|uv| {
+        assert!(
+            (2.0..=3.0).contains(&uv.x) && (6.5..=7.0).contains(&uv.y),
+            "{uv:?}"
+        );
+    });
+    mesh.face_iter().flatten().for_each(|v| {
+        let uv = mesh.uv_coords()[v.uv.unwrap()];
+        assert_near!(mesh.positions()[v.pos], surface.subs(uv.x, uv.y));
+    });
+}
+
+#[test]
+fn search_parameter_in_sub_range() {
+    // Newton's method converges from anywhere on the flat surface.
+    let surface = large_surface(0.0);
+    let point = surface.subs(2.5, 6.8);
+    let (u, v) = surface
+        .search_parameter(point, ((2.0, 3.0), (6.5, 7.0)), 100)
+        .unwrap();
+    assert_near!(surface.subs(u, v), point);
+    assert!((2.0..=3.0).contains(&u) && (6.5..=7.0).contains(&v));
+    // the point is not on the part of the surface on the range
+    assert!(surface
+        .search_parameter(point, ((5.0, 6.0), (1.0, 2.0)), 100)
+        .is_none());
+    assert!(surface
+        .search_nearest_parameter(point, ((5.0, 6.0), (1.0, 2.0)), 100)
+        .is_none());
+}
+
+#[test]
+fn search_nearest_parameter_of_extrusion_in_sub_range() {
+    let line = BSplineCurve::new(
+        KnotVec::bezier_knot(1),
+        vec![Point3::new(0.0, 0.0, 0.0), Point3::new(10.0, 0.0, 0.0)],
+    );
+    let surface = ExtrudedCurve::by_extrusion(line, Vector3::new(0.0, 10.0, 0.0));
+    let point = surface.subs(0.25, 0.68);
+    let (u, v) = surface
+        .search_nearest_parameter(point, ((0.2, 0.3), (0.65, 0.7)), 100)
+        .unwrap();
+    assert_near!(surface.subs(u, v), point);
+    assert!(surface
+        .search_nearest_parameter(point, ((0.5, 0.6), (0.1, 0.2)), 100)
+        .is_none());
+}
@@ -0,0 +1,70 @@
+use super::*;
+use std::f64::consts::PI;
+
+#[test]
+fn sweep_with_guide_is_closed() {
+    // the tube twisted by the guide, capped at both ends
+    let v = builder::vertices([
+        (1.0, 0.0, 0.0),
+        (0.0, 0.0, 0.0),
+        (0.0, 0.0, 4.0),
+        (2.0, 0.0, 0.0),
+    ]);
+    let circle = builder::rsweep(
+        &v[0],
+        Point3::new(0.5, 0.0, 0.0),
+        Vector3::unit_z(),
+        Rad(2.0 * PI),
+        4,
+    );
+    let path: Edge = builder::line(&v[1], &v[2]);
+    let guide: Edge = builder::bezier(
+        &v[3],
+        &builder::vertex(Point3::new(0.0, 2.0, 4.0)),
+        vec![Point3::new(2.0, 2.0, 2.0)],
+    );
+    let mut shell: Shell = builder::sweep_with_guide(&circle, &path, &guide).unwrap();
+    shell.extract_boundaries().into_iter().for_each(|boundary| {
+        shell.push(builder::try_attach_plane(vec![boundary.inverse()]).unwrap());
+    });
+    assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+
+    let meshed = shell.triangulation(0.01);
+    assert!(meshed.face_iter().all(|face| face.surface().is_some()));
+    let mut mesh = meshed.to_polygon();
+    mesh.put_together_same_attrs(TOLERANCE);
+    assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+}
+
+#[test]
+fn two_rail_sweep_edges_on_surfaces() {
+    // the half circle swept between the bent rails
+    let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+    let profile: Wire = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(PI), 2);
+    let (front, back) = (
+        profile.front_vertex().unwrap(),
+        profile.back_vertex().unwrap(),
+    );
+    let end = [(1.5, 3.0, 4.0), (-1.5, 4.0, 4.0)].map(|p| builder::vertex(Point3::from(p)));
+    let rail0: Edge = builder::bezier(front, &end[0], vec![Point3::new(2.0, 0.0, 3.0)]);
+    let rail1: Edge = builder::bezier(back, &end[1], vec![Point3::new(-1.0, 2.0, 3.0)]);
+    let shell: Shell = builder::two_rail_sweep(&profile, &rail0, &rail1).unwrap();
+    shell.face_iter().for_each(|face| {
+        let surface = face.surface();
+        face.edge_iter().for_each(|edge| {
+            let curve = edge.curve();
+            let (t0, t1) = curve.range_tuple();
+            (0..=10).for_each(|i| {
+                let p = curve.subs(t0 + (t1 - t0) * i as f64 / 10.0);
+                let (u, v) = surface.search_nearest_parameter(p, None, 100).unwrap();
+                assert_near!(surface.subs(u, v), p);
+            });
+        });
+    });
+
+    // the meshes of the faces are connected along the shared edge
+    let mut mesh = shell.triangulation(0.01).to_polygon();
+    mesh.put_together_same_attrs(TOLERANCE);
+    assert_eq!(mesh.shell_condition(), ShellCondition::Oriented);
+    assert_eq!(mesh.extract_boundaries().len(), 1);
+}
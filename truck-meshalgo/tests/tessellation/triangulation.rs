@@ -165,3 +165,36 @@ fn robust_closed() {
         .remove_unused_attrs();
     assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
 }
+
+#[test]
+fn seamless_cylinder() {
+    let tol_ang = std::f64::consts::PI / 6.0;
+    let mesh = special_cylinder_model()
+        .triangulation(0.01)
+        .to_seamless_polygon(tol_ang);
+    assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+    mesh.face_iter().for_each(|face| {
+        let p = face
+            .iter()
+            .map(|v| mesh.positions()[v.pos])
+            .collect::<Vec<_>>();
+        let face_normal = (p[1] - p[0]).cross(p[2] - p[0]).normalize();
+        face.iter().for_each(|v| {
+            let normal = mesh.normals()[v.nor.unwrap()];
+            assert!(normal.dot(face_normal) > f64::cos(tol_ang));
+        });
+    });
+}
+
+#[test]
+fn write_obj_bottle_round_trip() {
+    let bottle = common::shapes::cylinder();
+    let mut obj = Vec::new();
+    write_obj(&bottle, 0.01, &mut obj).unwrap();
+    let res = obj::read(obj.as_slice()).unwrap();
+    let ans = bottle.triangulation(0.01).to_polygon();
+    assert_eq!(res.positions().len(), ans.positions().len());
+    assert_eq!(res.faces().len(), ans.faces().len());
+    assert!(res.is_clung_to_by(ans.positions(), TOLERANCE));
+    assert!(ans.is_clung_to_by(res.positions(), TOLERANCE));
+}
@@ -0,0 +1,31 @@
+use super::*;
+use std::f64::consts::PI;
+
+fn area(mesh: &PolygonMesh) -> f64 {
+    let positions = mesh.positions();
+    mesh.faces().triangle_iter().fold(0.0, |sum, [a, b, c]| {
+        let (p, q, r) = (positions[a.pos], positions[b.pos], positions[c.pos]);
+        sum + (q - p).cross(r - p).magnitude() / 2.0
+    })
+}
+
+#[test]
+fn convex_rectangular_patch() {
+    // a quarter of the side of a cylinder, whose domain is a rectangle
+    let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+    let e = builder::tsweep(&v, Vector3::new(0.0, 0.0, 2.0));
+    let shell: Shell = builder::rsweep(&e, Point3::origin(), Vector3::unit_z(), Rad(PI / 2.0), 1);
+
+    let full = triangulation_with(&shell, Default::default()).to_polygon();
+    let options = TessellationOptions {
+        trim: TrimMode::AssumeConvexNoHoles,
+        ..Default::default()
+    };
+    let convex = triangulation_with(&shell, options).to_polygon();
+
+    assert!(convex.faces().triangle_iter().len() >= full.faces().triangle_iter().len());
+    // the area of the quarter of the cylinder
+    let exact = PI;
+    assert!(f64::abs(area(&convex) - exact) < 0.01, "{}", area(&convex));
+    assert!(area(&convex) >= area(&full) - TOLERANCE);
+}
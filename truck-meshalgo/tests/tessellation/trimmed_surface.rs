@@ -0,0 +1,137 @@
+use super::*;
+use std::slice::from_ref;
+
+#[test]
+fn plane_with_square_hole() {
+    let plane = Plane::new(
+        Point3::origin(),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+    );
+    let outer = [
+        Point2::new(0.0, 0.0),
+        Point2::new(1.0, 0.0),
+        Point2::new(1.0, 1.0),
+        Point2::new(0.0, 1.0),
+    ];
+    // counter-clockwise and closed, to check that the orientation is corrected
+    let hole = vec![
+        Point2::new(0.25, 0.25),
+        Point2::new(0.75, 0.25),
+        Point2::new(0.75, 0.75),
+        Point2::new(0.25, 0.75),
+        Point2::new(0.25, 0.25),
+    ];
+    let mesh = trimmed_surface_mesh(&plane, &outer, &[hole], 0.01);
+    assert!(!mesh.faces().is_empty());
+
+    let positions = mesh.positions();
+    let mut area = 0.0;
+    mesh.faces().triangle_iter().for_each(|[a, b, c]| {
+        let (p, q, r) = (positions[a.pos], positions[b.pos], positions[c.pos]);
+        let center = Point3::from_vec((p.to_vec() + q.to_vec() + r.to_vec()) / 3.0);
+        assert!(
+            !(0.25 < center.x && center.x < 0.75 && 0.25 < center.y && center.y < 0.75),
+            "a triangle is in the hole: {center:?}"
+        );
+        let normal = (q - p).cross(r - p);
+        assert!(normal.z > 0.0, "flipped triangle: {normal:?}");
+        area += normal.magnitude() / 2.0;
+    });
+    assert_near!(area, 0.75);
+}
+
+#[test]
+fn clockwise_square() {
+    let plane = Plane::new(
+        Point3::origin(),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+    );
+    let outer = [
+        Point2::new(0.0, 0.0),
+        Point2::new(0.0, 1.0),
+        Point2::new(1.0, 1.0),
+        Point2::new(1.0, 0.0),
+    ];
+    let area = |mesh: &PolygonMesh| {
+        let positions = mesh.positions();
+        mesh.faces()
+            .triangle_iter()
+            .map(|[a, b, c]| {
+                let (p, q, r) = (positions[a.pos], positions[b.pos], positions[c.pos]);
+                let normal = (q - p).cross(r - p);
+                assert!(normal.z > 0.0, "flipped triangle: {normal:?}");
+                normal.z / 2.0
+            })
+            .sum::<f64>()
+    };
+
+    let mesh = trimmed_surface_mesh(&plane, &outer, &[], 0.01);
+    assert_near!(area(&mesh), 1.0);
+
+    let contour = PolylineCurve(outer.to_vec());
+    assert!(contour.signed_area() < 0.0);
+    assert!(contour.include(Point2::new(0.5, 0.5)));
+    [FillRule::NonZero, FillRule::EvenOdd]
+        .into_iter()
+        .for_each(|fill_rule| {
+            let mesh = triangulate_contours(from_ref(&contour), fill_rule);
+            assert_near!(area(&mesh), 1.0);
+        });
+}
+
+#[test]
+fn crossing_loops() {
+    let plane = Plane::new(
+        Point3::origin(),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+    );
+    let outer = [
+        Point2::new(0.0, 0.0),
+        Point2::new(1.0, 0.0),
+        Point2::new(1.0, 1.0),
+        Point2::new(0.0, 1.0),
+    ];
+    // sticks out of the right side of the outer loop, so that two segments cross the outer loop
+    let hole = vec![
+        Point2::new(0.5, 0.25),
+        Point2::new(1.5, 0.25),
+        Point2::new(1.5, 0.75),
+        Point2::new(0.5, 0.75),
+    ];
+    let mesh = trimmed_surface_mesh(&plane, &outer, from_ref(&hole), 0.01);
+    let positions = mesh.positions();
+    let mut edges = mesh
+        .faces()
+        .triangle_iter()
+        .flat_map(|[a, b, c]| [(a.pos, b.pos), (b.pos, c.pos), (c.pos, a.pos)])
+        .map(|(a, b)| (usize::min(a, b), usize::max(a, b)))
+        .collect::<Vec<_>>();
+    edges.sort();
+    edges.dedup();
+    let edges = edges
+        .into_iter()
+        .map(|(a, b)| (positions[a], positions[b]))
+        .collect::<Vec<_>>();
+    // every segment inside the outer loop is covered by the edges of the mesh
+    [(hole[3], hole[0]), (hole[0], Point2::new(1.0, 0.25))]
+        .into_iter()
+        .chain([(Point2::new(1.0, 0.75), hole[3])])
+        .for_each(|(p, q)| {
+            let (p, q) = (Point3::new(p.x, p.y, 0.0), Point3::new(q.x, q.y, 0.0));
+            let on_segment = |r: Point3| {
+                (r - p).cross(q - p).so_small() && {
+                    let t = (r - p).dot(q - p) / (q - p).magnitude2();
+                    -TOLERANCE < t && t < 1.0 + TOLERANCE
+                }
+            };
+            let length = edges
+                .iter()
+                .filter(|(a, b)| on_segment(*a) && on_segment(*b))
+                .map(|(a, b)| a.distance(*b))
+                .sum::<f64>();
+            assert_near!(length, (q - p).magnitude(), "{p:?} - {q:?}");
+        });
+}
@@ -0,0 +1,69 @@
+use super::*;
+use std::{collections::HashSet, f64::consts::PI};
+
+#[test]
+fn wireframe_of_cylinder() {
+    let shell = &common::shapes::cylinder();
+
+    let polylines = wireframe(shell, 0.01);
+    // four arcs on each of the top and bottom circles, and four vertical lines
+    assert_eq!(polylines.len(), 12);
+    let mut ids = HashSet::new();
+    let edges = shell.edge_iter().filter(|edge| ids.insert(edge.id()));
+    polylines.iter().zip(edges).for_each(|(polyline, edge)| {
+        assert_near!(polyline.front(), edge.absolute_front().point());
+        assert_near!(polyline.back(), edge.absolute_back().point());
+    });
+
+    // the same polylines as the edges of the meshed shell
+    let meshed = shell.triangulation(0.01);
+    let mut ids = HashSet::new();
+    let edges = meshed.edge_iter().filter(|edge| ids.insert(edge.id()));
+    polylines.iter().zip(edges).for_each(|(polyline, edge)| {
+        assert_eq!(polyline, &edge.curve());
+    });
+}
+
+#[test]
+fn boundary_loops_of_face_with_hole() {
+    let v = builder::vertices([
+        Point3::new(-1.0, -1.0, 0.0),
+        Point3::new(1.0, -1.0, 0.0),
+        Point3::new(1.0, 1.0, 0.0),
+        Point3::new(-1.0, 1.0, 0.0),
+    ]);
+    let outer: Wire = (0..4)
+        .map(|i| builder::line(&v[i], &v[(i + 1) % 4]))
+        .collect();
+    let v = builder::vertex(Point3::new(0.5, 0.0, 0.0));
+    let circle: Wire = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(2.0 * PI), 4);
+    let face: Face = builder::try_attach_plane(vec![outer, circle.inverse()]).unwrap();
+
+    let loops = face_boundary_loops(&face, 0.01);
+    assert_eq!(loops.len(), 2);
+    assert!(loops
+        .iter()
+        .all(|polyline| polyline.front() == polyline.back()));
+    // the outer loop follows the square
+    assert_eq!(loops[0].len(), 5);
+    assert_near!(loops[0].front(), Point3::new(-1.0, -1.0, 0.0));
+    // the hole is divided along the arcs
+    assert!(loops[1].len() > 9);
+    assert!(loops[1]
+        .iter()
+        .all(|p| f64::abs(p.to_vec().magnitude() - 0.5) < 0.01));
+    // the hole runs in the opposite direction
+    let signed_area = |polyline: &PolylineCurve<Point3>| {
+        polyline
+            .windows(2)
+            .map(|p| p[0].x * p[1].y - p[1].x * p[0].y)
+            .sum::<f64>()
+            / 2.0
+    };
+    assert_near!(signed_area(&loops[0]), 4.0);
+    assert!(signed_area(&loops[1]) < -0.7);
+    // no duplicated points at the joints of the edges
+    assert!(loops
+        .iter()
+        .all(|polyline| polyline.windows(2).all(|p| !p[0].near(&p[1]))));
+}
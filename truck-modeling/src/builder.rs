@@ -1,9 +1,11 @@
+pub use crate::sketch::Sketch;
 use crate::{
     errors::Error,
     geom_impls::{self, ArcConnector, ExtrudeConnector, LineConnector, RevoluteConnector},
     topo_traits::*,
     Result,
 };
+use std::iter::once;
 use truck_geometry::prelude::*;
 use truck_topology::*;
 const PI: Rad<f64> = Rad(std::f64::consts::PI);
@@ -12,6 +14,7 @@ type Edge<C> = truck_topology::Edge<Point3, C>;
 type Wire<C> = truck_topology::Wire<Point3, C>;
 type Face<C, S> = truck_topology::Face<Point3, C, S>;
 type Shell<C, S> = truck_topology::Shell<Point3, C, S>;
+type Solid<C, S> = truck_topology::Solid<Point3, C, S>;
 
 /// Creates and returns a vertex by a three dimensional point.
 /// # Examples
@@ -282,6 +285,403 @@ where
     Ok(shell)
 }
 
+/// Returns a shell sweeping `profile` along the two rails `rail0` and `rail1`.
+///
+/// The rail `rail0` must start from the front vertex of `profile`, and `rail1` from the back
+/// vertex. The rails are matched by the ratio of their arc lengths, which is the second parameter
+/// of the surfaces. At each pair of matched points, the profile is moved by the similarity
+/// transformation which maps the ends of the profile to the points on the rails, and whose
+/// orientation around the chord follows the tangents of the rails. Hence the rails control the
+/// position, the scale, and the orientation of the profile.
+///
+/// Each edge of `profile` becomes a face whose surface is the NURBS surface interpolating the
+/// moved profile at 17 x 17 points. The edges of the faces are the boundary curves of the
+/// surfaces, so the boundary of the resulting shell consists of the interpolations of `profile`,
+/// the rails, and the profile moved to the ends of the rails. They are new edges sharing the
+/// vertices of `profile` and the rails.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+///
+/// // a line profile between two diverging rails
+/// let v0 = builder::vertex(Point3::new(0.0, 0.0, 0.0));
+/// let v1 = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+/// let v2 = builder::vertex(Point3::new(-1.0, 4.0, 0.0));
+/// let v3 = builder::vertex(Point3::new(2.0, 4.0, 0.0));
+/// let profile: Wire = vec![builder::line(&v0, &v1)].into();
+/// let rail0: Edge = builder::line(&v0, &v2);
+/// let rail1: Edge = builder::line(&v1, &v3);
+///
+/// let shell: Shell = builder::two_rail_sweep(&profile, &rail0, &rail1).unwrap();
+/// assert_eq!(shell.len(), 1);
+/// let boundary = shell.extract_boundaries();
+/// assert_eq!(boundary[0].len(), 4);
+///
+/// // the ruled surface between the rails
+/// let surface = shell[0].oriented_surface();
+/// const N: usize = 10;
+/// for i in 0..=N {
+///     for j in 0..=N {
+///         let (u, v) = (i as f64 / N as f64, j as f64 / N as f64);
+///         let pt = Point3::new(-v + (1.0 + 2.0 * v) * u, 4.0 * v, 0.0);
+///         assert_near!(surface.subs(u, v), pt);
+///     }
+/// }
+/// assert_near!(surface.normal(0.5, 0.5), Vector3::unit_z());
+/// ```
+/// ```
+/// // a profile of two edges follows the turning rails.
+/// use truck_modeling::*;
+///
+/// let v0 = builder::vertex(Point3::new(0.0, 0.0, 0.0));
+/// let v1 = builder::vertex(Point3::new(1.0, 0.0, 1.0));
+/// let v2 = builder::vertex(Point3::new(2.0, 0.0, 0.0));
+/// let v3 = builder::vertex(Point3::new(4.0, 4.0, 0.0));
+/// let v4 = builder::vertex(Point3::new(6.0, 2.0, 0.0));
+/// let profile: Wire = vec![builder::line(&v0, &v1), builder::line(&v1, &v2)].into();
+/// let rail0: Edge = builder::bezier(&v0, &v3, vec![Point3::new(0.0, 4.0, 0.0)]);
+/// let rail1: Edge = builder::bezier(&v2, &v4, vec![Point3::new(2.0, 2.0, 0.0)]);
+///
+/// let shell: Shell = builder::two_rail_sweep(&profile, &rail0, &rail1).unwrap();
+/// assert_eq!(shell.len(), 2);
+/// let boundary = shell.extract_boundaries();
+/// assert_eq!(boundary.len(), 1);
+/// assert_eq!(boundary[0].len(), 6);
+///
+/// // the apex of the profile at the ends of the rails
+/// let end = shell[0].boundaries()[0][1].back().point();
+/// // the profile is scaled by the ratio of the chords
+/// assert_near!(end, Point3::new(5.0, 3.0, f64::sqrt(2.0)));
+/// ```
+/// # Failures
+/// If the rails do not start from the ends of the profile, then return
+/// `Error::ProfileNotOnRails`.
+/// ```
+/// use truck_modeling::{*, errors::Error};
+///
+/// let v0 = builder::vertex(Point3::new(0.0, 0.0, 0.0));
+/// let v1 = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+/// let v2 = builder::vertex(Point3::new(0.0, 4.0, 0.0));
+/// let v3 = builder::vertex(Point3::new(1.0, 4.0, 0.0));
+/// let profile: Wire = vec![builder::line(&v0, &v1)].into();
+/// let rail0: Edge = builder::line(&v0, &v2);
+/// let rail1: Edge = builder::line(&v3, &v1);
+///
+/// assert!(matches!(
+///     builder::two_rail_sweep::<Curve, Surface>(&profile, &rail0, &rail1),
+///     Err(Error::ProfileNotOnRails),
+/// ));
+/// ```
+/// If the rails meet, or a rail runs along the chord between the rails, then the orientation of
+/// the profile is not determined and return `Error::DegenerateRails`.
+/// ```
+/// use truck_modeling::{*, errors::Error};
+///
+/// let v0 = builder::vertex(Point3::new(0.0, 0.0, 0.0));
+/// let v1 = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+/// let v2 = builder::vertex(Point3::new(2.0, 0.0, 0.0));
+/// let v3 = builder::vertex(Point3::new(3.0, 0.0, 0.0));
+/// let profile: Wire = vec![builder::line(&v0, &v1)].into();
+/// let rail0: Edge = builder::line(&v0, &v2);
+/// let rail1: Edge = builder::line(&v1, &v3);
+///
+/// assert!(matches!(
+///     builder::two_rail_sweep::<Curve, Surface>(&profile, &rail0, &rail1),
+///     Err(Error::DegenerateRails),
+/// ));
+/// ```
+pub fn two_rail_sweep<C, S>(
+    profile: &Wire<C>,
+    rail0: &Edge<C>,
+    rail1: &Edge<C>,
+) -> Result<Shell<C, S>>
+where
+    C: ParametricCurve3D + BoundedCurve + Invertible + Transformed<Matrix4>,
+    BSplineCurve<Point3>: ToSameGeometry<C>,
+    NurbsSurface<Vector4>: ToSameGeometry<S>,
+{
+    let (Some(front), Some(back)) = (profile.front_vertex(), profile.back_vertex()) else {
+        return Err(Error::ProfileNotOnRails);
+    };
+    if rail0.front() != front || rail1.front() != back {
+        return Err(Error::ProfileNotOnRails);
+    }
+
+    let (_, greville) = sweep_knots();
+    let (curve0, curve1) = (rail0.oriented_curve(), rail1.oriented_curve());
+    let params0 = arc_length_parameters(&curve0, &greville);
+    let params1 = arc_length_parameters(&curve1, &greville);
+    let frame = |t0: f64, t1: f64| {
+        let tangent = curve0.der(t0) + curve1.der(t1);
+        rail_frame(curve0.subs(t0), curve1.subs(t1), tangent)
+    };
+    let (origin, chord, frame0) = frame(params0[0], params1[0]).ok_or(Error::DegenerateRails)?;
+    let transforms = params0
+        .iter()
+        .zip(&params1)
+        .map(|(t0, t1)| {
+            let (point, vector, frame) = frame(*t0, *t1)?;
+            let linear = frame * frame0.transpose() * (vector.magnitude() / chord.magnitude());
+            Some(
+                Matrix4::from_translation(point.to_vec())
+                    * Matrix4::from(linear)
+                    * Matrix4::from_translation(-origin.to_vec()),
+            )
+        })
+        .collect::<Option<Vec<_>>>()
+        .ok_or(Error::DegenerateRails)?;
+    interpolated_sweep(profile, &transforms, Some([rail0, rail1]))
+}
+
+/// Sweeps `profile` along `path`, and orients the profile so that the reference point follows
+/// `guide`.
+///
+/// # Details
+/// The path and the guide are reparameterized by the ratio of their arc lengths, so the points
+/// of the same ratio correspond to each other. At each point of the path, the profile is moved
+/// rigidly by the frame whose first axis is along the chord from the path to the guide and whose
+/// second axis is along the tangent of the path. Hence, the reference point, i.e. the start point
+/// of the guide, follows the guide if the distance between the path and the guide is constant.
+/// The profile is moved relatively to the frame at the start point of the path, so it need not
+/// be on the path.
+///
+/// Same as [`two_rail_sweep`], each edge of `profile` becomes a face whose surface is the NURBS
+/// surface interpolating the moved profile at 17 x 17 points, and whose edges are the boundary
+/// curves of the surface. The boundary of the resulting shell consists of the interpolations of
+/// `profile` and of the profile moved to the end of the path.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// use std::f64::consts::PI;
+///
+/// // the quarter of the helix around the z-axis
+/// let points = (0..=8)
+///     .map(|i| {
+///         let t = i as f64 / 8.0;
+///         Point3::new(f64::cos(PI / 2.0 * t), f64::sin(PI / 2.0 * t), 2.0 * t)
+///     })
+///     .collect::<Vec<_>>();
+/// let knot_vec = KnotVec::uniform_knot(3, 6);
+/// let parameter_points = (0..=8)
+///     .map(|i| (knot_vec[i + 1] + knot_vec[i + 2] + knot_vec[i + 3]) / 3.0)
+///     .zip(points)
+///     .collect::<Vec<_>>();
+/// let helix = BSplineCurve::interpolate(knot_vec, parameter_points);
+///
+/// let v = builder::vertices([
+///     (0.0, 0.0, 0.0),
+///     (0.0, 0.0, 2.0),
+///     (1.0, 0.0, 0.0),
+///     (2.0, 0.0, 0.0),
+///     (0.0, 1.0, 2.0),
+/// ]);
+/// let path: Edge = builder::line(&v[0], &v[1]);
+/// let guide = Edge::new(&v[2], &v[4], Curve::BSplineCurve(helix));
+/// let profile: Wire = vec![builder::line(&v[2], &v[3])].into();
+///
+/// // the twisted ribbon
+/// let shell: Shell = builder::sweep_with_guide(&profile, &path, &guide).unwrap();
+/// assert_eq!(shell.len(), 1);
+/// let boundary = shell.extract_boundaries();
+/// assert_eq!(boundary[0].len(), 4);
+///
+/// // the profile is rotated by the right angle at the end
+/// let end = &shell[0].boundaries()[0][2];
+/// assert_near!(end.front().point(), Point3::new(0.0, 2.0, 2.0));
+/// assert_near!(end.back().point(), Point3::new(0.0, 1.0, 2.0));
+/// ```
+/// # Failures
+/// If the guide meets the path, or the guide runs along the tangent of the path, then the
+/// orientation of the profile is not determined and return `Error::DegenerateGuide`.
+/// ```
+/// use truck_modeling::{*, errors::Error};
+///
+/// let v = builder::vertices([(0.0, 0.0, 0.0), (0.0, 0.0, 2.0), (1.0, 0.0, 0.0), (0.0, 0.0, 1.0)]);
+/// let path: Edge = builder::line(&v[0], &v[1]);
+/// let guide: Edge = builder::line(&v[2], &v[3]);
+/// let profile: Wire = vec![builder::line(&v[0], &v[2])].into();
+///
+/// assert!(matches!(
+///     builder::sweep_with_guide::<Curve, Surface>(&profile, &path, &guide),
+///     Err(Error::DegenerateGuide),
+/// ));
+/// ```
+pub fn sweep_with_guide<C, S>(
+    profile: &Wire<C>,
+    path: &Edge<C>,
+    guide: &Edge<C>,
+) -> Result<Shell<C, S>>
+where
+    C: ParametricCurve3D + BoundedCurve + Invertible + Transformed<Matrix4>,
+    BSplineCurve<Point3>: ToSameGeometry<C>,
+    NurbsSurface<Vector4>: ToSameGeometry<S>,
+{
+    let (_, greville) = sweep_knots();
+    let path = ArcLengthCurve::new(path.oriented_curve(), TOLERANCE);
+    let guide = ArcLengthCurve::new(guide.oriented_curve(), TOLERANCE);
+    // the points of the same ratio of the arc lengths, and the unit tangent of the path
+    let frame = |ratio: f64| {
+        let (s0, s1) = (ratio * path.length(), ratio * guide.length());
+        rail_frame(path.subs(s0), guide.subs(s1), path.der(s0))
+    };
+    let (origin, _, frame0) = frame(greville[0]).ok_or(Error::DegenerateGuide)?;
+    let transforms = greville
+        .iter()
+        .map(|ratio| {
+            let (point, _, frame) = frame(*ratio)?;
+            Some(
+                Matrix4::from_translation(point.to_vec())
+                    * Matrix4::from(frame * frame0.transpose())
+                    * Matrix4::from_translation(-origin.to_vec()),
+            )
+        })
+        .collect::<Option<Vec<_>>>()
+        .ok_or(Error::DegenerateGuide)?;
+    interpolated_sweep(profile, &transforms, None)
+}
+
+/// the division of the interpolations of the sweeps
+const SWEEP_DIVISION: usize = 16;
+
+/// Returns the uniform cubic knot vector and its Greville abscissae for the interpolations of the
+/// sweeps.
+fn sweep_knots() -> (KnotVec, Vec<f64>) {
+    let knot_vec = KnotVec::uniform_knot(3, SWEEP_DIVISION - 2);
+    let greville = (0..=SWEEP_DIVISION)
+        .map(|i| (knot_vec[i + 1] + knot_vec[i + 2] + knot_vec[i + 3]) / 3.0)
+        .collect();
+    (knot_vec, greville)
+}
+
+/// Sweeps `profile` by the transforms at the Greville abscissae of [`sweep_knots`].
+///
+/// All edges of the faces are the iso-parametric curves on the boundaries of the surfaces, so
+/// that the boundaries are exactly on the surfaces. If `rails` are given, the sides from the front
+/// and back vertices of `profile` end at the back vertices of the rails.
+fn interpolated_sweep<C, S>(
+    profile: &Wire<C>,
+    transforms: &[Matrix4],
+    rails: Option<[&Edge<C>; 2]>,
+) -> Result<Shell<C, S>>
+where
+    C: ParametricCurve3D + BoundedCurve + Invertible + Transformed<Matrix4>,
+    BSplineCurve<Point3>: ToSameGeometry<C>,
+    NurbsSurface<Vector4>: ToSameGeometry<S>,
+{
+    let Some(front) = profile.front_vertex() else {
+        return Ok(Shell::new());
+    };
+    let (knot_vec, greville) = sweep_knots();
+    let last = transforms[SWEEP_DIVISION];
+
+    let interpolate = |points: Vec<Point3>| {
+        let parameter_points = greville.iter().copied().zip(points).collect::<Vec<_>>();
+        BSplineCurve::interpolate(knot_vec.clone(), parameter_points)
+    };
+    let path = |point: Point3| {
+        interpolate(
+            transforms
+                .iter()
+                .map(|m| m.transform_point(point))
+                .collect(),
+        )
+    };
+
+    let mut vertices = once(front)
+        .chain(profile.edge_iter().map(|edge| edge.back()))
+        .collect::<Vec<_>>();
+    // the back vertex of the closed profile is the front one
+    if profile.is_closed() {
+        vertices.pop();
+    }
+    let n = vertices.len() - 1;
+    let end_vertices = vertices
+        .iter()
+        .enumerate()
+        .map(|(i, v)| match rails {
+            Some([rail0, _]) if i == 0 => rail0.back().clone(),
+            Some([_, rail1]) if i == n => rail1.back().clone(),
+            _ => vertex(last.transform_point(v.point())),
+        })
+        .collect::<Vec<_>>();
+    // The sides are the iso-parametric curves of both adjacent surfaces, even along the rails.
+    let sides = vertices
+        .iter()
+        .zip(&end_vertices)
+        .map(|(v0, v1)| Ok(Edge::try_new(v0, v1, path(v0.point()).to_same_geometry())?))
+        .collect::<Result<Vec<_>>>()?;
+
+    profile
+        .edge_iter()
+        .enumerate()
+        .map(|(i, edge)| {
+            let next = (i + 1) % vertices.len();
+            let curve = edge.oriented_curve();
+            let (t0, t1) = curve.range_tuple();
+            let rows = greville
+                .iter()
+                .map(|u| path(curve.subs(t0 + (t1 - t0) * u)))
+                .collect::<Vec<_>>();
+            let columns = (0..=SWEEP_DIVISION)
+                .map(|j| interpolate(rows.iter().map(|row| *row.control_point(j)).collect()))
+                .collect::<Vec<_>>();
+            let control_points = (0..=SWEEP_DIVISION)
+                .map(|i| {
+                    columns
+                        .iter()
+                        .map(|column| *column.control_point(i))
+                        .collect()
+                })
+                .collect();
+            let surface = BSplineSurface::new((knot_vec.clone(), knot_vec.clone()), control_points);
+            let first_column = columns[0].clone().to_same_geometry();
+            let last_column = columns[SWEEP_DIVISION].clone().to_same_geometry();
+            let start_edge = Edge::try_new(vertices[i], vertices[next], first_column)?;
+            let end_edge = Edge::try_new(&end_vertices[i], &end_vertices[next], last_column)?;
+            let wire = wire![
+                start_edge,
+                sides[next].clone(),
+                end_edge.inverse(),
+                sides[i].inverse(),
+            ];
+            let surface = NurbsSurface::<Vector4>::from(surface);
+            Ok(Face::try_new(vec![wire], surface.to_same_geometry())?)
+        })
+        .collect()
+}
+
+/// Returns the parameters of `curve` at which the ratios of the arc length are `ratios`.
+fn arc_length_parameters<C>(curve: &C, ratios: &[f64]) -> Vec<f64>
+where C: ParametricCurve3D + BoundedCurve {
+    let curve = ArcLengthCurve::new(curve, TOLERANCE);
+    let length = curve.length();
+    ratios
+        .iter()
+        .map(|ratio| curve.parameter_of(ratio * length))
+        .collect()
+}
+
+/// Returns the origin, the chord, and the frame of the profile between the points `point0` and
+/// `point1` on the rails. The frame is the orthonormal matrix whose first column is along the
+/// chord and whose second column is along `tangent` of the rails.
+fn rail_frame(
+    point0: Point3,
+    point1: Point3,
+    tangent: Vector3,
+) -> Option<(Point3, Vector3, Matrix3)> {
+    let chord = point1 - point0;
+    if chord.magnitude().so_small() {
+        return None;
+    }
+    let x = chord.normalize();
+    let y = tangent - x * x.dot(tangent);
+    if y.magnitude().so_small() {
+        return None;
+    }
+    let y = y.normalize();
+    Some((point0, chord, Matrix3::from_cols(x, y, x.cross(y))))
+}
+
 /// Try attatiching a plane whose boundary is `wire`.
 /// # Examples
 /// ```
@@ -357,6 +757,199 @@ where
     Ok(Face::new_unchecked(wires, plane.to_same_geometry()))
 }
 
+/// Creates a planar face bounded by the closed wire `wire`.
+///
+/// Same as [`try_attach_plane`] with the single wire. The plane is fitted to the wire, and its
+/// normal is oriented so that the wire goes around counter-clockwise, i.e. the oriented normal
+/// of the face is the right-handed one with respect to the direction of the wire.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let v = builder::vertices([
+///     (0.0, 0.0, 1.0),
+///     (1.0, 0.0, 1.0),
+///     (1.0, 1.0, 1.0),
+///     (0.0, 1.0, 1.0),
+/// ]);
+/// let wire: Wire = (0..4).map(|i| builder::line(&v[i], &v[(i + 1) % 4])).collect();
+/// let face: Face = builder::planar_face(wire.clone()).unwrap();
+/// assert_eq!(face.boundaries(), vec![wire.clone()]);
+/// let normal = face.oriented_surface().normal(0.5, 0.5);
+/// assert_near!(normal, Vector3::unit_z());
+///
+/// // the reversed wire makes the face facing the opposite side.
+/// let face: Face = builder::planar_face(wire.inverse()).unwrap();
+/// let normal = face.oriented_surface().normal(0.5, 0.5);
+/// assert_near!(normal, -Vector3::unit_z());
+/// ```
+/// # Failures
+/// - If `wire` is not closed, then returns `Error::FromTopology(NotClosedWire)`.
+/// - If `wire` is not on one plane within `TOLERANCE`, then returns `Error::WireNotInOnePlane`.
+/// ```
+/// use truck_modeling::{*, errors::Error};
+/// let v = builder::vertices([
+///     (0.0, 0.0, 0.0),
+///     (1.0, 0.0, 0.0),
+///     (1.0, 1.0, 0.1),
+///     (0.0, 1.0, 0.0),
+/// ]);
+/// let open: Wire = (0..3).map(|i| builder::line(&v[i], &v[i + 1])).collect();
+/// assert_eq!(
+///     builder::planar_face::<_, Surface>(open).unwrap_err(),
+///     Error::FromTopology(truck_topology::errors::Error::NotClosedWire),
+/// );
+/// let skewed: Wire = (0..4).map(|i| builder::line(&v[i], &v[(i + 1) % 4])).collect();
+/// assert_eq!(
+///     builder::planar_face::<_, Surface>(skewed).unwrap_err(),
+///     Error::WireNotInOnePlane,
+/// );
+/// ```
+#[inline(always)]
+pub fn planar_face<C, S>(wire: Wire<C>) -> Result<Face<C, S>>
+where
+    C: ParametricCurve3D + BoundedCurve,
+    Plane: IncludeCurve<C> + ToSameGeometry<S>, {
+    try_attach_plane(vec![wire])
+}
+
+/// Splits a face of `shell` into two faces along a curve on the face.
+///
+/// `curve` is the curve on the surface of the face, given by a curve in the parameter space.
+/// The new edge along `curve` is shared by the two new faces, which have the same surface as the
+/// original face.
+///
+/// # Details
+/// - If the end points of `curve` are different, they must lie on the boundary of the face, at
+///   vertices or on edges. The edges containing the end points are cut in the whole shell,
+///   so the adjacent faces keep sharing them. The face must have only one boundary.
+/// - If `curve` is closed, it must lie in the interior of the face. Then, the region enclosed
+///   by `curve` becomes a new face, and `curve` becomes a new hole of the original face.
+///
+/// The intersections between `curve` and the boundaries of the face are not checked.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let v = builder::vertex(Point3::new(0.0, 0.0, 0.0));
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let face: Face = builder::tsweep(&e, Vector3::unit_y());
+/// let mut shell: Shell = vec![face.clone()].into();
+///
+/// // the diagonal of the square
+/// let plane = Plane::new(Point3::origin(), Point3::new(1.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0));
+/// let diagonal = Line(Point2::new(0.0, 0.0), Point2::new(1.0, 1.0));
+/// builder::split_face(&mut shell, face.id(), PCurve::new(diagonal, plane)).unwrap();
+/// assert_eq!(shell.len(), 2);
+/// assert!(shell.iter().all(|face| face.boundaries()[0].len() == 3));
+/// assert!(shell.is_geometric_consistent());
+/// ```
+/// # Failures
+/// - If the face is not in `shell`, then returns `Error::FaceNotInShell`.
+/// - If an end point of `curve` is not on the boundary of the face, then returns
+///   `Error::CurveNotOnBoundary`.
+/// - If the face has several boundaries, then returns `Error::FaceHasSeveralBoundaries`.
+///
+/// The shell is not changed on failures.
+pub fn split_face<C, S, D, T>(
+    shell: &mut Shell<C, S>,
+    face_id: FaceID<S>,
+    curve: PCurve<D, T>,
+) -> Result<()>
+where
+    C: Cut<Point = Point3> + SearchParameter<D1, Point = Point3>,
+    S: Clone,
+    D: ParametricCurve2D + BoundedCurve + Cut,
+    T: ParametricSurface3D + Clone,
+    PCurve<D, T>: ToSameGeometry<C>,
+{
+    let idx = shell
+        .iter()
+        .position(|face| face.id() == face_id)
+        .ok_or(Error::FaceNotInShell)?;
+    let (p0, p1) = (curve.front(), curve.back());
+    if p0.near(&p1) {
+        let (face0, face1) = split_face_by_loop(&shell[idx], curve)?;
+        shell[idx] = face0;
+        shell.push(face1);
+        return Ok(());
+    }
+    let mut new_shell = shell.clone();
+    let v0 = boundary_vertex(&mut new_shell, idx, p0)?;
+    let v1 = boundary_vertex(&mut new_shell, idx, p1)?;
+    if v0 == v1 {
+        return Err(Error::CurveNotOnBoundary);
+    }
+    let edge = Edge::new(&v0, &v1, curve.to_same_geometry());
+    let (face0, face1) = new_shell[idx]
+        .cut_by_wire(vec![edge].into())
+        .ok_or(Error::FaceHasSeveralBoundaries)?;
+    new_shell[idx] = face0;
+    new_shell.push(face1);
+    *shell = new_shell;
+    Ok(())
+}
+
+/// Returns the vertex of the boundary of the `idx`th face at `point`, cutting an edge if needed.
+fn boundary_vertex<C, S>(shell: &mut Shell<C, S>, idx: usize, point: Point3) -> Result<Vertex>
+where C: Cut<Point = Point3> + SearchParameter<D1, Point = Point3> {
+    if let Some(v) = shell[idx].vertex_iter().find(|v| v.point().near(&point)) {
+        return Ok(v);
+    }
+    let vertex = Vertex::new(point);
+    let edges = shell[idx].edge_iter().collect::<Vec<_>>();
+    edges
+        .into_iter()
+        .find(|edge| shell.cut_edge(edge.id(), &vertex).is_some())
+        .map(|_| vertex)
+        .ok_or(Error::CurveNotOnBoundary)
+}
+
+/// Splits `face` into the region enclosed by the closed `curve` and the rest.
+fn split_face_by_loop<C, S, D, T>(
+    face: &Face<C, S>,
+    mut curve: PCurve<D, T>,
+) -> Result<(Face<C, S>, Face<C, S>)>
+where
+    S: Clone,
+    D: ParametricCurve2D + BoundedCurve + Cut,
+    T: ParametricSurface3D + Clone,
+    PCurve<D, T>: ToSameGeometry<C>,
+{
+    const DIVISION: usize = 64;
+    let (t0, t1) = curve.range_tuple();
+    let area = (0..DIVISION).fold(0.0, |sum, i| {
+        let p = curve
+            .curve()
+            .subs(t0 + (t1 - t0) * i as f64 / DIVISION as f64);
+        let q = curve
+            .curve()
+            .subs(t0 + (t1 - t0) * (i + 1) as f64 / DIVISION as f64);
+        sum + (p.x + q.x) * (q.y - p.y)
+    });
+    let t = (t0 + t1) / 2.0;
+    let v0 = Vertex::new(curve.front());
+    let v1 = Vertex::new(curve.subs(t));
+    let latter = curve.cut(t);
+    let mut wire: Wire<C> = vec![
+        Edge::new(&v0, &v1, curve.to_same_geometry()),
+        Edge::new(&v1, &v0, latter.to_same_geometry()),
+    ]
+    .into();
+    // The absolute boundary of the inner face is counter-clockwise in the parameter space.
+    if area < 0.0 {
+        wire.invert();
+    }
+    let mut inner = Face::try_new(vec![wire.clone()], face.surface())?;
+    if !face.orientation() {
+        inner.invert();
+    }
+    let mut outer = face.clone();
+    outer.try_add_boundary(match face.orientation() {
+        true => wire.inverse(),
+        false => wire,
+    })?;
+    Ok((outer, inner))
+}
+
 /// Returns another topology whose points, curves, and surfaces are cloned.
 /// # Examples
 /// ```
@@ -726,6 +1319,275 @@ where
     shell
 }
 
+/// Sweeps a closed profile by the rotation, and caps the ends by planar faces if `cap_ends`.
+/// # Details
+/// The shell is the same as [`rsweep`] of `wire`. If `cap_ends` and the rotation is partial, i.e.
+/// the absolute value of `angle` is less than 2π rad, then the start and end cross-sections are
+/// closed by the planar faces sharing the boundary edges of the shell, so the result is a closed
+/// shell. The caps are oriented consistently with the other faces, and all faces are inverted if
+/// needed so that the faces are oriented outward, whatever the orientation of `wire` is. For the
+/// whole revolution, the shell is closed without caps and `cap_ends` is ignored.
+/// # Remarks
+/// `axis` must be normalized. If not, panics occurs in debug mode.
+/// # Panics
+/// The same as [`rsweep`].
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// use std::f64::consts::PI;
+///
+/// // the square profile on the xz-plane
+/// let v = builder::vertices([(2.0, 0.0, -0.5), (3.0, 0.0, -0.5), (3.0, 0.0, 0.5), (2.0, 0.0, 0.5)]);
+/// let wire: Wire = (0..4).map(|i| builder::line(&v[i], &v[(i + 1) % 4])).collect();
+///
+/// let shell: Shell =
+///     builder::revolve(&wire, Point3::origin(), Vector3::unit_z(), Rad(PI / 2.0), 1, true).unwrap();
+/// assert_eq!(shell.len(), 6);
+/// assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+/// let solid = Solid::new(vec![shell]);
+/// # assert!(solid.is_geometric_consistent());
+///
+/// // without caps, the ends are open
+/// let shell: Shell =
+///     builder::revolve(&wire, Point3::origin(), Vector3::unit_z(), Rad(PI / 2.0), 1, false).unwrap();
+/// assert_eq!(shell.len(), 4);
+/// assert_eq!(shell.extract_boundaries().len(), 2);
+/// ```
+/// # Failures
+/// If `cap_ends` and the rotation is partial, the end cross-sections must be closed planar wires.
+/// - If `wire` is not closed, then returns `Error::FromTopology(NotClosedWire)`.
+/// - If `wire` is not on one plane within `TOLERANCE`, then returns `Error::WireNotInOnePlane`.
+pub fn revolve<C, S, R>(
+    wire: &Wire<C>,
+    origin: Point3,
+    axis: Vector3,
+    angle: R,
+    division: usize,
+    cap_ends: bool,
+) -> Result<Shell<C, S>>
+where
+    C: ParametricCurve3D + BoundedCurve + Cut + Invertible + Transformed<Matrix4>,
+    S: Invertible,
+    R: Into<Rad<f64>>,
+    Processor<TrimmedCurve<UnitCircle<Point3>>, Matrix4>: ToSameGeometry<C>,
+    RevolutedCurve<C>: ToSameGeometry<S>,
+    Plane: IncludeCurve<C> + ToSameGeometry<S>,
+{
+    let angle = angle.into();
+    let mut shell: Shell<C, S> = rsweep(wire, origin, axis, angle, division);
+    if !cap_ends || angle.0.abs() >= 2.0 * PI.0 {
+        return Ok(shell);
+    }
+    if !wire.is_closed() {
+        return Err(Error::FromTopology(errors::Error::NotClosedWire));
+    }
+    let profile = wire.vertex_iter().map(|v| v.point()).collect::<Vec<_>>();
+    let sweep_axis = f64::signum(angle.0) * axis;
+    let mut start_inward = None;
+    let caps = shell
+        .extract_boundaries()
+        .into_iter()
+        .map(|boundary| {
+            let boundary = boundary.inverse();
+            let is_start = boundary
+                .vertex_iter()
+                .all(|v| profile.iter().any(|p| p.near(&v.point())));
+            if is_start {
+                // the start cap is outward if its normal is against the rotation
+                let (normal, center) = normal_and_center(&boundary);
+                let direction = sweep_axis.cross(center - origin);
+                start_inward = Some(normal.dot(direction) > 0.0);
+            }
+            planar_face(boundary)
+        })
+        .collect::<Result<Vec<Face<C, S>>>>()?;
+    shell.extend(caps);
+    if start_inward == Some(true) {
+        shell.face_iter_mut().for_each(|face| {
+            face.invert();
+        });
+    }
+    Ok(shell)
+}
+
+/// Returns the normal vector by Newell's method and the centroid of the sample points of `wire`.
+fn normal_and_center<C>(wire: &Wire<C>) -> (Vector3, Point3)
+where C: ParametricCurve3D + BoundedCurve + Invertible {
+    const DIVISION: usize = 8;
+    let points = wire
+        .edge_iter()
+        .flat_map(|edge| {
+            let curve = edge.oriented_curve();
+            let (t0, t1) = curve.range_tuple();
+            (0..DIVISION).map(move |i| curve.subs(t0 + (t1 - t0) * i as f64 / DIVISION as f64))
+        })
+        .collect::<Vec<_>>();
+    let normal = (0..points.len()).fold(Vector3::zero(), |sum, i| {
+        let (p, q) = (points[i], points[(i + 1) % points.len()]);
+        sum + p.to_vec().cross(q.to_vec())
+    });
+    let sum = points
+        .iter()
+        .fold(Vector3::zero(), |sum, p| sum + p.to_vec());
+    (normal, Point3::from_vec(sum / points.len() as f64))
+}
+
+/// Extrudes a planar face bounded by line segments, tilting the side walls by the draft angle.
+/// # Details
+/// The face is extruded by `height` along `direction`, and each top edge is offset from the
+/// translated bottom edge by `height * tan(draft_angle)` toward the inside of the face, so each side
+/// wall is a planar face tilted by `draft_angle` from the extrusion direction around its bottom
+/// edge. The positive angle shrinks the top face and the negative angle expands it. The holes of
+/// the face are drafted in the same way, i.e. they are expanded by the positive angle.
+///
+/// The bottom face is the inverse of `face` sharing its edges, and the faces of the solid are
+/// oriented outward, whether `direction` is on the front or back side of `face`. The intersections
+/// of the offset edges are not checked, so the angle must be small enough that the top edges do
+/// not vanish.
+/// # Remarks
+/// `direction` is normalized in this function, so its length does not matter. The top edges are
+/// offset in the plane of the top face, so the walls are tilted by exactly `draft_angle` only if
+/// `direction` is perpendicular to `face`. For an oblique direction, the walls are still planar,
+/// but their angles from `direction` differ from `draft_angle`.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let v = builder::vertices([
+///     (0.0, 0.0, 0.0),
+///     (2.0, 0.0, 0.0),
+///     (2.0, 2.0, 0.0),
+///     (0.0, 2.0, 0.0),
+/// ]);
+/// let wire: Wire = (0..4).map(|i| builder::line(&v[i], &v[(i + 1) % 4])).collect();
+/// let face: Face = builder::planar_face(wire).unwrap();
+///
+/// // the frustum of the square pyramid whose side walls are tilted by 45 degrees
+/// let solid: Solid =
+///     builder::extrude_with_draft(&face, Vector3::unit_z(), 0.5, Deg(45.0)).unwrap();
+/// let shell = &solid.boundaries()[0];
+/// assert_eq!(shell.len(), 6);
+/// let top = shell.last().unwrap();
+/// let corners = top.vertex_iter().map(|v| v.point()).collect::<Vec<_>>();
+/// assert!(corners.iter().any(|p| p.near(&Point3::new(0.5, 0.5, 0.5))));
+/// assert!(corners.iter().any(|p| p.near(&Point3::new(1.5, 1.5, 0.5))));
+/// # assert!(solid.is_geometric_consistent());
+/// ```
+/// # Failures
+/// - If `face` is not on one plane within `TOLERANCE`, then returns `Error::WireNotInOnePlane`.
+/// - If an edge of `face` is not a line segment, then returns `Error::ProfileNotPolygonal`.
+/// - If `direction` is parallel to the plane of `face`, then returns
+///   `Error::DirectionAlongProfile`.
+/// - If two adjacent edges of `face` are antiparallel, then returns `Error::ProfileFoldsBack`.
+pub fn extrude_with_draft<C, S, R>(
+    face: &Face<C, S>,
+    direction: Vector3,
+    height: f64,
+    draft_angle: R,
+) -> Result<Solid<C, S>>
+where
+    C: ParametricCurve3D + BoundedCurve + Invertible,
+    S: Clone + Invertible,
+    R: Into<Rad<f64>>,
+    Line<Point3>: ToSameGeometry<C>,
+    Plane: IncludeCurve<C> + ToSameGeometry<S>,
+{
+    let boundaries = face.boundaries();
+    let (normal, center) = boundaries.iter().map(normal_and_center).fold(
+        (Vector3::zero(), Point3::origin()),
+        |(n, c), (normal, center)| (n + normal, c + center.to_vec() / boundaries.len() as f64),
+    );
+    if normal.so_small() {
+        return Err(Error::WireNotInOnePlane);
+    }
+    let normal = normal.normalize();
+    let on_plane = face
+        .vertex_iter()
+        .all(|v| (v.point() - center).dot(normal).so_small());
+    if !on_plane {
+        return Err(Error::WireNotInOnePlane);
+    }
+    let straight = face.edge_iter().all(|edge| {
+        let curve = edge.curve();
+        let (t0, t1) = curve.range_tuple();
+        let (p, q) = (curve.subs(t0), curve.subs(t1));
+        let dir = (q - p).normalize();
+        (1..4).all(|i| {
+            let r = curve.subs(t0 + (t1 - t0) * i as f64 / 4.0);
+            (r - p - dir * (r - p).dot(dir)).so_small()
+        })
+    });
+    if !straight {
+        return Err(Error::ProfileNotPolygonal);
+    }
+    let vector = direction.normalize() * height;
+    if vector.dot(normal).so_small() {
+        return Err(Error::DirectionAlongProfile);
+    }
+    // the profile facing the extrusion, whose inverse is the bottom face
+    let (profile, normal) = match vector.dot(normal) > 0.0 {
+        true => (face.clone(), normal),
+        false => (face.inverse(), -normal),
+    };
+    let boundaries = profile.boundaries();
+    let offset = vector.magnitude() * f64::tan(draft_angle.into().0);
+    let drafted = boundaries
+        .iter()
+        .map(|wire| {
+            // the outward normals of the edges in the plane
+            let outwards = wire
+                .edge_iter()
+                .map(|edge| {
+                    (edge.back().point() - edge.front().point())
+                        .cross(normal)
+                        .normalize()
+                })
+                .collect::<Vec<_>>();
+            let len = outwards.len();
+            let top_vertices = wire
+                .vertex_iter()
+                .enumerate()
+                .map(|(i, v)| {
+                    let (m0, m1) = (outwards[(i + len - 1) % len], outwards[i]);
+                    if (1.0 + m0.dot(m1)).so_small() {
+                        return Err(Error::ProfileFoldsBack);
+                    }
+                    let miter = (m0 + m1) / (1.0 + m0.dot(m1));
+                    Ok(vertex(v.point() + vector - offset * miter))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let verticals = wire
+                .vertex_iter()
+                .zip(&top_vertices)
+                .map(|(v, w)| line(&v, w))
+                .collect::<Vec<Edge<C>>>();
+            let top_wire = (0..len)
+                .map(|i| line(&top_vertices[i], &top_vertices[(i + 1) % len]))
+                .collect::<Wire<C>>();
+            let walls = wire
+                .edge_iter()
+                .enumerate()
+                .map(|(i, edge)| {
+                    let wall = [
+                        edge.clone(),
+                        verticals[(i + 1) % len].clone(),
+                        top_wire[i].inverse(),
+                        verticals[i].inverse(),
+                    ];
+                    planar_face(wall.into_iter().collect())
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Ok((walls, top_wire))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let (walls, top_wires): (Vec<_>, Vec<_>) = drafted.into_iter().unzip();
+    let top = try_attach_plane(top_wires)?;
+    let shell = once(profile.inverse())
+        .chain(walls.into_iter().flatten())
+        .chain(once(top))
+        .collect::<Shell<C, S>>();
+    Ok(Solid::try_new(vec![shell])?)
+}
+
 #[cfg(test)]
 mod partial_torus {
     use crate::*;
@@ -798,3 +1660,447 @@ mod partial_torus {
         assert!(torus.is_geometric_consistent());
     }
 }
+
+#[cfg(test)]
+mod split_face {
+    use crate::*;
+    use std::collections::HashSet;
+    use truck_topology::shell::ShellCondition;
+
+    fn plane_of(face: &Face) -> Plane {
+        match face.surface() {
+            Surface::Plane(plane) => plane,
+            _ => panic!("the faces of a cuboid must be planes"),
+        }
+    }
+
+    #[test]
+    fn split_cube_face() {
+        let bdd = BoundingBox::from_iter([Point3::origin(), Point3::new(1.0, 1.0, 1.0)]);
+        let solid: Solid = primitive::cuboid(bdd);
+        let mut shell = solid.into_boundaries().pop().unwrap();
+        let top = shell
+            .iter()
+            .find(|face| face.vertex_iter().all(|v| v.point().z.near(&1.0)))
+            .unwrap()
+            .clone();
+        let plane = plane_of(&top);
+        let uv = |p: Point3| Point2::from(plane.search_parameter(p, None, 1).unwrap());
+        let line = Line(
+            uv(Point3::new(0.5, 0.0, 1.0)),
+            uv(Point3::new(0.5, 1.0, 1.0)),
+        );
+        builder::split_face(&mut shell, top.id(), PCurve::new(line, plane)).unwrap();
+        assert_eq!(shell.len(), 7);
+        let vertices = shell.vertex_iter().map(|v| v.id()).collect::<HashSet<_>>();
+        assert_eq!(vertices.len(), 10);
+        assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+        assert!(shell.is_geometric_consistent());
+        assert!(Solid::try_new(vec![shell.clone()]).is_ok());
+
+        // The split faces can not be found by the old id.
+        let line = Line(Point2::new(0.0, 0.0), Point2::new(1.0, 1.0));
+        assert_eq!(
+            builder::split_face(&mut shell, top.id(), PCurve::new(line, plane)),
+            Err(errors::Error::FaceNotInShell),
+        );
+
+        // The curve must end on the boundary.
+        let face = shell[0].clone();
+        let plane = plane_of(&face);
+        let line = Line(Point2::new(0.25, 0.25), Point2::new(0.75, 0.75));
+        assert_eq!(
+            builder::split_face(&mut shell, face.id(), PCurve::new(line, plane)),
+            Err(errors::Error::CurveNotOnBoundary),
+        );
+        assert_eq!(shell.len(), 7);
+    }
+
+    #[test]
+    fn split_face_by_loop() {
+        let v = builder::vertex(Point3::new(0.0, 0.0, 0.0));
+        let e = builder::tsweep(&v, Vector3::unit_x());
+        let face: Face = builder::tsweep(&e, Vector3::unit_y());
+        let plane = plane_of(&face);
+        let mut shell: Shell = vec![face.clone()].into();
+        // a clockwise square in the parameter space
+        let square = BSplineCurve::new(
+            KnotVec::uniform_knot(1, 4),
+            vec![
+                Point2::new(0.25, 0.25),
+                Point2::new(0.25, 0.75),
+                Point2::new(0.75, 0.75),
+                Point2::new(0.75, 0.25),
+                Point2::new(0.25, 0.25),
+            ],
+        );
+        builder::split_face(&mut shell, face.id(), PCurve::new(square, plane)).unwrap();
+        assert_eq!(shell.len(), 2);
+        assert_eq!(shell[0].boundaries().len(), 2);
+        assert_eq!(shell[1].boundaries().len(), 1);
+        assert_eq!(shell.shell_condition(), ShellCondition::Oriented);
+        assert!(shell.is_geometric_consistent());
+        // The inner face has the same orientation as the original face.
+        let area = |face: &Face| {
+            let vertices = face.boundaries()[0]
+                .edge_iter()
+                .flat_map(|edge| {
+                    let curve = edge.oriented_curve();
+                    let (t0, t1) = curve.range_tuple();
+                    (0..8).map(move |i| curve.subs(t0 + (t1 - t0) * i as f64 / 8.0))
+                })
+                .collect::<Vec<_>>();
+            (0..vertices.len()).fold(0.0, |sum, i| {
+                let (p, q) = (vertices[i], vertices[(i + 1) % vertices.len()]);
+                sum + (p.x + q.x) * (q.y - p.y)
+            })
+        };
+        assert!(area(&shell[1]) * area(&face) > 0.0);
+    }
+}
+
+#[cfg(test)]
+mod revolve {
+    use crate::*;
+    use std::f64::consts::PI;
+    use truck_topology::shell::ShellCondition;
+
+    /// Returns the normal of the face at the parameter of the point on the face.
+    fn normal_at(face: &Face, point: Point3) -> Vector3 {
+        let surface = face.oriented_surface();
+        let (u, v) = surface.search_parameter(point, None, 100).unwrap();
+        surface.normal(u, v)
+    }
+
+    #[test]
+    fn capped_quarter() {
+        let v = builder::vertices([
+            (2.0, 0.0, -0.5),
+            (3.0, 0.0, -0.5),
+            (3.0, 0.0, 0.5),
+            (2.0, 0.0, 0.5),
+        ]);
+        let wire: Wire = (0..4)
+            .map(|i| builder::line(&v[i], &v[(i + 1) % 4]))
+            .collect();
+        [wire.clone(), wire.inverse()].into_iter().for_each(|wire| {
+            [Rad(PI / 2.0), Rad(-PI / 2.0)]
+                .into_iter()
+                .for_each(|angle| {
+                    let shell: Shell = builder::revolve(
+                        &wire,
+                        Point3::origin(),
+                        Vector3::unit_z(),
+                        angle,
+                        2,
+                        true,
+                    )
+                    .unwrap();
+                    assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+                    assert!(shell.is_geometric_consistent());
+                    let end = Matrix3::from_angle_z(angle);
+                    // the outer side, the start cap and the end cap
+                    let checks = [
+                        (Point3::new(3.0, 0.0, 0.0), Vector3::unit_x()),
+                        (
+                            Point3::new(2.5, 0.0, 0.0),
+                            -angle.0.signum() * Vector3::unit_y(),
+                        ),
+                        (
+                            Point3::from_vec(end * Vector3::new(2.5, 0.0, 0.0)),
+                            angle.0.signum() * (end * Vector3::unit_y()),
+                        ),
+                    ];
+                    checks.into_iter().for_each(|(point, outward)| {
+                        let face = shell
+                            .iter()
+                            .find(|face| {
+                                let surface = face.oriented_surface();
+                                surface
+                                    .search_parameter(point, None, 100)
+                                    .is_some_and(|(u, v)| surface.subs(u, v).near(&point))
+                            })
+                            .unwrap();
+                        assert!(normal_at(face, point).dot(outward) > 0.0);
+                    });
+                    Solid::new(vec![shell]);
+                })
+        });
+
+        let open: Wire = (0..3).map(|i| builder::line(&v[i], &v[i + 1])).collect();
+        let res: Result<Shell> = builder::revolve(
+            &open,
+            Point3::origin(),
+            Vector3::unit_z(),
+            Rad(1.0),
+            1,
+            true,
+        );
+        assert_eq!(
+            res.unwrap_err(),
+            errors::Error::FromTopology(truck_topology::errors::Error::NotClosedWire)
+        );
+    }
+}
+
+#[cfg(test)]
+mod extrude_with_draft {
+    use crate::{errors::Error, *};
+    use truck_topology::shell::ShellCondition;
+
+    fn square(size: f64) -> Wire {
+        let v = builder::vertices([
+            (-size, -size, 0.0),
+            (size, -size, 0.0),
+            (size, size, 0.0),
+            (-size, size, 0.0),
+        ]);
+        (0..4)
+            .map(|i| builder::line(&v[i], &v[(i + 1) % 4]))
+            .collect()
+    }
+
+    /// Returns the bounding box of the face whose vertices are on the plane `z`.
+    fn section(solid: &Solid, z: f64) -> BoundingBox<Point3> {
+        let shell = &solid.boundaries()[0];
+        let face = shell
+            .iter()
+            .find(|face| face.vertex_iter().all(|v| v.point().z.near(&z)))
+            .unwrap();
+        face.vertex_iter().map(|v| v.point()).collect()
+    }
+
+    fn assert_outward(solid: &Solid) {
+        let shell = &solid.boundaries()[0];
+        assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+        assert!(solid.is_geometric_consistent());
+        let center = shell
+            .vertex_iter()
+            .map(|v| v.point().to_vec())
+            .sum::<Vector3>()
+            / shell.vertex_iter().count() as f64;
+        shell.iter().for_each(|face| {
+            let surface = face.oriented_surface();
+            let p = face.vertex_iter().next().unwrap().point();
+            let (u, v) = surface.search_parameter(p, None, 100).unwrap();
+            let q = face
+                .vertex_iter()
+                .map(|v| v.point().to_vec())
+                .sum::<Vector3>()
+                / face.vertex_iter().count() as f64;
+            assert!(surface.normal(u, v).dot(q - center) > 0.0);
+        });
+    }
+
+    #[test]
+    fn drafted_square() {
+        let face: Face = builder::planar_face(square(1.0)).unwrap();
+        let height = 2.0;
+        let angle = Deg(10.0);
+        let offset = height * f64::tan(Rad::from(angle).0);
+
+        let solid = builder::extrude_with_draft(&face, Vector3::unit_z(), height, angle).unwrap();
+        assert_outward(&solid);
+        let top = section(&solid, height);
+        assert_near!(top.max(), Point3::new(1.0 - offset, 1.0 - offset, height));
+        assert_near!(top.min(), Point3::new(offset - 1.0, offset - 1.0, height));
+        assert!(top.diameter() < section(&solid, 0.0).diameter());
+
+        // the negative draft expands the top face
+        let solid = builder::extrude_with_draft(&face, Vector3::unit_z(), height, -angle).unwrap();
+        assert_outward(&solid);
+        let top = section(&solid, height);
+        assert_near!(top.max(), Point3::new(1.0 + offset, 1.0 + offset, height));
+
+        // extruding to the back side of the face
+        let solid = builder::extrude_with_draft(&face, -Vector3::unit_z(), height, angle).unwrap();
+        assert_outward(&solid);
+        let top = section(&solid, -height);
+        assert_near!(top.max(), Point3::new(1.0 - offset, 1.0 - offset, -height));
+
+        // no draft is the same as the translational sweep
+        let solid =
+            builder::extrude_with_draft(&face, Vector3::unit_z(), height, Rad(0.0)).unwrap();
+        assert_outward(&solid);
+        assert_near!(section(&solid, height).max(), Point3::new(1.0, 1.0, height));
+    }
+
+    #[test]
+    fn drafted_frame() {
+        let face: Face =
+            builder::try_attach_plane(vec![square(2.0), square(1.0).inverse()]).unwrap();
+        let solid = builder::extrude_with_draft(&face, Vector3::unit_z(), 0.25, Deg(45.0)).unwrap();
+        assert_eq!(
+            solid.boundaries()[0].shell_condition(),
+            ShellCondition::Closed
+        );
+        assert!(solid.is_geometric_consistent());
+        let top = solid.boundaries()[0].last().unwrap().clone();
+        assert_near!(top.oriented_surface().normal(0.0, 0.0), Vector3::unit_z());
+        let boundaries = top.boundaries();
+        assert_eq!(boundaries.len(), 2);
+        let bdd = |wire: &Wire| {
+            wire.vertex_iter()
+                .map(|v| v.point())
+                .collect::<BoundingBox<_>>()
+        };
+        // the outer boundary shrinks and the hole expands
+        assert_near!(bdd(&boundaries[0]).max(), Point3::new(1.75, 1.75, 0.25));
+        assert_near!(bdd(&boundaries[1]).max(), Point3::new(1.25, 1.25, 0.25));
+        assert_eq!(solid.boundaries()[0].len(), 10);
+    }
+
+    #[test]
+    fn failures() {
+        let face: Face = builder::planar_face(square(1.0)).unwrap();
+        assert_eq!(
+            builder::extrude_with_draft(&face, Vector3::unit_x(), 1.0, Deg(5.0)).unwrap_err(),
+            Error::DirectionAlongProfile,
+        );
+        let v = builder::vertices([(1.0, 0.0, 0.0), (-1.0, 0.0, 0.0)]);
+        let wire: Wire = vec![
+            builder::circle_arc(&v[0], &v[1], Point3::new(0.0, 1.0, 0.0)),
+            builder::line(&v[1], &v[0]),
+        ]
+        .into();
+        let face: Face = builder::planar_face(wire).unwrap();
+        assert_eq!(
+            builder::extrude_with_draft(&face, Vector3::unit_z(), 1.0, Deg(5.0)).unwrap_err(),
+            Error::ProfileNotPolygonal,
+        );
+        // the profile turns back at the second vertex
+        let v = builder::vertices([
+            (0.0, 0.0, 0.0),
+            (2.0, 0.0, 0.0),
+            (1.0, 0.0, 0.0),
+            (0.0, 1.0, 0.0),
+        ]);
+        let wire: Wire = (0..4)
+            .map(|i| builder::line(&v[i], &v[(i + 1) % 4]))
+            .collect();
+        let face: Face = builder::planar_face(wire).unwrap();
+        assert_eq!(
+            builder::extrude_with_draft(&face, Vector3::unit_z(), 1.0, Deg(5.0)).unwrap_err(),
+            Error::ProfileFoldsBack,
+        );
+    }
+}
+
+#[cfg(test)]
+mod sweep_with_guide {
+    use crate::{errors::Error, *};
+    use std::f64::consts::PI;
+    use truck_topology::shell::ShellCondition;
+
+    const HEIGHT: f64 = 4.0;
+
+    /// the point on the unit helix, turning once while rising by `HEIGHT`
+    fn helix_point(t: f64) -> Point3 {
+        Point3::new(f64::cos(2.0 * PI * t), f64::sin(2.0 * PI * t), HEIGHT * t)
+    }
+
+    fn helix(v0: &Vertex, v1: &Vertex) -> Edge {
+        const N: usize = 64;
+        let knot_vec = KnotVec::uniform_knot(3, N - 2);
+        let parameter_points = (0..=N)
+            .map(|i| {
+                let u = (knot_vec[i + 1] + knot_vec[i + 2] + knot_vec[i + 3]) / 3.0;
+                (u, helix_point(i as f64 / N as f64))
+            })
+            .collect::<Vec<_>>();
+        let curve = BSplineCurve::interpolate(knot_vec, parameter_points);
+        Edge::new(v0, v1, Curve::BSplineCurve(curve))
+    }
+
+    /// Returns the path along the z-axis and the helical guide.
+    fn path_and_guide() -> (Edge, Edge) {
+        let v = builder::vertices([
+            (0.0, 0.0, 0.0),
+            (0.0, 0.0, HEIGHT),
+            (1.0, 0.0, 0.0),
+            (1.0, 0.0, HEIGHT),
+        ]);
+        (builder::line(&v[0], &v[1]), helix(&v[2], &v[3]))
+    }
+
+    #[test]
+    fn twisted_ribbon() {
+        let (path, guide) = path_and_guide();
+        let v = builder::vertices([(-1.0, 0.0, 0.0), (1.0, 0.0, 0.0)]);
+        let profile: Wire = vec![builder::line(&v[0], &v[1])].into();
+        let shell: Shell = builder::sweep_with_guide(&profile, &path, &guide).unwrap();
+        assert_eq!(shell.len(), 1);
+        assert_eq!(shell.shell_condition(), ShellCondition::Oriented);
+
+        // The profile turns once, so the end is the same position as the start.
+        let end = &shell[0].boundaries()[0][2];
+        assert_near!(end.front().point(), Point3::new(1.0, 0.0, HEIGHT));
+        assert_near!(end.back().point(), Point3::new(-1.0, 0.0, HEIGHT));
+
+        // The side from the reference point follows the guide.
+        let side = shell[0].boundaries()[0][1].oriented_curve();
+        let (t0, t1) = side.range_tuple();
+        (0..=32).for_each(|i| {
+            let p = side.subs(t0 + (t1 - t0) * i as f64 / 32.0);
+            assert!(p.distance(helix_point(p.z / HEIGHT)) < 1.0e-3, "{p:?}");
+        });
+
+        // The surface is on the helicoid.
+        let surface = shell[0].oriented_surface();
+        (0..=10).for_each(|i| {
+            (0..=10).for_each(|j| {
+                let (u, v) = (i as f64 / 10.0, j as f64 / 10.0);
+                let p = surface.subs(u, v);
+                let angle = 2.0 * PI * p.z / HEIGHT;
+                let error = p.x * f64::sin(angle) - p.y * f64::cos(angle);
+                assert!(error.abs() < 1.0e-2, "{p:?}");
+            })
+        });
+    }
+
+    #[test]
+    fn twisted_square_tube() {
+        let (path, guide) = path_and_guide();
+        let v = builder::vertices([
+            (0.5, -0.5, 0.0),
+            (0.5, 0.5, 0.0),
+            (-0.5, 0.5, 0.0),
+            (-0.5, -0.5, 0.0),
+        ]);
+        let profile: Wire = (0..4)
+            .map(|i| builder::line(&v[i], &v[(i + 1) % 4]))
+            .collect();
+        let shell: Shell = builder::sweep_with_guide(&profile, &path, &guide).unwrap();
+        assert_eq!(shell.len(), 4);
+        assert_eq!(shell.shell_condition(), ShellCondition::Oriented);
+        let boundaries = shell.extract_boundaries();
+        assert_eq!(boundaries.len(), 2);
+        assert!(boundaries.iter().all(|boundary| boundary.len() == 4));
+
+        // At the half of the height, the square is rotated by the half turn.
+        let side = shell[0].boundaries()[0][3].inverse().oriented_curve();
+        let (t0, t1) = side.range_tuple();
+        assert_near!(
+            side.subs((t0 + t1) / 2.0),
+            Point3::new(-0.5, 0.5, HEIGHT / 2.0)
+        );
+    }
+
+    #[test]
+    fn degenerate_guide() {
+        let v = builder::vertices([
+            (0.0, 0.0, 0.0),
+            (0.0, 0.0, 2.0),
+            (1.0, 0.0, 0.0),
+            (-1.0, 0.0, 2.0),
+        ]);
+        let path: Edge = builder::line(&v[0], &v[1]);
+        // The guide crosses the path at the middle.
+        let guide: Edge = builder::line(&v[2], &v[3]);
+        let profile: Wire = vec![builder::line(&v[0], &v[2])].into();
+        assert!(matches!(
+            builder::sweep_with_guide::<Curve, Surface>(&profile, &path, &guide),
+            Err(Error::DegenerateGuide),
+        ));
+    }
+}
@@ -0,0 +1,450 @@
+use crate::{errors::Error, *};
+use rustc_hash::FxHashMap as HashMap;
+use std::{
+    io::Read,
+    iter::{from_fn, once},
+    mem::replace,
+    str::FromStr,
+};
+
+/// Reads the curves in the `ENTITIES` section of the ASCII DXF and assembles them into wires.
+///
+/// # Details
+/// The entities `LINE`, `ARC`, `CIRCLE`, `LWPOLYLINE` and `SPLINE` are converted into edges,
+/// and the other entities are ignored. The arcs and circles, including the bulged segments of the
+/// polylines, are the exact NURBS curves, and the splines are the B-spline curves, or the NURBS
+/// curves if the weights are given. The coordinates of the arcs, the circles and the polylines are
+/// mapped from their object coordinate systems by the extrusion directions.
+///
+/// The end points nearer than [`TOLERANCE`] are regarded as the same vertex, and the edges are
+/// chained at the vertices. A chain is closed if it returns to its first vertex, otherwise it is
+/// returned as an open wire. A circle or a closed spline becomes a closed wire with two edges.
+///
+/// # Errors
+/// Returns [`Error::InvalidDxf`] if the input cannot be read or an entity lacks its coordinates.
+///
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+///
+/// // a unit square as a closed polyline, and a free line
+/// let dxf = "0\nSECTION\n2\nENTITIES\n\
+///     0\nLWPOLYLINE\n90\n4\n70\n1\n\
+///     10\n0.0\n20\n0.0\n10\n1.0\n20\n0.0\n10\n1.0\n20\n1.0\n10\n0.0\n20\n1.0\n\
+///     0\nLINE\n10\n2.0\n20\n0.0\n30\n0.0\n11\n3.0\n21\n0.0\n31\n0.0\n\
+///     0\nENDSEC\n0\nEOF\n";
+/// let wires = dxf::read_wires(dxf.as_bytes()).unwrap();
+/// assert_eq!(wires.len(), 2);
+/// assert_eq!(wires[0].len(), 4);
+/// assert!(wires[0].is_closed());
+/// assert!(!wires[1].is_closed());
+/// ```
+pub fn read_wires<R: Read>(mut reader: R) -> Result<Vec<Wire>> {
+    let mut text = String::new();
+    reader
+        .read_to_string(&mut text)
+        .map_err(|e| invalid(e.to_string()))?;
+    let groups = group_pairs(&text)?;
+    let mut vertices = VertexPool::default();
+    let mut segments = Vec::new();
+    entities(&groups).try_for_each(|entity| entity.segments(&mut vertices, &mut segments))?;
+    let vertices = vertices
+        .points
+        .into_iter()
+        .map(builder::vertex)
+        .collect::<Vec<_>>();
+    let edges = segments
+        .into_iter()
+        .map(|segment| {
+            let [v0, v1] = segment.ends.map(|i| &vertices[i]);
+            let edge = match segment.kind {
+                SegmentKind::Line => builder::line(v0, v1),
+                SegmentKind::Arc(transit) => builder::circle_arc(v0, v1, transit),
+                SegmentKind::Curve(curve) => Edge::new(v0, v1, curve),
+            };
+            (edge, segment.ends)
+        })
+        .collect::<Vec<_>>();
+    Ok(chain_edges(edges, vertices.len()))
+}
+
+fn invalid(message: impl Into<String>) -> Error { Error::InvalidDxf(message.into()) }
+
+/// The pairs of the group codes and the values.
+fn group_pairs(text: &str) -> Result<Vec<(i32, &str)>> {
+    let mut lines = text.lines();
+    from_fn(|| {
+        let code = lines.next()?.trim();
+        let value = lines.next().map(str::trim);
+        Some(match (code.parse::<i32>(), value) {
+            (Ok(code), Some(value)) => Ok((code, value)),
+            (Ok(code), None) => Err(invalid(format!("the group code {code} has no value"))),
+            (Err(_), _) => Err(invalid(format!(
+                "the group code {code:?} is not an integer"
+            ))),
+        })
+    })
+    .filter(|res| !matches!(res, Ok((999, _))))
+    .collect()
+}
+
+/// The entities in the `ENTITIES` section.
+fn entities<'a>(groups: &'a [(i32, &'a str)]) -> impl Iterator<Item = Entity<'a>> + 'a {
+    let start = groups
+        .windows(2)
+        .position(|w| w[0] == (0, "SECTION") && w[1] == (2, "ENTITIES"))
+        .map_or(groups.len(), |i| i + 2);
+    let section = &groups[start..];
+    let end = section
+        .iter()
+        .position(|group| *group == (0, "ENDSEC"))
+        .unwrap_or(section.len());
+    section[..end]
+        .split_inclusive(|(code, _)| *code == 0)
+        .scan(None, |kind, groups| {
+            // each chunk has the groups of the entity and the head of the next entity
+            let (body, next) = match groups.last() {
+                Some((0, next_kind)) => (&groups[..groups.len() - 1], Some(*next_kind)),
+                _ => (groups, None),
+            };
+            let entity = kind.map(|kind| Entity { kind, groups: body });
+            *kind = next;
+            Some(entity)
+        })
+        .flatten()
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Entity<'a> {
+    kind: &'a str,
+    groups: &'a [(i32, &'a str)],
+}
+
+impl<'a> Entity<'a> {
+    fn values(&self, code: i32) -> impl Iterator<Item = &'a str> + 'a {
+        self.groups
+            .iter()
+            .filter(move |(c, _)| *c == code)
+            .map(|(_, value)| *value)
+    }
+
+    fn parse<T: FromStr>(&self, value: &str) -> Result<T> {
+        value
+            .parse()
+            .map_err(|_| invalid(format!("{value:?} is not a number in {}", self.kind)))
+    }
+
+    fn real_or(&self, code: i32, default: f64) -> Result<f64> {
+        self.values(code)
+            .next()
+            .map_or(Ok(default), |value| self.parse(value))
+    }
+
+    fn real(&self, code: i32) -> Result<f64> {
+        let value = self.values(code).next();
+        let value = value.ok_or_else(|| invalid(format!("{} has no group {code}", self.kind)))?;
+        self.parse(value)
+    }
+
+    fn integer_or(&self, code: i32, default: i64) -> Result<i64> {
+        self.values(code)
+            .next()
+            .map_or(Ok(default), |value| self.parse(value))
+    }
+
+    /// The point by the codes `x`, `x + 10` and `x + 20`, whose z-coordinate is zero by default.
+    fn point(&self, x: i32) -> Result<Point3> {
+        Ok(Point3::new(
+            self.real(x)?,
+            self.real(x + 10)?,
+            self.real_or(x + 20, 0.0)?,
+        ))
+    }
+
+    /// The repeated points by the codes `x`, `x + 10` and `x + 20` in order.
+    fn points(&self, x: i32) -> Result<Vec<Point3>> {
+        let mut points = Vec::<Point3>::new();
+        self.groups.iter().try_for_each(|(code, value)| {
+            match (*code - x, points.last_mut()) {
+                (0, _) => points.push(Point3::new(self.parse(value)?, 0.0, 0.0)),
+                (10, Some(p)) => p.y = self.parse(value)?,
+                (20, Some(p)) => p.z = self.parse(value)?,
+                _ => {}
+            }
+            Ok::<_, Error>(())
+        })?;
+        Ok(points)
+    }
+
+    /// The object coordinate system by the arbitrary axis algorithm of DXF.
+    fn ocs(&self) -> Result<Matrix3> {
+        let normal = Vector3::new(
+            self.real_or(210, 0.0)?,
+            self.real_or(220, 0.0)?,
+            self.real_or(230, 1.0)?,
+        );
+        if normal.so_small() {
+            return Err(invalid(format!("{} has the zero extrusion", self.kind)));
+        }
+        let normal = normal.normalize();
+        let ax = match f64::abs(normal.x) < 1.0 / 64.0 && f64::abs(normal.y) < 1.0 / 64.0 {
+            true => Vector3::unit_y().cross(normal),
+            false => Vector3::unit_z().cross(normal),
+        }
+        .normalize();
+        Ok(Matrix3::from_cols(ax, normal.cross(ax), normal))
+    }
+
+    fn segments(&self, vertices: &mut VertexPool, segments: &mut Vec<Segment>) -> Result<()> {
+        match self.kind {
+            "LINE" => {
+                let (p0, p1) = (self.point(10)?, self.point(11)?);
+                segments.extend(Segment::new(vertices, p0, p1, SegmentKind::Line));
+            }
+            "ARC" | "CIRCLE" => {
+                let ocs = self.ocs()?;
+                let (center, radius) = (self.point(10)?, self.real(40)?);
+                let point = |degree: f64| {
+                    let (s, c) = degree.to_radians().sin_cos();
+                    Point3::from_vec(ocs * (center.to_vec() + radius * Vector3::new(c, s, 0.0)))
+                };
+                let (start, sweep) = match self.kind {
+                    "ARC" => {
+                        let (start, end) = (self.real(50)?, self.real(51)?);
+                        let sweep = (end - start).rem_euclid(360.0);
+                        (start, if sweep.so_small() { 360.0 } else { sweep })
+                    }
+                    _ => (0.0, 360.0),
+                };
+                if radius.so_small() {
+                    return Ok(());
+                }
+                // a full circle is split into two arcs
+                let n = if sweep.near(&360.0) { 2 } else { 1 };
+                let sweep = sweep / n as f64;
+                (0..n).for_each(|i| {
+                    let t = start + sweep * i as f64;
+                    let (p0, p1) = (point(t), point(t + sweep));
+                    let kind = SegmentKind::Arc(point(t + sweep / 2.0));
+                    segments.extend(Segment::new(vertices, p0, p1, kind));
+                });
+            }
+            "LWPOLYLINE" => {
+                let ocs = self.ocs()?;
+                let elevation = self.real_or(38, 0.0)?;
+                let mut points = Vec::<(Point2, f64)>::new();
+                self.groups.iter().try_for_each(|(code, value)| {
+                    match (*code, points.last_mut()) {
+                        (10, _) => points.push((Point2::new(self.parse(value)?, 0.0), 0.0)),
+                        (20, Some((p, _))) => p.y = self.parse(value)?,
+                        (42, Some((_, bulge))) => *bulge = self.parse(value)?,
+                        _ => {}
+                    }
+                    Ok::<_, Error>(())
+                })?;
+                let closed = self.integer_or(70, 0)? & 1 == 1;
+                let n = points.len();
+                let to_world = |p: Point2| Point3::from_vec(ocs * p.to_vec().extend(elevation));
+                let len = if closed && n > 1 {
+                    n
+                } else {
+                    n.saturating_sub(1)
+                };
+                (0..len).for_each(|i| {
+                    let ((p0, bulge), (p1, _)) = (points[i], points[(i + 1) % n]);
+                    let kind = match bulge.so_small() {
+                        true => SegmentKind::Line,
+                        false => {
+                            // the sagitta is the half of the chord times the bulge
+                            let chord = p1 - p0;
+                            let transit =
+                                p0.midpoint(p1) + Vector2::new(chord.y, -chord.x) * bulge / 2.0;
+                            SegmentKind::Arc(to_world(transit))
+                        }
+                    };
+                    let segment = Segment::new(vertices, to_world(p0), to_world(p1), kind);
+                    segments.extend(segment);
+                });
+            }
+            "SPLINE" => {
+                let degree = self.integer_or(71, 3)?;
+                let knots = self
+                    .values(40)
+                    .map(|value| self.parse(value))
+                    .collect::<Result<Vec<f64>>>()?;
+                let weights = self
+                    .values(41)
+                    .map(|value| self.parse(value))
+                    .collect::<Result<Vec<f64>>>()?;
+                let control_points = self.points(10)?;
+                if control_points.is_empty() {
+                    return Err(invalid(
+                        "SPLINE without the control points is not supported",
+                    ));
+                }
+                if knots.len() != control_points.len() + degree as usize + 1 {
+                    return Err(invalid(
+                        "the numbers of the knots and the control points of SPLINE do not match",
+                    ));
+                }
+                let knot_vec = KnotVec::from(knots);
+                let curve = match weights.len() == control_points.len() {
+                    true => {
+                        let control_points = control_points
+                            .into_iter()
+                            .zip(weights)
+                            .map(|(p, w)| Vector4::new(p.x * w, p.y * w, p.z * w, w))
+                            .collect();
+                        let curve = BSplineCurve::try_new(knot_vec, control_points)
+                            .map_err(|e| invalid(e.to_string()))?;
+                        Curve::NurbsCurve(NurbsCurve::new(curve))
+                    }
+                    false => {
+                        let curve = BSplineCurve::try_new(knot_vec, control_points)
+                            .map_err(|e| invalid(e.to_string()))?;
+                        Curve::BSplineCurve(curve)
+                    }
+                };
+                let (p0, p1) = (curve.front(), curve.back());
+                match p0.near(&p1) {
+                    // a closed spline is split into two curves
+                    true => {
+                        let (t0, t1) = curve.range_tuple();
+                        let mut curve0 = curve;
+                        let curve1 = curve0.cut((t0 + t1) / 2.0);
+                        let mid = curve1.front();
+                        segments.extend(Segment::new(
+                            vertices,
+                            p0,
+                            mid,
+                            SegmentKind::Curve(curve0),
+                        ));
+                        segments.extend(Segment::new(
+                            vertices,
+                            mid,
+                            p1,
+                            SegmentKind::Curve(curve1),
+                        ));
+                    }
+                    false => {
+                        segments.extend(Segment::new(vertices, p0, p1, SegmentKind::Curve(curve)))
+                    }
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+/// The points of the vertices, where the near points are identified.
+///
+/// The cells of the hash map have the width `TOLERANCE`, so the near points are searched in the
+/// neighboring cells.
+#[derive(Clone, Debug, Default)]
+struct VertexPool {
+    points: Vec<Point3>,
+    cells: HashMap<[i64; 3], Vec<usize>>,
+}
+
+impl VertexPool {
+    fn index(&mut self, point: Point3) -> usize {
+        let key = [point.x, point.y, point.z].map(|x| (x / TOLERANCE).floor() as i64);
+        let registered = (0..27)
+            .map(|n| {
+                [
+                    key[0] + n % 3 - 1,
+                    key[1] + n / 3 % 3 - 1,
+                    key[2] + n / 9 - 1,
+                ]
+            })
+            .find_map(|cell| {
+                let mut indices = self.cells.get(&cell)?.iter().copied();
+                indices.find(|i| self.points[*i].near(&point))
+            });
+        registered.unwrap_or_else(|| {
+            self.points.push(point);
+            self.cells
+                .entry(key)
+                .or_default()
+                .push(self.points.len() - 1);
+            self.points.len() - 1
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+enum SegmentKind {
+    Line,
+    /// The circle arc via the point.
+    Arc(Point3),
+    Curve(Curve),
+}
+
+#[derive(Clone, Debug)]
+struct Segment {
+    ends: [usize; 2],
+    kind: SegmentKind,
+}
+
+impl Segment {
+    /// Returns `None` if the segment is degenerated to a point.
+    fn new(vertices: &mut VertexPool, p0: Point3, p1: Point3, kind: SegmentKind) -> Option<Self> {
+        let ends = [vertices.index(p0), vertices.index(p1)];
+        (ends[0] != ends[1]).then_some(Self { ends, kind })
+    }
+}
+
+/// Chains the edges at the shared vertices into wires.
+fn chain_edges(edges: Vec<(Edge, [usize; 2])>, vertex_len: usize) -> Vec<Wire> {
+    let mut incidence = vec![Vec::new(); vertex_len];
+    edges.iter().enumerate().for_each(|(i, (_, [v0, v1]))| {
+        incidence[*v0].push(i);
+        incidence[*v1].push(i);
+    });
+    let mut used = vec![false; edges.len()];
+    // takes an unused edge at `vertex`, oriented from `vertex` if `outgoing`
+    let take = |used: &mut [bool], vertex: usize, outgoing: bool| {
+        let i = *incidence[vertex].iter().find(|i| !used[**i])?;
+        used[i] = true;
+        let (edge, [v0, v1]) = &edges[i];
+        Some(match (*v0 == vertex) == outgoing {
+            true => (edge.clone(), if outgoing { *v1 } else { *v0 }),
+            false => (edge.inverse(), if outgoing { *v0 } else { *v1 }),
+        })
+    };
+    (0..edges.len())
+        .filter_map(|i| {
+            if replace(&mut used[i], true) {
+                return None;
+            }
+            let (edge, [mut front, mut back]) = edges[i].clone();
+            let forward = from_fn(|| {
+                if back == front {
+                    return None;
+                }
+                let (edge, vertex) = take(&mut used, back, true)?;
+                back = vertex;
+                Some(edge)
+            })
+            .collect::<Vec<_>>();
+            let backward = from_fn(|| {
+                if back == front {
+                    return None;
+                }
+                let (edge, vertex) = take(&mut used, front, false)?;
+                front = vertex;
+                Some(edge)
+            })
+            .collect::<Vec<_>>();
+            Some(
+                backward
+                    .into_iter()
+                    .rev()
+                    .chain(once(edge))
+                    .chain(forward)
+                    .collect(),
+            )
+        })
+        .collect()
+}
@@ -14,6 +14,50 @@ pub enum Error {
     /// cf. [`builder::try_wire_homotopy`](../builder/fn.try_wire_homotopy.html)
     #[error("The wires must contain the same number of edges to create a homotopy.")]
     NotSameNumberOfEdges,
+    /// tried to split a face which is not contained in the shell.
+    /// cf. [`builder::split_face`](../builder/fn.split_face.html)
+    #[error("The face is not contained in the shell.")]
+    FaceNotInShell,
+    /// tried to split a face by a curve whose end points are not on the boundary of the face.
+    /// cf. [`builder::split_face`](../builder/fn.split_face.html)
+    #[error("The end points of the curve must be on the boundary of the face.")]
+    CurveNotOnBoundary,
+    /// tried to split a face with several boundaries by a curve which is not closed.
+    /// cf. [`builder::split_face`](../builder/fn.split_face.html)
+    #[error("The face with several boundaries cannot be split by a curve which is not closed.")]
+    FaceHasSeveralBoundaries,
+    /// tried to sweep a profile along rails which do not start from the ends of the profile.
+    /// cf. [`builder::two_rail_sweep`](../builder/fn.two_rail_sweep.html)
+    #[error("The rails must start from the front and back vertices of the profile.")]
+    ProfileNotOnRails,
+    /// tried to sweep a profile along rails which meet or run along the chord between them.
+    /// cf. [`builder::two_rail_sweep`](../builder/fn.two_rail_sweep.html)
+    #[error("The orientation of the profile is not determined by the rails.")]
+    DegenerateRails,
+    /// tried to sweep a profile with a guide which meets or runs along the tangent of the path.
+    /// cf. [`builder::sweep_with_guide`](../builder/fn.sweep_with_guide.html)
+    #[error("The orientation of the profile is not determined by the guide.")]
+    DegenerateGuide,
+    /// tried to extrude a profile with draft which contains the edges other than line segments.
+    /// cf. [`builder::extrude_with_draft`](../builder/fn.extrude_with_draft.html)
+    #[error("The profile extruded with draft must be bounded by line segments.")]
+    ProfileNotPolygonal,
+    /// tried to extrude a profile along the direction parallel to its plane.
+    /// cf. [`builder::extrude_with_draft`](../builder/fn.extrude_with_draft.html)
+    #[error("The extrusion direction must not be parallel to the plane of the profile.")]
+    DirectionAlongProfile,
+    /// tried to extrude a profile with draft whose adjacent edges are antiparallel.
+    /// cf. [`builder::extrude_with_draft`](../builder/fn.extrude_with_draft.html)
+    #[error("The adjacent edges of the profile extruded with draft must not turn back.")]
+    ProfileFoldsBack,
+    /// tried to sketch an arc whose radius is less than the half of its chord.
+    /// cf. [`builder::Sketch::arc_to`](../builder/struct.Sketch.html#method.arc_to)
+    #[error("The radius of the arc must be no less than the half of the chord.")]
+    ArcRadiusTooSmall,
+    /// failed to read the DXF input.
+    /// cf. [`dxf::read_wires`](../dxf/fn.read_wires.html)
+    #[error("invalid DXF: {0}")]
+    InvalidDxf(String),
 }
 
 #[test]
@@ -90,6 +90,27 @@ impl ToSameGeometry<Curve> for BSplineCurve<Point3> {
     fn to_same_geometry(&self) -> Curve { Curve::from(self.clone()) }
 }
 
+impl ToSameGeometry<Curve> for PCurve<Line<Point2>, Plane> {
+    #[inline]
+    fn to_same_geometry(&self) -> Curve {
+        let (line, plane) = (self.curve(), self.surface());
+        let (p, q) = (line.0, line.1);
+        Curve::Line(Line(plane.subs(p.x, p.y), plane.subs(q.x, q.y)))
+    }
+}
+
+impl ToSameGeometry<Curve> for PCurve<BSplineCurve<Point2>, Plane> {
+    fn to_same_geometry(&self) -> Curve {
+        let (curve, plane) = (self.curve(), self.surface());
+        let control_points = curve
+            .control_points()
+            .iter()
+            .map(|p| plane.subs(p.x, p.y))
+            .collect();
+        Curve::BSplineCurve(BSplineCurve::new(curve.knot_vec().clone(), control_points))
+    }
+}
+
 impl Curve {
     /// Into non-ratinalized 4-dimensional B-spline curve
     pub fn lift_up(&self) -> BSplineCurve<Vector4> {
@@ -161,11 +182,39 @@ macro_rules! derive_surface_self_method {
     };
 }
 
+impl Surface {
+    /// Returns the name of the variant, e.g. for [`Shell::summary`](truck_topology::Shell::summary).
+    /// # Examples
+    /// ```
+    /// use truck_modeling::*;
+    /// let v = builder::vertex(Point3::origin());
+    /// let e = builder::tsweep(&v, Vector3::unit_x());
+    /// let f = builder::tsweep(&e, Vector3::unit_y());
+    /// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    /// let summary = cube.summary(|surface| surface.type_name());
+    /// assert!(summary.contains("6 faces, closed"));
+    /// assert!(summary.contains("face 0: Plane"));
+    /// ```
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Self::Plane(_) => "Plane",
+            Self::BSplineSurface(_) => "BSplineSurface",
+            Self::NurbsSurface(_) => "NurbsSurface",
+            Self::RevolutedCurve(_) => "RevolutedCurve",
+            Self::TSplineSurface(_) => "TSplineSurface",
+        }
+    }
+}
+
 impl ParametricSurface3D for Surface {
     #[inline(always)]
     fn normal(&self, u: f64, v: f64) -> Vector3 {
         derive_surface_method!(self, ParametricSurface3D::normal, u, v)
     }
+    #[inline(always)]
+    fn bounding_box(&self, range: ((f64, f64), (f64, f64)), tol: f64) -> BoundingBox<Point3> {
+        derive_surface_method!(self, ParametricSurface3D::bounding_box, range, tol)
+    }
 }
 
 impl Transformed<Matrix4> for Surface {
@@ -204,8 +253,15 @@ impl IncludeCurve<Curve> for Surface {
                 surface.search_parameter(p, None, 1).is_some()
             }),
             Surface::RevolutedCurve(surface) => match surface.entity_curve() {
-                &Curve::Line(curve) => {
-                    self.include(&Curve::BSplineCurve(BSplineCurve::from(curve)))
+                &Curve::Line(entity_curve) => {
+                    let entity_curve = Curve::BSplineCurve(BSplineCurve::from(entity_curve));
+                    let surface =
+                        Surface::RevolutedCurve(Processor::new(RevolutedCurve::by_revolution(
+                            entity_curve,
+                            surface.origin(),
+                            surface.axis(),
+                        )));
+                    surface.include(curve)
                 }
                 Curve::BSplineCurve(entity_curve) => {
                     let surface = RevolutedCurve::by_revolution(
@@ -252,6 +308,10 @@ impl ToSameGeometry<Surface> for Plane {
     fn to_same_geometry(&self) -> Surface { (*self).into() }
 }
 
+impl ToSameGeometry<Surface> for NurbsSurface<Vector4> {
+    fn to_same_geometry(&self) -> Surface { Surface::NurbsSurface(self.clone()) }
+}
+
 impl ToSameGeometry<Surface> for RevolutedCurve<Curve> {
     fn to_same_geometry(&self) -> Surface { Surface::RevolutedCurve(Processor::new(self.clone())) }
 }
@@ -274,14 +334,9 @@ impl SearchNearestParameter<D2> for Surface {
                 surface.search_nearest_parameter(point, hint, trials)
             }
             Surface::RevolutedCurve(rotted) => {
-                let hint = match hint.into() {
-                    SPHint2D::Parameter(hint0, hint1) => (hint0, hint1),
-                    SPHint2D::Range(x, y) => algo::surface::presearch(rotted, point, (x, y), 100),
-                    SPHint2D::None => {
-                        algo::surface::presearch(rotted, point, rotted.range_tuple(), 100)
-                    }
-                };
-                algo::surface::search_nearest_parameter(rotted, point, hint, trials)
+                algo::surface::search_with_hint(rotted, point, hint.into(), 100, |hint| {
+                    algo::surface::search_nearest_parameter(rotted, point, hint, trials)
+                })
             }
         }
     }
@@ -101,6 +101,11 @@ pub type Result<T> = std::result::Result<T, errors::Error>;
 /// the building model utility API
 pub mod builder;
 mod closed_sweep;
+/// importing the planar profiles from DXF
+///
+/// Requires the `dxf` feature flag.
+#[cfg(feature = "dxf")]
+pub mod dxf;
 /// declare errors
 pub mod errors;
 mod geom_impls;
@@ -108,6 +113,7 @@ mod mapped;
 mod multi_sweep;
 /// primitive shapes
 pub mod primitive;
+mod sketch;
 mod sweep;
 mod topo_impls;
 
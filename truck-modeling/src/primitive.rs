@@ -189,3 +189,71 @@ where
 
     Solid::new(vec![shell])
 }
+
+/// torus, or its section if `angle` is less than the whole round
+///
+/// The torus is centered at the origin with the axis along the z-axis, and the section starts
+/// from the xz-plane and turns counterclockwise around the z-axis. If `angle` is less than `2π`,
+/// the section is closed by the two flat disks at the ends.
+///
+/// The surfaces are exact rational surfaces of revolution, and the division is chosen so that each
+/// piece of the revolution turns at most a quarter round.
+/// # Example
+/// ```
+/// use truck_modeling::*;
+/// use std::f64::consts::PI;
+/// let half: Solid = primitive::partial_torus(2.0, 0.5, Rad(PI));
+/// let shell = &half.boundaries()[0];
+/// assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+/// // two disks at the ends, and the revolution of four quarter arcs in two pieces
+/// assert_eq!(shell.len(), 10);
+///
+/// // The disks at the ends face outward, which is -y for both ends of the half round.
+/// let caps = shell
+///     .face_iter()
+///     .filter(|face| matches!(face.surface(), Surface::Plane(_)))
+///     .collect::<Vec<_>>();
+/// assert_eq!(caps.len(), 2);
+/// caps.iter().for_each(|face| {
+///     let Surface::Plane(plane) = face.oriented_surface() else { unreachable!() };
+///     let wire = &face.boundaries()[0];
+///     let x = wire.vertex_iter().map(|v| v.point().x).sum::<f64>() / wire.len() as f64;
+///     assert_near!(x.abs(), 2.0);
+///     assert_near!(plane.normal(), -Vector3::unit_y());
+/// });
+///
+/// // the whole torus has no caps.
+/// let torus: Solid = primitive::partial_torus(2.0, 0.5, Rad(2.0 * PI));
+/// let shell = &torus.boundaries()[0];
+/// assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+/// assert!(shell.face_iter().all(|face| !matches!(face.surface(), Surface::Plane(_))));
+/// ```
+pub fn partial_torus<C, S, R>(
+    major_radius: f64,
+    minor_radius: f64,
+    angle: R,
+) -> Solid<Point3, C, S>
+where
+    R: Into<Rad<f64>>,
+    C: ParametricCurve3D + BoundedCurve,
+    Plane: IncludeCurve<C> + ToSameGeometry<S>,
+    Processor<TrimmedCurve<UnitCircle<Point3>>, Matrix4>: ToSameGeometry<C>,
+    Face<Point3, C, S>: crate::ClosedSweep<
+        Matrix4,
+        crate::geom_impls::ArcConnector,
+        crate::geom_impls::RevoluteConnector,
+        Solid<Point3, C, S>,
+    >,
+{
+    let angle = angle.into();
+    let start = Point3::new(major_radius + minor_radius, 0.0, 0.0);
+    let center = Point3::new(major_radius, 0.0, 0.0);
+    let wire = circle(start, center, Vector3::unit_y(), 4);
+    let face: Face<Point3, C, S> =
+        builder::try_attach_plane(vec![wire]).expect("the circle must be planar");
+    let division = match angle.0.abs() >= 2.0 * PI {
+        true => 4,
+        false => usize::max(f64::ceil(angle.0.abs() / (PI / 2.0)) as usize, 1),
+    };
+    builder::rsweep(&face, Point3::origin(), Vector3::unit_z(), angle, division)
+}
@@ -0,0 +1,160 @@
+use crate::{builder, errors::Error, Result};
+use truck_geometry::prelude::*;
+use truck_topology::{errors::Error as TopologyError, Vertex, Wire};
+
+/// Two dimensional profile consisting of lines and circle arcs, which is built into a wire on a
+/// plane.
+///
+/// # Details
+/// The sketch starts from the point given by [`Sketch::start`], and each segment starts from the
+/// end of the previous one. The coordinates are measured along the unit vectors of the plane:
+/// the `x`-axis is the normalized `u`-axis of the plane and the `y`-axis is the cross product of
+/// the normal and the `x`-axis, so the circle arcs are exact even if the axes of the plane are not
+/// orthonormal.
+///
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// // the rounded rectangle with the corner radius 0.5
+/// let mut sketch = builder::Sketch::start(Point2::new(0.5, 0.0));
+/// sketch
+///     .line_to(Point2::new(2.5, 0.0))
+///     .arc_to(Point2::new(3.0, 0.5), 0.5)
+///     .line_to(Point2::new(3.0, 1.5))
+///     .arc_to(Point2::new(2.5, 2.0), 0.5)
+///     .line_to(Point2::new(0.5, 2.0))
+///     .arc_to(Point2::new(0.0, 1.5), 0.5)
+///     .line_to(Point2::new(0.0, 0.5))
+///     .arc_to(Point2::new(0.5, 0.0), 0.5)
+///     .close();
+/// let wire: Wire = sketch.build_wire(&Plane::xy()).unwrap();
+/// assert_eq!(wire.len(), 8);
+/// assert!(wire.is_closed());
+///
+/// // the plate extruded from the profile
+/// let face: Face = builder::planar_face(wire).unwrap();
+/// let solid: Solid = builder::tsweep(&face, Vector3::new(0.0, 0.0, 0.1));
+/// # assert!(solid.is_geometric_consistent());
+/// ```
+#[derive(Clone, Debug)]
+pub struct Sketch {
+    start: Point2,
+    segments: Vec<(Point2, Segment)>,
+    closed: bool,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Segment {
+    Line,
+    Arc(f64),
+}
+
+impl Sketch {
+    /// Starts the sketch at `point`.
+    #[inline(always)]
+    pub fn start(point: Point2) -> Self {
+        Self {
+            start: point,
+            segments: Vec::new(),
+            closed: false,
+        }
+    }
+
+    /// Adds the line segment from the current point to `point`.
+    #[inline(always)]
+    pub fn line_to(&mut self, point: Point2) -> &mut Self {
+        self.segments.push((point, Segment::Line));
+        self
+    }
+
+    /// Adds the circle arc with the radius `radius.abs()` from the current point to `point`.
+    ///
+    /// The arc is the shorter one of the two arcs on the circle. It turns counter-clockwise if
+    /// `radius` is positive, and clockwise if `radius` is negative.
+    #[inline(always)]
+    pub fn arc_to(&mut self, point: Point2, radius: f64) -> &mut Self {
+        self.segments.push((point, Segment::Arc(radius)));
+        self
+    }
+
+    /// Closes the sketch by the line segment from the last point to the start point.
+    ///
+    /// If the last point is already at the start point within `TOLERANCE`, the last segment ends
+    /// at the start point and no segment is added.
+    #[inline(always)]
+    pub fn close(&mut self) -> &mut Self {
+        self.closed = true;
+        self
+    }
+
+    /// Returns the current point, i.e. the end of the last segment.
+    #[inline(always)]
+    pub fn current_point(&self) -> Point2 {
+        self.segments.last().map_or(self.start, |(point, _)| *point)
+    }
+
+    /// Builds the wire of the sketch on `plane`.
+    ///
+    /// The lines are the edges of `Line` and the arcs are the edges of the exact NURBS circles.
+    /// The wire is closed if [`Sketch::close`] has been called.
+    ///
+    /// # Failures
+    /// - If two consecutive points are the same within `TOLERANCE`, then returns
+    ///   `Error::FromTopology(SameVertex)`.
+    /// - If the radius of an arc is less than the half of its chord, then returns
+    ///   `Error::ArcRadiusTooSmall`.
+    pub fn build_wire<C>(&self, plane: &Plane) -> Result<Wire<Point3, C>>
+    where
+        Line<Point3>: ToSameGeometry<C>,
+        Processor<TrimmedCurve<UnitCircle<Point3>>, Matrix4>: ToSameGeometry<C>, {
+        let mut segments = self.segments.clone();
+        if self.closed {
+            match segments.last_mut() {
+                Some((point, _)) if (*point).near(&self.start) => *point = self.start,
+                _ => segments.push((self.start, Segment::Line)),
+            }
+        }
+        let x_axis = plane.u_axis().normalize();
+        let y_axis = plane.normal().cross(x_axis);
+        let to_world = |p: Point2| plane.origin() + x_axis * p.x + y_axis * p.y;
+        let first = Vertex::new(to_world(self.start));
+        let mut current = (self.start, first.clone());
+        segments
+            .iter()
+            .enumerate()
+            .map(|(i, (point, segment))| {
+                let (p0, v0) = current.clone();
+                if p0.near(point) {
+                    return Err(Error::FromTopology(TopologyError::SameVertex));
+                }
+                let v1 = match self.closed && i + 1 == segments.len() {
+                    true => first.clone(),
+                    false => Vertex::new(to_world(*point)),
+                };
+                let edge = match *segment {
+                    Segment::Line => builder::line(&v0, &v1),
+                    Segment::Arc(radius) => {
+                        let transit = arc_transit(p0, *point, radius)?;
+                        builder::circle_arc(&v0, &v1, to_world(transit))
+                    }
+                };
+                current = (*point, v1);
+                Ok(edge)
+            })
+            .collect()
+    }
+}
+
+/// Returns the midpoint of the arc from `p0` to `p1` with the signed radius `radius`.
+fn arc_transit(p0: Point2, p1: Point2, radius: f64) -> Result<Point2> {
+    let chord = p1 - p0;
+    let half = chord.magnitude() / 2.0;
+    let r = radius.abs();
+    if r < half - TOLERANCE {
+        return Err(Error::ArcRadiusTooSmall);
+    }
+    // the center is on the left of the chord for the counter-clockwise arc
+    let left = Vector2::new(-chord.y, chord.x).normalize() * radius.signum();
+    let height = f64::sqrt(f64::max(r * r - half * half, 0.0));
+    Ok(p0.midpoint(p1) + left * (height - r))
+}
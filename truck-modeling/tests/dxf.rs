@@ -0,0 +1,144 @@
+#![cfg(feature = "dxf")]
+
+use std::slice::from_ref;
+use truck_modeling::*;
+
+fn entities(entities: &[&str]) -> String {
+    let body = entities.concat();
+    format!("0\nSECTION\n2\nENTITIES\n{body}0\nENDSEC\n0\nEOF\n")
+}
+
+fn line(p: (f64, f64), q: (f64, f64)) -> String {
+    format!(
+        "0\nLINE\n8\n0\n10\n{}\n20\n{}\n30\n0.0\n11\n{}\n21\n{}\n31\n0.0\n",
+        p.0, p.1, q.0, q.1
+    )
+}
+
+#[test]
+fn rectangle_with_fillet() {
+    // the rectangle [0, 2] x [0, 1] whose upper right corner is rounded by the radius 0.5,
+    // with the entities shuffled and some lines reversed
+    let dxf = entities(&[
+        &line((0.0, 1.0), (0.0, 0.0)),
+        &line((2.0, 0.0), (2.0, 0.5)),
+        "0\nARC\n10\n1.5\n20\n0.5\n30\n0.0\n40\n0.5\n50\n0.0\n51\n90.0\n",
+        &line((0.0, 0.0), (2.0, 0.0)),
+        &line((0.0, 1.0), (1.5, 1.0)),
+    ]);
+    let wires = dxf::read_wires(dxf.as_bytes()).unwrap();
+    assert_eq!(wires.len(), 1);
+    let wire = &wires[0];
+    assert_eq!(wire.len(), 5);
+    assert!(wire.is_closed());
+
+    let arc = wire
+        .edge_iter()
+        .find(|edge| matches!(edge.curve(), Curve::NurbsCurve(_)))
+        .unwrap();
+    let curve = arc.oriented_curve();
+    let center = Point3::new(1.5, 0.5, 0.0);
+    let (t0, t1) = curve.range_tuple();
+    (0..=10).for_each(|i| {
+        let t = t0 + (t1 - t0) * i as f64 / 10.0;
+        assert_near!(curve.subs(t).distance(center), 0.5);
+    });
+
+    let face: Face = builder::try_attach_plane(from_ref(wire)).unwrap();
+    let normal = face.oriented_surface().normal(0.0, 0.0);
+    assert!(normal.cross(Vector3::unit_z()).so_small());
+}
+
+#[test]
+fn circles_polylines_and_splines() {
+    let dxf = entities(&[
+        "0\nCIRCLE\n10\n5.0\n20\n0.0\n30\n0.0\n40\n1.0\n",
+        // a slot by the bulges of the semicircles, flipped by the extrusion direction
+        "0\nLWPOLYLINE\n90\n4\n70\n1\n38\n2.0\n\
+         10\n0.0\n20\n0.0\n\
+         10\n1.0\n20\n0.0\n42\n1.0\n\
+         10\n1.0\n20\n1.0\n\
+         10\n0.0\n20\n1.0\n42\n1.0\n\
+         210\n0.0\n220\n0.0\n230\n-1.0\n",
+        // an open cubic spline
+        "0\nSPLINE\n70\n8\n71\n3\n72\n8\n73\n4\n\
+         40\n0.0\n40\n0.0\n40\n0.0\n40\n0.0\n40\n1.0\n40\n1.0\n40\n1.0\n40\n1.0\n\
+         10\n0.0\n20\n5.0\n30\n0.0\n10\n1.0\n20\n6.0\n30\n0.0\n\
+         10\n2.0\n20\n4.0\n30\n0.0\n10\n3.0\n20\n5.0\n30\n0.0\n",
+        "0\nPOINT\n10\n0.0\n20\n0.0\n30\n0.0\n",
+    ]);
+    let wires = dxf::read_wires(dxf.as_bytes()).unwrap();
+    assert_eq!(wires.len(), 3);
+
+    assert_eq!(wires[0].len(), 2);
+    assert!(wires[0].is_closed());
+    wires[0].edge_iter().for_each(|edge| {
+        let curve = edge.oriented_curve();
+        let (t0, t1) = curve.range_tuple();
+        let p = curve.subs((t0 + t1) / 2.0);
+        assert_near!(p.distance(Point3::new(5.0, 0.0, 0.0)), 1.0);
+    });
+
+    // the object coordinate system with the extrusion -z maps `(x, y, z)` to `(-x, y, -z)`
+    assert_eq!(wires[1].len(), 4);
+    assert!(wires[1].is_closed());
+    wires[1].vertex_iter().for_each(|v| {
+        let p = v.point();
+        assert!(p.x < TOLERANCE && p.z.near(&-2.0));
+    });
+    let extreme = wires[1]
+        .edge_iter()
+        .filter(|edge| matches!(edge.curve(), Curve::NurbsCurve(_)))
+        .map(|edge| {
+            let curve = edge.oriented_curve();
+            let (t0, t1) = curve.range_tuple();
+            curve.subs((t0 + t1) / 2.0)
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(extreme.len(), 2);
+    assert!(extreme
+        .iter()
+        .any(|p| p.near(&Point3::new(-1.5, 0.5, -2.0))));
+    assert!(extreme.iter().any(|p| p.near(&Point3::new(0.5, 0.5, -2.0))));
+
+    assert_eq!(wires[2].len(), 1);
+    assert!(!wires[2].is_closed());
+    assert_near!(
+        wires[2].front_vertex().unwrap().point(),
+        Point3::new(0.0, 5.0, 0.0)
+    );
+    assert_near!(
+        wires[2].back_vertex().unwrap().point(),
+        Point3::new(3.0, 5.0, 0.0)
+    );
+}
+
+#[test]
+fn invalid_input() {
+    let dxf = entities(&["0\nLINE\n10\n0.0\n20\n0.0\n"]);
+    assert!(matches!(
+        dxf::read_wires(dxf.as_bytes()),
+        Err(errors::Error::InvalidDxf(_))
+    ));
+    let dxf = entities(&["0\nLINE\n10\n0.0\n20\nzero\n11\n1.0\n21\n0.0\n"]);
+    assert!(matches!(
+        dxf::read_wires(dxf.as_bytes()),
+        Err(errors::Error::InvalidDxf(_))
+    ));
+    assert!(dxf::read_wires("0\nEOF\n".as_bytes()).unwrap().is_empty());
+}
+
+#[test]
+fn near_ends_across_cells() {
+    // the ends are nearer than `TOLERANCE`, but on both sides of the origin
+    let eps = 0.4 * TOLERANCE;
+    let dxf = entities(&[
+        &line((0.0, 0.0), (1.0, 0.0)),
+        &line((1.0, 0.0), (0.0, 1.0)),
+        &line((0.0, 1.0), (-eps, -eps)),
+    ]);
+    let wires = dxf::read_wires(dxf.as_bytes()).unwrap();
+    assert_eq!(wires.len(), 1);
+    assert_eq!(wires[0].len(), 3);
+    assert!(wires[0].is_closed());
+}
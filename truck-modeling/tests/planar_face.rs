@@ -0,0 +1,25 @@
+use truck_modeling::*;
+
+#[test]
+fn planar_square_face() {
+    let v = builder::vertices([
+        (0.0, 0.0, 0.0),
+        (2.0, 0.0, 0.0),
+        (2.0, 0.0, 2.0),
+        (0.0, 0.0, 2.0),
+    ]);
+    let wire: Wire = (0..4)
+        .map(|i| builder::line(&v[i], &v[(i + 1) % 4]))
+        .collect();
+    let face: Face = builder::planar_face(wire).unwrap();
+    // The normal is right-handed with respect to the wire.
+    let surface = face.oriented_surface();
+    let normal = surface.normal(0.5, 0.5);
+    assert!(normal.near(&-Vector3::unit_y()), "{normal:?}");
+    // The vertices are on the surface.
+    v.iter().for_each(|v| {
+        let p = v.point();
+        let (u, w) = surface.search_parameter(p, None, 100).unwrap();
+        assert!(surface.subs(u, w).near(&p));
+    });
+}
@@ -0,0 +1,134 @@
+use truck_modeling::{errors::Error, *};
+use truck_topology::shell::ShellCondition;
+
+/// the rectangle `[0, width] x [0, height]` whose corners are rounded by `radius`
+fn rounded_rectangle(width: f64, height: f64, radius: f64) -> builder::Sketch {
+    let mut sketch = builder::Sketch::start(Point2::new(radius, 0.0));
+    sketch
+        .line_to(Point2::new(width - radius, 0.0))
+        .arc_to(Point2::new(width, radius), radius)
+        .line_to(Point2::new(width, height - radius))
+        .arc_to(Point2::new(width - radius, height), radius)
+        .line_to(Point2::new(radius, height))
+        .arc_to(Point2::new(0.0, height - radius), radius)
+        .line_to(Point2::new(0.0, radius))
+        .arc_to(Point2::new(radius, 0.0), radius)
+        .close();
+    sketch
+}
+
+#[test]
+fn extrude_rounded_rectangle() {
+    let (width, height, radius) = (4.0, 3.0, 0.5);
+    let wire: Wire = rounded_rectangle(width, height, radius)
+        .build_wire(&Plane::xy())
+        .unwrap();
+    assert_eq!(wire.len(), 8);
+    assert!(wire.is_closed());
+    assert_eq!(wire.vertex_iter().count(), 8);
+
+    // the arcs are on the circles around the centers of the corners
+    let centers = [
+        Point3::new(width - radius, radius, 0.0),
+        Point3::new(width - radius, height - radius, 0.0),
+        Point3::new(radius, height - radius, 0.0),
+        Point3::new(radius, radius, 0.0),
+    ];
+    wire.iter()
+        .skip(1)
+        .step_by(2)
+        .zip(centers)
+        .for_each(|(edge, center)| {
+            let curve = edge.oriented_curve();
+            let (t0, t1) = curve.range_tuple();
+            (0..=8).for_each(|i| {
+                let p = curve.subs(t0 + (t1 - t0) * i as f64 / 8.0);
+                assert_near!(p.distance(center), radius);
+                assert!(p.x < center.x + radius + TOLERANCE);
+            });
+        });
+
+    let face: Face = builder::planar_face(wire).unwrap();
+    assert_near!(face.oriented_surface().normal(0.5, 0.5), Vector3::unit_z());
+    let solid: Solid = builder::tsweep(&face, Vector3::new(0.0, 0.0, 1.0));
+    let shell = &solid.boundaries()[0];
+    assert_eq!(shell.len(), 10);
+    assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+    assert!(solid.is_geometric_consistent());
+}
+
+#[test]
+fn sketch_on_tilted_plane() {
+    // The axes of the plane are not orthonormal, but the coordinates of the sketch are metric.
+    let plane = Plane::new(
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(1.0, 2.0, 0.0),
+        Point3::new(1.0, 1.0, 3.0),
+    );
+    let mut sketch = builder::Sketch::start(Point2::new(0.0, 0.0));
+    sketch
+        .line_to(Point2::new(2.0, 0.0))
+        .arc_to(Point2::new(0.0, 0.0), 1.0)
+        .close();
+    let wire: Wire = sketch.build_wire(&plane).unwrap();
+    // the half disk, since the last point is already the start point
+    assert_eq!(wire.len(), 2);
+    assert!(wire.is_closed());
+    assert_near!(wire[0].back().point(), Point3::new(1.0, 2.0, 0.0));
+    let curve = wire[1].oriented_curve();
+    let (t0, t1) = curve.range_tuple();
+    let center = Point3::new(1.0, 1.0, 0.0);
+    (0..=8).for_each(|i| {
+        let p = curve.subs(t0 + (t1 - t0) * i as f64 / 8.0);
+        assert_near!(p.distance(center), 1.0);
+        assert!(p.z > -TOLERANCE);
+    });
+    let face: Face = builder::planar_face(wire).unwrap();
+    let solid: Solid = builder::tsweep(&face, Vector3::unit_x());
+    assert!(solid.is_geometric_consistent());
+}
+
+#[test]
+fn arc_directions() {
+    let transit = |radius: f64| {
+        let mut sketch = builder::Sketch::start(Point2::new(1.0, 0.0));
+        sketch.arc_to(Point2::new(0.0, 1.0), radius);
+        let wire: Wire = sketch.build_wire(&Plane::xy()).unwrap();
+        assert!(!wire.is_closed());
+        let curve = wire[0].oriented_curve();
+        let (t0, t1) = curve.range_tuple();
+        curve.subs((t0 + t1) / 2.0)
+    };
+    let a = f64::sqrt(0.5);
+    // counter-clockwise around the origin
+    assert_near!(transit(1.0), Point3::new(a, a, 0.0));
+    // clockwise around (1, 1)
+    assert_near!(transit(-1.0), Point3::new(1.0 - a, 1.0 - a, 0.0));
+    // the half circle
+    let r = f64::sqrt(2.0) / 2.0;
+    let p = transit(r);
+    assert_near!(p.distance(Point3::new(0.5, 0.5, 0.0)), r);
+    assert_near!(
+        (p - Point3::new(0.5, 0.5, 0.0)).angle(Vector3::new(1.0, 1.0, 0.0)),
+        Rad(0.0)
+    );
+}
+
+#[test]
+fn sketch_failures() {
+    let mut sketch = builder::Sketch::start(Point2::new(0.0, 0.0));
+    sketch.arc_to(Point2::new(3.0, 0.0), 1.0);
+    assert_eq!(
+        sketch.build_wire::<Curve>(&Plane::xy()).unwrap_err(),
+        Error::ArcRadiusTooSmall,
+    );
+    let mut sketch = builder::Sketch::start(Point2::new(0.0, 0.0));
+    sketch
+        .line_to(Point2::new(1.0, 0.0))
+        .line_to(Point2::new(1.0, 0.0));
+    assert_eq!(
+        sketch.build_wire::<Curve>(&Plane::xy()).unwrap_err(),
+        Error::FromTopology(truck_topology::errors::Error::SameVertex),
+    );
+    assert_near!(sketch.current_point(), Point2::new(1.0, 0.0));
+}
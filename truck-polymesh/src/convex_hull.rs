@@ -0,0 +1,192 @@
+use crate::*;
+use rustc_hash::FxHashSet as HashSet;
+
+impl PolygonMesh {
+    /// Returns the convex hull of `points` as a closed, outward-oriented triangle mesh.
+    ///
+    /// Only the points on the hull are contained in the positions of the returned mesh.
+    ///
+    /// # Details
+    /// The hull is constructed incrementally from a tetrahedron spanned by extreme points.
+    /// The points nearer than `TOLERANCE` to the current hull are regarded as inside, so the
+    /// points on the faces of the hull are dropped, and the hull faces are always triangles.
+    ///
+    /// Degenerate inputs are handled as follows:
+    /// - If all points are on one plane, the hull is one flat polygon, the convex hull in the
+    ///   plane. The polygon is oriented counter-clockwise with respect to the normal
+    ///   `(p1 - p0).cross(p2 - p0)` of the first three extreme points.
+    /// - If all points are on one line or there are fewer than three distinct points,
+    ///   the returned mesh is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// // the eight corners of the unit cube and some interior points
+    /// let mut points = (0..8)
+    ///     .map(|i| Point3::new((i & 1) as f64, ((i >> 1) & 1) as f64, (i >> 2) as f64))
+    ///     .collect::<Vec<_>>();
+    /// points.extend([
+    ///     Point3::new(0.5, 0.5, 0.5),
+    ///     Point3::new(0.2, 0.7, 0.4),
+    ///     Point3::new(0.9, 0.1, 0.3),
+    ///     Point3::new(0.5, 0.5, 1.0),
+    /// ]);
+    /// let hull = PolygonMesh::convex_hull(&points);
+    /// assert_eq!(hull.positions().len(), 8);
+    /// assert_eq!(hull.tri_faces().len(), 12);
+    /// assert_eq!(hull.faces().len(), 12);
+    /// // all faces are outward
+    /// let center = Point3::new(0.5, 0.5, 0.5);
+    /// hull.face_iter().for_each(|face| {
+    ///     let p = face.iter().map(|v| hull.positions()[v.pos]).collect::<Vec<_>>();
+    ///     let normal = (p[1] - p[0]).cross(p[2] - p[0]);
+    ///     assert!(normal.dot(p[0] - center) > 0.0);
+    /// });
+    ///
+    /// // coplanar points: one flat polygon
+    /// let square = [
+    ///     Point3::new(0.0, 0.0, 0.0),
+    ///     Point3::new(1.0, 0.0, 0.0),
+    ///     Point3::new(0.5, 0.5, 0.0),
+    ///     Point3::new(1.0, 1.0, 0.0),
+    ///     Point3::new(0.0, 1.0, 0.0),
+    /// ];
+    /// let flat = PolygonMesh::convex_hull(&square);
+    /// assert_eq!(flat.positions().len(), 4);
+    /// assert_eq!(flat.faces().len(), 1);
+    /// assert_eq!(flat.faces()[0].len(), 4);
+    ///
+    /// // collinear points: empty
+    /// let line = [Point3::origin(), Point3::new(1.0, 1.0, 1.0), Point3::new(2.0, 2.0, 2.0)];
+    /// assert!(PolygonMesh::convex_hull(&line).faces().is_empty());
+    /// ```
+    pub fn convex_hull(points: &[Point3]) -> PolygonMesh {
+        let farthest = |dist: &dyn Fn(&Point3) -> f64| {
+            points
+                .iter()
+                .enumerate()
+                .map(|(i, p)| (i, dist(p)))
+                .max_by(|x, y| x.1.total_cmp(&y.1))
+        };
+        let Some(&p0) = points.first() else {
+            return PolygonMesh::default();
+        };
+        let (i1, _) = farthest(&|p| p.distance2(p0)).unwrap();
+        let p1 = points[i1];
+        let dir = p1 - p0;
+        if dir.magnitude2().so_small2() {
+            return PolygonMesh::default();
+        }
+        let (i2, d2) = farthest(&|p| (p - p0).cross(dir).magnitude() / dir.magnitude()).unwrap();
+        if d2.so_small() {
+            return PolygonMesh::default();
+        }
+        let normal = dir.cross(points[i2] - p0).normalize();
+        let (i3, d3) = farthest(&|p| (p - p0).dot(normal).abs()).unwrap();
+        if d3.so_small() {
+            return planar_hull(points, normal);
+        }
+
+        let mut tris = match (points[i3] - p0).dot(normal) > 0.0 {
+            true => vec![[0, i2, i1], [0, i1, i3], [i1, i2, i3], [i2, 0, i3]],
+            false => vec![[0, i1, i2], [0, i3, i1], [i1, i3, i2], [i2, i3, 0]],
+        };
+        let signed_distance = |[a, b, c]: [usize; 3], p: Point3| {
+            let n = (points[b] - points[a]).cross(points[c] - points[a]);
+            (p - points[a]).dot(n) / n.magnitude()
+        };
+        points.iter().enumerate().for_each(|(i, p)| {
+            if [0, i1, i2, i3].contains(&i) {
+                return;
+            }
+            let (visible, rest): (Vec<_>, Vec<_>) = tris
+                .iter()
+                .copied()
+                .partition(|tri| signed_distance(*tri, *p) > TOLERANCE);
+            if visible.is_empty() {
+                return;
+            }
+            let edges = visible
+                .iter()
+                .flat_map(|[a, b, c]: &[usize; 3]| [(*a, *b), (*b, *c), (*c, *a)])
+                .collect::<HashSet<_>>();
+            let horizon = edges
+                .iter()
+                .filter(|(a, b)| !edges.contains(&(*b, *a)))
+                .map(|(a, b)| [*a, *b, i])
+                .collect::<Vec<_>>();
+            tris = rest.into_iter().chain(horizon).collect();
+        });
+
+        let mut indices = vec![usize::MAX; points.len()];
+        let mut positions = Vec::new();
+        let tris = tris
+            .into_iter()
+            .map(|tri| {
+                tri.map(|i| {
+                    if indices[i] == usize::MAX {
+                        indices[i] = positions.len();
+                        positions.push(points[i]);
+                    }
+                    indices[i]
+                })
+            })
+            .collect::<Vec<_>>();
+        PolygonMesh::new(
+            StandardAttributes {
+                positions,
+                ..Default::default()
+            },
+            Faces::from_iter(&tris),
+        )
+    }
+}
+
+/// Returns the convex hull of the coplanar `points` as one polygon on the plane with `normal`.
+fn planar_hull(points: &[Point3], normal: Vector3) -> PolygonMesh {
+    let axis = match normal.x.abs() < 0.5 {
+        true => Vector3::unit_x(),
+        false => Vector3::unit_y(),
+    };
+    let u = normal.cross(axis).normalize();
+    let v = normal.cross(u);
+    let project = |p: &Point3| Point2::new(p.to_vec().dot(u), p.to_vec().dot(v));
+    let mut indices = (0..points.len()).collect::<Vec<_>>();
+    indices.sort_by(|i, j| {
+        let (p, q) = (project(&points[*i]), project(&points[*j]));
+        p.x.total_cmp(&q.x).then(p.y.total_cmp(&q.y))
+    });
+    // Andrew's monotone chain
+    let cross = |o: usize, a: usize, b: usize| {
+        let (o, a, b) = (
+            project(&points[o]),
+            project(&points[a]),
+            project(&points[b]),
+        );
+        (a - o).perp_dot(b - o)
+    };
+    let chain = |iter: &mut dyn Iterator<Item = usize>| {
+        let mut hull = Vec::<usize>::new();
+        iter.for_each(|i| {
+            while hull.len() >= 2
+                && cross(hull[hull.len() - 2], hull[hull.len() - 1], i) <= TOLERANCE2
+            {
+                hull.pop();
+            }
+            hull.push(i);
+        });
+        hull.pop();
+        hull
+    };
+    let mut hull = chain(&mut indices.iter().copied());
+    hull.extend(chain(&mut indices.iter().rev().copied()));
+    let positions = hull.iter().map(|i| points[*i]).collect::<Vec<_>>();
+    let face = (0..positions.len()).collect::<Vec<_>>();
+    PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        Faces::from_iter([face.as_slice()]),
+    )
+}
@@ -0,0 +1,113 @@
+use crate::*;
+
+impl CoordinateSystem {
+    /// Returns the matrix which maps the coordinates in `self` to the coordinates in `to`.
+    ///
+    /// The matrix is orthogonal, and its determinant is `-1` if and only if the handedness
+    /// of `self` and `to` differs.
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// let mat = CoordinateSystem::YUpRightHanded.conversion_matrix(CoordinateSystem::ZUpRightHanded);
+    /// // up goes to up, and forward goes to forward.
+    /// assert_near!(mat * Vector3::unit_y(), Vector3::unit_z());
+    /// assert_near!(mat * Vector3::unit_z(), -Vector3::unit_y());
+    /// assert_near!(mat.determinant(), 1.0);
+    ///
+    /// let mat = CoordinateSystem::YUpLeftHanded.conversion_matrix(CoordinateSystem::ZUpRightHanded);
+    /// assert_near!(mat * Vector3::unit_y(), Vector3::unit_z());
+    /// assert_near!(mat.determinant(), -1.0);
+    /// ```
+    pub fn conversion_matrix(self, to: CoordinateSystem) -> Matrix3 {
+        to.to_truck().transpose() * self.to_truck()
+    }
+
+    /// Returns `true` if the system is right-handed.
+    #[inline(always)]
+    pub const fn is_right_handed(self) -> bool {
+        matches!(
+            self,
+            CoordinateSystem::ZUpRightHanded | CoordinateSystem::YUpRightHanded
+        )
+    }
+
+    /// the matrix which maps the coordinates in `self` to the ones in `ZUpRightHanded`
+    fn to_truck(self) -> Matrix3 {
+        // columns are the images of x, y and z-axes.
+        match self {
+            CoordinateSystem::ZUpRightHanded => Matrix3::identity(),
+            CoordinateSystem::YUpRightHanded => Matrix3::new(
+                1.0, 0.0, 0.0, //
+                0.0, 0.0, 1.0, //
+                0.0, -1.0, 0.0,
+            ),
+            CoordinateSystem::ZUpLeftHanded => Matrix3::new(
+                1.0, 0.0, 0.0, //
+                0.0, -1.0, 0.0, //
+                0.0, 0.0, 1.0,
+            ),
+            CoordinateSystem::YUpLeftHanded => Matrix3::new(
+                1.0, 0.0, 0.0, //
+                0.0, 0.0, 1.0, //
+                0.0, 1.0, 0.0,
+            ),
+        }
+    }
+}
+
+impl PolygonMesh {
+    /// Converts the coordinates of positions and normals from `from` to `to`.
+    ///
+    /// If the handedness of `from` and `to` differs, the winding of faces is reversed so that
+    /// the faces keep facing the same side as the normals.
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// // a triangle on the ground of a y-up left-handed asset
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(0.0, 0.0, 1.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///         ],
+    ///         normals: vec![Vector3::unit_y()],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter([[(0, None, Some(0)), (1, None, Some(0)), (2, None, Some(0))]]),
+    /// );
+    /// mesh.convert_coordinate_system(
+    ///     CoordinateSystem::YUpLeftHanded,
+    ///     CoordinateSystem::ZUpRightHanded,
+    /// );
+    /// assert_near!(mesh.normals()[0], Vector3::unit_z());
+    /// assert_near!(mesh.positions()[1], Point3::new(0.0, 1.0, 0.0));
+    ///
+    /// // the winding agrees with the normal after the conversion.
+    /// let face = mesh.faces().tri_faces()[0];
+    /// let p = |i: usize| mesh.positions()[face[i].pos];
+    /// assert!((p(1) - p(0)).cross(p(2) - p(0)).z > 0.0);
+    /// ```
+    pub fn convert_coordinate_system(
+        &mut self,
+        from: CoordinateSystem,
+        to: CoordinateSystem,
+    ) -> &mut Self {
+        if from == to {
+            return self;
+        }
+        let mat = from.conversion_matrix(to);
+        self.attributes
+            .positions
+            .iter_mut()
+            .for_each(|p| *p = Point3::from_vec(mat * p.to_vec()));
+        self.attributes
+            .normals
+            .iter_mut()
+            .for_each(|n| *n = mat * *n);
+        if from.is_right_handed() != to.is_right_handed() {
+            self.faces.invert();
+        }
+        self
+    }
+}
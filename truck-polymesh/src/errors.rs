@@ -80,6 +80,12 @@ pub enum Error<V: std::fmt::Debug = crate::StandardVertex> {
     /// ```
     #[error("This division vector is unsorted.")]
     UnsortedDivision,
+    /// Some attributes of the mesh cannot be stored in STL.
+    /// cf. [`stl::write_checked`](crate::stl::write_checked)
+    #[error(
+        "STL cannot store the attribute {0:?}. Use a lossless format, e.g. OBJ, PLY, or glTF."
+    )]
+    DroppedByStl(crate::stl::DroppedAttribute),
     /// Errors caused by obj files I/O.
     #[error(transparent)]
     FromIO(#[from] std::io::Error),
@@ -13,6 +13,37 @@ impl From<&Vertex> for Vertex {
     fn from(v: &Vertex) -> Vertex { *v }
 }
 
+impl Vertex {
+    /// Returns the vertex whose indices of the position, the texture coordinate and the normal
+    /// are all shifted by `offset`.
+    ///
+    /// This is useful to build faces against external index bases, e.g. `shifted(-1)` converts
+    /// 1-based indices into the 0-based indices of truck.
+    ///
+    /// # Panics
+    /// Panics if some shifted index is negative or overflows.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// let one_based = StandardVertex { pos: 4, uv: None, nor: Some(1) };
+    /// let zero_based = one_based.shifted(-1);
+    /// assert_eq!(zero_based, StandardVertex { pos: 3, uv: None, nor: Some(0) });
+    /// assert_eq!(zero_based.shifted(1), one_based);
+    /// ```
+    pub fn shifted(&self, offset: isize) -> Vertex {
+        let shift = |idx: usize| {
+            idx.checked_add_signed(offset)
+                .unwrap_or_else(|| panic!("The index {idx} shifted by {offset} is out of range."))
+        };
+        Vertex {
+            pos: shift(self.pos),
+            uv: self.uv.map(shift),
+            nor: self.nor.map(shift),
+        }
+    }
+}
+
 impl<T: AsVertexSlice> AsVertexSlice for &T {
     type V = T::V;
 }
@@ -179,6 +210,35 @@ impl<V: Copy> Faces<V> {
     }
 
     /// Returns the vector of triangles.
+    ///
+    /// Together with [`Faces::quad_faces`] and [`Faces::other_faces`], the faces can be read
+    /// by their types, e.g. to build separate index buffers without triangulating quadrangles.
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// let slice: &[&[usize]] = &[
+    ///     &[0, 1, 2],
+    ///     &[0, 2, 3, 4],
+    ///     &[4, 5, 6],
+    ///     &[0, 1, 4, 5, 6],
+    /// ];
+    /// let faces: Faces = Faces::from_iter(slice);
+    /// let tri_indices: Vec<u32> = faces
+    ///     .tri_faces()
+    ///     .iter()
+    ///     .flatten()
+    ///     .map(|v| v.pos as u32)
+    ///     .collect();
+    /// assert_eq!(tri_indices, vec![0, 1, 2, 4, 5, 6]);
+    /// let quad_indices: Vec<u32> = faces
+    ///     .quad_faces()
+    ///     .iter()
+    ///     .flatten()
+    ///     .map(|v| v.pos as u32)
+    ///     .collect();
+    /// assert_eq!(quad_indices, vec![0, 2, 3, 4]);
+    /// assert_eq!(faces.other_faces().len(), 1);
+    /// ```
     #[inline(always)]
     pub const fn tri_faces(&self) -> &Vec<[V; 3]> { &self.tri_faces }
 
@@ -0,0 +1,361 @@
+use crate::*;
+use std::io::Write;
+
+type Result<T> = std::result::Result<T, errors::Error>;
+
+const GLB_MAGIC: u32 = 0x4654_6C67;
+const CHUNK_JSON: u32 = 0x4E4F_534A;
+const CHUNK_BIN: u32 = 0x004E_4942;
+const FLOAT: u32 = 5126;
+const UNSIGNED_INT: u32 = 5125;
+const ARRAY_BUFFER: u32 = 34962;
+const ELEMENT_ARRAY_BUFFER: u32 = 34963;
+
+/// Writes [`PolygonMesh`] to binary glTF (GLB) as a scene with a single node.
+///
+/// Same as [`write_scene`] with a single unnamed node with the identity transform.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// let mesh = PolygonMesh::new(
+///     StandardAttributes {
+///         positions: vec![
+///             Point3::new(0.0, 0.0, 0.0),
+///             Point3::new(1.0, 0.0, 0.0),
+///             Point3::new(0.0, 1.0, 0.0),
+///         ],
+///         ..Default::default()
+///     },
+///     Faces::from_iter(&[[0, 1, 2]]),
+/// );
+/// let mut bytes = Vec::new();
+/// gltf::write(&mesh, &mut bytes).unwrap();
+/// assert_eq!(&bytes[0..4], b"glTF");
+/// assert_eq!(bytes.len() % 4, 0);
+/// ```
+pub fn write<W: Write>(mesh: &PolygonMesh, writer: W) -> Result<()> {
+    write_scene(&[(String::new(), Matrix4::identity(), mesh)], writer)
+}
+
+/// Writes [`PolygonMesh`] with the colors of positions to binary glTF (GLB) as a scene with a
+/// single node.
+///
+/// Same as [`write`], and writes the colors as the attribute `COLOR_0` of `FLOAT` `VEC3`. The
+/// `i`-th color corresponds to `mesh.positions()[i]`, and the number of colors must be that of
+/// positions.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// let mesh = PolygonMesh::new(
+///     StandardAttributes {
+///         positions: vec![
+///             Point3::new(0.0, 0.0, 0.0),
+///             Point3::new(1.0, 0.0, 0.0),
+///             Point3::new(0.0, 1.0, 0.0),
+///         ],
+///         ..Default::default()
+///     },
+///     Faces::from_iter(&[[0, 1, 2]]),
+/// );
+/// let mut bytes = Vec::new();
+/// gltf::write_with_colors(&mesh, &[[1.0, 0.0, 0.0]; 3], &mut bytes).unwrap();
+/// assert_eq!(&bytes[0..4], b"glTF");
+/// assert!(gltf::write_with_colors(&mesh, &[[1.0, 0.0, 0.0]; 2], &mut Vec::new()).is_err());
+/// ```
+pub fn write_with_colors<W: Write>(
+    mesh: &PolygonMesh,
+    colors: &[[f32; 3]],
+    writer: W,
+) -> Result<()> {
+    if colors.len() != mesh.positions().len() {
+        return Err(invalid_input(
+            "the number of colors is not that of positions",
+        ));
+    }
+    write_nodes(&[("", Matrix4::identity(), (mesh, Some(colors)))], writer)
+}
+
+/// Writes named and transformed meshes to binary glTF (GLB) as a scene with one node per mesh.
+///
+/// # Details
+/// - Each node has the name and the transform matrix, which must be affine.
+/// - The nodes referring to the same mesh, compared by reference, share one glTF mesh.
+/// - All meshes are packed into one binary buffer. Each mesh is triangulated, and its
+///   vertices are expanded so that each vertex has a unique set of attributes.
+/// - The normals and the texture coordinates are written if all vertices of the mesh have them.
+///   The texture coordinates are flipped vertically since the origin of glTF is at the top left.
+///   The colors are written only by [`write_with_colors`] for a single mesh.
+/// - The coordinates are written as they are. glTF is Y-up, so Z-up meshes should be converted
+///   by [`PolygonMesh::convert_coordinate_system`] or by the transforms of nodes.
+/// - Returns an error if the transform matrices or the positions have infinite or NaN
+///   components, including the positions overflowing `f32`, since JSON has no infinity or NaN.
+pub fn write_scene<W: Write>(nodes: &[(String, Matrix4, &PolygonMesh)], writer: W) -> Result<()> {
+    let nodes = nodes
+        .iter()
+        .map(|(name, matrix, mesh)| (name.as_str(), *matrix, (*mesh, None)))
+        .collect::<Vec<_>>();
+    write_nodes(&nodes, writer)
+}
+
+/// a mesh with the optional colors of its positions
+type ColoredMesh<'a> = (&'a PolygonMesh, Option<&'a [[f32; 3]]>);
+
+fn write_nodes<W: Write>(nodes: &[(&str, Matrix4, ColoredMesh<'_>)], mut writer: W) -> Result<()> {
+    let same = |(m0, c0): ColoredMesh<'_>, (m1, c1): ColoredMesh<'_>| {
+        std::ptr::eq(m0, m1) && c0.map(<[_]>::as_ptr) == c1.map(<[_]>::as_ptr)
+    };
+    let mut meshes = Vec::<ColoredMesh<'_>>::new();
+    let node_meshes = nodes
+        .iter()
+        .map(
+            |(_, _, mesh)| match meshes.iter().position(|m| same(*m, *mesh)) {
+                Some(idx) => idx,
+                None => {
+                    meshes.push(*mesh);
+                    meshes.len() - 1
+                }
+            },
+        )
+        .collect::<Vec<_>>();
+    let finite_matrices = nodes.iter().all(|(_, matrix, _)| {
+        let matrix: &[f64; 16] = matrix.as_ref();
+        matrix.iter().all(|x| x.is_finite())
+    });
+    if !finite_matrices {
+        return Err(invalid_input(
+            "the transform matrix has a non-finite component",
+        ));
+    }
+    let mut buffer = Buffer::default();
+    let gltf_meshes = meshes
+        .iter()
+        .map(|(mesh, colors)| buffer.push_mesh(mesh, *colors))
+        .collect::<Result<Vec<_>>>()?;
+    let mesh_indices = gltf_meshes
+        .iter()
+        .scan(0, |count, primitive| {
+            let idx = primitive.as_ref().map(|_| *count);
+            *count += primitive.is_some() as usize;
+            Some(idx)
+        })
+        .collect::<Vec<_>>();
+    let json_nodes = nodes
+        .iter()
+        .zip(node_meshes)
+        .map(|((name, matrix, _), mesh)| {
+            let matrix: &[f64; 16] = matrix.as_ref();
+            let mesh = match mesh_indices[mesh] {
+                Some(idx) => format!(",\"mesh\":{idx}"),
+                None => String::new(),
+            };
+            format!(
+                "{{\"name\":{},\"matrix\":{}{mesh}}}",
+                json_string(name),
+                json_array(matrix)
+            )
+        })
+        .collect::<Vec<_>>();
+    let json_meshes = gltf_meshes
+        .into_iter()
+        .flatten()
+        .map(|primitive| format!("{{\"primitives\":[{primitive}]}}"))
+        .collect::<Vec<_>>();
+    let mut json = format!(
+        "{{\"asset\":{{\"version\":\"2.0\",\"generator\":\"truck-polymesh\"}},\
+         \"scene\":0,\"scenes\":[{{\"nodes\":{}}}],\"nodes\":[{}]",
+        json_array(0..nodes.len()),
+        json_nodes.join(","),
+    );
+    if !buffer.bin.is_empty() {
+        json += &format!(
+            ",\"meshes\":[{}],\"accessors\":[{}],\"bufferViews\":[{}],\
+             \"buffers\":[{{\"byteLength\":{}}}]",
+            json_meshes.join(","),
+            buffer.accessors.join(","),
+            buffer.buffer_views.join(","),
+            buffer.bin.len(),
+        );
+    }
+    json += "}";
+
+    let mut json = json.into_bytes();
+    json.resize(json.len().next_multiple_of(4), b' ');
+    let mut bin = buffer.bin;
+    bin.resize(bin.len().next_multiple_of(4), 0);
+    let bin_chunk_len = match bin.is_empty() {
+        true => 0,
+        false => 8 + bin.len(),
+    };
+    let length = 12 + 8 + json.len() + bin_chunk_len;
+    writer.write_all(&GLB_MAGIC.to_le_bytes())?;
+    writer.write_all(&2_u32.to_le_bytes())?;
+    writer.write_all(&(length as u32).to_le_bytes())?;
+    writer.write_all(&(json.len() as u32).to_le_bytes())?;
+    writer.write_all(&CHUNK_JSON.to_le_bytes())?;
+    writer.write_all(&json)?;
+    if !bin.is_empty() {
+        writer.write_all(&(bin.len() as u32).to_le_bytes())?;
+        writer.write_all(&CHUNK_BIN.to_le_bytes())?;
+        writer.write_all(&bin)?;
+    }
+    Ok(())
+}
+
+/// the binary buffer with the JSON descriptions of its views and accessors
+#[derive(Debug, Default)]
+struct Buffer {
+    bin: Vec<u8>,
+    buffer_views: Vec<String>,
+    accessors: Vec<String>,
+}
+
+impl Buffer {
+    /// Pushes a view of `data` and its accessor, and returns the index of the accessor.
+    fn push_accessor<T: bytemuck::Pod>(
+        &mut self,
+        data: &[T],
+        (component_type, r#type, target): (u32, &str, u32),
+        bounds: Option<(&[f32], &[f32])>,
+    ) -> usize {
+        let bytes: &[u8] = bytemuck::cast_slice(data);
+        self.buffer_views.push(format!(
+            "{{\"buffer\":0,\"byteOffset\":{},\"byteLength\":{},\"target\":{target}}}",
+            self.bin.len(),
+            bytes.len(),
+        ));
+        self.bin.extend_from_slice(bytes);
+        let bounds = match bounds {
+            Some((min, max)) => format!(",\"min\":{},\"max\":{}", json_array(min), json_array(max)),
+            None => String::new(),
+        };
+        self.accessors.push(format!(
+            "{{\"bufferView\":{},\"componentType\":{component_type},\"count\":{},\"type\":\"{}\"{bounds}}}",
+            self.buffer_views.len() - 1,
+            data.len(),
+            r#type,
+        ));
+        self.accessors.len() - 1
+    }
+
+    /// Pushes the data of `mesh` with the colors of positions, and returns the JSON of the
+    /// primitive, or `None` if `mesh` has no triangles.
+    ///
+    /// Returns an error if some positions are not finite in `f32`, since their bounds are written
+    /// in JSON.
+    fn push_mesh(
+        &mut self,
+        mesh: &PolygonMesh,
+        colors: Option<&[[f32; 3]]>,
+    ) -> Result<Option<String>> {
+        let colored = PolygonMesh::debug_new(
+            ColoredAttributes {
+                attributes: mesh.attributes(),
+                colors,
+            },
+            mesh.faces().clone(),
+        );
+        let expanded = colored.expands(|attr| attr);
+        let (vertices, colors): (Vec<_>, Vec<_>) = expanded.attributes().iter().copied().unzip();
+        let indices = expanded
+            .faces()
+            .triangle_iter()
+            .flatten()
+            .map(|i| i as u32)
+            .collect::<Vec<_>>();
+        if indices.is_empty() {
+            return Ok(None);
+        }
+        let positions = vertices
+            .iter()
+            .map(|v| {
+                let position: [f64; 3] = v.position.into();
+                let position = position.map(|x| x as f32);
+                match position.iter().all(|x| x.is_finite()) {
+                    true => Ok(position),
+                    false => Err(invalid_input("the position has a non-finite component")),
+                }
+            })
+            .collect::<Result<Vec<[f32; 3]>>>()?;
+        let (min, max) = positions.iter().fold(
+            ([f32::INFINITY; 3], [f32::NEG_INFINITY; 3]),
+            |(min, max), p| {
+                (
+                    [0, 1, 2].map(|i| f32::min(min[i], p[i])),
+                    [0, 1, 2].map(|i| f32::max(max[i], p[i])),
+                )
+            },
+        );
+        let position = self.push_accessor(
+            &positions,
+            (FLOAT, "VEC3", ARRAY_BUFFER),
+            Some((&min, &max)),
+        );
+        let mut attributes = format!("\"POSITION\":{position}");
+        let normals = vertices
+            .iter()
+            .map(|v| Some(v.normal?.cast::<f32>()?.into()))
+            .collect::<Option<Vec<[f32; 3]>>>();
+        if let Some(normals) = normals {
+            let normal = self.push_accessor(&normals, (FLOAT, "VEC3", ARRAY_BUFFER), None);
+            attributes += &format!(",\"NORMAL\":{normal}");
+        }
+        let uv_coords = vertices
+            .iter()
+            .map(|v| v.uv_coord.map(|uv| [uv.x as f32, 1.0 - uv.y as f32]))
+            .collect::<Option<Vec<[f32; 2]>>>();
+        if let Some(uv_coords) = uv_coords {
+            let uv = self.push_accessor(&uv_coords, (FLOAT, "VEC2", ARRAY_BUFFER), None);
+            attributes += &format!(",\"TEXCOORD_0\":{uv}");
+        }
+        if let Some(colors) = colors.into_iter().collect::<Option<Vec<[f32; 3]>>>() {
+            let color = self.push_accessor(&colors, (FLOAT, "VEC3", ARRAY_BUFFER), None);
+            attributes += &format!(",\"COLOR_0\":{color}");
+        }
+        let indices = self.push_accessor(
+            &indices,
+            (UNSIGNED_INT, "SCALAR", ELEMENT_ARRAY_BUFFER),
+            None,
+        );
+        Ok(Some(format!(
+            "{{\"attributes\":{{{attributes}}},\"indices\":{indices},\"mode\":4}}"
+        )))
+    }
+}
+
+/// the attributes with the colors of positions
+#[derive(Clone, Copy, Debug)]
+struct ColoredAttributes<'a> {
+    attributes: &'a StandardAttributes,
+    colors: Option<&'a [[f32; 3]]>,
+}
+
+impl Attributes<StandardVertex> for ColoredAttributes<'_> {
+    type Output = (StandardAttribute, Option<[f32; 3]>);
+    fn get(&self, v: StandardVertex) -> Option<Self::Output> {
+        let color = match self.colors {
+            Some(colors) => Some(*colors.get(v.pos)?),
+            None => None,
+        };
+        Some((self.attributes.get(v)?, color))
+    }
+}
+
+fn invalid_input(message: &str) -> errors::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidInput, message).into()
+}
+
+fn json_array<T: std::fmt::Display>(iter: impl IntoIterator<Item = T>) -> String {
+    let elements = iter.into_iter().map(|x| x.to_string()).collect::<Vec<_>>();
+    format!("[{}]", elements.join(","))
+}
+
+fn json_string(s: &str) -> String {
+    let mut res = String::from("\"");
+    s.chars().for_each(|c| match c {
+        '"' => res += "\\\"",
+        '\\' => res += "\\\\",
+        c if (c as u32) < 0x20 => res += &format!("\\u{:04x}", c as u32),
+        c => res.push(c),
+    });
+    res + "\""
+}
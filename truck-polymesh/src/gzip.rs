@@ -0,0 +1,32 @@
+use flate2::read::GzDecoder;
+use std::io::{Chain, Cursor, Read, Result};
+
+type Peeked<R> = Chain<Cursor<Vec<u8>>, R>;
+
+/// The reader which decompresses the stream if it starts with the gzip magic bytes.
+pub(crate) enum MaybeGzip<R: Read> {
+    Plain(Peeked<R>),
+    Gzip(GzDecoder<Peeked<R>>),
+}
+
+impl<R: Read> MaybeGzip<R> {
+    pub(crate) fn new(mut reader: R) -> Result<Self> {
+        let mut magic = Vec::with_capacity(2);
+        (&mut reader).take(2).read_to_end(&mut magic)?;
+        let is_gzip = magic == [0x1f, 0x8b];
+        let reader = Cursor::new(magic).chain(reader);
+        Ok(match is_gzip {
+            true => MaybeGzip::Gzip(GzDecoder::new(reader)),
+            false => MaybeGzip::Plain(reader),
+        })
+    }
+}
+
+impl<R: Read> Read for MaybeGzip<R> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        match self {
+            MaybeGzip::Plain(reader) => reader.read(buf),
+            MaybeGzip::Gzip(reader) => reader.read(buf),
+        }
+    }
+}
@@ -0,0 +1,263 @@
+use crate::*;
+use rustc_hash::FxHashMap as HashMap;
+
+/// A half-edge of [`HalfEdgeMesh`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct HalfEdge {
+    vertex: StandardVertex,
+    next: usize,
+    prev: usize,
+    twin: Option<usize>,
+    face: usize,
+}
+
+/// Polygon mesh in the half-edge data structure, for the navigation around faces and vertices.
+///
+/// Half-edges, faces and vertices are referred by indices. Each face is a loop of half-edges
+/// linked by [`HalfEdgeMesh::next`], and each half-edge starts from [`HalfEdgeMesh::vertex`].
+///
+/// # Non-manifold policy
+/// A half-edge is paired with its [`HalfEdgeMesh::twin`] only if its edge is shared by exactly
+/// two faces with the opposite directions. The half-edges on the other edges, i.e. the edges
+/// on the boundary, shared by more than two faces, or shared by inconsistently oriented faces,
+/// have no twins and are regarded as boundary. Hence any mesh is converted, and the conversion
+/// into [`PolygonMesh`] restores the original one.
+///
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// use half_edge::HalfEdgeMesh;
+/// // the unit cube
+/// let positions = (0..8)
+///     .map(|i| Point3::new((i & 1) as f64, ((i >> 1) & 1) as f64, (i >> 2) as f64))
+///     .collect::<Vec<_>>();
+/// let faces = Faces::from_iter(&[
+///     [0, 2, 3, 1],
+///     [4, 5, 7, 6],
+///     [0, 1, 5, 4],
+///     [1, 3, 7, 5],
+///     [3, 2, 6, 7],
+///     [2, 0, 4, 6],
+/// ]);
+/// let cube = PolygonMesh::new(
+///     StandardAttributes {
+///         positions,
+///         ..Default::default()
+///     },
+///     faces,
+/// );
+///
+/// let half_edge_mesh = HalfEdgeMesh::from_polygon_mesh(&cube);
+/// assert_eq!(half_edge_mesh.half_edge_len(), 24);
+/// assert!(half_edge_mesh.is_closed());
+///
+/// // walk around the first face
+/// let h = half_edge_mesh.face_half_edge(0);
+/// let loop_len = std::iter::successors(Some(h), |h| Some(half_edge_mesh.next(*h)))
+///     .skip(1)
+///     .position(|x| x == h);
+/// assert_eq!(loop_len, Some(3));
+///
+/// // the twin is on the adjacent face, and runs the opposite direction
+/// let twin = half_edge_mesh.twin(h).unwrap();
+/// assert_ne!(half_edge_mesh.face(twin), 0);
+/// assert_eq!(half_edge_mesh.vertex(twin), half_edge_mesh.vertex(half_edge_mesh.next(h)));
+///
+/// // three faces around each corner
+/// assert_eq!(half_edge_mesh.outgoing_half_edges(0).count(), 3);
+///
+/// // round trip
+/// assert_eq!(half_edge_mesh.into_polygon_mesh(), cube);
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct HalfEdgeMesh {
+    attributes: StandardAttributes,
+    half_edges: Vec<HalfEdge>,
+    face_half_edges: Vec<usize>,
+    vertex_half_edges: Vec<Option<usize>>,
+}
+
+impl HalfEdgeMesh {
+    /// Converts `mesh` into the half-edge data structure.
+    ///
+    /// The faces are indexed in the order of [`PolygonMesh::face_iter`], and the half-edges of
+    /// each face are indexed in the order of its vertices.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// use half_edge::HalfEdgeMesh;
+    /// // three triangles sharing the edge from 0 to 1, and one more triangle next to the first
+    /// let positions = vec![
+    ///     Point3::new(0.0, 0.0, 0.0),
+    ///     Point3::new(1.0, 0.0, 0.0),
+    ///     Point3::new(0.5, 1.0, 0.0),
+    ///     Point3::new(0.5, -1.0, 0.0),
+    ///     Point3::new(0.5, 0.0, 1.0),
+    ///     Point3::new(1.5, 1.0, 0.0),
+    /// ];
+    /// let mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions,
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[0, 1, 2], [1, 0, 3], [0, 1, 4], [1, 5, 2]]),
+    /// );
+    /// let half_edge_mesh = HalfEdgeMesh::from_polygon_mesh(&mesh);
+    /// // the non-manifold edge is regarded as boundary
+    /// (0..3).for_each(|face| {
+    ///     let h = half_edge_mesh.face_half_edge(face);
+    ///     assert!(half_edge_mesh.is_boundary(h));
+    /// });
+    /// // the manifold edge from 1 to 2
+    /// let h = half_edge_mesh.next(half_edge_mesh.face_half_edge(0));
+    /// assert_eq!(half_edge_mesh.face(half_edge_mesh.twin(h).unwrap()), 3);
+    /// assert_eq!(half_edge_mesh.into_polygon_mesh(), mesh);
+    /// ```
+    pub fn from_polygon_mesh(mesh: &PolygonMesh) -> Self {
+        let mut half_edges = Vec::new();
+        let face_half_edges = mesh
+            .face_iter()
+            .enumerate()
+            .map(|(face, vertices)| {
+                let (start, n) = (half_edges.len(), vertices.len());
+                half_edges.extend(vertices.iter().enumerate().map(|(i, vertex)| HalfEdge {
+                    vertex: *vertex,
+                    next: start + (i + 1) % n,
+                    prev: start + (i + n - 1) % n,
+                    twin: None,
+                    face,
+                }));
+                start
+            })
+            .collect::<Vec<_>>();
+
+        let mut edges = HashMap::<[usize; 2], Vec<usize>>::default();
+        (0..half_edges.len()).for_each(|h| {
+            let (a, b) = (
+                half_edges[h].vertex.pos,
+                half_edges[half_edges[h].next].vertex.pos,
+            );
+            if a != b {
+                edges.entry([a.min(b), a.max(b)]).or_default().push(h);
+            }
+        });
+        edges.into_values().for_each(|pair| {
+            if let [h0, h1] = pair[..] {
+                if half_edges[h0].vertex.pos == half_edges[half_edges[h1].next].vertex.pos {
+                    half_edges[h0].twin = Some(h1);
+                    half_edges[h1].twin = Some(h0);
+                }
+            }
+        });
+
+        // The boundary half-edges are preferred, so that the rotation around a boundary vertex
+        // starts on the boundary.
+        let mut vertex_half_edges = vec![None; mesh.positions().len()];
+        half_edges.iter().enumerate().for_each(|(h, half_edge)| {
+            let entry = &mut vertex_half_edges[half_edge.vertex.pos];
+            if entry.is_none() || half_edge.twin.is_none() {
+                *entry = Some(h);
+            }
+        });
+
+        Self {
+            attributes: mesh.attributes().clone(),
+            half_edges,
+            face_half_edges,
+            vertex_half_edges,
+        }
+    }
+
+    /// Converts into the polygon mesh.
+    pub fn into_polygon_mesh(self) -> PolygonMesh {
+        let faces = self
+            .face_half_edges
+            .iter()
+            .map(|h| {
+                self.face_loop(*h)
+                    .map(|h| self.vertex(h))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        PolygonMesh::new_unchecked(self.attributes, faces.iter().collect())
+    }
+
+    /// Returns the attributes.
+    #[inline(always)]
+    pub fn attributes(&self) -> &StandardAttributes { &self.attributes }
+
+    /// Returns the number of half-edges.
+    #[inline(always)]
+    pub fn half_edge_len(&self) -> usize { self.half_edges.len() }
+
+    /// Returns the number of faces.
+    #[inline(always)]
+    pub fn face_len(&self) -> usize { self.face_half_edges.len() }
+
+    /// Returns the next half-edge on the same face.
+    #[inline(always)]
+    pub fn next(&self, half_edge: usize) -> usize { self.half_edges[half_edge].next }
+
+    /// Returns the previous half-edge on the same face.
+    #[inline(always)]
+    pub fn prev(&self, half_edge: usize) -> usize { self.half_edges[half_edge].prev }
+
+    /// Returns the half-edge in the opposite direction on the adjacent face, or `None` if
+    /// `half_edge` is on the boundary.
+    #[inline(always)]
+    pub fn twin(&self, half_edge: usize) -> Option<usize> { self.half_edges[half_edge].twin }
+
+    /// Returns the vertex from which `half_edge` starts.
+    #[inline(always)]
+    pub fn vertex(&self, half_edge: usize) -> StandardVertex { self.half_edges[half_edge].vertex }
+
+    /// Returns the face which `half_edge` is on.
+    #[inline(always)]
+    pub fn face(&self, half_edge: usize) -> usize { self.half_edges[half_edge].face }
+
+    /// Returns the first half-edge of `face`.
+    #[inline(always)]
+    pub fn face_half_edge(&self, face: usize) -> usize { self.face_half_edges[face] }
+
+    /// Returns a half-edge starting from the position with index `pos`, or `None` if the position
+    /// is not used by any face. A boundary half-edge is returned if there is.
+    #[inline(always)]
+    pub fn vertex_half_edge(&self, pos: usize) -> Option<usize> { self.vertex_half_edges[pos] }
+
+    /// Returns `true` if `half_edge` has no twin.
+    #[inline(always)]
+    pub fn is_boundary(&self, half_edge: usize) -> bool { self.twin(half_edge).is_none() }
+
+    /// Returns `true` if all half-edges have twins.
+    #[inline(always)]
+    pub fn is_closed(&self) -> bool { self.half_edges.iter().all(|h| h.twin.is_some()) }
+
+    /// Returns the iterator over the half-edges of the face of `half_edge`, from `half_edge`.
+    pub fn face_loop(&self, half_edge: usize) -> impl Iterator<Item = usize> + '_ {
+        let mut current = Some(half_edge);
+        std::iter::from_fn(move || {
+            let res = current?;
+            let next = self.next(res);
+            current = Some(next).filter(|next| *next != half_edge);
+            Some(res)
+        })
+    }
+
+    /// Returns the iterator over the half-edges starting from the position with index `pos`,
+    /// rotating around the position over the twins.
+    ///
+    /// The rotation starts from [`HalfEdgeMesh::vertex_half_edge`] and stops at the boundary,
+    /// so only one fan is visited around a non-manifold vertex.
+    pub fn outgoing_half_edges(&self, pos: usize) -> impl Iterator<Item = usize> + '_ {
+        let start = self.vertex_half_edge(pos);
+        let mut current = start;
+        std::iter::from_fn(move || {
+            let res = current?;
+            current = self
+                .twin(self.prev(res))
+                .filter(|next| Some(*next) != start);
+            Some(res)
+        })
+    }
+}
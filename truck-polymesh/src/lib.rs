@@ -48,8 +48,10 @@ pub struct StandardAttributes {
     /// positions
     pub positions: Vec<Point3>,
     /// texture uv coordinates
+    #[serde(default)]
     pub uv_coords: Vec<Vector2>,
     /// normals at vertices
+    #[serde(default)]
     pub normals: Vec<Vector3>,
 }
 
@@ -81,8 +83,11 @@ pub struct StandardVertex {
 /// there are vectors which consist by each triangles and quadrilaterals, internally.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Faces<V = StandardVertex> {
+    #[serde(default = "Vec::new")]
     tri_faces: Vec<[V; 3]>,
+    #[serde(default = "Vec::new")]
     quad_faces: Vec<[V; 4]>,
+    #[serde(default = "Vec::new")]
     other_faces: Vec<Vec<V>>,
 }
 
@@ -91,12 +96,47 @@ pub struct Faces<V = StandardVertex> {
 /// The polygon data is held in a method compliant with wavefront obj.
 /// Position, uv (texture) coordinates, and normal vectors are held in separate arrays,
 /// and each face vertex accesses those values by an indices triple.
-#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+///
+/// The serialized form has the field `version`, the format version
+/// [`PolygonMesh::FORMAT_VERSION`], in addition to the attributes and the faces.
+/// cf: `PolygonMesh::to_json_value`, available with the feature `serde_json`.
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct PolygonMesh<V = StandardVertex, A = StandardAttributes> {
     attributes: A,
     faces: Faces<V>,
 }
 
+/// Builder of [`PolygonMesh`] from the values of attributes, not from indices.
+///
+/// The values of attributes nearer than `TOLERANCE` are shared by a hash map on insertion.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// let mut builder = PolygonMeshBuilder::new();
+/// builder.push_triangle(
+///     Point3::new(0.0, 0.0, 0.0),
+///     Point3::new(1.0, 0.0, 0.0),
+///     Point3::new(0.0, 1.0, 0.0),
+/// );
+/// builder.push_triangle(
+///     Point3::new(1.0, 0.0, 0.0),
+///     Point3::new(1.0, 1.0, 0.0),
+///     Point3::new(0.0, 1.0, 0.0),
+/// );
+/// let mesh = builder.build();
+/// // The two vertices of the common edge are shared.
+/// assert_eq!(mesh.positions().len(), 4);
+/// assert_eq!(mesh.tri_faces()[1][0].pos, mesh.tri_faces()[0][1].pos);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct PolygonMeshBuilder {
+    attributes: StandardAttributes,
+    faces: Faces,
+    positions: rustc_hash::FxHashMap<[i64; 3], Vec<usize>>,
+    uv_coords: rustc_hash::FxHashMap<[i64; 2], Vec<usize>>,
+    normals: rustc_hash::FxHashMap<[i64; 3], Vec<usize>>,
+}
+
 /// structured quadrangle mesh
 #[derive(Clone, Debug, Serialize)]
 pub struct StructuredMesh {
@@ -105,23 +145,96 @@ pub struct StructuredMesh {
     normals: Option<Vec<Vec<Vector3>>>,
 }
 
+/// The diagonal along which each quadrangle of [`StructuredMesh`] is split into two triangles.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Diagonal {
+    /// the diagonal from the vertex `(i - 1, j - 1)` to the vertex `(i, j)`
+    Forward,
+    /// the diagonal from the vertex `(i, j - 1)` to the vertex `(i - 1, j)`
+    Backward,
+    /// the shorter one of the two diagonals, which reduces the skew of triangles on distorted grids
+    Shortest,
+}
+
+/// Options for [`StructuredMesh::destruct_with`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DestructOptions {
+    /// If `true`, the winding of all faces is inverted.
+    pub flip_winding: bool,
+    /// If `Some`, the quadrangles are split into triangles along the diagonal.
+    /// If `None`, the quadrangles are kept as they are.
+    pub diagonal: Option<Diagonal>,
+}
+
+/// The convention of axes of a mesh, used to convert meshes from and to other applications.
+///
+/// truck itself uses [`CoordinateSystem::ZUpRightHanded`]. In every system, the x-axis is
+/// shared, and the forward axis is the one which is neither the x-axis nor the up axis.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CoordinateSystem {
+    /// right-handed with z-axis up, the convention of truck, Blender and STEP
+    #[default]
+    ZUpRightHanded,
+    /// right-handed with y-axis up, the convention of glTF, Maya and most OBJ assets
+    YUpRightHanded,
+    /// left-handed with z-axis up
+    ZUpLeftHanded,
+    /// left-handed with y-axis up, the convention of Unity and DirectX
+    YUpLeftHanded,
+}
+
 /// polyline curve
+///
+/// The curve behaves like the vector of its points.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// let mut polyline: PolylineCurve<Point2> = (0..3).map(|i| Point2::new(i as f64, 0.0)).collect();
+/// polyline.push(Point2::new(3.0, 1.0));
+/// assert_eq!(polyline.len(), 4);
+/// assert_eq!(polyline[3], Point2::new(3.0, 1.0));
+///
+/// // iterate by reference, mutable reference, or value.
+/// let sum = (&polyline).into_iter().fold(0.0, |sum, p| sum + p.x);
+/// assert_eq!(sum, 6.0);
+/// for p in &mut polyline {
+///     p.y += 1.0;
+/// }
+/// polyline.extend([Point2::new(4.0, 1.0)]);
+/// let ys: Vec<f64> = polyline.into_iter().map(|p| p.y).collect();
+/// assert_eq!(ys, vec![1.0, 1.0, 1.0, 2.0, 1.0]);
+/// ```
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PolylineCurve<P>(pub Vec<P>);
 
 mod attributes;
+mod convex_hull;
+mod coordinate_system;
 /// Defines errors
 pub mod errors;
 mod expand;
 /// Defines triangle
 pub mod faces;
+/// binary glTF output
+pub mod gltf;
+#[cfg(feature = "gzip")]
+mod gzip;
+/// Defines the half-edge data structure [`HalfEdgeMesh`](./half_edge/struct.HalfEdgeMesh.html).
+pub mod half_edge;
 mod meshing_shape;
 /// wavefront obj I/O
 pub mod obj;
+mod oriented_bounding_box;
+/// Best-fit planes, planarity and point classification of 3D polygons.
+pub mod planarity;
+/// ASCII PLY input
+pub mod ply;
 /// Defines [`PolygonMeshEditor`](./polygon_mesh/struct.PolygonMeshEditor.html).
 pub mod polygon_mesh;
+mod polygon_mesh_builder;
 /// Defines generalized polyline curve.
 pub mod polyline_curve;
 /// STL I/O
 pub mod stl;
+mod straight_skeleton;
 mod structured_mesh;
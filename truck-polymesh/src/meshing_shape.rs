@@ -1,4 +1,6 @@
 use crate::*;
+use algo::surface::{cap_division, DivisionOptions};
+use std::iter::once;
 
 #[cfg(not(target_arch = "wasm32"))]
 use rayon::prelude::*;
@@ -15,6 +17,73 @@ impl<P> PolylineCurve<P> {
     }
 }
 
+impl PolylineCurve<Point3> {
+    /// Meshes the curve on `surface`, refining it where the surface bends across the curve.
+    ///
+    /// # Details
+    /// The division of [`PolylineCurve::from_curve`] only considers the curvature of the curve
+    /// itself, so a straight edge of a twisted face is meshed by a single segment, while the mesh
+    /// of the face is dense around it. Here, each segment is bisected while its length times the
+    /// angle between the normals of `surface` at its ends exceeds `8 * tol`, which is the same
+    /// criterion as the chordal tolerance for the normal section of the surface. The segments whose
+    /// ends cannot be found on `surface` are left as they are.
+    pub fn from_curve_on_surface<C, S>(curve: C, range: (f64, f64), surface: &S, tol: f64) -> Self
+    where
+        C: ParametricCurve3D + ParameterDivision1D<Point = Point3>,
+        S: ParametricSurface3D + SearchParameter<D2, Point = Point3>, {
+        let (params, points) = curve.parameter_division(range, tol);
+        let mut hint = None;
+        let uvs = points
+            .iter()
+            .map(|p| {
+                let uv = surface.search_parameter(*p, hint, 100);
+                hint = uv.or(hint);
+                uv
+            })
+            .collect::<Vec<_>>();
+        let mut res = vec![points[0]];
+        (1..points.len()).for_each(|i| {
+            let end = (params[i], points[i], uvs[i]);
+            let start = (params[i - 1], points[i - 1], uvs[i - 1]);
+            sub_division_on_surface(&curve, surface, start, end, tol, 20, &mut res);
+        });
+        PolylineCurve(res)
+    }
+}
+
+type PointOnSurface = (f64, Point3, Option<(f64, f64)>);
+
+/// Pushes the division of the segment from `start` to `end` except `start`.
+fn sub_division_on_surface<C, S>(
+    curve: &C,
+    surface: &S,
+    start: PointOnSurface,
+    end: PointOnSurface,
+    tol: f64,
+    trials: usize,
+    res: &mut Vec<Point3>,
+) where
+    C: ParametricCurve3D,
+    S: ParametricSurface3D + SearchParameter<D2, Point = Point3>,
+{
+    let (Some(uv0), Some(uv1)) = (start.2, end.2) else {
+        res.push(end.1);
+        return;
+    };
+    let (n0, n1) = (surface.normal(uv0.0, uv0.1), surface.normal(uv1.0, uv1.1));
+    let angle = n0.angle(n1).0;
+    if trials == 0 || !angle.is_finite() || start.1.distance(end.1) * angle <= 8.0 * tol {
+        res.push(end.1);
+        return;
+    }
+    let t = (start.0 + end.0) / 2.0;
+    let p = curve.subs(t);
+    let hint = ((uv0.0 + uv1.0) / 2.0, (uv0.1 + uv1.1) / 2.0);
+    let mid = (t, p, surface.search_parameter(p, hint, 100));
+    sub_division_on_surface(curve, surface, start, mid, tol, trials - 1, res);
+    sub_division_on_surface(curve, surface, mid, end, tol, trials - 1, res);
+}
+
 fn eval_row(
     surface: &impl ParametricSurface3D,
     u: f64,
@@ -31,6 +100,36 @@ impl StructuredMesh {
     /// * `surface` - surface to be meshed.
     /// * `range` - parameter range.
     /// * `tol` - standard tolerance for meshing.
+    ///
+    /// The surface is sampled on the grid of [`ParameterDivision2D::parameter_division`], so the
+    /// mesh has the positions, the uv division and the normals. This is cheaper than the
+    /// tessellation with trimming and suited for untrimmed surfaces.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// use truck_polymesh::StructuredMesh;
+    /// // a bicubic patch like a heightfield
+    /// let control_points = (0..4)
+    ///     .map(|i| {
+    ///         (0..4)
+    ///             .map(|j| Point3::new(i as f64, j as f64, ((i * j) % 3) as f64))
+    ///             .collect()
+    ///     })
+    ///     .collect();
+    /// let knots = (KnotVec::bezier_knot(3), KnotVec::bezier_knot(3));
+    /// let surface = BSplineSurface::new(knots, control_points);
+    ///
+    /// let range = ((0.0, 1.0), (0.0, 1.0));
+    /// let mesh = StructuredMesh::from_surface(&surface, range, 0.01);
+    /// let (udiv, vdiv) = surface.parameter_division(range, 0.01);
+    /// assert!(udiv.len() > 2 && vdiv.len() > 2);
+    /// assert_eq!(mesh.uv_division(), Some((&udiv, &vdiv)));
+    /// assert_eq!(mesh.positions().len(), udiv.len());
+    /// assert!(mesh.positions().iter().all(|row| row.len() == vdiv.len()));
+    /// let normals = mesh.normals().unwrap();
+    /// assert_eq!(normals.len(), udiv.len());
+    /// assert_near!(mesh.positions()[1][1], surface.subs(udiv[1], vdiv[1]));
+    /// ```
     pub fn from_surface<S>(
         surface: &S,
         range: ((f64, f64), (f64, f64)),
@@ -73,4 +172,258 @@ impl StructuredMesh {
             normals: Some(normals),
         }
     }
+
+    /// Meshes the surface on the grid refined by the curvature.
+    ///
+    /// # Details
+    /// Starting from the whole `range`, each interval of the u-division is bisected if, at its
+    /// midpoint on some v-line or in the middle of some v-interval, the sagitta
+    /// `kappa * (|S_u| * du)^2 / 8` or the distance of the surface from the chord exceeds `tol`,
+    /// where `kappa` is the maximum absolute principal curvature from
+    /// [`ParametricSurface3D::mean_curvature`] and [`ParametricSurface3D::gaussian_curvature`].
+    /// The v-division is refined in the same way, and they are refined alternately until no
+    /// interval is bisected, or at most 20 times. Hence the grid lines are dense only where the
+    /// surface bends along them, and the `uv_division` is not uniform in general.
+    ///
+    /// Same as [`algo::surface::parameter_division`], the initial grid only has the corners of
+    /// `range`, so bumps between the sample points of the first steps can be missed.
+    ///
+    /// The number of the intervals is not bounded, so use
+    /// [`StructuredMesh::from_surface_adaptive_with_options`] for the surfaces with singular
+    /// curvatures.
+    ///
+    /// # Panics
+    /// `tol` must be greater than or equal to `TOLERANCE`.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// use truck_polymesh::StructuredMesh;
+    /// // a flat bicubic patch with a bump in the middle
+    /// let control_points = (0..9)
+    ///     .map(|i| {
+    ///         (0..9)
+    ///             .map(|j| {
+    ///                 let z = if (i, j) == (4, 4) { 1.0 } else { 0.0 };
+    ///                 Point3::new(i as f64, j as f64, z)
+    ///             })
+    ///             .collect()
+    ///     })
+    ///     .collect();
+    /// let knots = KnotVec::uniform_knot(3, 6);
+    /// let surface = BSplineSurface::new((knots.clone(), knots), control_points);
+    ///
+    /// let range = ((0.0, 1.0), (0.0, 1.0));
+    /// let mesh = StructuredMesh::from_surface_adaptive(&surface, range, 0.01);
+    /// let (udiv, vdiv) = mesh.uv_division().unwrap();
+    /// assert_eq!(mesh.positions().len(), udiv.len());
+    /// assert!(mesh.positions().iter().all(|row| row.len() == vdiv.len()));
+    /// // the grid lines are denser around the bump than on the flat border
+    /// let width = |t: f64| {
+    ///     let i = udiv.iter().position(|u| *u > t).unwrap();
+    ///     udiv[i] - udiv[i - 1]
+    /// };
+    /// assert!(width(0.5) < width(0.05) / 2.0);
+    /// // fewer grid lines than the division of the surface
+    /// let (udiv0, _) = surface.parameter_division(range, 0.01);
+    /// assert!(udiv.len() < udiv0.len());
+    /// ```
+    #[inline(always)]
+    pub fn from_surface_adaptive<S>(
+        surface: &S,
+        range: ((f64, f64), (f64, f64)),
+        tol: f64,
+    ) -> StructuredMesh
+    where
+        S: ParametricSurface3D,
+    {
+        Self::from_surface_adaptive_with_options(surface, range, tol, DivisionOptions::default())
+    }
+
+    /// Meshes the surface on the grid refined by the curvature, with at most
+    /// `options.max_division` intervals in each direction.
+    ///
+    /// The grid is the same as [`StructuredMesh::from_surface_adaptive`] as long as it is within
+    /// the bound. Otherwise, only the intervals with the largest errors are bisected in the last
+    /// step of each direction, by [`algo::surface::cap_division`], so the grid does not satisfy
+    /// `tol`.
+    ///
+    /// # Panics
+    /// `tol` must be greater than or equal to `TOLERANCE`, and `options.max_division` must be
+    /// positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::f64::consts::PI;
+    /// use truck_geometry::prelude::*;
+    /// use truck_polymesh::StructuredMesh;
+    /// use algo::surface::DivisionOptions;
+    /// let sphere = Sphere::new(Point3::origin(), 1.0);
+    /// let range = ((0.0, 2.0 * PI), (0.0, PI));
+    /// let options = DivisionOptions { max_division: 8 };
+    /// let mesh = StructuredMesh::from_surface_adaptive_with_options(&sphere, range, 0.001, options);
+    /// let (udiv, vdiv) = mesh.uv_division().unwrap();
+    /// assert!(udiv.len() <= 9 && vdiv.len() <= 9);
+    /// ```
+    pub fn from_surface_adaptive_with_options<S>(
+        surface: &S,
+        (urange, vrange): ((f64, f64), (f64, f64)),
+        tol: f64,
+        options: DivisionOptions,
+    ) -> StructuredMesh
+    where
+        S: ParametricSurface3D,
+    {
+        nonpositive_tolerance!(tol);
+        assert!(options.max_division > 0, "max_division must be positive.");
+        let max = options.max_division;
+        let (mut div0, mut div1) = (vec![urange.0, urange.1], vec![vrange.0, vrange.1]);
+        for _ in 0..20 {
+            let divided0 = refine_division(&mut div0, &div1, tol, max, |u, v| (u, v), surface);
+            let divided1 = refine_division(&mut div1, &div0, tol, max, |v, u| (u, v), surface);
+            if !divided0 && !divided1 {
+                break;
+            }
+        }
+        let (positions, normals): (Vec<_>, Vec<_>) =
+            div0.iter().map(|u| eval_row(surface, *u, &div1)).unzip();
+        StructuredMesh {
+            positions,
+            uv_division: Some((div0, div1)),
+            normals: Some(normals),
+        }
+    }
+}
+
+/// Bisects the intervals of `div` where the surface bends too much along them, at most
+/// `max_division` intervals in total, and returns `true` if some interval is bisected. `uv` maps
+/// the parameters of `div` and `other` into the parameters of `surface`.
+fn refine_division<S: ParametricSurface3D>(
+    div: &mut Vec<f64>,
+    other: &[f64],
+    tol: f64,
+    max_division: usize,
+    uv: impl Fn(f64, f64) -> (f64, f64),
+    surface: &S,
+) -> bool {
+    let samples = other
+        .iter()
+        .copied()
+        .chain(other.windows(2).map(|w| (w[0] + w[1]) / 2.0))
+        .collect::<Vec<_>>();
+    let error = |t0: f64, t1: f64| {
+        let t = (t0 + t1) / 2.0;
+        samples
+            .iter()
+            .map(|s| {
+                let ((u, v), (u0, v0), (u1, v1)) = (uv(t, *s), uv(t0, *s), uv(t1, *s));
+                let der = match (u0 == u1, v0 == v1) {
+                    (false, _) => surface.uder(u, v) * (u1 - u0),
+                    _ => surface.vder(u, v) * (v1 - v0),
+                };
+                let (h, k) = (
+                    surface.mean_curvature(u, v),
+                    surface.gaussian_curvature(u, v),
+                );
+                let kappa = h.abs() + f64::sqrt(f64::max(h * h - k, 0.0));
+                let (p0, p1) = (surface.subs(u0, v0), surface.subs(u1, v1));
+                let (diff, chord) = (surface.subs(u, v) - p0, p1 - p0);
+                let deviation = match chord.so_small() {
+                    true => diff.magnitude(),
+                    false => diff.cross(chord).magnitude() / chord.magnitude(),
+                };
+                f64::max(kappa * der.magnitude2() / 8.0, deviation)
+            })
+            .fold(0.0, f64::max)
+    };
+    // the errors of the intervals to be bisected, or zero for the other intervals
+    let mut flags = div
+        .windows(2)
+        .map(|w| match error(w[0], w[1]) {
+            error if error > tol => error,
+            _ => 0.0,
+        })
+        .collect::<Vec<_>>();
+    cap_division(&mut flags, max_division);
+    let divided = flags.iter().any(|flag| *flag > 0.0);
+    *div = once(div[0])
+        .chain(div.windows(2).zip(&flags).flat_map(|(w, flag)| {
+            let mid = (*flag > 0.0).then(|| (w[0] + w[1]) / 2.0);
+            mid.into_iter().chain(once(w[1]))
+        }))
+        .collect();
+    divided
+}
+
+impl PolygonMesh {
+    /// Projects the positions onto `surface`, and replaces the normals by the ones of `surface`.
+    ///
+    /// Each position is moved to `surface.subs(u, v)`, where `(u, v)` is the nearest parameter
+    /// given by [`SearchNearestParameter`]. Every projected position has its own normal, and the
+    /// normals of the vertices at the other positions are removed. Returns the indices of the
+    /// positions whose parameters are not found, which are left in place.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// use truck_polymesh::*;
+    /// // a planar grid around the center of the sphere
+    /// let positions = (0..25)
+    ///     .map(|i| Point3::new((i % 5) as f64 - 2.0, (i / 5) as f64 - 2.0, 0.0))
+    ///     .collect::<Vec<_>>();
+    /// let faces = (0..16)
+    ///     .map(|i| {
+    ///         let k = i / 4 * 5 + i % 4;
+    ///         [k, k + 1, k + 6, k + 5]
+    ///     })
+    ///     .collect::<Vec<_>>();
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes { positions, ..Default::default() },
+    ///     Faces::from_iter(&faces),
+    /// );
+    ///
+    /// let sphere = Sphere::new(Point3::origin(), 1.0);
+    /// let failed = mesh.project_onto_surface(&sphere);
+    /// // the center has no nearest point
+    /// assert_eq!(failed, vec![12]);
+    /// assert_eq!(mesh.positions()[12], Point3::origin());
+    /// mesh.positions().iter().enumerate().for_each(|(i, p)| {
+    ///     if i != 12 {
+    ///         assert_near!(p.to_vec().magnitude(), 1.0);
+    ///     }
+    /// });
+    /// mesh.face_iter().flatten().for_each(|v| match v.nor {
+    ///     Some(nor) => assert_near!(mesh.normals()[nor], mesh.positions()[v.pos].to_vec()),
+    ///     None => assert_eq!(v.pos, 12),
+    /// });
+    /// ```
+    pub fn project_onto_surface<S>(&mut self, surface: &S) -> Vec<usize>
+    where S: ParametricSurface3D + SearchNearestParameter<D2, Point = Point3> {
+        let mut failed = Vec::new();
+        let mut normals = Vec::new();
+        let normal_indices = self
+            .attributes
+            .positions
+            .iter_mut()
+            .enumerate()
+            .map(|(i, p)| {
+                let uv = surface
+                    .search_nearest_parameter(*p, None, 100)
+                    .filter(|(u, v)| u.is_finite() && v.is_finite());
+                let Some((u, v)) = uv else {
+                    failed.push(i);
+                    return None;
+                };
+                *p = surface.subs(u, v);
+                normals.push(surface.normal(u, v));
+                Some(normals.len() - 1)
+            })
+            .collect::<Vec<_>>();
+        self.attributes.normals = normals;
+        self.faces
+            .face_iter_mut()
+            .flatten()
+            .for_each(|v| v.nor = normal_indices[v.pos]);
+        failed
+    }
 }
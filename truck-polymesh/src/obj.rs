@@ -47,12 +47,85 @@ type Result<T> = std::result::Result<T, errors::Error>;
 ///     },
 ///     faces,
 /// );
-/// obj::write(&mesh, std::fs::File::create("meshdata.obj").unwrap());
+/// let mut buffer = Vec::new();
+/// obj::write(&mesh, &mut buffer).unwrap();
+/// assert!(String::from_utf8(buffer).unwrap().starts_with("v "));
 /// ```
 pub fn write<W: Write>(mesh: &PolygonMesh, writer: W) -> Result<()> {
     sub_write(mesh, &mut BufWriter::new(writer))
 }
 
+/// Writes obj data to output stream with the smoothing groups `s` of the faces.
+///
+/// The `i`-th value of `groups` is the smoothing group of the `i`-th face of
+/// [`PolygonMesh::face_iter`], and `0` means that the smoothing is off. If `groups` is shorter
+/// than the number of faces, the remaining faces are in the group `0`. The directive `s` is
+/// written only where the group changes.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// let mesh = PolygonMesh::from_obj_str(
+///     "v 0 0 0
+///      v 1 0 0
+///      v 1 1 0
+///      v 0 1 0
+///      v 0 0 1
+///      f 1 2 3
+///      f 1 3 4
+///      f 1 2 5",
+/// )
+/// .unwrap();
+/// let mut obj = Vec::new();
+/// obj::write_with_smoothing_groups(&mesh, &[1, 1, 2], &mut obj).unwrap();
+/// let obj_str = String::from_utf8(obj.clone()).unwrap();
+/// assert_eq!(obj_str.matches("s ").count(), 2);
+///
+/// let (read_mesh, groups) = obj::read_with_smoothing_groups(obj.as_slice()).unwrap();
+/// assert_eq!(read_mesh, mesh);
+/// assert_eq!(groups, vec![1, 1, 2]);
+/// ```
+pub fn write_with_smoothing_groups<W: Write>(
+    mesh: &PolygonMesh,
+    groups: &[u32],
+    writer: W,
+) -> Result<()> {
+    let mut writer = BufWriter::new(writer);
+    write3vec(&mut writer, mesh.positions(), "v")?;
+    write2vec(&mut writer, mesh.uv_coords(), "vt")?;
+    write3vec(&mut writer, mesh.normals(), "vn")?;
+    let groups = groups.iter().copied().chain(std::iter::repeat(0));
+    let mut current = 0;
+    for (face, group) in mesh.face_iter().zip(groups) {
+        if group != current {
+            match group {
+                0 => writer.write_all(b"s off\n")?,
+                _ => writer.write_fmt(format_args!("s {group}\n"))?,
+            }
+            current = group;
+        }
+        writer.write_all(b"f")?;
+        for v in face {
+            writer.write_all(b" ")?;
+            v.write(&mut writer, [0; 3])?;
+        }
+        writer.write_all(b"\n")?;
+    }
+    Ok(())
+}
+
+/// Writes obj data to output stream in the coordinate system `to`.
+///
+/// The mesh is assumed to be in truck's [`CoordinateSystem::ZUpRightHanded`].
+pub fn write_with_coordinate_system<W: Write>(
+    mesh: &PolygonMesh,
+    writer: W,
+    to: CoordinateSystem,
+) -> Result<()> {
+    let mut mesh = mesh.clone();
+    mesh.convert_coordinate_system(CoordinateSystem::ZUpRightHanded, to);
+    write(&mesh, writer)
+}
+
 /// Writes obj data to output stream
 pub fn write_vec<W: Write>(mesh: &[PolygonMesh], writer: W) -> Result<()> {
     let mut writer = BufWriter::new(writer);
@@ -63,6 +136,71 @@ pub fn write_vec<W: Write>(mesh: &[PolygonMesh], writer: W) -> Result<()> {
     Ok(())
 }
 
+/// Writes the instances of `base` transformed by each of `transforms` to obj data.
+///
+/// # Details
+/// OBJ has no native instancing, so the attributes of `base` are written once per transform,
+/// and the faces of `base` are written with the indices shifted to the instance. Each instance
+/// is grouped by the directive `o Instance_{i}`, where `i` is the index of the transform.
+/// The normals are transformed as vectors, same as [`Transformed::transformed`] of the mesh.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// let mesh = PolygonMesh::new(
+///     StandardAttributes {
+///         positions: vec![
+///             Point3::new(0.0, 0.0, 0.0),
+///             Point3::new(1.0, 0.0, 0.0),
+///             Point3::new(0.0, 1.0, 0.0),
+///         ],
+///         ..Default::default()
+///     },
+///     Faces::from_iter(&[[0, 1, 2]]),
+/// );
+/// let transforms = [
+///     Matrix4::identity(),
+///     Matrix4::from_translation(Vector3::new(2.0, 0.0, 0.0)),
+/// ];
+/// let mut bytes = Vec::new();
+/// obj::write_instanced(&mesh, &transforms, &mut bytes).unwrap();
+/// assert!(String::from_utf8_lossy(&bytes).contains("o Instance_1\n"));
+///
+/// let read = obj::read(bytes.as_slice()).unwrap();
+/// assert_eq!(read.positions().len(), 6);
+/// assert_eq!(read.faces().len(), 2);
+/// assert_near!(read.positions()[4], Point3::new(3.0, 0.0, 0.0));
+/// ```
+pub fn write_instanced<W: Write>(
+    base: &PolygonMesh,
+    transforms: &[Matrix4],
+    writer: W,
+) -> Result<()> {
+    let mut writer = BufWriter::new(writer);
+    let lens = [
+        base.positions().len(),
+        base.uv_coords().len(),
+        base.normals().len(),
+    ];
+    for (i, trans) in transforms.iter().enumerate() {
+        writer.write_fmt(format_args!("o Instance_{i}\n"))?;
+        let positions = base
+            .positions()
+            .iter()
+            .map(|p| trans.transform_point(*p))
+            .collect::<Vec<_>>();
+        write3vec(&mut writer, &positions, "v")?;
+        write2vec(&mut writer, base.uv_coords(), "vt")?;
+        let normals = base
+            .normals()
+            .iter()
+            .map(|n| trans.transform_vector(*n))
+            .collect::<Vec<_>>();
+        write3vec(&mut writer, &normals, "vn")?;
+        base.faces.write(&mut writer, lens.map(|len| len * i))?;
+    }
+    Ok(())
+}
+
 fn write2vec<V: std::ops::Index<usize, Output = f64>, W: Write>(
     writer: &mut BufWriter<W>,
     vecs: &[V],
@@ -89,25 +227,42 @@ fn write3vec<V: std::ops::Index<usize, Output = f64>, W: Write>(
 }
 
 impl Vertex {
-    fn write<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
-        match (self.uv, self.nor) {
-            (None, None) => writer.write_fmt(format_args!("{}", self.pos + 1)),
-            (Some(uv), None) => writer.write_fmt(format_args!("{}/{}", self.pos + 1, uv + 1)),
-            (None, Some(nor)) => writer.write_fmt(format_args!("{}//{}", self.pos + 1, nor + 1)),
-            (Some(uv), Some(nor)) => {
-                writer.write_fmt(format_args!("{}/{}/{}", self.pos + 1, uv + 1, nor + 1))
-            }
+    /// Writes the indices shifted by `offset`, the numbers of preceding positions, texture
+    /// coordinates and normals.
+    fn write<W: Write>(&self, writer: &mut W, offset: [usize; 3]) -> std::io::Result<()> {
+        let pos = obj_index(self.pos, offset[0]);
+        let uv = self.uv.map(|uv| obj_index(uv, offset[1]));
+        let nor = self.nor.map(|nor| obj_index(nor, offset[2]));
+        match (uv, nor) {
+            (None, None) => writer.write_fmt(format_args!("{pos}")),
+            (Some(uv), None) => writer.write_fmt(format_args!("{pos}/{uv}")),
+            (None, Some(nor)) => writer.write_fmt(format_args!("{pos}//{nor}")),
+            (Some(uv), Some(nor)) => writer.write_fmt(format_args!("{pos}/{uv}/{nor}")),
         }
     }
 }
 
+/// Converts the 0-based index shifted by `offset` into the 1-based index of OBJ.
+#[inline(always)]
+fn obj_index(idx: usize, offset: usize) -> usize { idx + offset + 1 }
+
+/// Parses the index of OBJ, 1-based or negative relative to the end of the `len` attributes
+/// read so far, into the 0-based index. Returns `None` for `0` or invalid strings.
+fn parse_obj_index(val: &str, len: usize) -> Option<usize> {
+    match val.parse::<isize>().ok()? {
+        0 => None,
+        idx if idx > 0 => Some(idx as usize - 1),
+        idx => len.checked_sub(idx.unsigned_abs()),
+    }
+}
+
 impl Faces {
-    fn write<W: Write>(&self, writer: &mut W) -> Result<()> {
+    fn write<W: Write>(&self, writer: &mut W, offset: [usize; 3]) -> Result<()> {
         for face in self.face_iter() {
             writer.write_all(b"f")?;
             for v in face {
                 writer.write_all(b" ")?;
-                v.write(writer)?;
+                v.write(writer, offset)?;
             }
             writer.write_all(b"\n")?;
         }
@@ -119,15 +274,212 @@ fn sub_write<W: Write>(mesh: &PolygonMesh, writer: &mut BufWriter<W>) -> Result<
     write3vec(writer, mesh.positions(), "v")?;
     write2vec(writer, mesh.uv_coords(), "vt")?;
     write3vec(writer, mesh.normals(), "vn")?;
-    mesh.faces.write(writer)
+    mesh.faces.write(writer, [0; 3])
+}
+
+/// Reads mesh data from wavefront obj file, which may be compressed by gzip.
+///
+/// The stream is decompressed if it starts with the gzip magic bytes, and is parsed directly otherwise.
+/// Available with the feature `gzip`.
+/// # Examples
+/// ```
+/// use std::io::Write;
+/// use flate2::{write::GzEncoder, Compression};
+/// use truck_polymesh::*;
+/// let mesh = PolygonMesh::new(
+///     StandardAttributes {
+///         positions: vec![
+///             Point3::new(0.0, 0.0, 0.0),
+///             Point3::new(1.0, 0.0, 0.0),
+///             Point3::new(0.0, 1.0, 0.0),
+///         ],
+///         ..Default::default()
+///     },
+///     Faces::from_iter(&[[0, 1, 2]]),
+/// );
+/// let mut plain = Vec::new();
+/// obj::write(&mesh, &mut plain).unwrap();
+/// let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+/// encoder.write_all(&plain).unwrap();
+/// let gzipped = encoder.finish().unwrap();
+///
+/// assert_eq!(obj::read_maybe_gzip(gzipped.as_slice()).unwrap(), mesh);
+/// assert_eq!(obj::read_maybe_gzip(plain.as_slice()).unwrap(), mesh);
+/// ```
+#[cfg(feature = "gzip")]
+pub fn read_maybe_gzip<R: Read>(reader: R) -> Result<PolygonMesh> {
+    read(gzip::MaybeGzip::new(reader)?)
+}
+
+impl PolygonMesh {
+    /// Parses the wavefront obj string, a shorthand for [`obj::read`](read) with `s.as_bytes()`.
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// let mesh = PolygonMesh::from_obj_str(
+    ///     "v 0 0 0
+    ///      v 1 0 0
+    ///      v 0 1 0
+    ///      f 1 2 3",
+    /// )
+    /// .unwrap();
+    /// assert_eq!(mesh.positions().len(), 3);
+    /// assert_eq!(mesh.faces().tri_faces(), &[[0.into(), 1.into(), 2.into()]]);
+    ///
+    /// assert!(PolygonMesh::from_obj_str("v 0 0 zero").is_err());
+    /// ```
+    #[inline(always)]
+    pub fn from_obj_str(s: &str) -> Result<PolygonMesh> { read(s.as_bytes()) }
+}
+
+/// Reads mesh data in the coordinate system `from` from wavefront obj file, and converts it to
+/// truck's [`CoordinateSystem::ZUpRightHanded`].
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// // a y-up triangle lying on the ground, facing up
+/// let obj = b"v 0 0 0\nv 1 0 0\nv 0 0 -1\nvn 0 1 0\nf 1//1 2//1 3//1\n";
+/// let mesh = obj::read_with_coordinate_system(&obj[..], CoordinateSystem::YUpRightHanded).unwrap();
+/// assert_near!(mesh.normals()[0], Vector3::unit_z());
+/// assert!(mesh.positions().iter().all(|p| p.z.so_small()));
+/// assert_near!(mesh.positions()[2], Point3::new(0.0, 1.0, 0.0));
+///
+/// // the round trip restores the original coordinates.
+/// let mut buf = Vec::new();
+/// obj::write_with_coordinate_system(&mesh, &mut buf, CoordinateSystem::YUpRightHanded).unwrap();
+/// assert_eq!(obj::read(buf.as_slice()).unwrap(), obj::read(&obj[..]).unwrap());
+/// ```
+pub fn read_with_coordinate_system<R: Read>(
+    reader: R,
+    from: CoordinateSystem,
+) -> Result<PolygonMesh> {
+    let mut mesh = read(reader)?;
+    mesh.convert_coordinate_system(from, CoordinateSystem::ZUpRightHanded);
+    Ok(mesh)
 }
 
 /// Reads mesh data from wavefront obj file.
+///
+/// The 1-based indices of OBJ are converted into the 0-based indices of [`StandardVertex`], and
+/// the negative indices are resolved relative to the attributes read so far.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// // a strip of many triangles, so that the indices have several digits
+/// let n = 2000;
+/// let positions = (0..n)
+///     .map(|i| Point3::new((i / 2) as f64, (i % 2) as f64, 0.0))
+///     .collect::<Vec<_>>();
+/// let uv_coords = positions.iter().map(|p| Vector2::new(p.x, p.y)).collect();
+/// let normals = vec![Vector3::unit_z()];
+/// let faces = (0..n - 2)
+///     .map(|i| {
+///         let v = |j: usize| StandardVertex { pos: j, uv: Some(j), nor: Some(0) };
+///         [v(i), v(i + 1), v(i + 2)]
+///     })
+///     .collect::<Faces>();
+/// let mesh = PolygonMesh::new(StandardAttributes { positions, uv_coords, normals }, faces);
+/// let mut obj = Vec::new();
+/// obj::write(&mesh, &mut obj).unwrap();
+/// // the last face refers the last vertex by 2000
+/// let obj_str = String::from_utf8(obj.clone()).unwrap();
+/// assert!(obj_str.trim_end().ends_with("f 1998/1998/1 1999/1999/1 2000/2000/1"));
+/// assert_eq!(obj::read(obj.as_slice()).unwrap(), mesh);
+///
+/// // negative indices are relative to the end
+/// let relative = obj::read(b"v 0 0 0\nv 1 0 0\nv 0 1 0\nf -3 -2 -1\n".as_slice()).unwrap();
+/// assert_eq!(relative.faces().tri_faces(), &[[0.into(), 1.into(), 2.into()]]);
+/// ```
 pub fn read<R: Read>(reader: R) -> Result<PolygonMesh> {
+    read_elements(reader).map(|(mesh, _, _)| mesh)
+}
+
+/// Reads mesh data and the smoothing groups `s` of the faces from wavefront obj file.
+///
+/// The `i`-th returned value is the smoothing group of the `i`-th face of
+/// [`PolygonMesh::face_iter`], and `0` means that the smoothing is off, i.e. `s off` or `s 0`.
+/// The faces before the first directive `s` are in the group `0`, and so are the faces after
+/// the directives whose values are not groups, e.g. `s on`.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// let obj = b"v 0 0 0
+/// v 1 0 0
+/// v 1 1 0
+/// v 0 1 0
+/// v 0 0 1
+/// f 1 4 3 2
+/// s 1
+/// f 1 2 5
+/// s off
+/// f 2 3 5
+/// s on
+/// f 3 4 5
+/// ";
+/// let (mesh, groups) = obj::read_with_smoothing_groups(&obj[..]).unwrap();
+/// // the quadrangle is the last one in `face_iter`
+/// assert_eq!(mesh.faces()[3].len(), 4);
+/// assert_eq!(groups, vec![1, 0, 0, 0]);
+/// ```
+pub fn read_with_smoothing_groups<R: Read>(reader: R) -> Result<(PolygonMesh, Vec<u32>)> {
+    read_elements(reader).map(|(mesh, _, groups)| (mesh, groups))
+}
+
+/// Reads mesh data and line elements `l` from wavefront obj file.
+///
+/// Each line element is returned as a polyline of the positions `v`, which are shared with the
+/// faces of the mesh. The texture coordinates of the line elements are ignored.
+/// # Errors
+/// Returns [`errors::Error::OutOfRange`] if a line element refers a position out of range.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// let obj = b"v 0 0 0
+/// v 1 0 0
+/// v 1 1 0
+/// v 0 1 0
+/// f 1 2 3 4
+/// # the diagonal and the boundary
+/// l 1 3
+/// l 1/1 2 3 4 -4
+/// ";
+/// let (mesh, lines) = obj::read_with_lines(&obj[..]).unwrap();
+/// assert_eq!(mesh.faces().len(), 1);
+/// assert_eq!(lines.len(), 2);
+/// assert_eq!(lines[0].0, vec![mesh.positions()[0], mesh.positions()[2]]);
+/// assert_eq!(lines[1].len(), 5);
+/// assert_eq!(lines[1][0], lines[1][4]);
+///
+/// // the faces are the same as `obj::read`
+/// assert_eq!(mesh, obj::read(&obj[..]).unwrap());
+/// assert!(obj::read_with_lines(b"v 0 0 0\nl 1 2\n".as_slice()).is_err());
+/// ```
+pub fn read_with_lines<R: Read>(reader: R) -> Result<(PolygonMesh, Vec<PolylineCurve<Point3>>)> {
+    let (mesh, lines, _) = read_elements(reader)?;
+    let lines = lines
+        .into_iter()
+        .map(|line| {
+            line.into_iter()
+                .map(|idx| match mesh.positions().get(idx) {
+                    Some(p) => Ok(p),
+                    None => Err(errors::Error::OutOfRange(Vertex::from(idx))),
+                })
+                .collect()
+        })
+        .collect::<Result<_>>()?;
+    Ok((mesh, lines))
+}
+
+/// Reads the mesh, the position indices of the line elements and the smoothing groups of faces.
+fn read_elements<R: Read>(reader: R) -> Result<(PolygonMesh, Vec<Vec<usize>>, Vec<u32>)> {
     let mut positions = Vec::new();
     let mut uv_coords = Vec::new();
     let mut normals = Vec::new();
     let mut faces = Faces::default();
+    let mut lines = Vec::new();
+    // the smoothing groups of triangles, quadrangles and the other polygons
+    let (mut tri_groups, mut quad_groups, mut other_groups) = (Vec::new(), Vec::new(), Vec::new());
+    let mut group = 0;
     let reader = BufReader::new(reader);
     for line in reader.lines().map(|s| s.unwrap()) {
         let mut args = line.split_whitespace();
@@ -155,32 +507,51 @@ pub fn read<R: Read>(reader: R) -> Result<PolygonMesh> {
                     let mut iter = vert_str.split('/');
                     let pos = iter
                         .next()
-                        .map(|val| val.parse::<usize>().map(|i| i - 1).ok())
-                        .unwrap_or(None);
+                        .and_then(|val| parse_obj_index(val, positions.len()));
                     let uv = iter
                         .next()
-                        .map(|val| val.parse::<usize>().map(|i| i - 1).ok())
-                        .unwrap_or(None);
+                        .and_then(|val| parse_obj_index(val, uv_coords.len()));
                     let nor = iter
                         .next()
-                        .map(|val| val.parse::<usize>().map(|i| i - 1).ok())
-                        .unwrap_or(None);
+                        .and_then(|val| parse_obj_index(val, normals.len()));
                     let vert = match (pos, uv, nor) {
                         (None, _, _) => continue,
                         (Some(pos), uv, nor) => Vertex { pos, uv, nor },
                     };
                     face.push(vert);
                 }
+                match face.len() {
+                    0..=2 => {}
+                    3 => tri_groups.push(group),
+                    4 => quad_groups.push(group),
+                    _ => other_groups.push(group),
+                }
                 faces.push(face);
+            } else if first_str == "s" {
+                group = match args.next() {
+                    Some("off") | None => 0,
+                    Some(val) => val.parse::<u32>().unwrap_or(0),
+                };
+            } else if first_str == "l" {
+                let line = args
+                    .take_while(|vert_str| !vert_str.starts_with('#'))
+                    .filter_map(|vert_str| {
+                        let val = vert_str.split('/').next()?;
+                        parse_obj_index(val, positions.len())
+                    })
+                    .collect::<Vec<_>>();
+                lines.push(line);
             }
         }
     }
-    PolygonMesh::try_new(
+    let mesh = PolygonMesh::try_new(
         StandardAttributes {
             positions,
             uv_coords,
             normals,
         },
         faces,
-    )
+    )?;
+    let groups = [tri_groups, quad_groups, other_groups].concat();
+    Ok((mesh, lines, groups))
 }
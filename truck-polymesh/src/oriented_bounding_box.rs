@@ -0,0 +1,197 @@
+use crate::*;
+
+impl PolygonMesh {
+    /// Returns an oriented bounding box of the positions as the pair of the transform and the
+    /// dimensions.
+    ///
+    /// The box is `[-d.x / 2, d.x / 2] x [-d.y / 2, d.y / 2] x [-d.z / 2, d.z / 2]` mapped by
+    /// the transform, a rigid motion, where `d` is the dimensions. The dimensions are sorted in
+    /// descending order, and the axes, the first three columns of the transform, are right-handed.
+    ///
+    /// # Details
+    /// The candidate frames are the principal axes of the positions, i.e. the eigenvectors of the
+    /// covariance matrix, and the frames spanned by the normal and an edge of each face of the
+    /// convex hull. The box of the minimum volume among them is returned, and the boxes of the
+    /// same volume are compared by the surface area. The box is not necessarily the minimum one,
+    /// but it is exact for boxes and tighter than the principal axes for symmetric shapes, e.g.
+    /// cubes whose covariance matrices are scalar.
+    ///
+    /// Degenerate positions result in thin boxes: the dimension perpendicular to planar
+    /// positions, or the two dimensions perpendicular to linear positions are zero.
+    /// Returns the identity and the zero vector for the empty mesh.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// // a 3 x 2 x 1 box rotated and moved
+    /// let rotation = Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0))
+    ///     * Matrix4::from_axis_angle(Vector3::new(1.0, 2.0, 2.0).normalize(), Rad(0.7));
+    /// let positions = (0..8)
+    ///     .map(|i| {
+    ///         let p = Point3::new(
+    ///             3.0 * ((i & 1) as f64 - 0.5),
+    ///             2.0 * (((i >> 1) & 1) as f64 - 0.5),
+    ///             (i >> 2) as f64 - 0.5,
+    ///         );
+    ///         rotation.transform_point(p)
+    ///     })
+    ///     .collect::<Vec<_>>();
+    /// let mesh = PolygonMesh::new(
+    ///     StandardAttributes { positions, ..Default::default() },
+    ///     Faces::from_iter(&[[0, 1, 2]]),
+    /// );
+    /// let (transform, dimensions) = mesh.oriented_bounding_box();
+    /// assert_near!(dimensions, Vector3::new(3.0, 2.0, 1.0));
+    /// assert_near!(transform.w.truncate(), Vector3::new(1.0, 2.0, 3.0));
+    /// assert_near!(transform.x.truncate().cross(rotation.x.truncate()), Vector3::zero());
+    ///
+    /// // linear positions: a thin box along the line
+    /// let positions = (0..5)
+    ///     .map(|i| Point3::new(1.0, 2.0, 2.0) * i as f64)
+    ///     .collect::<Vec<_>>();
+    /// let line = PolygonMesh::new(
+    ///     StandardAttributes { positions, ..Default::default() },
+    ///     Faces::from_iter(&[[0, 1, 2]]),
+    /// );
+    /// let (transform, dimensions) = line.oriented_bounding_box();
+    /// assert_near!(dimensions, Vector3::new(12.0, 0.0, 0.0));
+    /// assert_near!(transform.transform_point(Point3::origin()), Point3::new(2.0, 4.0, 4.0));
+    /// ```
+    pub fn oriented_bounding_box(&self) -> (Matrix4, Vector3) {
+        let points = self.positions();
+        if points.is_empty() {
+            return (Matrix4::identity(), Vector3::zero());
+        }
+        let mut best = fit_box(points, principal_axes(points));
+        let hull = PolygonMesh::convex_hull(points);
+        let hull_points = hull.positions();
+        hull.face_iter().for_each(|face| {
+            let polygon = face.iter().map(|v| hull_points[v.pos]).collect::<Vec<_>>();
+            let normal = planarity::newell_normal(&polygon);
+            if normal.magnitude2().so_small2() {
+                return;
+            }
+            let normal = normal.normalize();
+            polygon
+                .iter()
+                .zip(polygon.iter().cycle().skip(1))
+                .for_each(|(p, q)| {
+                    let dir = (q - p) - normal * normal.dot(q - p);
+                    if dir.magnitude2().so_small2() {
+                        return;
+                    }
+                    let dir = dir.normalize();
+                    let candidate = fit_box(hull_points, [normal, dir, normal.cross(dir)]);
+                    if candidate.is_smaller_than(&best) {
+                        best = candidate;
+                    }
+                });
+        });
+        best.into_transform()
+    }
+}
+
+/// A box given by the center, the orthonormal axes and the dimensions along them.
+struct FittedBox {
+    center: Point3,
+    axes: [Vector3; 3],
+    dimensions: [f64; 3],
+}
+
+impl FittedBox {
+    fn volume(&self) -> f64 { self.dimensions.iter().product() }
+    fn area(&self) -> f64 {
+        let [a, b, c] = self.dimensions;
+        a * b + b * c + c * a
+    }
+    fn is_smaller_than(&self, other: &Self) -> bool {
+        let (volume0, volume1) = (self.volume(), other.volume());
+        match volume0.near(&volume1) {
+            true => self.area() < other.area() && !self.area().near(&other.area()),
+            false => volume0 < volume1,
+        }
+    }
+    fn into_transform(self) -> (Matrix4, Vector3) {
+        let Self {
+            center,
+            mut axes,
+            mut dimensions,
+        } = self;
+        let mut order = [0, 1, 2];
+        order.sort_by(|i, j| dimensions[*j].total_cmp(&dimensions[*i]));
+        (axes, dimensions) = (order.map(|i| axes[i]), order.map(|i| dimensions[i]));
+        if axes[0].cross(axes[1]).dot(axes[2]) < 0.0 {
+            axes[2] = -axes[2];
+        }
+        let transform = Matrix4::from_cols(
+            axes[0].extend(0.0),
+            axes[1].extend(0.0),
+            axes[2].extend(0.0),
+            center.to_homogeneous(),
+        );
+        (transform, dimensions.into())
+    }
+}
+
+/// Returns the box of `points` along the orthonormal `axes`.
+fn fit_box(points: &[Point3], axes: [Vector3; 3]) -> FittedBox {
+    let (min, max) = points.iter().fold(
+        ([f64::INFINITY; 3], [f64::NEG_INFINITY; 3]),
+        |(min, max), p| {
+            let coords = axes.map(|axis| p.to_vec().dot(axis));
+            (
+                std::array::from_fn(|i| f64::min(min[i], coords[i])),
+                std::array::from_fn(|i| f64::max(max[i], coords[i])),
+            )
+        },
+    );
+    let center = (0..3).fold(Point3::origin(), |center, i| {
+        center + axes[i] * (min[i] + max[i]) / 2.0
+    });
+    FittedBox {
+        center,
+        axes,
+        dimensions: std::array::from_fn(|i| max[i] - min[i]),
+    }
+}
+
+/// Returns the eigenvectors of the covariance matrix of `points`.
+fn principal_axes(points: &[Point3]) -> [Vector3; 3] {
+    let n = points.len() as f64;
+    let mean = points
+        .iter()
+        .fold(Vector3::zero(), |sum, p| sum + p.to_vec())
+        / n;
+    let covariance = points.iter().fold(Matrix3::zero(), |sum, p| {
+        let d = p.to_vec() - mean;
+        sum + Matrix3::from_cols(d * d.x, d * d.y, d * d.z)
+    }) / n;
+    let eigenvectors = symmetric_eigenvectors(covariance);
+    [eigenvectors.x, eigenvectors.y, eigenvectors.z]
+}
+
+/// Returns the orthogonal matrix whose columns are the eigenvectors of the symmetric matrix `a`,
+/// by the Jacobi eigenvalue algorithm.
+fn symmetric_eigenvectors(mut a: Matrix3) -> Matrix3 {
+    let mut eigenvectors = Matrix3::identity();
+    let scale = (0..3).map(|i| a[i][i].abs()).sum::<f64>();
+    for _ in 0..64 {
+        let (p, q) = [(0, 1), (0, 2), (1, 2)]
+            .into_iter()
+            .max_by(|(i, j), (k, l)| a[*j][*i].abs().total_cmp(&a[*l][*k].abs()))
+            .unwrap();
+        if a[q][p].abs() <= f64::EPSILON * scale {
+            break;
+        }
+        let theta = 0.5 * f64::atan2(2.0 * a[q][p], a[q][q] - a[p][p]);
+        let (s, c) = theta.sin_cos();
+        let mut rotation = Matrix3::identity();
+        rotation[p][p] = c;
+        rotation[q][q] = c;
+        rotation[q][p] = s;
+        rotation[p][q] = -s;
+        a = rotation.transpose() * a * rotation;
+        eigenvectors = eigenvectors * rotation;
+    }
+    eigenvectors
+}
@@ -0,0 +1,167 @@
+use crate::*;
+
+/// Returns the Newell normal of the polygon `points`, which is not normalized.
+///
+/// The length is twice the area of the polygon projected onto the plane perpendicular to the
+/// normal, so it is robust for non-convex or slightly non-planar polygons.
+/// Returns the zero vector if there are fewer than three points.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// use truck_polymesh::planarity::newell_normal;
+/// let points = [
+///     Point3::new(0.0, 0.0, 0.0),
+///     Point3::new(2.0, 0.0, 0.0),
+///     Point3::new(2.0, 1.0, 0.0),
+///     Point3::new(0.0, 1.0, 0.0),
+/// ];
+/// assert_near!(newell_normal(&points), Vector3::new(0.0, 0.0, 4.0));
+/// ```
+pub fn newell_normal(points: &[Point3]) -> Vector3 {
+    if points.len() < 3 {
+        return Vector3::zero();
+    }
+    let center = centroid(points).to_vec();
+    points
+        .iter()
+        .zip(points.iter().cycle().skip(1))
+        .fold(Vector3::zero(), |sum, (p, q)| {
+            sum + (p.to_vec() - center).cross(q.to_vec() - center)
+        })
+}
+
+/// Returns the best-fit plane of the polygon `points` as the pair of the centroid and the unit
+/// Newell normal.
+///
+/// If there are fewer than three points or the polygon is degenerate, the normal is the zero vector.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// use truck_polymesh::planarity::best_fit_plane;
+/// let points = [
+///     Point3::new(0.0, 0.0, 1.0),
+///     Point3::new(1.0, 0.0, 1.0),
+///     Point3::new(1.0, 1.0, 1.0),
+///     Point3::new(0.0, 1.0, 1.0),
+/// ];
+/// let (origin, normal) = best_fit_plane(&points);
+/// assert_near!(origin, Point3::new(0.5, 0.5, 1.0));
+/// assert_near!(normal, Vector3::unit_z());
+///
+/// let (_, normal) = best_fit_plane(&points[..2]);
+/// assert_eq!(normal, Vector3::zero());
+/// ```
+pub fn best_fit_plane(points: &[Point3]) -> (Point3, Vector3) {
+    let normal = newell_normal(points);
+    let normal = match normal.magnitude2().so_small2() {
+        true => Vector3::zero(),
+        false => normal.normalize(),
+    };
+    (centroid(points), normal)
+}
+
+/// Returns the maximum distance of the polygon `points` from its best-fit plane.
+///
+/// Returns `0.0` if there are fewer than three points or the polygon is degenerate.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// use truck_polymesh::planarity::planarity_deviation;
+/// // a quadrangle whose one vertex is lifted slightly
+/// let points = [
+///     Point3::new(0.0, 0.0, 0.0),
+///     Point3::new(1.0, 0.0, 0.0),
+///     Point3::new(1.0, 1.0, 0.01),
+///     Point3::new(0.0, 1.0, 0.0),
+/// ];
+/// let deviation = planarity_deviation(&points);
+/// assert!(0.0 < deviation && deviation < 0.01);
+///
+/// // triangles are always planar
+/// assert_near!(planarity_deviation(&points[..3]), 0.0);
+/// ```
+pub fn planarity_deviation(points: &[Point3]) -> f64 {
+    let (origin, normal) = best_fit_plane(points);
+    points
+        .iter()
+        .map(|p| (p - origin).dot(normal).abs())
+        .fold(0.0, f64::max)
+}
+
+/// Returns whether `p` is inside the planar polygon `loop3d` whose plane has the normal
+/// `plane_normal`.
+///
+/// # Details
+/// - The loop and `p` are projected onto the plane perpendicular to `plane_normal`, and `p` is
+///   classified by the winding number in the plane, same as [`PolylineCurve::include`].
+///   So the points off the plane are classified by their orthogonal projections, and the
+///   distance from the plane should be checked in advance if it matters.
+/// - The orientation of the loop does not matter, and the last point may repeat the first.
+/// - If `plane_normal` is the zero vector, the Newell normal of the loop is used.
+/// - Returns `false` if the loop is degenerate. The result for the points on the loop is
+///   unspecified.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// use truck_polymesh::planarity::point_in_planar_loop;
+/// use std::f64::consts::PI;
+/// // a regular pentagon on the plane tilted around the x-axis by 45 degrees
+/// let (c, s) = (f64::cos(PI / 4.0), f64::sin(PI / 4.0));
+/// let pentagon = (0..5)
+///     .map(|i| {
+///         let t = 2.0 * PI * i as f64 / 5.0;
+///         Point3::new(f64::cos(t), c * f64::sin(t), s * f64::sin(t))
+///     })
+///     .collect::<Vec<_>>();
+/// let normal = Vector3::new(0.0, -s, c);
+///
+/// assert!(point_in_planar_loop(&pentagon, normal, Point3::new(0.5, 0.0, 0.0)));
+/// assert!(point_in_planar_loop(&pentagon, normal, Point3::new(0.0, 0.5 * c, 0.5 * s)));
+/// assert!(!point_in_planar_loop(&pentagon, normal, Point3::new(0.0, 0.9 * c, 0.9 * s)));
+/// assert!(!point_in_planar_loop(&pentagon, normal, Point3::new(-0.9, 0.0, 0.0)));
+/// // off the plane: classified by the projection
+/// let offset = normal * 2.0;
+/// assert!(point_in_planar_loop(&pentagon, normal, Point3::new(0.5, 0.0, 0.0) + offset));
+/// // the orientation and the normal do not matter
+/// let reversed = pentagon.iter().rev().copied().collect::<Vec<_>>();
+/// assert!(point_in_planar_loop(&reversed, -normal, Point3::new(0.5, 0.0, 0.0)));
+/// assert!(point_in_planar_loop(&reversed, Vector3::zero(), Point3::new(0.5, 0.0, 0.0)));
+/// ```
+pub fn point_in_planar_loop(loop3d: &[Point3], plane_normal: Vector3, p: Point3) -> bool {
+    let normal = match plane_normal.magnitude2().so_small2() {
+        true => newell_normal(loop3d),
+        false => plane_normal,
+    };
+    if loop3d.len() < 3 || normal.magnitude2().so_small2() {
+        return false;
+    }
+    let normal = normal.normalize();
+    let axis = match normal.x.abs() < 0.5 {
+        true => Vector3::unit_x(),
+        false => Vector3::unit_y(),
+    };
+    let u = normal.cross(axis).normalize();
+    let v = normal.cross(u);
+    let origin = loop3d[0];
+    let project = |q: Point3| Point2::new((q - origin).dot(u), (q - origin).dot(v));
+    let mut polygon = loop3d
+        .iter()
+        .map(|q| project(*q))
+        .collect::<PolylineCurve<_>>();
+    if polygon.area() < 0.0 {
+        polygon.invert();
+    }
+    polygon.include(project(p))
+}
+
+fn centroid(points: &[Point3]) -> Point3 {
+    match points.is_empty() {
+        true => Point3::origin(),
+        false => Point3::from_vec(
+            points
+                .iter()
+                .fold(Vector3::zero(), |sum, p| sum + p.to_vec())
+                / points.len() as f64,
+        ),
+    }
+}
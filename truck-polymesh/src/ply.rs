@@ -0,0 +1,351 @@
+use crate::*;
+use std::io::{BufRead, BufReader, Read, Write};
+
+type Result<T> = std::result::Result<T, errors::Error>;
+
+/// Reads mesh data from ASCII PLY file.
+///
+/// # Details
+/// - The properties of each element are read by the positions declared in the header, so they can
+///   be in any order. The unknown properties and elements are skipped.
+/// - The positions are read from `x`, `y`, `z`, the normals from `nx`, `ny`, `nz`, and the texture
+///   coordinates from `s`, `t` or `u`, `v`. The normals and the texture coordinates are read only
+///   if all their components are declared.
+/// - The faces are read from the list property `vertex_indices` or `vertex_index` of the element
+///   `face`. The vertices of faces refer to the texture coordinates and normals with the same
+///   indices as the positions.
+/// - The binary formats are not supported.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// let ply = b"ply
+/// format ascii 1.0
+/// comment the properties in an unusual order
+/// element vertex 3
+/// property float z
+/// property float y
+/// property float x
+/// property float confidence
+/// element face 1
+/// property list uchar int vertex_indices
+/// end_header
+/// 3 0 0 0.5
+/// 2 1 0 0.9
+/// 1 0 1 0.7
+/// 3 0 1 2
+/// ";
+/// let mesh = ply::read(&ply[..]).unwrap();
+/// assert_eq!(mesh.positions()[0], Point3::new(0.0, 0.0, 3.0));
+/// assert_eq!(mesh.positions()[1], Point3::new(0.0, 1.0, 2.0));
+/// assert_eq!(mesh.tri_faces()[0][2].pos, 2);
+/// assert!(mesh.normals().is_empty());
+/// ```
+pub fn read<R: Read>(reader: R) -> Result<PolygonMesh> { Ok(read_with_colors(reader)?.0) }
+
+/// Reads mesh data and the colors of positions from ASCII PLY file.
+///
+/// The colors are read from `red`, `green`, `blue` of the element `vertex` in the same way as
+/// [`read`]. The integer components are divided by 255, and the floating point components are
+/// read as they are. The colors are empty if some components are not declared.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// let ply = b"ply
+/// format ascii 1.0
+/// element vertex 3
+/// property float x
+/// property float y
+/// property float z
+/// property uchar red
+/// property uchar green
+/// property uchar blue
+/// element face 1
+/// property list uchar int vertex_indices
+/// end_header
+/// 0 0 0 255 0 0
+/// 1 0 0 0 255 0
+/// 0 1 0 0 0 255
+/// 3 0 1 2
+/// ";
+/// let (mesh, colors) = ply::read_with_colors(&ply[..]).unwrap();
+/// assert_eq!(mesh.positions().len(), 3);
+/// assert_eq!(colors, vec![[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]);
+/// ```
+pub fn read_with_colors<R: Read>(reader: R) -> Result<(PolygonMesh, Vec<[f32; 3]>)> {
+    let mut lines = BufReader::new(reader).lines();
+    let mut next_line = move || -> Result<String> {
+        match lines.next() {
+            Some(line) => Ok(line?),
+            None => Err(invalid_data("unexpected end of file")),
+        }
+    };
+    let elements = read_header(&mut next_line)?;
+    let mut positions = Vec::new();
+    let mut uv_coords = Vec::new();
+    let mut normals = Vec::new();
+    let mut colors = Vec::new();
+    let mut faces = Faces::default();
+    for element in &elements {
+        let position = element.indices(["x", "y", "z"]);
+        let normal = element.indices(["nx", "ny", "nz"]);
+        let uv_coord = element
+            .indices(["s", "t"])
+            .or_else(|| element.indices(["u", "v"]))
+            .or_else(|| element.indices(["texture_u", "texture_v"]));
+        let color = element.indices(["red", "green", "blue"]);
+        let indices = element
+            .properties
+            .iter()
+            .position(|p| p.is_list && matches!(&*p.name, "vertex_indices" | "vertex_index"));
+        for _ in 0..element.count {
+            let line = next_line()?;
+            if element.name == "vertex" {
+                let values = element.scalars(&line)?;
+                if let Some([x, y, z]) = position {
+                    positions.push(Point3::new(values[x], values[y], values[z]));
+                }
+                if let Some([x, y, z]) = normal {
+                    normals.push(Vector3::new(values[x], values[y], values[z]));
+                }
+                if let Some([u, v]) = uv_coord {
+                    uv_coords.push(Vector2::new(values[u], values[v]));
+                }
+                if let Some(color) = color {
+                    colors.push(color.map(|i| match element.properties[i].is_float() {
+                        true => values[i] as f32,
+                        false => values[i] as f32 / 255.0,
+                    }));
+                }
+            } else if let (true, Some(idx)) = (element.name == "face", indices) {
+                let face = element
+                    .list(&line, idx)?
+                    .into_iter()
+                    .map(|i| StandardVertex {
+                        pos: i,
+                        uv: (!uv_coords.is_empty()).then_some(i),
+                        nor: (!normals.is_empty()).then_some(i),
+                    })
+                    .collect::<Vec<_>>();
+                faces.push(face);
+            }
+        }
+    }
+    let mesh = PolygonMesh::try_new(
+        StandardAttributes {
+            positions,
+            uv_coords,
+            normals,
+        },
+        faces,
+    )?;
+    Ok((mesh, colors))
+}
+
+/// Writes the positions and the faces of [`PolygonMesh`] to ASCII PLY file.
+///
+/// Same as [`write_with_colors`] without colors.
+pub fn write<W: Write>(mesh: &PolygonMesh, writer: W) -> Result<()> {
+    write_with_colors(mesh, &[], writer)
+}
+
+/// Writes the positions, the faces and the colors of positions of [`PolygonMesh`] to ASCII PLY
+/// file.
+///
+/// # Details
+/// - The `i`-th color corresponds to `mesh.positions()[i]`, and is written as `red`, `green`,
+///   `blue` of `uchar` after clamped to `[0, 1]`. No colors are written if `colors` is empty.
+/// - PLY attaches the attributes to positions, and truck can attach normals and texture
+///   coordinates to the vertices of faces independently. So, the normals and the texture
+///   coordinates are not written.
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// let mesh = PolygonMesh::new(
+///     StandardAttributes {
+///         positions: vec![
+///             Point3::new(0.0, 0.0, 0.0),
+///             Point3::new(1.0, 0.0, 0.0),
+///             Point3::new(0.0, 1.0, 0.0),
+///         ],
+///         ..Default::default()
+///     },
+///     Faces::from_iter(&[[0, 1, 2]]),
+/// );
+/// let red = [1.0, 0.0, 0.0];
+/// let mut bytes = Vec::new();
+/// ply::write_with_colors(&mesh, &[red; 3], &mut bytes).unwrap();
+/// let (read_mesh, colors) = ply::read_with_colors(bytes.as_slice()).unwrap();
+/// assert_eq!(read_mesh.positions(), mesh.positions());
+/// assert_eq!(colors, vec![red; 3]);
+/// ```
+pub fn write_with_colors<W: Write>(
+    mesh: &PolygonMesh,
+    colors: &[[f32; 3]],
+    mut writer: W,
+) -> Result<()> {
+    let positions = mesh.positions();
+    if !colors.is_empty() && colors.len() != positions.len() {
+        return Err(invalid_input(
+            "the number of colors is not that of positions",
+        ));
+    }
+    writeln!(
+        writer,
+        "ply\nformat ascii 1.0\nelement vertex {}",
+        positions.len()
+    )?;
+    writeln!(
+        writer,
+        "property double x\nproperty double y\nproperty double z"
+    )?;
+    if !colors.is_empty() {
+        writeln!(
+            writer,
+            "property uchar red\nproperty uchar green\nproperty uchar blue"
+        )?;
+    }
+    writeln!(writer, "element face {}", mesh.faces().len())?;
+    writeln!(writer, "property list uchar int vertex_indices\nend_header")?;
+    let quantize = |x: f32| (f32::clamp(x, 0.0, 1.0) * 255.0).round() as u8;
+    positions.iter().enumerate().try_for_each(|(i, p)| {
+        write!(writer, "{} {} {}", p.x, p.y, p.z)?;
+        if let Some(color) = colors.get(i) {
+            let [r, g, b] = color.map(quantize);
+            write!(writer, " {r} {g} {b}")?;
+        }
+        writeln!(writer)
+    })?;
+    mesh.face_iter().try_for_each(|face| {
+        write!(writer, "{}", face.len())?;
+        face.iter().try_for_each(|v| write!(writer, " {}", v.pos))?;
+        writeln!(writer)
+    })?;
+    Ok(())
+}
+
+#[derive(Clone, Debug)]
+struct Property {
+    name: String,
+    data_type: String,
+    is_list: bool,
+}
+
+impl Property {
+    /// Returns whether the property is a floating point scalar.
+    fn is_float(&self) -> bool {
+        matches!(&*self.data_type, "float" | "float32" | "double" | "float64")
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Element {
+    name: String,
+    count: usize,
+    properties: Vec<Property>,
+}
+
+impl Element {
+    /// Returns the positions of the scalar properties with `names`.
+    fn indices<const N: usize>(&self, names: [&str; N]) -> Option<[usize; N]> {
+        let indices = names.map(|name| {
+            self.properties
+                .iter()
+                .position(|p| !p.is_list && p.name == name)
+        });
+        match indices.iter().all(Option::is_some) {
+            true => Some(indices.map(Option::unwrap)),
+            false => None,
+        }
+    }
+
+    /// Returns the values of the properties, where the lists are parsed as `NaN`.
+    fn scalars(&self, line: &str) -> Result<Vec<f64>> {
+        let mut tokens = line.split_whitespace();
+        self.properties
+            .iter()
+            .map(|p| {
+                let token = tokens
+                    .next()
+                    .ok_or_else(|| invalid_data("missing property"))?;
+                if p.is_list {
+                    skip(&mut tokens, token.parse()?)?;
+                    Ok(f64::NAN)
+                } else {
+                    Ok(token.parse::<f64>()?)
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the items of the `idx`th property, which is a list of indices.
+    fn list(&self, line: &str, idx: usize) -> Result<Vec<usize>> {
+        let mut tokens = line.split_whitespace();
+        for p in &self.properties[..idx] {
+            let token = tokens
+                .next()
+                .ok_or_else(|| invalid_data("missing property"))?;
+            if p.is_list {
+                skip(&mut tokens, token.parse()?)?;
+            }
+        }
+        let len = tokens
+            .next()
+            .ok_or_else(|| invalid_data("missing property"))?
+            .parse::<usize>()?;
+        let res = tokens
+            .take(len)
+            .map(|token| token.parse::<usize>())
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        match res.len() == len {
+            true => Ok(res),
+            false => Err(invalid_data("missing list item")),
+        }
+    }
+}
+
+fn skip<'a>(tokens: &mut impl Iterator<Item = &'a str>, len: usize) -> Result<()> {
+    match tokens.take(len).count() == len {
+        true => Ok(()),
+        false => Err(invalid_data("missing list item")),
+    }
+}
+
+fn read_header(next_line: &mut impl FnMut() -> Result<String>) -> Result<Vec<Element>> {
+    if next_line()?.trim() != "ply" {
+        return Err(invalid_data("not a PLY file"));
+    }
+    let mut elements = Vec::<Element>::new();
+    loop {
+        let line = next_line()?;
+        let args = line.split_whitespace().collect::<Vec<_>>();
+        match args.as_slice() {
+            ["format", "ascii", _] => {}
+            ["format", ..] => return Err(invalid_data("only the ASCII format is supported")),
+            ["element", name, count] => elements.push(Element {
+                name: name.to_string(),
+                count: count.parse()?,
+                properties: Vec::new(),
+            }),
+            ["property", "list", _, _, name] | ["property", _, name] => {
+                let element = elements
+                    .last_mut()
+                    .ok_or_else(|| invalid_data("property without element"))?;
+                element.properties.push(Property {
+                    name: name.to_string(),
+                    data_type: args[args.len() - 2].to_string(),
+                    is_list: args[1] == "list",
+                });
+            }
+            ["end_header"] => return Ok(elements),
+            _ => {}
+        }
+    }
+}
+
+fn invalid_data(message: &str) -> errors::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, message).into()
+}
+
+fn invalid_input(message: &str) -> errors::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidInput, message).into()
+}
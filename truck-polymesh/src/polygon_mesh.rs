@@ -126,6 +126,44 @@ impl PolygonMesh {
     /// Creates the bounding box of the polygon mesh.
     #[inline(always)]
     pub fn bounding_box(&self) -> BoundingBox<Point3> { self.positions().iter().collect() }
+    /// Returns the iterator of the triangles as the triples of positions.
+    ///
+    /// The faces are triangulated in the same way as [`Faces::triangle_iter`]: quadrangles are
+    /// split into two triangles and the other polygons are fanned from their first vertices.
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// let positions = vec![
+    ///     Point3::new(0.0, 0.0, 0.0),
+    ///     Point3::new(1.0, 0.0, 0.0),
+    ///     Point3::new(1.0, 1.0, 0.0),
+    ///     Point3::new(0.0, 1.0, 0.0),
+    ///     Point3::new(2.0, 0.0, 0.0),
+    /// ];
+    /// let faces = Faces::from_iter([&[0, 1, 2, 3][..], &[1, 4, 2]]);
+    /// let mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions,
+    ///         ..Default::default()
+    ///     },
+    ///     faces,
+    /// );
+    ///
+    /// // the unit square and the triangle with the area 0.5
+    /// let area: f64 = mesh
+    ///     .triangles()
+    ///     .map(|[p, q, r]| (q - p).cross(r - p).magnitude() / 2.0)
+    ///     .sum();
+    /// assert_eq!(mesh.triangles().len(), 3);
+    /// assert!(f64::abs(area - 1.5) < 1.0e-10);
+    /// ```
+    #[inline(always)]
+    pub fn triangles(&self) -> impl ExactSizeIterator<Item = [Point3; 3]> + '_ {
+        let positions = self.positions();
+        self.faces
+            .triangle_iter()
+            .map(move |tri| tri.map(|v| positions[v.pos]))
+    }
     /// Returns a polygon mesh with only positions information.
     #[inline(always)]
     pub fn to_positions_mesh(&self) -> PolygonMesh<usize, Vec<Point3>> {
@@ -177,6 +215,28 @@ impl Invertible for PolygonMesh {
 
 impl PolygonMesh {
     /// Returns the vector of all positions.
+    ///
+    /// The attributes and the faces are readable without [`PolygonMesh::editor`].
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// let mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 1.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[0, 1, 2], [0, 2, 3]]),
+    /// );
+    /// assert_eq!(mesh.positions().len(), 4);
+    /// assert_eq!(mesh.positions()[2], Point3::new(1.0, 1.0, 0.0));
+    /// assert!(mesh.uv_coords().is_empty() && mesh.normals().is_empty());
+    /// assert_eq!(mesh.faces().len(), 2);
+    /// ```
     #[inline(always)]
     pub const fn positions(&self) -> &Vec<Point3> { &self.attributes.positions }
 
@@ -236,6 +296,87 @@ impl<V, A: Default> Default for PolygonMesh<V, A> {
     }
 }
 
+impl PolygonMesh {
+    /// The version of the serialized form written by this crate.
+    ///
+    /// The serialized meshes without the field `version`, written before the versioning, are
+    /// regarded as the version `0`. The versions up to this are deserialized, and the missing
+    /// arrays of the attributes and the faces are regarded as empty.
+    pub const FORMAT_VERSION: u32 = 1;
+
+    /// Returns the JSON value of the serialized form of the mesh.
+    /// Available with the feature `serde_json`.
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// let mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(1.0, 0.0, 0.0),
+    ///             Point3::new(0.0, 1.0, 0.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[[0, 1, 2]]),
+    /// );
+    /// let value = mesh.to_json_value().unwrap();
+    /// assert_eq!(value["version"], PolygonMesh::FORMAT_VERSION);
+    /// assert_eq!(PolygonMesh::from_json_value(value).unwrap(), mesh);
+    /// ```
+    #[cfg(feature = "serde_json")]
+    #[inline(always)]
+    pub fn to_json_value(&self) -> serde_json::Result<serde_json::Value> {
+        serde_json::to_value(self)
+    }
+
+    /// Creates the mesh from the JSON value of the serialized form.
+    /// Available with the feature `serde_json`.
+    /// # Errors
+    /// Returns the error if `value` is not the serialized mesh, its version is newer than
+    /// [`PolygonMesh::FORMAT_VERSION`], or an index of the faces is out of range.
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// // the mesh written before the versioning, without uv coordinates and normals
+    /// let value = serde_json::json!({
+    ///     "attributes": {
+    ///         "positions": [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+    ///     },
+    ///     "faces": {
+    ///         "tri_faces": [[[0, null, null], [1, null, null], [2, null, null]]],
+    ///     },
+    /// });
+    /// let mesh = PolygonMesh::from_json_value(value).unwrap();
+    /// assert_eq!(mesh.positions().len(), 3);
+    /// assert!(mesh.uv_coords().is_empty());
+    /// assert_eq!(mesh.tri_faces().len(), 1);
+    /// ```
+    #[cfg(feature = "serde_json")]
+    #[inline(always)]
+    pub fn from_json_value(value: serde_json::Value) -> serde_json::Result<Self> {
+        serde_json::from_value(value)
+    }
+}
+
+impl<V: Serialize, A: Serialize> Serialize for PolygonMesh<V, A> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where S: serde::Serializer {
+        #[derive(Serialize)]
+        struct PolygonMesh_<'a, V, A> {
+            version: u32,
+            attributes: &'a A,
+            faces: &'a Faces<V>,
+        }
+        PolygonMesh_ {
+            version: PolygonMesh::FORMAT_VERSION,
+            attributes: &self.attributes,
+            faces: &self.faces,
+        }
+        .serialize(serializer)
+    }
+}
+
 impl<'de, V, A> Deserialize<'de> for PolygonMesh<V, A>
 where
     V: Copy + Debug + Deserialize<'de>,
@@ -245,10 +386,22 @@ where
     where D: serde::Deserializer<'de> {
         #[derive(Deserialize)]
         struct PolygonMesh_<V, A> {
+            #[serde(default)]
+            version: u32,
             attributes: A,
+            #[serde(default = "Faces::default")]
             faces: Faces<V>,
         }
-        let PolygonMesh_ { attributes, faces } = PolygonMesh_::<V, A>::deserialize(deserializer)?;
+        let PolygonMesh_ {
+            version,
+            attributes,
+            faces,
+        } = PolygonMesh_::<V, A>::deserialize(deserializer)?;
+        if version > PolygonMesh::FORMAT_VERSION {
+            return Err(serde::de::Error::custom(format!(
+                "unsupported version of polygon mesh: {version}"
+            )));
+        }
         Self::try_new(attributes, faces).map_err(serde::de::Error::custom)
     }
 }
@@ -0,0 +1,108 @@
+use crate::*;
+use rustc_hash::FxHashMap as HashMap;
+use std::array;
+
+impl PolygonMeshBuilder {
+    /// Creates an empty builder.
+    #[inline(always)]
+    pub fn new() -> Self { Self::default() }
+
+    /// Pushes a triangle without texture coordinates and normals.
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// let mut builder = PolygonMeshBuilder::new();
+    /// let p = Point3::new(0.0, 0.0, 0.0);
+    /// builder.push_triangle(p, Point3::new(1.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0));
+    /// // nearer than `TOLERANCE`, but in the other cell of the hash map
+    /// let q = Point3::new(-0.4 * TOLERANCE, 0.0, 0.0);
+    /// builder.push_triangle(q, Point3::new(0.0, -1.0, 0.0), Point3::new(1.0, 0.0, 0.0));
+    /// let mesh = builder.build();
+    /// assert_eq!(mesh.positions().len(), 4);
+    /// assert_eq!(mesh.tri_faces()[1][0].pos, mesh.tri_faces()[0][0].pos);
+    /// ```
+    #[inline(always)]
+    pub fn push_triangle(&mut self, p0: Point3, p1: Point3, p2: Point3) -> &mut Self {
+        let attr = |position| StandardAttribute {
+            position,
+            uv_coord: None,
+            normal: None,
+        };
+        self.push_face([attr(p0), attr(p1), attr(p2)])
+    }
+
+    /// Pushes a polygon with the attributes of its vertices.
+    ///
+    /// The polygons with less than three vertices are ignored, same as [`Faces::push`].
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// let attr = |x: f64, y: f64| StandardAttribute {
+    ///     position: Point3::new(x, y, 0.0),
+    ///     uv_coord: Some(Vector2::new(x, y)),
+    ///     normal: Some(Vector3::unit_z()),
+    /// };
+    /// let mut builder = PolygonMeshBuilder::new();
+    /// builder.push_face([attr(0.0, 0.0), attr(1.0, 0.0), attr(1.0, 1.0), attr(0.0, 1.0)]);
+    /// let mesh = builder.build();
+    /// assert_eq!(mesh.quad_faces().len(), 1);
+    /// assert_eq!(mesh.uv_coords().len(), 4);
+    /// // All vertices share one normal.
+    /// assert_eq!(mesh.normals().len(), 1);
+    /// ```
+    pub fn push_face(&mut self, vertices: impl AsRef<[StandardAttribute]>) -> &mut Self {
+        let face = vertices
+            .as_ref()
+            .iter()
+            .map(|attr| StandardVertex {
+                pos: signup(
+                    attr.position.into(),
+                    &mut self.positions,
+                    &mut self.attributes.positions,
+                ),
+                uv: attr.uv_coord.map(|uv| {
+                    signup(
+                        uv.into(),
+                        &mut self.uv_coords,
+                        &mut self.attributes.uv_coords,
+                    )
+                }),
+                nor: attr
+                    .normal
+                    .map(|nor| signup(nor.into(), &mut self.normals, &mut self.attributes.normals)),
+            })
+            .collect::<Vec<_>>();
+        self.faces.push(face);
+        self
+    }
+
+    /// Returns the built mesh.
+    #[inline(always)]
+    pub fn build(self) -> PolygonMesh { PolygonMesh::new(self.attributes, self.faces) }
+}
+
+/// Returns the index of the registered value nearer than `TOLERANCE` to `value` in `values`, and
+/// appends `value` if there is no such value.
+///
+/// The cells of `map` have the width `TOLERANCE`, so the near values are searched in the
+/// neighboring cells.
+fn signup<const N: usize, T: Copy + From<[f64; N]> + Into<[f64; N]>>(
+    value: [f64; N],
+    map: &mut HashMap<[i64; N], Vec<usize>>,
+    values: &mut Vec<T>,
+) -> usize {
+    let key = value.map(|x| (x / TOLERANCE).floor() as i64);
+    let is_near = |idx: &usize| {
+        let other: [f64; N] = values[*idx].into();
+        let dist2 = (0..N).map(|i| (value[i] - other[i]).powi(2)).sum::<f64>();
+        dist2 < TOLERANCE * TOLERANCE
+    };
+    let registered = (0..3_usize.pow(N as u32))
+        .map(|code| array::from_fn(|i| key[i] + (code / 3_usize.pow(i as u32) % 3) as i64 - 1))
+        .find_map(|cell: [i64; N]| map.get(&cell)?.iter().copied().find(is_near));
+    registered.unwrap_or_else(|| {
+        values.push(value.into());
+        map.entry(key).or_default().push(values.len() - 1);
+        values.len() - 1
+    })
+}
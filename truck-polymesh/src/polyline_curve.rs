@@ -1,6 +1,9 @@
 use crate::*;
 use itertools::Itertools;
-use std::ops::{Bound, Deref, DerefMut};
+use std::{
+    f64::consts::PI,
+    ops::{Bound, Deref, DerefMut},
+};
 use truck_base::cgmath64::control_point::ControlPoint;
 
 impl PolylineCurve<Point2> {
@@ -24,12 +27,45 @@ impl PolylineCurve<Point2> {
         self.iter().circular_tuple_windows().fold(0.0, sum) / 2.0
     }
 
+    /// Signed area of the polygon, positive if counter-clockwise. Same as [`PolylineCurve::area`].
+    ///
+    /// The polyline is regarded as closed: the last point is connected to the first one. So
+    /// open polylines and closed ones whose last point repeats the first have the same area.
+    /// # Example
+    /// ```
+    /// use truck_polymesh::*;
+    /// let mut square = PolylineCurve(vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(1.0, 0.0),
+    ///     Point2::new(1.0, 1.0),
+    ///     Point2::new(0.0, 1.0),
+    /// ]);
+    /// assert_near!(square.signed_area(), 1.0);
+    /// assert!(square.is_ccw());
+    /// // closing explicitly does not change the area
+    /// square.push(Point2::new(0.0, 0.0));
+    /// assert_near!(square.signed_area(), 1.0);
+    /// square.invert();
+    /// assert_near!(square.signed_area(), -1.0);
+    /// assert!(!square.is_ccw());
+    /// ```
+    #[inline(always)]
+    pub fn signed_area(&self) -> f64 { self.area() }
+
+    /// Returns whether the polygon is counter-clockwise, i.e. the signed area is positive.
+    /// Degenerate polygons with zero area are not counter-clockwise.
+    #[inline(always)]
+    pub fn is_ccw(&self) -> bool { self.area() > 0.0 }
+
     /// whether `c` is included in enclosed domain when endpoints are connected
+    ///
+    /// The orientation of the polygon does not matter: clockwise polygons include the same points
+    /// as counter-clockwise ones.
     /// # Example
     /// ```
     /// use truck_polymesh::*;
     /// use std::f64::consts::PI;
-    /// let hexagon = (0..6).map(|i| {
+    /// let mut hexagon = (0..6).map(|i| {
     ///         let t = PI / 3.0 * i as f64;
     ///         Point2::new(f64::cos(t), f64::sin(t))
     ///     })
@@ -38,34 +74,204 @@ impl PolylineCurve<Point2> {
     /// assert!(hexagon.include(p0));
     /// let p1 = Point2::new(0.0, 1.0);
     /// assert!(!hexagon.include(p1));
+    /// // clockwise
+    /// hexagon.invert();
+    /// assert!(hexagon.include(p0));
+    /// assert!(!hexagon.include(p1));
     /// ```
     pub fn include(&self, c: Point2) -> bool {
-        let t = 2.0 * std::f64::consts::PI * HashGen::hash1(c);
-        let r = Vector2::new(f64::cos(t), f64::sin(t));
-        self.iter()
-            .circular_tuple_windows()
-            .try_fold(0_i32, move |counter, (p0, p1)| {
-                let a = p0 - c;
-                let b = p1 - c;
-                let s0 = r.x * a.y - r.y * a.x; // v times a
-                let s1 = r.x * b.y - r.y * b.x; // v times b
-                let s2 = a.x * b.y - a.y * b.x; // a times b
-                let x = s2 / (s1 - s0);
-                if x.so_small() && s0 * s1 < 0.0 {
-                    None
-                } else if x > 0.0 && s0 <= 0.0 && s1 > 0.0 {
-                    Some(counter + 1)
-                } else if x > 0.0 && s0 >= 0.0 && s1 < 0.0 {
-                    Some(counter - 1)
-                } else {
-                    Some(counter)
-                }
-            })
-            .map(|counter| counter > 0)
+        crossing_counter(self.iter().copied().circular_tuple_windows(), c)
+            .map(|counter| counter != 0)
             .unwrap_or(false)
     }
 }
 
+impl<P: MetricSpace<Metric = f64> + Copy> PolylineCurve<P> {
+    /// Returns whether the first and the last points are within `tol`.
+    ///
+    /// The polylines with less than two points are not closed.
+    /// # Example
+    /// ```
+    /// use truck_polymesh::*;
+    /// let polyline = PolylineCurve(vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(1.0, 0.0),
+    ///     Point2::new(1.0, 1.0),
+    ///     Point2::new(0.0, 1.0e-3),
+    /// ]);
+    /// assert!(!polyline.is_closed(1.0e-4));
+    /// assert!(polyline.is_closed(1.0e-2));
+    /// assert!(!PolylineCurve(vec![Point2::new(0.0, 0.0)]).is_closed(1.0));
+    /// ```
+    pub fn is_closed(&self, tol: f64) -> bool {
+        match (self.first(), self.last()) {
+            (Some(p), Some(q)) if self.len() > 1 => p.distance(*q) <= tol,
+            _ => false,
+        }
+    }
+
+    /// Makes the polyline closed.
+    ///
+    /// If the last point is within `tol` from the first point, the last point is snapped to the
+    /// first one. Otherwise, the first point is appended as the closing point. The polylines with
+    /// less than two points are not changed.
+    /// # Example
+    /// ```
+    /// use truck_polymesh::*;
+    /// // an almost closed square
+    /// let mut square = PolylineCurve(vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(1.0, 0.0),
+    ///     Point2::new(1.0, 1.0),
+    ///     Point2::new(0.0, 1.0),
+    ///     Point2::new(1.0e-3, 0.0),
+    /// ]);
+    /// square.close(1.0e-2);
+    /// assert_eq!(square.len(), 5);
+    /// assert_eq!(square[4], square[0]);
+    ///
+    /// // an open square
+    /// let mut square = PolylineCurve(vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(1.0, 0.0),
+    ///     Point2::new(1.0, 1.0),
+    ///     Point2::new(0.0, 1.0),
+    /// ]);
+    /// square.close(1.0e-2);
+    /// assert_eq!(square.len(), 5);
+    /// assert!(square.is_closed(0.0));
+    /// ```
+    pub fn close(&mut self, tol: f64) {
+        if self.len() < 2 {
+            return;
+        }
+        let first = self[0];
+        match self.is_closed(tol) {
+            true => *self.last_mut().unwrap() = first,
+            false => self.push(first),
+        }
+    }
+}
+
+impl<P> PolylineCurve<P>
+where
+    P: EuclideanSpace<Scalar = f64>,
+    P::Diff: InnerSpace<Scalar = f64>,
+{
+    /// Returns the length of the polyline.
+    /// # Example
+    /// ```
+    /// use truck_polymesh::*;
+    /// let polyline = PolylineCurve(vec![
+    ///     Point3::new(0.0, 0.0, 0.0),
+    ///     Point3::new(3.0, 0.0, 0.0),
+    ///     Point3::new(3.0, 4.0, 0.0),
+    /// ]);
+    /// assert_near!(polyline.length(), 7.0);
+    /// ```
+    pub fn length(&self) -> f64 {
+        self.windows(2)
+            .fold(0.0, |sum, p| sum + (p[1] - p[0]).magnitude())
+    }
+
+    /// Returns the arc length parameter and the point on the polyline nearest to `point`.
+    ///
+    /// The single-point polylines and the polylines with zero length return their first point
+    /// with the parameter `0.0`. The empty polylines return the origin.
+    /// # Example
+    /// ```
+    /// use truck_polymesh::*;
+    /// let polyline = PolylineCurve(vec![
+    ///     Point3::new(0.0, 0.0, 0.0),
+    ///     Point3::new(2.0, 0.0, 0.0),
+    ///     Point3::new(2.0, 2.0, 2.0),
+    /// ]);
+    /// let (s, p) = polyline.closest_point(Point3::new(3.0, 1.0, 1.0));
+    /// assert_near!(p, Point3::new(2.0, 1.0, 1.0));
+    /// assert_near!(s, 2.0 + f64::sqrt(2.0));
+    /// assert_near!(polyline.length() - s, f64::sqrt(2.0));
+    /// ```
+    pub fn closest_point(&self, point: P) -> (f64, P) {
+        let Some(first) = self.first() else {
+            return (0.0, P::origin());
+        };
+        let init = (f64::INFINITY, (0.0, *first));
+        let (_, res) = self
+            .windows(2)
+            .scan(0.0, |length, p| {
+                let b = p[1] - p[0];
+                let len2 = b.magnitude2();
+                let t = match len2 > 0.0 {
+                    true => f64::clamp((point - p[0]).dot(b) / len2, 0.0, 1.0),
+                    false => 0.0,
+                };
+                let res = (*length + len2.sqrt() * t, p[0] + b * t);
+                *length += len2.sqrt();
+                Some(res)
+            })
+            .fold(init, |(dist2, res), (s, q)| {
+                let d2 = (q - point).magnitude2();
+                match d2 < dist2 {
+                    true => (d2, (s, q)),
+                    false => (dist2, res),
+                }
+            });
+        res
+    }
+
+    /// Removes the interior points whose incoming and outgoing segments are collinear within
+    /// `angle_tol`, i.e. the angle between the directions of the segments is at most `angle_tol`.
+    ///
+    /// The runs of collinear points are merged into one segment, since the incoming segment is
+    /// measured from the last remaining point. The interior points coincident with the last
+    /// remaining point are also removed. The points turning back are not removed even if the
+    /// segments are on the same line. The first and the last points are always kept, so closed
+    /// polylines remain closed.
+    /// # Example
+    /// ```
+    /// use truck_polymesh::*;
+    /// // a straight run of five points
+    /// let mut polyline = (0..5)
+    ///     .map(|i| Point3::new(i as f64, 2.0 * i as f64, 0.0))
+    ///     .collect::<PolylineCurve<_>>();
+    /// polyline.merge_collinear(Rad(1.0e-6));
+    /// assert_eq!(polyline.0, vec![Point3::new(0.0, 0.0, 0.0), Point3::new(4.0, 8.0, 0.0)]);
+    ///
+    /// // a closed square with extra points, slightly off the edges
+    /// let mut square = PolylineCurve(vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(0.5, 1.0e-4),
+    ///     Point2::new(1.0, 0.0),
+    ///     Point2::new(1.0, 0.5),
+    ///     Point2::new(1.0, 1.0),
+    ///     Point2::new(0.0, 1.0),
+    ///     Point2::new(0.0, 0.0),
+    /// ]);
+    /// square.merge_collinear(Rad(1.0e-3));
+    /// assert_eq!(square.len(), 5);
+    /// assert_eq!(square[0], square[4]);
+    /// ```
+    pub fn merge_collinear(&mut self, angle_tol: Rad<f64>) {
+        if self.len() < 3 {
+            return;
+        }
+        let last = self[self.len() - 1];
+        let mut res = vec![self[0]];
+        self.windows(2).skip(1).for_each(|p| {
+            let prev = res[res.len() - 1];
+            let (incoming, outgoing) = (p[0] - prev, p[1] - p[0]);
+            let coincident = incoming.magnitude().so_small();
+            let collinear =
+                !outgoing.magnitude().so_small() && incoming.angle(outgoing) <= angle_tol;
+            if !coincident && !collinear {
+                res.push(p[0]);
+            }
+        });
+        res.push(last);
+        self.0 = res;
+    }
+}
+
 /// Calculate the area of a region bounded by multiple polylines
 /// # Example
 /// ```
@@ -107,42 +313,130 @@ pub fn area<'a>(boundaries: impl IntoIterator<Item = &'a PolylineCurve<Point2>>)
 ///         Point2::new(f64::sin(t), f64::cos(t))
 ///     })
 ///     .collect::<PolylineCurve<_>>();
-/// let boundaries = [large_hexagon, small_hexagon];
+/// let mut boundaries = [large_hexagon, small_hexagon];
 /// let p0 = Point2::new(1.5, 0.0);
 /// assert!(polyline_curve::include(&boundaries, p0));
 /// let p1 = Point2::new(0.0, 0.0);
 /// assert!(!polyline_curve::include(&boundaries, p1));
+///
+/// // the clockwise outer boundary and the counter-clockwise inner boundary
+/// boundaries.iter_mut().for_each(|boundary| boundary.invert());
+/// assert!(polyline_curve::include(&boundaries, p0));
+/// assert!(!polyline_curve::include(&boundaries, p1));
 /// ```
+///
+/// # Orientation
+/// The outer boundaries are expected to have the opposite orientation to the inner ones. Both
+/// the counter-clockwise outer boundaries and the clockwise ones are accepted: if the total
+/// signed area is negative, all boundaries are regarded as reversed.
 pub fn include<'a>(
     boundaries: impl IntoIterator<Item = &'a PolylineCurve<Point2>>,
     c: Point2,
 ) -> bool {
-    let t = 2.0 * std::f64::consts::PI * HashGen::hash1(c);
-    let r = Vector2::new(f64::cos(t), f64::sin(t));
-    boundaries
+    let boundaries = boundaries.into_iter().collect::<Vec<_>>();
+    let sign = match boundaries.iter().map(|poly| poly.area()).sum::<f64>() < 0.0 {
+        true => -1,
+        false => 1,
+    };
+    let edges = boundaries
         .into_iter()
-        .flat_map(|boundary| boundary.iter().circular_tuple_windows())
-        .try_fold(0_i32, move |counter, (p0, p1)| {
-            let a = p0 - c;
-            let b = p1 - c;
-            let s0 = r.x * a.y - r.y * a.x; // v times a
-            let s1 = r.x * b.y - r.y * b.x; // v times b
-            let s2 = a.x * b.y - a.y * b.x; // a times b
-            let x = s2 / (s1 - s0);
-            if x.so_small() && s0 * s1 < 0.0 {
-                None
-            } else if x > 0.0 && s0 <= 0.0 && s1 > 0.0 {
-                Some(counter + 1)
-            } else if x > 0.0 && s0 >= 0.0 && s1 < 0.0 {
-                Some(counter - 1)
-            } else {
-                Some(counter)
-            }
-        })
-        .map(|counter| counter > 0)
+        .flat_map(|boundary| boundary.iter().copied().circular_tuple_windows());
+    crossing_counter(edges, c)
+        .map(|counter| sign * counter > 0)
         .unwrap_or(false)
 }
 
+/// The number of the trials of the rays in [`crossing_counter`].
+const RAY_TRIALS: usize = 16;
+/// The fractional part of the golden ratio, the rotation of the rays between the trials.
+const RAY_ROTATION: f64 = 0.618_033_988_749_894_9;
+
+/// the failures of counting the crossings with a ray
+enum RayFailure {
+    /// the point is on an edge
+    OnEdge,
+    /// the ray is parallel to an edge, so the sides of its end points are not determined
+    Parallel,
+}
+
+/// Returns the signed number of the crossings of `edges` with a ray from `c`, or `None` if `c` is
+/// on an edge.
+///
+/// The counter is incremented by the edges crossing the ray from right to left, and decremented by
+/// the edges crossing it from left to right, so it is the winding number of closed polylines.
+/// The edges with length zero are ignored.
+///
+/// # Details
+/// The direction of the ray is given by the hash of `c`. If the ray is parallel to an edge, the
+/// sides of the ray at the end points of the edge are not determined, so the ray is rotated and
+/// tried again. If all the 16 rays are parallel to some edges, `c` is regarded as outside, and
+/// `Some(0)` is returned.
+/// # Examples
+/// ```
+/// use itertools::Itertools;
+/// use truck_polymesh::*;
+/// let square = [
+///     Point2::new(0.0, 0.0),
+///     Point2::new(1.0, 0.0),
+///     Point2::new(1.0, 1.0),
+///     Point2::new(0.0, 1.0),
+/// ];
+/// let edges = square.into_iter().circular_tuple_windows();
+/// assert_eq!(polyline_curve::crossing_counter(edges.clone(), Point2::new(0.5, 0.5)), Some(1));
+/// assert_eq!(polyline_curve::crossing_counter(edges.clone(), Point2::new(1.5, 0.5)), Some(0));
+/// assert_eq!(polyline_curve::crossing_counter(edges, Point2::new(1.0, 0.5)), None);
+/// ```
+pub fn crossing_counter(
+    edges: impl Iterator<Item = (Point2, Point2)> + Clone,
+    c: Point2,
+) -> Option<i32> {
+    let t = HashGen::hash1(c);
+    let counted = (0..RAY_TRIALS).find_map(|k| {
+        let t = 2.0 * PI * (t + RAY_ROTATION * k as f64);
+        let r = Vector2::new(f64::cos(t), f64::sin(t));
+        match ray_crossing_counter(edges.clone(), c, r) {
+            Ok(counter) => Some(Some(counter)),
+            Err(RayFailure::OnEdge) => Some(None),
+            Err(RayFailure::Parallel) => None,
+        }
+    });
+    // all the rays are parallel to some edges
+    counted.unwrap_or(Some(0))
+}
+
+/// Returns the signed number of the crossings of `edges` with the ray from `c` to the direction `r`.
+fn ray_crossing_counter(
+    mut edges: impl Iterator<Item = (Point2, Point2)>,
+    c: Point2,
+    r: Vector2,
+) -> Result<i32, RayFailure> {
+    edges.try_fold(0_i32, move |counter, (p0, p1)| {
+        let a = p0 - c;
+        let b = p1 - c;
+        let d = b - a;
+        if d.so_small() {
+            return Ok(counter);
+        }
+        // the cross products of the ray and the end points, and of the end points
+        let s0 = r.x * a.y - r.y * a.x;
+        let s1 = r.x * b.y - r.y * b.x;
+        let s2 = a.x * b.y - a.y * b.x;
+        if f64::abs(s1 - s0) <= TOLERANCE * d.magnitude() {
+            return Err(RayFailure::Parallel);
+        }
+        let x = s2 / (s1 - s0);
+        if x.so_small() && s0 * s1 < 0.0 {
+            Err(RayFailure::OnEdge)
+        } else if x > 0.0 && s0 <= 0.0 && s1 > 0.0 {
+            Ok(counter + 1)
+        } else if x > 0.0 && s0 >= 0.0 && s1 < 0.0 {
+            Ok(counter - 1)
+        } else {
+            Ok(counter)
+        }
+    })
+}
+
 impl<P> AsRef<Vec<P>> for PolylineCurve<P> {
     #[inline(always)]
     fn as_ref(&self) -> &Vec<P> { &self.0 }
@@ -189,6 +483,11 @@ impl<P> FromIterator<P> for PolylineCurve<P> {
     fn from_iter<I: IntoIterator<Item = P>>(iter: I) -> Self { Self(Vec::from_iter(iter)) }
 }
 
+impl<P> Extend<P> for PolylineCurve<P> {
+    #[inline(always)]
+    fn extend<I: IntoIterator<Item = P>>(&mut self, iter: I) { self.0.extend(iter) }
+}
+
 impl<P> IntoIterator for PolylineCurve<P> {
     type Item = P;
     type IntoIter = std::vec::IntoIter<P>;
@@ -203,6 +502,13 @@ impl<'a, P> IntoIterator for &'a PolylineCurve<P> {
     fn into_iter(self) -> Self::IntoIter { self.0.iter() }
 }
 
+impl<'a, P> IntoIterator for &'a mut PolylineCurve<P> {
+    type Item = &'a mut P;
+    type IntoIter = std::slice::IterMut<'a, P>;
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter { self.0.iter_mut() }
+}
+
 impl<P: ControlPoint<f64>> ParametricCurve for PolylineCurve<P> {
     type Point = P;
     type Vector = P::Diff;
@@ -1,7 +1,10 @@
 use crate::*;
 use bytemuck::{Pod, Zeroable};
 use rustc_hash::FxHashMap as HashMap;
-use std::io::{BufRead, BufReader, Lines, Read, Write};
+use std::{
+    io::{BufRead, BufReader, Lines, Read, Write},
+    slice::Iter,
+};
 
 const FACESIZE: usize = size_of::<StlFace>();
 const CHUNKSIZE: usize = FACESIZE + 2;
@@ -90,11 +93,11 @@ impl<R: Read> StlReader<R> {
             StlReader::Binary(_, _) => StlType::Binary,
         }
     }
-}
 
-impl<R: Read> Iterator for StlReader<R> {
-    type Item = Result<StlFace>;
-    fn next(&mut self) -> Option<Self::Item> {
+    /// Reads the next face together with its attribute byte count.
+    ///
+    /// ASCII STL has no attribute field, so the attribute of an ASCII face is always `0`.
+    pub fn next_with_attribute(&mut self) -> Option<Result<(StlFace, u16)>> {
         let res = match self {
             StlReader::Binary(reader, length) => {
                 if *length == 0 {
@@ -104,7 +107,7 @@ impl<R: Read> Iterator for StlReader<R> {
                     binary_one_read(reader)
                 }
             }
-            StlReader::Ascii(lines) => ascii_one_read(lines),
+            StlReader::Ascii(lines) => ascii_one_read(lines).map(|face| face.map(|f| (f, 0))),
         };
         match res {
             Ok(Some(got)) => Some(Ok(got)),
@@ -114,6 +117,15 @@ impl<R: Read> Iterator for StlReader<R> {
     }
 }
 
+impl<R: Read> Iterator for StlReader<R> {
+    type Item = Result<StlFace>;
+    #[inline(always)]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_with_attribute()
+            .map(|res| res.map(|(face, _)| face))
+    }
+}
+
 fn ascii_one_read<R: BufRead>(lines: &mut Lines<R>) -> Result<Option<StlFace>> {
     let mut face = StlFace::default();
     let mut num_ver = 0;
@@ -147,13 +159,14 @@ fn ascii_one_read<R: BufRead>(lines: &mut Lines<R>) -> Result<Option<StlFace>> {
     }
 }
 
-fn binary_one_read<R: Read>(reader: &mut R) -> Result<Option<StlFace>> {
+fn binary_one_read<R: Read>(reader: &mut R) -> Result<Option<(StlFace, u16)>> {
     let mut chunk = [0; CHUNKSIZE];
     let size = reader.read(&mut chunk)?;
     if size == CHUNKSIZE {
         let mut buf = [0; FACESIZE];
         buf.copy_from_slice(&chunk[..FACESIZE]);
-        Ok(Some(bytemuck::cast(buf)))
+        let attribute = u16::from_le_bytes([chunk[FACESIZE], chunk[FACESIZE + 1]]);
+        Ok(Some((bytemuck::cast(buf), attribute)))
     } else {
         Err(syntax_error().into())
     }
@@ -162,6 +175,40 @@ fn binary_one_read<R: Read>(reader: &mut R) -> Result<Option<StlFace>> {
 /// Write STL file in `stl_type` format.
 ///
 /// If `stl_type == StlType::Automatic`, write the binary format.
+/// The faces of a [`PolygonMesh`] are triangulated as described in [`PolygonMeshStlFaceIterator`].
+///
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// use stl::StlType;
+/// // a rhombus whose diagonal from 1 to 3 is shorter, and a unit square
+/// let positions = vec![
+///     Point3::new(-2.0, 0.0, 0.0),
+///     Point3::new(0.0, -1.0, 0.0),
+///     Point3::new(2.0, 0.0, 0.0),
+///     Point3::new(0.0, 1.0, 0.0),
+///     Point3::new(3.0, 0.0, 0.0),
+///     Point3::new(4.0, 0.0, 0.0),
+///     Point3::new(4.0, 1.0, 0.0),
+///     Point3::new(3.0, 1.0, 0.0),
+/// ];
+/// let mesh = PolygonMesh::new(
+///     StandardAttributes { positions, ..Default::default() },
+///     Faces::from_iter(&[[0, 1, 2, 3], [4, 5, 6, 7]]),
+/// );
+/// let mut bytes = Vec::new();
+/// stl::write(&mesh, &mut bytes, StlType::Ascii).unwrap();
+/// let read = stl::read(bytes.as_slice(), StlType::Automatic).unwrap();
+/// // two triangles per quadrangle
+/// assert_eq!(read.tri_faces().len(), 4);
+/// // no triangle has the longer diagonal of the rhombus
+/// let has = |tri: &[StandardVertex; 3], p: Point3| {
+///     tri.iter().any(|v| read.positions()[v.pos].near(&p))
+/// };
+/// assert!(read.tri_faces().iter().all(|tri| {
+///     !(has(tri, Point3::new(-2.0, 0.0, 0.0)) && has(tri, Point3::new(2.0, 0.0, 0.0)))
+/// }));
+/// ```
 #[inline(always)]
 pub fn write<I: IntoStlIterator, W: Write>(
     iter: I,
@@ -174,6 +221,87 @@ pub fn write<I: IntoStlIterator, W: Write>(
     }
 }
 
+/// An attribute of [`PolygonMesh`] which STL cannot store, reported by [`write_checked`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DroppedAttribute {
+    /// The mesh has uv coordinates.
+    UvCoords,
+    /// The mesh has normals which are not the facet normals.
+    Normals,
+}
+
+/// Writes STL file in `stl_type` format as [`write()`], checking the attributes lost in STL.
+///
+/// STL stores only the positions and the facet normals, which are computed from the positions.
+/// Returns the attributes of `mesh` which are dropped: the uv coordinates, and the normals
+/// which differ from the facet normals, e.g. smooth normals. The normals of a mesh read from STL
+/// are not dropped. If `strict` is `true`, nothing is written and
+/// `Error::DroppedByStl` is returned if some attributes are dropped. Use a lossless format,
+/// e.g. OBJ, PLY, or glTF, for such meshes.
+///
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// use stl::{DroppedAttribute, StlType};
+/// use errors::Error;
+/// let mesh = PolygonMesh::new(
+///     StandardAttributes {
+///         positions: vec![
+///             Point3::new(0.0, 0.0, 0.0),
+///             Point3::new(1.0, 0.0, 0.0),
+///             Point3::new(1.0, 1.0, 0.0),
+///         ],
+///         uv_coords: vec![Vector2::new(0.0, 0.0)],
+///         normals: vec![Vector3::unit_z(), Vector3::new(0.0, 0.6, 0.8)],
+///     },
+///     Faces::from_iter([[(0, Some(0), Some(0)), (1, Some(0), Some(0)), (2, Some(0), Some(1))]]),
+/// );
+///
+/// let mut bytes = Vec::new();
+/// let dropped = stl::write_checked(&mesh, &mut bytes, StlType::Binary, false).unwrap();
+/// assert_eq!(dropped, vec![DroppedAttribute::UvCoords, DroppedAttribute::Normals]);
+///
+/// // the facet normal is computed from the positions
+/// let read = stl::read(bytes.as_slice(), StlType::Binary).unwrap();
+/// assert_near!(read.normals()[0], Vector3::unit_z());
+/// // and is not regarded as dropped
+/// let mut bytes = Vec::new();
+/// let dropped = stl::write_checked(&read, &mut bytes, StlType::Binary, true).unwrap();
+/// assert!(dropped.is_empty());
+///
+/// // strict mode
+/// let mut bytes = Vec::new();
+/// let res = stl::write_checked(&mesh, &mut bytes, StlType::Binary, true);
+/// assert!(matches!(res, Err(Error::DroppedByStl(DroppedAttribute::UvCoords))));
+/// assert!(bytes.is_empty());
+/// ```
+pub fn write_checked<W: Write>(
+    mesh: &PolygonMesh,
+    writer: &mut W,
+    stl_type: StlType,
+    strict: bool,
+) -> Result<Vec<DroppedAttribute>> {
+    let mut dropped = Vec::new();
+    if mesh.face_iter().flatten().any(|v| v.uv.is_some()) {
+        dropped.push(DroppedAttribute::UvCoords);
+    }
+    let (positions, normals) = (mesh.positions(), mesh.normals());
+    let normal_dropped = stl_triangles(mesh).any(|face| {
+        let normal = facet_normal(array![i => positions[face[i].pos]; 3]);
+        face.iter()
+            .filter_map(|v| v.nor)
+            .any(|nor| !normals[nor].near(&normal))
+    });
+    if normal_dropped {
+        dropped.push(DroppedAttribute::Normals);
+    }
+    if let (true, Some(attribute)) = (strict, dropped.first()) {
+        return Err(errors::Error::DroppedByStl(*attribute));
+    }
+    write(mesh, writer, stl_type)?;
+    Ok(dropped)
+}
+
 /// Writes ASCII STL data.
 fn write_ascii<I: IntoStlIterator, W: Write>(iter: I, writer: &mut W) -> Result<()> {
     let mut iter = iter.into_iter();
@@ -200,17 +328,167 @@ fn write_ascii<I: IntoStlIterator, W: Write>(iter: I, writer: &mut W) -> Result<
 /// Writes binary STL data.
 #[inline(always)]
 fn write_binary<I: IntoStlIterator, W: Write>(iter: I, writer: &mut W) -> Result<()> {
-    let mut iter = iter.into_iter();
+    write_binary_with_attributes(iter, std::iter::repeat(0), writer)
+}
+
+/// Writes binary STL data with the attribute byte counts.
+fn write_binary_with_attributes<I, A, W>(iter: I, attributes: A, writer: &mut W) -> Result<()>
+where
+    I: IntoStlIterator,
+    A: IntoIterator<Item = u16>,
+    W: Write, {
+    let iter = iter.into_iter();
     let len = iter.len() as u32;
     writer.write_all(&[0u8; 80])?;
     writer.write_all(&len.to_le_bytes())?;
-    iter.try_for_each(|face| {
+    iter.zip(attributes).try_for_each(|(face, attribute)| {
         writer.write_all(bytemuck::cast_slice(&[face]))?;
-        writer.write_all(&[0u8, 0u8])?;
+        writer.write_all(&attribute.to_le_bytes())?;
         Ok(())
     })
 }
 
+/// Writes binary STL data with the per-facet "attribute byte count".
+///
+/// The `i`-th value of `attributes` is written into the trailing 2 bytes of the `i`-th facet.
+/// If `attributes` is shorter than the number of facets, the remaining facets get `0`.
+/// Use [`pack_color`] to store facet colors by the VisCAM/SolidView convention.
+///
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// use stl::{StlFace, StlType};
+/// let faces = vec![StlFace {
+///     normal: [0.0, 0.0, 1.0],
+///     vertices: [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+/// }];
+/// let red = stl::pack_color([1.0, 0.0, 0.0]);
+/// let mut bytes = Vec::new();
+/// stl::write_with_attributes(faces.clone(), &[red], &mut bytes).unwrap();
+/// let (mesh, attributes) = stl::read_with_attributes(bytes.as_slice(), StlType::Binary).unwrap();
+/// assert_eq!(mesh.tri_faces().len(), 1);
+/// assert_eq!(stl::unpack_color(attributes[0]), Some([1.0, 0.0, 0.0]));
+/// ```
+#[inline(always)]
+pub fn write_with_attributes<I: IntoStlIterator, W: Write>(
+    iter: I,
+    attributes: &[u16],
+    writer: &mut W,
+) -> Result<()> {
+    let attributes = attributes.iter().copied().chain(std::iter::repeat(0));
+    write_binary_with_attributes(iter, attributes, writer)
+}
+
+/// Packs a color into the 15-bit RGB "attribute byte count" by the VisCAM/SolidView convention.
+///
+/// Each component of `color` is clamped to `[0, 1]` and quantized into 5 bits.
+/// Bits 0-4 are blue, 5-9 green, 10-14 red, and bit 15 is set to mark the color as valid.
+pub fn pack_color(color: [f32; 3]) -> u16 {
+    let quantize = |x: f32| (f32::clamp(x, 0.0, 1.0) * 31.0).round() as u16;
+    let [r, g, b] = color.map(quantize);
+    0x8000 | (r << 10) | (g << 5) | b
+}
+
+/// Unpacks the 15-bit RGB "attribute byte count" packed by [`pack_color`].
+///
+/// Returns `None` if the valid bit (bit 15) is not set.
+pub fn unpack_color(attribute: u16) -> Option<[f32; 3]> {
+    let dequantize = |shift: u16| ((attribute >> shift) & 0x1f) as f32 / 31.0;
+    match attribute & 0x8000 {
+        0 => None,
+        _ => Some([dequantize(10), dequantize(5), dequantize(0)]),
+    }
+}
+
+/// Converts the colors of positions into the colors of the STL facets of `mesh`.
+///
+/// STL supports only per-facet colors, so the color of each facet is the average of the colors
+/// of its three vertices. The `i`-th vertex color corresponds to `mesh.positions()[i]`, and the
+/// facets are ordered in the same way as [`write()`], i.e. by [`PolygonMeshStlFaceIterator`].
+/// The colors of positions are read and written as they are by [`ply::read_with_colors`],
+/// [`ply::write_with_colors`] and [`gltf::write_with_colors`].
+///
+/// [`ply::read_with_colors`]: crate::ply::read_with_colors
+/// [`ply::write_with_colors`]: crate::ply::write_with_colors
+/// [`gltf::write_with_colors`]: crate::gltf::write_with_colors
+///
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// let mesh = PolygonMesh::new(
+///     StandardAttributes {
+///         positions: vec![
+///             Point3::new(0.0, 0.0, 0.0),
+///             Point3::new(1.0, 0.0, 0.0),
+///             Point3::new(1.0, 1.0, 0.0),
+///             Point3::new(0.0, 1.0, 0.0),
+///         ],
+///         ..Default::default()
+///     },
+///     Faces::from_iter(&[[0, 1, 2, 3]]),
+/// );
+/// let red = [1.0, 0.0, 0.0];
+/// let face_colors = stl::face_colors_from_vertex_colors(&mesh, &[red; 4]);
+/// let attributes = face_colors.into_iter().map(stl::pack_color).collect::<Vec<_>>();
+///
+/// let mut bytes = Vec::new();
+/// stl::write_with_attributes(&mesh, &attributes, &mut bytes).unwrap();
+/// let (read_mesh, attributes) =
+///     stl::read_with_attributes(bytes.as_slice(), stl::StlType::Binary).unwrap();
+/// let face_colors = attributes
+///     .into_iter()
+///     .map(|a| stl::unpack_color(a).unwrap())
+///     .collect::<Vec<_>>();
+/// assert_eq!(face_colors, vec![red; 2]);
+/// let vertex_colors = stl::vertex_colors_from_face_colors(&read_mesh, &face_colors);
+/// assert_eq!(vertex_colors, vec![red; 4]);
+/// ```
+pub fn face_colors_from_vertex_colors(
+    mesh: &PolygonMesh,
+    vertex_colors: &[[f32; 3]],
+) -> Vec<[f32; 3]> {
+    stl_triangles(mesh)
+        .map(|face| {
+            let sum = face.iter().fold(
+                [0.0; 3],
+                |sum, v| array![i => sum[i] + vertex_colors[v.pos][i]; 3],
+            );
+            sum.map(|x| x / 3.0)
+        })
+        .collect()
+}
+
+/// Converts the colors of the STL facets of `mesh` into the colors of positions.
+///
+/// The color of each position is the average of the colors of the facets around it, and the
+/// positions not used by any facets get black. This is the converse of
+/// [`face_colors_from_vertex_colors`], and the `i`-th facet color corresponds to the `i`-th
+/// triangle of [`Faces::triangle_iter`], as the attributes of [`read_with_attributes`].
+///
+/// [`Faces::triangle_iter`]: crate::Faces::triangle_iter
+pub fn vertex_colors_from_face_colors(
+    mesh: &PolygonMesh,
+    face_colors: &[[f32; 3]],
+) -> Vec<[f32; 3]> {
+    let mut sums = vec![([0.0; 3], 0); mesh.positions().len()];
+    mesh.faces()
+        .triangle_iter()
+        .zip(face_colors)
+        .for_each(|(face, color)| {
+            face.iter().for_each(|v| {
+                let (sum, count) = &mut sums[v.pos];
+                *sum = array![i => sum[i] + color[i]; 3];
+                *count += 1;
+            })
+        });
+    sums.into_iter()
+        .map(|(sum, count)| match count {
+            0 => sum,
+            _ => sum.map(|x| x / count as f32),
+        })
+        .collect()
+}
+
 /// By implementing [`IntoStlIterator`] for a type you define how it will be
 /// converted to an iterator.
 ///
@@ -223,26 +501,57 @@ pub trait IntoStlIterator {
 }
 
 /// Generate an STL faces from from a [`PolygonMesh`].
+///
+/// STL only has triangles, so every face of the mesh is triangulated: each quadrangle is split
+/// along its shorter diagonal, and the other polygons are split by the fan from their first
+/// vertices. For another triangulation of the polygons, triangulate the mesh before writing.
+///
+/// The normal of each facet is the unit normal of the triangle oriented by the right-hand rule,
+/// computed from the positions, and the normals of the mesh are ignored. The degenerate
+/// triangles have the zero normal.
+///
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// use stl::IntoStlIterator;
+/// let positions = vec![
+///     Point3::new(0.0, 0.0, 0.0),
+///     Point3::new(1.0, 0.0, 0.0),
+///     Point3::new(0.0, 1.0, 0.0),
+///     Point3::new(0.0, 0.0, 1.0),
+///     Point3::new(2.0, 0.0, 0.0),
+/// ];
+/// let mesh = PolygonMesh::new(
+///     StandardAttributes { positions: positions.clone(), ..Default::default() },
+///     Faces::from_iter(&[[0, 2, 1], [0, 1, 3], [1, 2, 3], [0, 1, 4]]),
+/// );
+/// let normals = IntoStlIterator::into_iter(&mesh)
+///     .map(|face| Vector3::from(face.normal.map(f64::from)))
+///     .collect::<Vec<_>>();
+/// assert_near!(normals[0], -Vector3::unit_z());
+/// assert_near!(normals[1], -Vector3::unit_y());
+/// assert_near!(normals[2], Vector3::new(1.0, 1.0, 1.0).normalize());
+/// // degenerate triangle
+/// assert_eq!(normals[3], Vector3::zero());
+/// ```
 #[derive(Debug)]
 pub struct PolygonMeshStlFaceIterator<'a> {
-    positions: &'a Vec<Point3>,
-    faces: faces::TriangleIterator<'a, Vertex>,
-    len: usize,
+    faces: StlTriangles<'a>,
 }
 
 impl Iterator for PolygonMeshStlFaceIterator<'_> {
     type Item = StlFace;
     fn next(&mut self) -> Option<StlFace> {
+        let positions = self.faces.positions;
         self.faces.next().map(|face| {
-            let p = array![i => self.positions[face[i].pos]; 3];
-            let n = (p[1] - p[0]).cross(p[2] - p[0]).normalize();
-            let normal = n.cast().unwrap().into();
+            let p = array![i => positions[face[i].pos]; 3];
+            let normal = facet_normal(p).cast().unwrap().into();
             let vertices = array![i => p[i].cast().unwrap().into(); 3];
             StlFace { normal, vertices }
         })
     }
     #[inline(always)]
-    fn size_hint(&self) -> (usize, Option<usize>) { (self.len, Some(self.len)) }
+    fn size_hint(&self) -> (usize, Option<usize>) { self.faces.size_hint() }
 }
 
 impl ExactSizeIterator for PolygonMeshStlFaceIterator<'_> {}
@@ -250,15 +559,92 @@ impl ExactSizeIterator for PolygonMeshStlFaceIterator<'_> {}
 impl<'a> IntoStlIterator for &'a PolygonMesh {
     type IntoIter = PolygonMeshStlFaceIterator<'a>;
     fn into_iter(self) -> Self::IntoIter {
-        let iter = self.faces().triangle_iter();
         Self::IntoIter {
-            positions: self.positions(),
-            len: iter.len(),
-            faces: iter,
+            faces: stl_triangles(self),
         }
     }
 }
 
+/// Returns the unit normal of the triangle, or the zero vector if the triangle is degenerate.
+fn facet_normal(p: [Point3; 3]) -> Vector3 {
+    let n = (p[1] - p[0]).cross(p[2] - p[0]);
+    match n.magnitude() {
+        mag if mag > 0.0 => n / mag,
+        _ => Vector3::zero(),
+    }
+}
+
+/// iterator run on the triangles of the STL facets, described in [`PolygonMeshStlFaceIterator`].
+#[derive(Clone, Debug)]
+struct StlTriangles<'a> {
+    positions: &'a [Point3],
+    tri_faces: Iter<'a, [Vertex; 3]>,
+    quad_faces: Iter<'a, [Vertex; 4]>,
+    other_faces: Iter<'a, Vec<Vertex>>,
+    /// the second triangle of the last quadrangle
+    quad_rest: Option<[Vertex; 3]>,
+    /// the polygon in the fan and the index of the last vertex of the next triangle
+    fan: Option<(&'a [Vertex], usize)>,
+}
+
+impl Iterator for StlTriangles<'_> {
+    type Item = [Vertex; 3];
+    fn next(&mut self) -> Option<[Vertex; 3]> {
+        let positions = self.positions;
+        self.tri_faces
+            .next()
+            .copied()
+            .or_else(|| self.quad_rest.take())
+            .or_else(|| {
+                let quad = self.quad_faces.next()?;
+                let diagonal =
+                    |i: usize, j: usize| positions[quad[i].pos].distance2(positions[quad[j].pos]);
+                let [first, second] = match diagonal(1, 3) < diagonal(0, 2) {
+                    true => [[quad[0], quad[1], quad[3]], [quad[1], quad[2], quad[3]]],
+                    false => [[quad[0], quad[1], quad[2]], [quad[0], quad[2], quad[3]]],
+                };
+                self.quad_rest = Some(second);
+                Some(first)
+            })
+            .or_else(|| {
+                let (face, i) = self
+                    .fan
+                    .take()
+                    .or_else(|| self.other_faces.next().map(|face| (face.as_slice(), 2)))?;
+                if i + 1 < face.len() {
+                    self.fan = Some((face, i + 1));
+                }
+                Some([face[0], face[i - 1], face[i]])
+            })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.tri_faces.len()
+            + 2 * self.quad_faces.len()
+            + self.quad_rest.iter().count()
+            + self.fan.map_or(0, |(face, i)| face.len() - i)
+            + self
+                .other_faces
+                .clone()
+                .map(|face| face.len() - 2)
+                .sum::<usize>();
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for StlTriangles<'_> {}
+
+/// Returns the triangles of the STL facets of `mesh`, described in [`PolygonMeshStlFaceIterator`].
+fn stl_triangles(mesh: &PolygonMesh) -> StlTriangles<'_> {
+    StlTriangles {
+        positions: mesh.positions(),
+        tri_faces: mesh.tri_faces().iter(),
+        quad_faces: mesh.quad_faces().iter(),
+        other_faces: mesh.other_faces().iter(),
+        quad_rest: None,
+        fan: None,
+    }
+}
+
 impl<I> IntoStlIterator for I
 where
     I: IntoIterator<Item = StlFace>,
@@ -312,3 +698,107 @@ impl FromIterator<StlFace> for PolygonMesh {
 pub fn read<R: Read>(reader: R, stl_type: StlType) -> Result<PolygonMesh> {
     StlReader::new(reader, stl_type)?.collect()
 }
+
+/// Read STL file, which may be compressed by gzip, and parse to [`PolygonMesh`].
+///
+/// The stream is decompressed if it starts with the gzip magic bytes, and is parsed directly otherwise.
+/// Available with the feature `gzip`.
+/// # Examples
+/// ```
+/// use std::io::Write;
+/// use flate2::{write::GzEncoder, Compression};
+/// use truck_polymesh::*;
+/// let mesh = PolygonMesh::new(
+///     StandardAttributes {
+///         positions: vec![
+///             Point3::new(0.0, 0.0, 0.0),
+///             Point3::new(1.0, 0.0, 0.0),
+///             Point3::new(0.0, 1.0, 0.0),
+///         ],
+///         normals: vec![Vector3::unit_z()],
+///         ..Default::default()
+///     },
+///     Faces::from_iter([[(0, None, Some(0)), (1, None, Some(0)), (2, None, Some(0))]]),
+/// );
+/// let mut plain = Vec::new();
+/// stl::write(&mesh, &mut plain, stl::StlType::Binary).unwrap();
+/// let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+/// encoder.write_all(&plain).unwrap();
+/// let gzipped = encoder.finish().unwrap();
+///
+/// let read = stl::read_maybe_gzip(gzipped.as_slice(), stl::StlType::Automatic).unwrap();
+/// assert_eq!(read, stl::read(plain.as_slice(), stl::StlType::Automatic).unwrap());
+/// assert_eq!(read.positions().len(), 3);
+/// ```
+#[inline(always)]
+#[cfg(feature = "gzip")]
+pub fn read_maybe_gzip<R: Read>(reader: R, stl_type: StlType) -> Result<PolygonMesh> {
+    read(gzip::MaybeGzip::new(reader)?, stl_type)
+}
+
+/// Read STL file in the coordinate system `from`, and parse to [`PolygonMesh`] in truck's
+/// [`CoordinateSystem::ZUpRightHanded`].
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// // a left-handed y-up triangle lying on the ground, facing up
+/// let stl = b"solid
+///   facet normal 0 1 0
+///     outer loop
+///       vertex 0 0 0
+///       vertex 0 0 1
+///       vertex 1 0 0
+///     endloop
+///   endfacet
+/// endsolid
+/// ";
+/// let mesh = stl::read_with_coordinate_system(
+///     &stl[..],
+///     stl::StlType::Ascii,
+///     CoordinateSystem::YUpLeftHanded,
+/// )
+/// .unwrap();
+/// assert_near!(mesh.normals()[0], Vector3::unit_z());
+/// let face = mesh.faces().tri_faces()[0];
+/// let p = |i: usize| mesh.positions()[face[i].pos];
+/// assert!((p(1) - p(0)).cross(p(2) - p(0)).z > 0.0);
+/// ```
+pub fn read_with_coordinate_system<R: Read>(
+    reader: R,
+    stl_type: StlType,
+    from: CoordinateSystem,
+) -> Result<PolygonMesh> {
+    let mut mesh = read(reader, stl_type)?;
+    mesh.convert_coordinate_system(from, CoordinateSystem::ZUpRightHanded);
+    Ok(mesh)
+}
+
+/// Write [`PolygonMesh`] in truck's [`CoordinateSystem::ZUpRightHanded`] to STL file in
+/// the coordinate system `to`.
+pub fn write_with_coordinate_system<W: Write>(
+    mesh: &PolygonMesh,
+    writer: &mut W,
+    stl_type: StlType,
+    to: CoordinateSystem,
+) -> Result<()> {
+    let mut mesh = mesh.clone();
+    mesh.convert_coordinate_system(CoordinateSystem::ZUpRightHanded, to);
+    write(&mesh, writer, stl_type)
+}
+
+/// Read STL file and parse to [`PolygonMesh`] and the attribute byte counts of facets.
+///
+/// The `i`-th attribute corresponds to the `i`-th triangle of the mesh.
+/// The attributes of ASCII STL are always `0`.
+pub fn read_with_attributes<R: Read>(
+    reader: R,
+    stl_type: StlType,
+) -> Result<(PolygonMesh, Vec<u16>)> {
+    let mut reader = StlReader::new(reader, stl_type)?;
+    let (faces, attributes): (Vec<StlFace>, Vec<u16>) =
+        std::iter::from_fn(|| reader.next_with_attribute())
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .unzip();
+    Ok((PolygonMesh::from_iter(faces), attributes))
+}
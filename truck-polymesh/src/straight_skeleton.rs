@@ -0,0 +1,223 @@
+use crate::*;
+use itertools::Itertools;
+use std::iter::successors;
+
+impl PolylineCurve<Point2> {
+    /// Returns the straight skeleton of the polygon enclosed when endpoints are connected.
+    ///
+    /// Each returned polyline is a segment of the skeleton: an arc traced by a vertex of the
+    /// shrinking wavefront, from a corner of the polygon or a skeleton node to the next node.
+    /// The orientation of the polygon does not matter, and the last point may repeat the first.
+    ///
+    /// # Details
+    /// The skeleton is computed by the wavefront propagation: each edge moves inward at
+    /// unit speed, and adjacent wavefront vertices are merged at edge events. Only the edge
+    /// events are handled. The split events, where a reflex vertex runs into a non-adjacent
+    /// edge, are not, so the wavefront is never split: the arcs from the reflex vertices and
+    /// the nodes after them are approximate. The result is exact for convex polygons and a best
+    /// effort for concave ones. Polygons with less than three points have no skeleton.
+    ///
+    /// # Example
+    /// ```
+    /// use truck_polymesh::*;
+    /// let rectangle = PolylineCurve(vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(2.0, 0.0),
+    ///     Point2::new(2.0, 1.0),
+    ///     Point2::new(0.0, 1.0),
+    /// ]);
+    /// let skeleton = rectangle.straight_skeleton();
+    /// // four arcs from the corners and the central ridge
+    /// assert_eq!(skeleton.len(), 5);
+    /// let ridge = skeleton
+    ///     .iter()
+    ///     .find(|arc| arc.iter().all(|p| f64::abs(p.y - 0.5) < 1.0e-6))
+    ///     .unwrap();
+    /// let mut xs = ridge.iter().map(|p| p.x).collect::<Vec<_>>();
+    /// xs.sort_by(f64::total_cmp);
+    /// assert_near!(xs[0], 0.5);
+    /// assert_near!(xs[1], 1.5);
+    ///
+    /// // a mildly concave polygon: arcs from six corners and two ridges
+    /// let l_shape = PolylineCurve(vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(2.0, 0.0),
+    ///     Point2::new(2.0, 1.0),
+    ///     Point2::new(1.0, 1.0),
+    ///     Point2::new(1.0, 2.0),
+    ///     Point2::new(0.0, 2.0),
+    /// ]);
+    /// assert_eq!(l_shape.straight_skeleton().len(), 8);
+    /// ```
+    pub fn straight_skeleton(&self) -> Vec<PolylineCurve<Point2>> {
+        let mut points = self.0.clone();
+        if points.len() > 1 && points[0].near(&points[points.len() - 1]) {
+            points.pop();
+        }
+        points.dedup_by(|p, q| (*p).near(q));
+        if points.len() < 3 {
+            return Vec::new();
+        }
+        if self.area() < 0.0 {
+            points.reverse();
+        }
+        Wavefront::new(&points).propagate()
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct WavefrontVertex {
+    /// the corner or the skeleton node where the vertex has been created
+    origin: Point2,
+    time: f64,
+    velocity: Vector2,
+    /// the unit directions of the incoming and outgoing edges
+    edges: (Vector2, Vector2),
+    prev: usize,
+    next: usize,
+    active: bool,
+}
+
+impl WavefrontVertex {
+    fn new(origin: Point2, time: f64, edges: (Vector2, Vector2)) -> Self {
+        let normal = |d: Vector2| Vector2::new(-d.y, d.x);
+        let (n0, n1) = (normal(edges.0), normal(edges.1));
+        let denom = 1.0 + n0.dot(n1);
+        // Antiparallel edges mean that the wavefront has collapsed to a segment.
+        let velocity = match denom > TOLERANCE {
+            true => (n0 + n1) / denom,
+            false => Vector2::zero(),
+        };
+        Self {
+            origin,
+            time,
+            velocity,
+            edges,
+            prev: 0,
+            next: 0,
+            active: true,
+        }
+    }
+
+    fn position(&self, time: f64) -> Point2 { self.origin + self.velocity * (time - self.time) }
+}
+
+struct Wavefront {
+    vertices: Vec<WavefrontVertex>,
+    time: f64,
+    skeleton: Vec<PolylineCurve<Point2>>,
+}
+
+impl Wavefront {
+    /// `points` must be counter-clockwise.
+    fn new(points: &[Point2]) -> Self {
+        let len = points.len();
+        let vertices = (0..len)
+            .map(|i| {
+                let (p, q, r) = (
+                    points[(i + len - 1) % len],
+                    points[i],
+                    points[(i + 1) % len],
+                );
+                let mut vertex =
+                    WavefrontVertex::new(q, 0.0, ((q - p).normalize(), (r - q).normalize()));
+                vertex.prev = (i + len - 1) % len;
+                vertex.next = (i + 1) % len;
+                vertex
+            })
+            .collect();
+        Self {
+            vertices,
+            time: 0.0,
+            skeleton: Vec::new(),
+        }
+    }
+
+    fn active(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.vertices.len()).filter(|i| self.vertices[*i].active)
+    }
+
+    /// Returns the time of the edge event between `i` and its next vertex.
+    fn edge_event(&self, i: usize) -> Option<f64> {
+        let (a, b) = (&self.vertices[i], &self.vertices[self.vertices[i].next]);
+        let diff = a.position(self.time) - b.position(self.time);
+        let dv = a.velocity - b.velocity;
+        if dv.magnitude2() < TOLERANCE2 {
+            return None;
+        }
+        let dt = -diff.dot(dv) / dv.magnitude2();
+        match dt > -TOLERANCE && (diff + dv * dt).magnitude() < TOLERANCE {
+            true => Some(self.time + f64::max(dt, 0.0)),
+            false => None,
+        }
+    }
+
+    fn push_arc(&mut self, p: Point2, q: Point2) {
+        let exists = |arc: &PolylineCurve<Point2>| {
+            (arc[0].near(&p) && arc[1].near(&q)) || (arc[0].near(&q) && arc[1].near(&p))
+        };
+        if !p.near(&q) && !self.skeleton.iter().any(exists) {
+            self.skeleton.push(PolylineCurve(vec![p, q]));
+        }
+    }
+
+    /// Returns `true` if the remaining wavefront has no area, and adds its rest to the skeleton.
+    fn collapse(&mut self) -> bool {
+        let active = self
+            .active()
+            .next()
+            .map(|start| {
+                let next = |i: &usize| Some(self.vertices[*i].next).filter(|j| *j != start);
+                successors(Some(start), next)
+                    .take(self.vertices.len())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        let current = active
+            .iter()
+            .map(|i| self.vertices[*i].position(self.time))
+            .collect::<PolylineCurve<_>>();
+        let collapsed = active.len() <= 2 || current.area().abs() <= TOLERANCE;
+        if collapsed {
+            active.iter().zip(&current.0).for_each(|(i, p)| {
+                self.push_arc(self.vertices[*i].origin, *p);
+            });
+            current
+                .iter()
+                .circular_tuple_windows()
+                .for_each(|(p, q)| self.push_arc(*p, *q));
+        }
+        collapsed
+    }
+
+    fn propagate(mut self) -> Vec<PolylineCurve<Point2>> {
+        // Each event deactivates one vertex, so the loop terminates. The split events are not
+        // searched, so the reflex vertices run on until an edge event.
+        (0..self.vertices.len()).try_for_each(|_| {
+            let event = match self.collapse() {
+                true => None,
+                false => self
+                    .active()
+                    .filter_map(|i| Some((i, self.edge_event(i)?)))
+                    .min_by(|(_, t0), (_, t1)| t0.total_cmp(t1)),
+            };
+            let (i, time) = event?;
+            self.time = time;
+            let j = self.vertices[i].next;
+            let (a, b) = (self.vertices[i], self.vertices[j]);
+            let node = a.position(time);
+            self.push_arc(a.origin, node);
+            self.push_arc(b.origin, node);
+            let mut vertex = WavefrontVertex::new(node, time, (a.edges.0, b.edges.1));
+            (vertex.prev, vertex.next) = (a.prev, b.next);
+            let k = self.vertices.len();
+            self.vertices.push(vertex);
+            self.vertices[a.prev].next = k;
+            self.vertices[b.next].prev = k;
+            self.vertices[i].active = false;
+            self.vertices[j].active = false;
+            Some(())
+        });
+        self.skeleton
+    }
+}
@@ -184,7 +184,53 @@ impl StructuredMesh {
 
     /// Creates new polygon mesh by destructing `self`.
     #[inline(always)]
-    pub fn destruct(self) -> PolygonMesh {
+    pub fn destruct(self) -> PolygonMesh { self.destruct_with(DestructOptions::default()) }
+
+    /// Creates new polygon mesh by destructing `self`, with the winding and the splitting of
+    /// quadrangles specified by `opts`.
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// let positions = vec![
+    ///     vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0)],
+    ///     vec![Point3::new(1.0, 0.0, 0.0), Point3::new(3.0, 1.0, 0.0)],
+    /// ];
+    /// let mesh = StructuredMesh::from_positions(positions);
+    /// let tri_faces = |opts: DestructOptions| -> Vec<[usize; 3]> {
+    ///     let polygon = mesh.clone().destruct_with(opts);
+    ///     polygon.faces().triangle_iter().map(|tri| tri.map(|v| v.pos)).collect()
+    /// };
+    ///
+    /// let forward = DestructOptions {
+    ///     diagonal: Some(Diagonal::Forward),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(tri_faces(forward), vec![[0, 2, 3], [0, 3, 1]]);
+    ///
+    /// let backward = DestructOptions {
+    ///     diagonal: Some(Diagonal::Backward),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(tri_faces(backward), vec![[0, 2, 1], [2, 3, 1]]);
+    ///
+    /// // The diagonal from 2 to 1 is shorter than the one from 0 to 3.
+    /// let shortest = DestructOptions {
+    ///     diagonal: Some(Diagonal::Shortest),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(tri_faces(shortest), tri_faces(backward));
+    ///
+    /// let flipped = DestructOptions {
+    ///     flip_winding: true,
+    ///     diagonal: Some(Diagonal::Forward),
+    /// };
+    /// assert_eq!(tri_faces(flipped), vec![[3, 2, 0], [1, 3, 0]]);
+    ///
+    /// // quadrangles are kept by default
+    /// let polygon = mesh.destruct_with(DestructOptions::default());
+    /// assert_eq!(polygon.faces().quad_faces().len(), 1);
+    /// ```
+    pub fn destruct_with(self, opts: DestructOptions) -> PolygonMesh {
         let StructuredMesh {
             positions,
             uv_division,
@@ -192,7 +238,7 @@ impl StructuredMesh {
         } = self;
         let m = positions.len();
         let n = positions[0].len();
-        let positions = positions.into_iter().flatten().collect();
+        let positions: Vec<Point3> = positions.into_iter().flatten().collect();
         let uv_coords = uv_division
             .map(move |(udiv, vdiv)| {
                 udiv.into_iter()
@@ -205,20 +251,45 @@ impl StructuredMesh {
             .unwrap_or_else(Vec::new);
         let uv = !uv_coords.is_empty();
         let nor = !normals.is_empty();
-        let quad_faces: Vec<_> = itertools::iproduct!(1..m, 1..n)
-            .map(move |(i, j)| {
-                [
-                    StandardVertex::tuple((i - 1) * n + j - 1, uv, nor),
-                    StandardVertex::tuple(i * n + j - 1, uv, nor),
-                    StandardVertex::tuple(i * n + j, uv, nor),
-                    StandardVertex::tuple((i - 1) * n + j, uv, nor),
-                ]
-            })
-            .collect();
-        let faces = Faces {
-            quad_faces,
-            ..Default::default()
+        let quads = itertools::iproduct!(1..m, 1..n).map(move |(i, j)| {
+            [
+                StandardVertex::tuple((i - 1) * n + j - 1, uv, nor),
+                StandardVertex::tuple(i * n + j - 1, uv, nor),
+                StandardVertex::tuple(i * n + j, uv, nor),
+                StandardVertex::tuple((i - 1) * n + j, uv, nor),
+            ]
+        });
+        let mut faces = match opts.diagonal {
+            None => Faces {
+                quad_faces: quads.collect(),
+                ..Default::default()
+            },
+            Some(diagonal) => {
+                let tri_faces = quads
+                    .flat_map(|[a, b, c, d]| {
+                        let forward = match diagonal {
+                            Diagonal::Forward => true,
+                            Diagonal::Backward => false,
+                            Diagonal::Shortest => {
+                                let p = |v: StandardVertex| positions[v.pos];
+                                p(a).distance2(p(c)) <= p(b).distance2(p(d))
+                            }
+                        };
+                        match forward {
+                            true => [[a, b, c], [a, c, d]],
+                            false => [[a, b, d], [b, c, d]],
+                        }
+                    })
+                    .collect();
+                Faces {
+                    tri_faces,
+                    ..Default::default()
+                }
+            }
         };
+        if opts.flip_winding {
+            faces.invert();
+        }
         PolygonMesh {
             attributes: StandardAttributes {
                 positions,
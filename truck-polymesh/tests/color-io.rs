@@ -0,0 +1,81 @@
+use serde_json::Value;
+use truck_polymesh::*;
+
+const RED: [f32; 3] = [1.0, 0.0, 0.0];
+
+fn red_cube() -> (PolygonMesh, Vec<[f32; 3]>) {
+    let positions = (0..8)
+        .map(|i| Point3::new((i & 1) as f64, ((i >> 1) & 1) as f64, ((i >> 2) & 1) as f64))
+        .collect::<Vec<_>>();
+    let faces = Faces::from_iter(&[
+        [0, 2, 3, 1],
+        [4, 5, 7, 6],
+        [0, 1, 5, 4],
+        [2, 6, 7, 3],
+        [0, 4, 6, 2],
+        [1, 3, 7, 5],
+    ]);
+    let mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        faces,
+    );
+    (mesh, vec![RED; 8])
+}
+
+#[test]
+fn stl_color_round_trip() {
+    let (mesh, colors) = red_cube();
+    let attributes = stl::face_colors_from_vertex_colors(&mesh, &colors)
+        .into_iter()
+        .map(stl::pack_color)
+        .collect::<Vec<_>>();
+    let mut bytes = Vec::new();
+    stl::write_with_attributes(&mesh, &attributes, &mut bytes).unwrap();
+    let (read_mesh, attributes) =
+        stl::read_with_attributes(bytes.as_slice(), stl::StlType::Binary).unwrap();
+    let face_colors = attributes
+        .into_iter()
+        .map(|a| stl::unpack_color(a).unwrap())
+        .collect::<Vec<_>>();
+    let vertex_colors = stl::vertex_colors_from_face_colors(&read_mesh, &face_colors);
+    assert_eq!(vertex_colors, vec![RED; read_mesh.positions().len()]);
+}
+
+#[test]
+fn ply_color_round_trip() {
+    let (mesh, colors) = red_cube();
+    let mut bytes = Vec::new();
+    ply::write_with_colors(&mesh, &colors, &mut bytes).unwrap();
+    let (read_mesh, read_colors) = ply::read_with_colors(bytes.as_slice()).unwrap();
+    assert_eq!(read_mesh.positions(), mesh.positions());
+    assert_eq!(read_mesh.faces().len(), 6);
+    assert_eq!(read_colors, colors);
+}
+
+#[test]
+fn gltf_color_round_trip() {
+    let (mesh, colors) = red_cube();
+    let mut bytes = Vec::new();
+    gltf::write_with_colors(&mesh, &colors, &mut bytes).unwrap();
+
+    let u32_at = |i: usize| u32::from_le_bytes(bytes[i..i + 4].try_into().unwrap()) as usize;
+    let json_len = u32_at(12);
+    let json: Value = serde_json::from_slice(&bytes[20..20 + json_len]).unwrap();
+    let bin = &bytes[28 + json_len..];
+    let accessors = json["accessors"].as_array().unwrap();
+    let attributes = &json["meshes"][0]["primitives"][0]["attributes"];
+    let color = &accessors[attributes["COLOR_0"].as_u64().unwrap() as usize];
+    assert_eq!(color["type"], "VEC3");
+    let view = &json["bufferViews"][color["bufferView"].as_u64().unwrap() as usize];
+    let offset = view["byteOffset"].as_u64().unwrap() as usize;
+    let len = view["byteLength"].as_u64().unwrap() as usize;
+    let read_colors = bin[offset..offset + len]
+        .chunks(12)
+        .map(|c| [0, 4, 8].map(|i| f32::from_le_bytes(c[i..i + 4].try_into().unwrap())))
+        .collect::<Vec<_>>();
+    assert_eq!(read_colors.len(), color["count"].as_u64().unwrap() as usize);
+    assert_eq!(read_colors, vec![RED; read_colors.len()]);
+}
@@ -0,0 +1,131 @@
+use serde_json::Value;
+use truck_polymesh::*;
+
+fn cube() -> PolygonMesh {
+    let positions = (0..8)
+        .map(|i| Point3::new((i & 1) as f64, ((i >> 1) & 1) as f64, ((i >> 2) & 1) as f64))
+        .collect();
+    let faces = Faces::from_iter(&[
+        [0, 2, 3, 1],
+        [4, 5, 7, 6],
+        [0, 1, 5, 4],
+        [2, 6, 7, 3],
+        [0, 4, 6, 2],
+        [1, 3, 7, 5],
+    ]);
+    PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        faces,
+    )
+}
+
+/// Returns the JSON chunk and the binary chunk of GLB.
+fn parse_glb(bytes: &[u8]) -> (Value, &[u8]) {
+    let u32_at = |i: usize| u32::from_le_bytes(bytes[i..i + 4].try_into().unwrap()) as usize;
+    assert_eq!(&bytes[0..4], b"glTF");
+    assert_eq!(u32_at(4), 2);
+    assert_eq!(u32_at(8), bytes.len());
+    let json_len = u32_at(12);
+    assert_eq!(&bytes[16..20], b"JSON");
+    let json = serde_json::from_slice(&bytes[20..20 + json_len]).unwrap();
+    let bin = 20 + json_len;
+    assert_eq!(&bytes[bin + 4..bin + 8], b"BIN\0");
+    (json, &bytes[bin + 8..bin + 8 + u32_at(bin)])
+}
+
+#[test]
+fn write_three_cubes() {
+    let cube = cube();
+    let nodes = (0..3)
+        .map(|i| {
+            let matrix = Matrix4::from_translation(Vector3::new(2.0 * i as f64, 0.0, 0.0));
+            (format!("cube \"{i}\""), matrix, &cube)
+        })
+        .collect::<Vec<_>>();
+    let mut bytes = Vec::new();
+    gltf::write_scene(&nodes, &mut bytes).unwrap();
+    let (json, bin) = parse_glb(&bytes);
+
+    assert_eq!(json["scenes"][0]["nodes"].as_array().unwrap().len(), 3);
+    let gltf_nodes = json["nodes"].as_array().unwrap();
+    assert_eq!(gltf_nodes.len(), 3);
+    gltf_nodes.iter().enumerate().for_each(|(i, node)| {
+        assert_eq!(node["name"], format!("cube \"{i}\""));
+        assert_eq!(node["matrix"][12], 2.0 * i as f64);
+        // The same mesh is shared.
+        assert_eq!(node["mesh"], 0);
+    });
+    assert_eq!(json["meshes"].as_array().unwrap().len(), 1);
+
+    let accessors = json["accessors"].as_array().unwrap();
+    let views = json["bufferViews"].as_array().unwrap();
+    let primitive = &json["meshes"][0]["primitives"][0];
+    let position = &accessors[primitive["attributes"]["POSITION"].as_u64().unwrap() as usize];
+    assert_eq!(position["count"], 8);
+    let max = position["max"].as_array().unwrap();
+    assert!(max.iter().all(|x| x.as_f64() == Some(1.0)));
+    let indices = &accessors[primitive["indices"].as_u64().unwrap() as usize];
+    assert_eq!(indices["count"], 36);
+    // The views are laid out contiguously in the buffer.
+    let mut offset = 0;
+    views.iter().for_each(|view| {
+        assert_eq!(view["byteOffset"], offset);
+        offset += view["byteLength"].as_u64().unwrap();
+    });
+    assert_eq!(json["buffers"][0]["byteLength"], offset);
+    assert!(bin.len() as u64 >= offset);
+}
+
+#[test]
+fn write_attributes() {
+    let normals = vec![
+        -Vector3::unit_z(),
+        Vector3::unit_z(),
+        -Vector3::unit_y(),
+        Vector3::unit_y(),
+        -Vector3::unit_x(),
+        Vector3::unit_x(),
+    ];
+    let faces = cube()
+        .face_iter()
+        .enumerate()
+        .map(|(i, face)| {
+            face.iter()
+                .map(|v| (v.pos, None, Some(i)))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let cube = PolygonMesh::new(
+        StandardAttributes {
+            positions: cube().positions().clone(),
+            normals,
+            ..Default::default()
+        },
+        Faces::from_iter(&faces),
+    );
+    let mut bytes = Vec::new();
+    gltf::write(&cube, &mut bytes).unwrap();
+    let (json, _) = parse_glb(&bytes);
+    let attributes = &json["meshes"][0]["primitives"][0]["attributes"];
+    assert!(attributes["NORMAL"].is_u64());
+    assert!(attributes["TEXCOORD_0"].is_null());
+    // Each face has its own normal.
+    let position = &json["accessors"][attributes["POSITION"].as_u64().unwrap() as usize];
+    assert_eq!(position["count"], 24);
+}
+
+#[test]
+fn reject_non_finite_values() {
+    let mut cube = cube();
+    let matrix = Matrix4::from_scale(f64::NAN);
+    let res = gltf::write_scene(&[("nan".to_string(), matrix, &cube)], &mut Vec::new());
+    assert!(res.is_err());
+
+    cube.positions_mut()[0] = Point3::new(f64::MAX, 0.0, 0.0);
+    assert!(gltf::write(&cube, &mut Vec::new()).is_err());
+    cube.positions_mut()[0] = Point3::new(f64::INFINITY, 0.0, 0.0);
+    assert!(gltf::write(&cube, &mut Vec::new()).is_err());
+}
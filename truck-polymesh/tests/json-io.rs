@@ -0,0 +1,104 @@
+#![cfg(feature = "serde_json")]
+
+use serde_json::json;
+use truck_polymesh::*;
+
+fn mesh() -> PolygonMesh {
+    let positions = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(1.0, 1.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.5, 2.0, 0.0),
+    ];
+    let uv_coords = positions.iter().map(|p| Vector2::new(p.x, p.y)).collect();
+    let normals = vec![Vector3::unit_z()];
+    let faces = Faces::from_iter([
+        vec![
+            (0, Some(0), Some(0)),
+            (1, Some(1), Some(0)),
+            (2, Some(2), Some(0)),
+        ],
+        vec![
+            (0, Some(0), Some(0)),
+            (2, Some(2), Some(0)),
+            (4, Some(4), Some(0)),
+            (3, Some(3), Some(0)),
+        ],
+        vec![
+            (0, None, None),
+            (1, None, None),
+            (2, None, None),
+            (4, None, None),
+            (3, None, None),
+        ],
+    ]);
+    PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            uv_coords,
+            normals,
+        },
+        faces,
+    )
+}
+
+#[test]
+fn json_round_trip() {
+    let mesh = mesh();
+    let value = mesh.to_json_value().unwrap();
+    assert_eq!(value["version"], PolygonMesh::FORMAT_VERSION);
+    assert_eq!(PolygonMesh::from_json_value(value).unwrap(), mesh);
+    let string = serde_json::to_string(&mesh).unwrap();
+    assert_eq!(serde_json::from_str::<PolygonMesh>(&string).unwrap(), mesh);
+}
+
+#[test]
+fn read_unversioned_json() {
+    // the form written before the versioning
+    let value = json!({
+        "attributes": {
+            "positions": [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [1.0, 1.0, 0.0], [0.0, 1.0, 0.0]],
+            "uv_coords": [],
+            "normals": [[0.0, 0.0, 1.0]],
+        },
+        "faces": {
+            "tri_faces": [],
+            "quad_faces": [[[0, null, 0], [1, null, 0], [2, null, 0], [3, null, 0]]],
+            "other_faces": [],
+        },
+    });
+    let mesh = PolygonMesh::from_json_value(value).unwrap();
+    assert_eq!(mesh.positions().len(), 4);
+    assert_eq!(mesh.normals(), &vec![Vector3::unit_z()]);
+    assert_eq!(mesh.quad_faces().len(), 1);
+    assert_eq!(mesh.quad_faces()[0][2].nor, Some(0));
+
+    // the missing arrays are empty
+    let value = json!({
+        "attributes": { "positions": [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]] },
+        "faces": { "tri_faces": [[[0, null, null], [1, null, null], [2, null, null]]] },
+    });
+    let mesh = PolygonMesh::from_json_value(value).unwrap();
+    assert!(mesh.uv_coords().is_empty());
+    assert!(mesh.normals().is_empty());
+    assert_eq!(mesh.faces().len(), 1);
+    let value = json!({ "version": 1, "attributes": { "positions": [] } });
+    assert!(PolygonMesh::from_json_value(value)
+        .unwrap()
+        .faces()
+        .is_empty());
+}
+
+#[test]
+fn reject_invalid_json() {
+    let mut value = mesh().to_json_value().unwrap();
+    value["version"] = json!(PolygonMesh::FORMAT_VERSION + 1);
+    assert!(PolygonMesh::from_json_value(value).is_err());
+
+    let value = json!({
+        "attributes": { "positions": [[0.0, 0.0, 0.0]] },
+        "faces": { "tri_faces": [[[0, null, null], [1, null, null], [2, null, null]]] },
+    });
+    assert!(PolygonMesh::from_json_value(value).is_err());
+}
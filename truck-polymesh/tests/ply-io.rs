@@ -0,0 +1,73 @@
+use truck_polymesh::*;
+
+#[test]
+fn read_custom_property_order() {
+    let ply = b"ply
+format ascii 1.0
+comment scanner output
+element vertex 4
+property float z
+property float y
+property float x
+property float confidence
+property float nz
+property float t
+property float ny
+property float s
+property float nx
+property float intensity
+element face 2
+property uchar flags
+property list uchar int vertex_indices
+property list uchar float texcoord
+element edge 1
+property int vertex1
+property int vertex2
+end_header
+0 0 0 0.9 1 0 0 0 0 128
+0 0 1 0.8 1 0 0 1 0 64
+0 1 1 0.7 1 1 0 1 0 32
+0 1 0 0.6 1 1 0 0 0 16
+1 3 0 1 2 6 0 0 1 0 1 1
+0 3 0 2 3 0
+0 2
+";
+    let mesh = ply::read(&ply[..]).unwrap();
+    assert_eq!(
+        mesh.positions(),
+        &[
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(1.0, 0.0, 0.0),
+            Point3::new(1.0, 1.0, 0.0),
+            Point3::new(0.0, 1.0, 0.0),
+        ]
+    );
+    assert!(mesh.normals().iter().all(|n| *n == Vector3::unit_z()));
+    assert_eq!(mesh.uv_coords()[2], Vector2::new(1.0, 1.0));
+    assert_eq!(mesh.tri_faces().len(), 2);
+    let face = mesh.tri_faces()[1];
+    assert_eq!(face.map(|v| v.pos), [0, 2, 3]);
+    assert_eq!(face[1].uv, Some(2));
+    assert_eq!(face[1].nor, Some(2));
+}
+
+#[test]
+fn read_invalid_ply() {
+    let binary = b"ply\nformat binary_little_endian 1.0\nelement vertex 0\nend_header\n";
+    assert!(ply::read(&binary[..]).is_err());
+    let truncated = b"ply\nformat ascii 1.0\nelement vertex 2\nproperty float x\nend_header\n0\n";
+    assert!(ply::read(&truncated[..]).is_err());
+    let out_of_range = b"ply
+format ascii 1.0
+element vertex 1
+property float x
+property float y
+property float z
+element face 1
+property list uchar int vertex_index
+end_header
+0 0 0
+3 0 1 2
+";
+    assert!(ply::read(&out_of_range[..]).is_err());
+}
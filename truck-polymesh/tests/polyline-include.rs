@@ -0,0 +1,67 @@
+use itertools::Itertools;
+use std::f64::consts::PI;
+use truck_polymesh::*;
+
+/// the direction of the `k`-th ray of the inclusion test from `c`, and its normal
+fn ray_frame(c: Point2, k: usize) -> (Vector2, Vector2) {
+    const ROTATION: f64 = 0.618_033_988_749_894_9;
+    let t = 2.0 * PI * (HashGen::hash1(c) + ROTATION * k as f64);
+    let r = Vector2::new(f64::cos(t), f64::sin(t));
+    (r, Vector2::new(-r.y, r.x))
+}
+
+fn sample_points() -> impl Iterator<Item = Point2> {
+    (0..100).map(|i| Point2::new(0.1 * i as f64 + 0.3, 0.4 - 0.03 * i as f64))
+}
+
+#[test]
+fn include_with_edge_along_ray() {
+    sample_points().for_each(|c| {
+        let (r, n) = ray_frame(c, 0);
+        // The triangles are outside of `c`, and have the edges on the ray.
+        [n, -n].into_iter().for_each(|n| {
+            let triangle = PolylineCurve(vec![c + r, c + 2.0 * r, c + 1.5 * r + n]);
+            assert!(!triangle.include(c), "{c:?}");
+            assert!(!polyline_curve::include([&triangle], c), "{c:?}");
+        });
+        // The quadrangle includes `c`, and has the edge on the ray.
+        let quadrangle = PolylineCurve(vec![c - r - n, c + r, c + 2.0 * r, c - r + n]);
+        assert!(quadrangle.include(c), "{c:?}");
+        assert!(polyline_curve::include([&quadrangle], c), "{c:?}");
+    });
+}
+
+#[test]
+fn include_with_degenerate_edges() {
+    let square = PolylineCurve(vec![
+        Point2::new(0.0, 0.0),
+        Point2::new(1.0, 0.0),
+        Point2::new(1.0, 0.0),
+        Point2::new(1.0, 1.0),
+        Point2::new(0.0, 1.0),
+        Point2::new(0.0, 1.0),
+    ]);
+    assert!(square.include(Point2::new(0.5, 0.5)));
+    assert!(square.include(Point2::new(0.9, 0.1)));
+    assert!(!square.include(Point2::new(1.5, 0.5)));
+    assert!(!square.include(Point2::new(-0.5, 1.0)));
+}
+
+#[test]
+fn include_with_all_rays_along_edges() {
+    sample_points().for_each(|c| {
+        let square = [
+            c + Vector2::new(-1.0, -1.0),
+            c + Vector2::new(1.0, -1.0),
+            c + Vector2::new(1.0, 1.0),
+            c + Vector2::new(-1.0, 1.0),
+        ];
+        // Each ray is parallel to one of the short segments far from `c`.
+        let segments = (0..16).map(|k| {
+            let (r, n) = ray_frame(c, k);
+            (c + 10.0 * n, c + 10.0 * n + 0.01 * r)
+        });
+        let edges = square.into_iter().circular_tuple_windows().chain(segments);
+        assert_eq!(polyline_curve::crossing_counter(edges, c), Some(0), "{c:?}");
+    });
+}
@@ -85,3 +85,103 @@ fn through_polymesh() {
         assert!(f32::abs(face0.normal[2] - face1.normal[2]) < 5.0e-4);
     }
 }
+
+#[test]
+fn stl_attributes_test() {
+    let mesh = vec![
+        StlFace {
+            normal: [0.0, 0.0, 1.0],
+            vertices: [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+        },
+        StlFace {
+            normal: [0.0, 1.0, 1.0],
+            vertices: [[0.0, 0.0, 0.0], [0.0, 0.0, 1.0], [1.0, 0.0, 0.0]],
+        },
+        StlFace {
+            normal: [1.0, 0.0, 1.0],
+            vertices: [[0.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+        },
+    ];
+    let colors = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]];
+    let attributes = colors.map(stl::pack_color);
+    let mut binary = Vec::new();
+    stl::write_with_attributes(mesh.iter().cloned(), &attributes, &mut binary).unwrap();
+    let mut reader = StlReader::<&[u8]>::new(&binary, StlType::Automatic).unwrap();
+    let read = std::iter::from_fn(|| reader.next_with_attribute())
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(read.len(), 3);
+    mesh.iter()
+        .zip(&read)
+        .for_each(|(face, (read, _))| assert_eq!(face, read));
+    assert_eq!(stl::unpack_color(read[0].1), Some(colors[0]));
+    assert_eq!(stl::unpack_color(read[1].1), Some(colors[1]));
+    assert_eq!(read[2].1, 0);
+    assert_eq!(stl::unpack_color(read[2].1), None);
+
+    let (polymesh, read_attributes) =
+        stl::read_with_attributes(binary.as_slice(), StlType::Binary).unwrap();
+    assert_eq!(polymesh.tri_faces().len(), 3);
+    assert_eq!(read_attributes, vec![attributes[0], attributes[1], 0]);
+
+    let mut ascii = Vec::new();
+    stl::write(mesh.iter().cloned(), &mut ascii, StlType::Ascii).unwrap();
+    let (_, read_attributes) = stl::read_with_attributes(ascii.as_slice(), StlType::Ascii).unwrap();
+    assert_eq!(read_attributes, vec![0; 3]);
+}
+
+#[test]
+fn quadrangles_through_stl() {
+    // a kite, whose diagonal from the first vertex is the longer one, and a pentagon
+    let positions = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(2.0, -0.5, 0.0),
+        Point3::new(4.0, 0.0, 0.0),
+        Point3::new(2.0, 0.5, 0.0),
+        Point3::new(5.0, 0.0, 0.0),
+        Point3::new(7.0, 0.0, 0.0),
+        Point3::new(8.0, 1.5, 0.0),
+        Point3::new(6.0, 3.0, 0.0),
+        Point3::new(4.0, 1.5, 0.0),
+    ];
+    let mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        Faces::from_iter([&[0, 1, 2, 3][..], &[4, 5, 6, 7, 8]]),
+    );
+    let area = |mesh: &PolygonMesh| {
+        let p = mesh.positions();
+        mesh.faces()
+            .triangle_iter()
+            .map(|[a, b, c]| (p[b.pos] - p[a.pos]).cross(p[c.pos] - p[a.pos]).z / 2.0)
+            .sum::<f64>()
+    };
+
+    let faces = IntoStlIterator::into_iter(&mesh);
+    assert_eq!(faces.len(), 5);
+    let mut bytes = Vec::new();
+    stl::write(&mesh, &mut bytes, StlType::Binary).unwrap();
+    let read = stl::read(bytes.as_slice(), StlType::Binary).unwrap();
+    assert_eq!(read.tri_faces().len(), 5);
+    // the positions are quantized in reading
+    assert!(f64::abs(area(&read) - area(&mesh)) < 1.0e-4);
+    // the kite is split along the shorter diagonal, between (2, -0.5) and (2, 0.5)
+    let p = read.positions();
+    let kite = read.tri_faces()[..2]
+        .iter()
+        .flat_map(|face| face.map(|v| p[v.pos]))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        kite.iter().filter(|p| f64::abs(p.x - 2.0) < 1.0e-4).count(),
+        4
+    );
+    assert!(read
+        .tri_faces()
+        .iter()
+        .all(|face| (p[face[1].pos] - p[face[0].pos])
+            .cross(p[face[2].pos] - p[face[0].pos])
+            .z
+            > 0.0));
+}
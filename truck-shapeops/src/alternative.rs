@@ -130,6 +130,13 @@ where
         range: ((f64, f64), (f64, f64)),
         tol: f64
     );
+    derive_method!(
+        parameter_division_with_options,
+        (Vec<f64>, Vec<f64>),
+        range: ((f64, f64), (f64, f64)),
+        tol: f64,
+        options: algo::surface::DivisionOptions
+    );
 }
 
 impl<D: SPDimension, T, U> SearchParameter<D> for Alternative<T, U>
@@ -10,6 +10,7 @@ use ruststep::{
     Holder,
 };
 use serde::{Deserialize, Serialize};
+use std::io::Read;
 use std::result::Result;
 use std::{collections::HashMap, f64::consts::PI};
 use truck_assembly::assy::*;
@@ -779,6 +780,46 @@ impl Table {
     }
 }
 
+/// Extracts all `CARTESIAN_POINT`s in a STEP file, in the order of the entity ids.
+///
+/// The topology is not reconstructed, so this is useful for sanity-checking files before the full import.
+pub fn extract_points<R: Read>(reader: R) -> Result<Vec<Point3>, StepConvertingError> {
+    let table = read_table(reader)?;
+    sorted_ids(&table.cartesian_point)
+        .map(|idx| {
+            let point = EntityTable::<CartesianPointHolder>::get_owned(&table, idx)?;
+            Ok(Point3::from(&point))
+        })
+        .collect()
+}
+
+/// Extracts all `POLYLINE`s in a STEP file, in the order of the entity ids.
+///
+/// The topology is not reconstructed, so this is useful for sanity-checking files before the full import.
+pub fn extract_polylines<R: Read>(
+    reader: R,
+) -> Result<Vec<PolylineCurve<Point3>>, StepConvertingError> {
+    let table = read_table(reader)?;
+    sorted_ids(&table.polyline)
+        .map(|idx| {
+            let polyline = EntityTable::<PolylineHolder>::get_owned(&table, idx)?;
+            Ok(PolylineCurve::from(&polyline))
+        })
+        .collect()
+}
+
+fn read_table<R: Read>(mut reader: R) -> Result<Table, StepConvertingError> {
+    let mut step_string = String::new();
+    reader.read_to_string(&mut step_string)?;
+    Table::from_step(&step_string).ok_or_else(|| "failed to parse STEP data".into())
+}
+
+fn sorted_ids<T>(map: &HashMap<u64, T>) -> impl Iterator<Item = u64> {
+    let mut ids = map.keys().copied().collect::<Vec<_>>();
+    ids.sort();
+    ids.into_iter()
+}
+
 impl<'a> FromIterator<&'a EntityInstance> for Table {
     fn from_iter<I: IntoIterator<Item = &'a EntityInstance>>(iter: I) -> Table {
         let mut res = Table::default();
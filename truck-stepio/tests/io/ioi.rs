@@ -46,3 +46,26 @@ fn ioi() {
         });
     });
 }
+
+#[test]
+fn open_shell_ioi() {
+    use truck_modeling::{builder, Shell};
+    let v = builder::vertex(Point3::new(0.0, 0.0, 0.0));
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let face = builder::tsweep(&e, Vector3::unit_y());
+    let cshell = Shell::from(vec![face]).compress();
+    let step_string =
+        CompleteStepDisplay::new(StepModel::from(&cshell), Default::default()).to_string();
+    assert!(step_string.contains("SHELL_BASED_SURFACE_MODEL"));
+    assert!(step_string.contains("OPEN_SHELL"));
+    assert!(!step_string.contains("CLOSED_SHELL"));
+    assert!(!step_string.contains("MANIFOLD_SOLID_BREP"));
+
+    let table = Table::from_step(&step_string).unwrap();
+    assert_eq!(table.shell_based_surface_model.len(), 1);
+    let step_shell = table.shell.values().next().unwrap();
+    let cshell = table.to_compressed_shell(step_shell).unwrap();
+    assert_eq!(cshell.faces.len(), 1);
+    let poly = cshell.triangulation(0.01).to_polygon();
+    assert_eq!(poly.shell_condition(), ShellCondition::Oriented);
+}
@@ -274,3 +274,38 @@ fn oi() {
         ],
     ));
 }
+
+#[test]
+fn extract_points_and_polylines() {
+    use truck_modeling::{primitive, Curve, Solid, Surface};
+    let bdd = BoundingBox::from_iter([Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 2.0, 3.0)]);
+    let cube: Solid = primitive::cuboid(bdd);
+    let compressed: truck_topology::compress::CompressedSolid<Point3, Curve, Surface> =
+        cube.compress();
+    let step_string =
+        CompleteStepDisplay::new(StepModel::from(&compressed), Default::default()).to_string();
+    let points = extract_points(step_string.as_bytes()).unwrap();
+    assert_eq!(
+        points.len(),
+        step_string.matches("CARTESIAN_POINT(").count()
+    );
+    cube.vertex_iter()
+        .for_each(|v| assert!(points.contains(&v.point())));
+    assert!(extract_polylines(step_string.as_bytes())
+        .unwrap()
+        .is_empty());
+
+    let polyline = PolylineCurve(vec![
+        Point3::new(0.0, 1.0, 2.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(2.0, 3.0, 1.0),
+    ]);
+    let step_string =
+        CompleteStepDisplay::new(StepDisplay::new(&polyline, 1), Default::default()).to_string();
+    assert_eq!(extract_points(step_string.as_bytes()).unwrap(), polyline.0);
+    assert_eq!(
+        extract_polylines(step_string.as_bytes()).unwrap(),
+        vec![polyline]
+    );
+    assert!(extract_points("not a step file".as_bytes()).is_err());
+}
@@ -96,6 +96,60 @@ pub struct CompressedSolid<P, C, S> {
     pub boundaries: Vec<CompressedShell<P, C, S>>,
 }
 
+#[cfg(feature = "bincode")]
+impl<P: Serialize, C: Serialize, S: Serialize> CompressedShell<P, C, S> {
+    /// Serializes the shell into the compact binary format by `bincode`. Requires the feature
+    /// `bincode`.
+    ///
+    /// The binary is much smaller than JSON and fast to parse, so it is suitable for caching
+    /// the tessellated shells. Note that the format depends on the types of the geometries.
+    /// # Examples
+    /// ```
+    /// use truck_topology::{compress::*, *};
+    /// let v = Vertex::news([0, 1, 2]);
+    /// let wire = wire![
+    ///     Edge::new(&v[0], &v[1], 3),
+    ///     Edge::new(&v[1], &v[2], 4),
+    ///     Edge::new(&v[2], &v[0], 5),
+    /// ];
+    /// let shell: Shell<i32, i32, i32> = vec![Face::new(vec![wire], 6)].into();
+    /// let compressed = shell.compress();
+    /// let bytes = compressed.to_bincode().unwrap();
+    /// assert_eq!(CompressedShell::from_bincode(&bytes).unwrap(), compressed);
+    /// ```
+    pub fn to_bincode(&self) -> bincode::Result<Vec<u8>> { bincode::serialize(self) }
+}
+
+#[cfg(feature = "bincode")]
+impl<P, C, S> CompressedShell<P, C, S>
+where
+    P: serde::de::DeserializeOwned,
+    C: serde::de::DeserializeOwned,
+    S: serde::de::DeserializeOwned,
+{
+    /// Deserializes the shell from the binary by [`CompressedShell::to_bincode`].
+    pub fn from_bincode(bytes: &[u8]) -> bincode::Result<Self> { bincode::deserialize(bytes) }
+}
+
+#[cfg(feature = "bincode")]
+impl<P: Serialize, C: Serialize, S: Serialize> CompressedSolid<P, C, S> {
+    /// Serializes the solid into the compact binary format by `bincode`.
+    ///
+    /// cf. [`CompressedShell::to_bincode`]
+    pub fn to_bincode(&self) -> bincode::Result<Vec<u8>> { bincode::serialize(self) }
+}
+
+#[cfg(feature = "bincode")]
+impl<P, C, S> CompressedSolid<P, C, S>
+where
+    P: serde::de::DeserializeOwned,
+    C: serde::de::DeserializeOwned,
+    S: serde::de::DeserializeOwned,
+{
+    /// Deserializes the solid from the binary by [`CompressedSolid::to_bincode`].
+    pub fn from_bincode(bytes: &[u8]) -> bincode::Result<Self> { bincode::deserialize(bytes) }
+}
+
 struct CompressDirector<P, C> {
     vmap: HashMap<VertexID<P>, (usize, P)>,
     emap: HashMap<EdgeID<C>, (usize, CompressedEdge<C>)>,
@@ -1101,6 +1101,70 @@ where
     }
 }
 
+impl<C, S> Face<Point3, C, S>
+where
+    C: BoundedCurve<Point = Point3> + ParameterDivision1D<Point = Point3>,
+    S: ParametricSurface3D + ParameterDivision2D + SearchParameter<D2, Point = Point3>,
+{
+    /// Returns a bounding box including the face, without tessellation.
+    ///
+    /// The boundary edges are sampled by `parameter_division` with `tol`, and the surface is
+    /// bounded by [`algo::surface::bounding_box`] over the parameter box of the boundary points.
+    /// Hence the domain of the face is assumed to be inside the parameter box of its boundaries,
+    /// which holds for the faces trimmed by their boundaries in the parameter space. If the face
+    /// has no boundaries or the parameter of a boundary point is not found, the whole parameter
+    /// range of the surface is used, and only the boundaries are bounded if the range is
+    /// unbounded. The box is conservative but not the tightest one.
+    ///
+    /// # Panics
+    /// `tol` must be more than `TOLERANCE`.
+    pub fn bounding_box(&self, tol: f64) -> BoundingBox<Point3> {
+        let points = self
+            .boundaries
+            .iter()
+            .flatten()
+            .flat_map(|edge| {
+                let curve = &*edge.curve.lock();
+                curve.parameter_division(curve.range_tuple(), tol).1
+            })
+            .collect::<Vec<_>>();
+        let mut bdb = points.iter().collect::<BoundingBox<Point3>>();
+        if !bdb.is_empty() {
+            let (min, max) = (bdb.min(), bdb.max());
+            let margin = Vector3::new(tol, tol, tol);
+            bdb = BoundingBox::from_iter([min - margin, max + margin]);
+        }
+
+        let surface = &*self.surface.lock();
+        let mut hint = None;
+        let uvs = points
+            .iter()
+            .map(|p| {
+                hint = Some(surface.search_parameter(*p, hint, 100)?);
+                hint
+            })
+            .collect::<Option<Vec<_>>>();
+        let range = match uvs {
+            Some(uvs) if !uvs.is_empty() => {
+                let uv_box = uvs
+                    .into_iter()
+                    .map(|(u, v)| Point2::new(u, v))
+                    .collect::<BoundingBox<Point2>>();
+                let (min, max) = (uv_box.min(), uv_box.max());
+                Some(((min.x, max.x), (min.y, max.y)))
+            }
+            _ => match surface.try_range_tuple() {
+                (Some(urange), Some(vrange)) => Some((urange, vrange)),
+                _ => None,
+            },
+        };
+        if let Some(range) = range {
+            bdb += algo::surface::bounding_box(surface, range, tol);
+        }
+        bdb
+    }
+}
+
 impl<P, C, S> Clone for Face<P, C, S> {
     #[inline(always)]
     fn clone(&self) -> Face<P, C, S> {
@@ -85,7 +85,7 @@ use parking_lot::Mutex;
 use std::collections::VecDeque;
 use std::fmt::{Debug, Formatter};
 use std::hash::{Hash, Hasher};
-use truck_base::{id::ID, tolerance::*};
+use truck_base::{bounding_box::BoundingBox, cgmath64::*, id::ID, tolerance::*};
 use truck_geotrait::*;
 
 #[cfg(feature = "rclite")]
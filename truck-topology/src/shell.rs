@@ -131,6 +131,49 @@ impl<P, C, S> Shell<P, C, S> {
         self.edge_iter().collect::<Boundaries<C>>().condition()
     }
 
+    /// Returns the edges used by exactly one face of the shell, i.e. the topological boundary.
+    ///
+    /// The edges are returned in the order of [`Shell::edge_iter`], with the orientations
+    /// in the faces which use them. Unlike [`Shell::extract_boundaries`], the edges are not
+    /// connected into wires, so this works for any shell, including non-manifold ones.
+    /// # Examples
+    /// ```
+    /// use truck_topology::*;
+    /// // a tetrahedron
+    /// let v = Vertex::news(&[(); 4]);
+    /// let edge = [
+    ///     Edge::new(&v[0], &v[1], ()),
+    ///     Edge::new(&v[0], &v[2], ()),
+    ///     Edge::new(&v[0], &v[3], ()),
+    ///     Edge::new(&v[1], &v[2], ()),
+    ///     Edge::new(&v[1], &v[3], ()),
+    ///     Edge::new(&v[2], &v[3], ()),
+    /// ];
+    /// let wire = vec![
+    ///     wire![&edge[1], &edge[3].inverse(), &edge[0].inverse()],
+    ///     wire![&edge[0], &edge[4], &edge[2].inverse()],
+    ///     wire![&edge[3], &edge[5], &edge[4].inverse()],
+    ///     wire![&edge[2], &edge[5].inverse(), &edge[1].inverse()],
+    /// ];
+    /// let mut shell: Shell<_, _, _> = wire.into_iter().map(|w| Face::new(vec![w], ())).collect();
+    /// assert!(shell.boundary_edges().is_empty());
+    ///
+    /// // Removing a face, its edges are on the boundary.
+    /// shell.pop();
+    /// assert_eq!(
+    ///     shell.boundary_edges(),
+    ///     vec![edge[1].clone(), edge[2].inverse(), edge[5].clone()],
+    /// );
+    /// ```
+    pub fn boundary_edges(&self) -> Vec<Edge<P, C>> {
+        let mut counter = HashMap::<EdgeID<C>, usize>::default();
+        self.edge_iter()
+            .for_each(|edge| *counter.entry(edge.id()).or_default() += 1);
+        self.edge_iter()
+            .filter(|edge| counter[&edge.id()] == 1)
+            .collect()
+    }
+
     /// Returns a vector of all boundaries as wires.
     /// # Examples
     /// ```
@@ -822,6 +865,74 @@ impl<P, C, S> Shell<P, C, S> {
             format,
         }
     }
+
+    /// Returns a concise report of the shell for debugging.
+    ///
+    /// The first line has the numbers of vertices, edges and faces, the [`ShellCondition`],
+    /// and the number of boundary edges. Each following line has the surface type of a face, which
+    /// is given by `surface_type`, e.g. the name of the variant of an enum.
+    /// # Examples
+    /// ```
+    /// use truck_topology::*;
+    /// enum Surface {
+    ///     Plane(usize),
+    ///     Sphere { radius: f64 },
+    /// }
+    /// let v = Vertex::news(&[(); 3]);
+    /// let wire: Wire<_, _> = wire![
+    ///     Edge::new(&v[0], &v[1], ()),
+    ///     Edge::new(&v[1], &v[2], ()),
+    ///     Edge::new(&v[2], &v[0], ()),
+    /// ];
+    /// let shell = shell![
+    ///     Face::new(vec![wire.clone()], Surface::Plane(0)),
+    ///     Face::new(vec![wire.inverse()], Surface::Sphere { radius: 1.0 }),
+    /// ];
+    /// let summary = shell.summary(|surface| match surface {
+    ///     Surface::Plane(_) => "Plane",
+    ///     Surface::Sphere { .. } => "Sphere",
+    /// });
+    /// assert_eq!(
+    ///     summary,
+    ///     "Shell: 3 vertices, 3 edges, 2 faces, closed, 0 boundary edges\n  \
+    ///      face 0: Plane\n  \
+    ///      face 1: Sphere\n",
+    /// );
+    /// ```
+    pub fn summary(&self, surface_type: impl Fn(&S) -> &str) -> String {
+        let vertices = self.vertex_iter().map(|v| v.id()).collect::<HashSet<_>>();
+        let edges = self.edge_iter().map(|e| e.id()).collect::<HashSet<_>>();
+        let condition = format!("{:?}", self.shell_condition()).to_lowercase();
+        let mut res = format!(
+            "Shell: {} vertices, {} edges, {} faces, {condition}, {} boundary edges\n",
+            vertices.len(),
+            edges.len(),
+            self.len(),
+            self.boundary_edges().len(),
+        );
+        self.face_iter().enumerate().for_each(|(i, face)| {
+            let surface = face.surface.lock();
+            res += &format!("  face {i}: {}\n", surface_type(&surface));
+        });
+        res
+    }
+}
+
+impl<C, S> Shell<Point3, C, S>
+where
+    C: BoundedCurve<Point = Point3> + ParameterDivision1D<Point = Point3>,
+    S: ParametricSurface3D + ParameterDivision2D + SearchParameter<D2, Point = Point3>,
+{
+    /// Returns a bounding box including the shell, without tessellation.
+    ///
+    /// The union of [`Face::bounding_box`] of all faces, for framing the shape before meshing.
+    ///
+    /// # Panics
+    /// `tol` must be more than `TOLERANCE`.
+    pub fn bounding_box(&self, tol: f64) -> BoundingBox<Point3> {
+        self.face_iter()
+            .fold(BoundingBox::new(), |bdb, face| bdb + face.bounding_box(tol))
+    }
 }
 
 impl<P, C, S> Clone for Shell<P, C, S> {
@@ -213,6 +213,18 @@ impl<P, C, S> Solid<P, C, S> {
             format,
         }
     }
+
+    /// Returns a concise report of the solid for debugging: the number of boundary shells and
+    /// the indented [`Shell::summary`] of each shell by `surface_type`.
+    pub fn summary(&self, surface_type: impl Fn(&S) -> &str) -> String {
+        let mut res = format!("Solid: {} shells\n", self.boundaries.len());
+        self.boundaries.iter().for_each(|shell| {
+            shell.summary(&surface_type).lines().for_each(|line| {
+                res += &format!("  {line}\n");
+            })
+        });
+        res
+    }
 }
 
 impl<P: Clone, C: Clone, S: Clone> Solid<P, C, Option<S>> {
@@ -318,3 +330,16 @@ pub(super) fn cube() -> Solid<(), (), ()> {
 
 #[test]
 fn cube_test() { cube(); }
+
+#[test]
+fn cube_summary() {
+    let summary = cube().summary(|_| "Plane");
+    let mut lines = summary.lines();
+    assert_eq!(lines.next(), Some("Solid: 1 shells"));
+    assert_eq!(
+        lines.next(),
+        Some("  Shell: 8 vertices, 12 edges, 6 faces, closed, 0 boundary edges"),
+    );
+    assert_eq!(lines.next(), Some("    face 0: Plane"));
+    assert_eq!(lines.count(), 5);
+}
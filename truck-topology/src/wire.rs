@@ -53,6 +53,30 @@ impl<P, C> Wire<P, C> {
     }
 
     /// Returns an iterator over the vertices.
+    /// The vertices are the front vertices of the edges in the order of traversal,
+    /// which respect the orientations of the edges.
+    /// The back vertex of an edge is inserted where the next edge does not start from it,
+    /// and the back vertex of the last edge is also returned if the wire is not cyclic.
+    /// # Examples
+    /// ```
+    /// use truck_topology::*;
+    /// let v = Vertex::news(&[(); 4]);
+    /// // a square, whose last edge is inverted
+    /// let wire = Wire::from(vec![
+    ///     Edge::new(&v[0], &v[1], ()),
+    ///     Edge::new(&v[1], &v[2], ()),
+    ///     Edge::new(&v[2], &v[3], ()),
+    ///     Edge::new(&v[0], &v[3], ()).inverse(),
+    /// ]);
+    /// assert!(wire.is_closed());
+    /// assert_eq!(wire.vertex_iter().collect::<Vec<_>>(), v);
+    ///
+    /// // an open wire
+    /// let wire = Wire::from(vec![Edge::new(&v[1], &v[0], ()), Edge::new(&v[0], &v[3], ())]);
+    /// assert!(!wire.is_closed());
+    /// let vertices = wire.vertex_iter().collect::<Vec<_>>();
+    /// assert_eq!(vertices, vec![v[1].clone(), v[0].clone(), v[3].clone()]);
+    /// ```
     #[inline(always)]
     pub fn vertex_iter(&self) -> VertexIter<'_, P, C> {
         VertexIter {